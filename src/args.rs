@@ -4,7 +4,7 @@
 //! Use `#[command(flatten)]` to include them in command-specific Args structs.
 //!
 //! Environment variable overrides:
-//! - `THREADS_FORMAT` → default format (pretty, plain, json, yaml)
+//! - `THREADS_FORMAT` → default format (pretty, plain, json, yaml, ndjson)
 //! - `THREADS_INCLUDE_CLOSED` → include closed threads by default
 //! - `THREADS_DOWN` → default --down depth
 //! - `THREADS_UP` → default --up depth
@@ -12,7 +12,7 @@
 use clap::Args;
 
 use crate::config::{env_bool, env_string, env_usize};
-use crate::output::OutputFormat;
+use crate::output::{self, ColorChoice, OutputFormat};
 use crate::workspace::FindOptions;
 
 // ============================================================================
@@ -64,6 +64,7 @@ impl FormatArgs {
                 "plain" => return OutputFormat::Plain,
                 "json" => return OutputFormat::Json,
                 "yaml" => return OutputFormat::Yaml,
+                "ndjson" => return OutputFormat::Ndjson,
                 _ => {} // Invalid value, fall through to default
             }
         }
@@ -73,6 +74,33 @@ impl FormatArgs {
     }
 }
 
+// ============================================================================
+// ColorArgs - Color control
+// ============================================================================
+
+/// Global `--color` flag, applied in `main` before any command runs.
+#[derive(Args, Clone, Debug, Default)]
+pub struct ColorArgs {
+    /// When to color output: auto (TTY, NO_COLOR, FORCE_COLOR), always, or never
+    // Own id: `config set-color` has a positional named `color`
+    #[arg(
+        id = "color_choice",
+        long = "color",
+        value_enum,
+        global = true,
+        value_name = "WHEN",
+        default_value_t
+    )]
+    pub color: ColorChoice,
+}
+
+impl ColorArgs {
+    /// Set the process-wide color override; `always`/`never` win over the env vars.
+    pub fn apply(&self) {
+        output::set_color_choice(self.color);
+    }
+}
+
 // ============================================================================
 // DirectionArgs - Search direction flags
 // ============================================================================
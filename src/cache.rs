@@ -1,9 +1,12 @@
-//! Thread file timestamp cache.
+//! Thread file timestamp cache and search index.
 //!
 //! Caches git commit dates (created/modified) for thread files to avoid
-//! expensive history walks on every `threads list` invocation.
+//! expensive history walks on every `threads list` invocation, and the
+//! searchable text of each thread so `threads search` only re-parses files
+//! that changed.
 //!
-//! Cache lives in `.threads-config/cache.json` at git root.
+//! Both live in `.threads-config/` at git root: `cache.json` and
+//! `search-index.json`.
 
 use std::collections::HashMap;
 use std::fs;
@@ -12,6 +15,8 @@ use std::path::{Path, PathBuf};
 use git2::Repository;
 use serde::{Deserialize, Serialize};
 
+use crate::thread::Thread;
+
 /// Cached timestamp info for a single file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileTimestamps {
@@ -320,3 +325,267 @@ impl TimestampCache {
         }
     }
 }
+
+// ============================================================================
+// Search index
+// ============================================================================
+
+/// Bumped whenever `ThreadDigest` changes shape, forcing a rebuild.
+const SEARCH_INDEX_VERSION: u32 = 1;
+
+/// Searchable text of one thread file, valid while its mtime and size match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadDigest {
+    /// File modification time (nanoseconds since epoch)
+    pub mtime_ns: u64,
+    pub size: u64,
+    pub id: String,
+    pub name: String,
+    pub desc: String,
+    pub status: String,
+    pub tags: Vec<String>,
+    /// Body split into lines, including blank ones
+    pub body: Vec<String>,
+    /// File line (1-based) of `body[0]`
+    pub body_line: usize,
+    pub notes: Vec<DigestItem>,
+    pub todo: Vec<DigestItem>,
+    pub log: Vec<DigestItem>,
+}
+
+/// A note, todo item, or log entry and the file line of its `text:` field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DigestItem {
+    pub line: usize,
+    pub text: String,
+}
+
+impl ThreadDigest {
+    fn new(t: &Thread, mtime_ns: u64, size: u64) -> Self {
+        let items = |key: &str, texts: Vec<String>| -> Vec<DigestItem> {
+            let lines = item_text_lines(&t.content, key);
+            texts
+                .into_iter()
+                .enumerate()
+                .map(|(i, text)| DigestItem {
+                    line: lines.get(i).copied().unwrap_or(1),
+                    text,
+                })
+                .collect()
+        };
+
+        ThreadDigest {
+            mtime_ns,
+            size,
+            id: t.id().to_string(),
+            name: t.name().to_string(),
+            desc: t.frontmatter.desc.clone(),
+            status: t.status().to_string(),
+            tags: t.tags().to_vec(),
+            body: t.body().lines().map(str::to_string).collect(),
+            body_line: t.content[..t.body_start.min(t.content.len())]
+                .matches('\n')
+                .count()
+                + 1,
+            notes: items("notes", t.get_notes().into_iter().map(|n| n.text).collect()),
+            todo: items(
+                "todo",
+                t.get_todo_items().into_iter().map(|i| i.text).collect(),
+            ),
+            log: items(
+                "log",
+                t.get_log_entries().into_iter().map(|e| e.text).collect(),
+            ),
+        }
+    }
+}
+
+/// 1-based file line of each item's `text:` field in frontmatter list `key`.
+fn item_text_lines(content: &str, key: &str) -> Vec<usize> {
+    let header = format!("{}:", key);
+    let mut lines = Vec::new();
+    let mut inside = false;
+    let mut pending = false;
+    for (i, line) in content.lines().enumerate().skip(1) {
+        if line == "---" {
+            break;
+        }
+        if !line.starts_with([' ', '-']) {
+            inside = line == header;
+            continue;
+        }
+        if !inside {
+            continue;
+        }
+        if line.starts_with("- ") {
+            pending = !line.starts_with("- text:");
+            if !pending {
+                lines.push(i + 1);
+            }
+        } else if pending && line.starts_with("  text:") {
+            pending = false;
+            lines.push(i + 1);
+        }
+    }
+    lines
+}
+
+/// On-disk index of thread digests, keyed by git-root-relative path.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SearchIndex {
+    pub version: u32,
+    pub files: HashMap<String, ThreadDigest>,
+    /// Whether entries changed since load
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl SearchIndex {
+    /// Load the index from disk, or return an empty one if missing, invalid,
+    /// or written by another index version.
+    pub fn load(git_root: &Path) -> Self {
+        let index: Self = fs::read_to_string(Self::index_path(git_root))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        if index.version == SEARCH_INDEX_VERSION {
+            index
+        } else {
+            Self {
+                version: SEARCH_INDEX_VERSION,
+                ..Self::default()
+            }
+        }
+    }
+
+    /// Save the index if anything changed since it was loaded.
+    pub fn save(&mut self, git_root: &Path) -> Result<(), String> {
+        if !self.dirty {
+            return Ok(());
+        }
+        let index_path = Self::index_path(git_root);
+        if let Some(parent) = index_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create .threads-config: {}", e))?;
+        }
+        let contents = serde_json::to_string(self)
+            .map_err(|e| format!("Failed to serialize search index: {}", e))?;
+        fs::write(&index_path, contents)
+            .map_err(|e| format!("Failed to write search index: {}", e))?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Get the index file path.
+    pub fn index_path(git_root: &Path) -> PathBuf {
+        git_root.join(".threads-config").join("search-index.json")
+    }
+
+    /// Digest for a thread file, re-parsing it only if its mtime or size no
+    /// longer match the indexed entry. None if the file can't be read or parsed.
+    pub fn get(&mut self, git_root: &Path, path: &Path) -> Option<&ThreadDigest> {
+        let rel = path
+            .strip_prefix(git_root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        let Some((mtime_ns, size)) = file_stamp(path) else {
+            self.dirty |= self.files.remove(&rel).is_some();
+            return None;
+        };
+
+        let fresh = self
+            .files
+            .get(&rel)
+            .is_some_and(|d| d.mtime_ns == mtime_ns && d.size == size);
+        if !fresh {
+            match Thread::parse(path) {
+                Ok(t) => {
+                    self.files
+                        .insert(rel.clone(), ThreadDigest::new(&t, mtime_ns, size));
+                }
+                Err(_) => {
+                    self.files.remove(&rel);
+                }
+            }
+            self.dirty = true;
+        }
+        self.files.get(&rel)
+    }
+
+    /// Drop entries for files that no longer exist.
+    pub fn prune(&mut self, git_root: &Path) {
+        let before = self.files.len();
+        self.files.retain(|rel, _| git_root.join(rel).exists());
+        self.dirty |= self.files.len() != before;
+    }
+
+    /// Build a fresh index covering `thread_files`.
+    pub fn rebuild(git_root: &Path, thread_files: &[PathBuf]) -> Self {
+        let mut index = Self {
+            version: SEARCH_INDEX_VERSION,
+            files: HashMap::new(),
+            dirty: true,
+        };
+        for path in thread_files {
+            index.get(git_root, path);
+        }
+        index
+    }
+}
+
+/// Modification time (ns since epoch) and size of a file.
+fn file_stamp(path: &Path) -> Option<(u64, u64)> {
+    let meta = fs::metadata(path).ok()?;
+    let mtime = meta
+        .modified()
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    Some((u64::try_from(mtime.as_nanos()).ok()?, meta.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_index_reparses_only_changed_files() {
+        let dir = std::env::temp_dir().join(format!("threads-index-{}", std::process::id()));
+        let threads = dir.join(".threads");
+        fs::create_dir_all(&threads).unwrap();
+        let file = threads.join("abc123-sample.md");
+        fs::write(
+            &file,
+            "---\nid: abc123\nname: Sample\nstatus: active\nnotes:\n- text: first note\n  hash: 1a2b\n---\n\nBody line\n",
+        )
+        .unwrap();
+
+        let mut index = SearchIndex::rebuild(&dir, std::slice::from_ref(&file));
+        let digest = index.get(&dir, &file).unwrap();
+        assert_eq!(digest.name, "Sample");
+        assert_eq!(digest.notes[0].line, 6);
+        assert_eq!(digest.notes[0].text, "first note");
+        let body = digest.body.iter().position(|l| l == "Body line").unwrap();
+        assert_eq!(digest.body_line + body, 10);
+
+        index.save(&dir).unwrap();
+        let mut index = SearchIndex::load(&dir);
+        assert!(index.get(&dir, &file).is_some());
+        assert!(!index.dirty, "unchanged file should come from the index");
+
+        fs::write(
+            &file,
+            "---\nid: abc123\nname: Renamed Sample\nstatus: active\n---\n",
+        )
+        .unwrap();
+        assert_eq!(index.get(&dir, &file).unwrap().name, "Renamed Sample");
+        assert!(index.dirty);
+
+        fs::remove_file(&file).unwrap();
+        index.prune(&dir);
+        assert!(index.files.is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
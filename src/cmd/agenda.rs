@@ -0,0 +1,220 @@
+use std::path::Path;
+
+use chrono::{Days, Local, NaiveDate};
+use clap::Args;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::args::{FilterArgs, FormatArgs};
+use crate::cmd::deadline::{recurrence_marker, style_deadline_date};
+use crate::config::Config;
+use crate::notify::{self, DueItem};
+use crate::output::{self, OutputFormat};
+use crate::thread::{self, Thread};
+use crate::workspace;
+
+#[derive(Args)]
+pub struct AgendaArgs {
+    /// Show items due within this many days (overdue deadlines always show)
+    #[arg(long, default_value_t = 14, conflicts_with = "all")]
+    days: u64,
+
+    /// Show every upcoming item regardless of date
+    #[arg(long)]
+    all: bool,
+
+    /// Notify deadlines and events due today once (see behavior.notify_command)
+    #[arg(long)]
+    notify: bool,
+
+    #[command(flatten)]
+    filter: FilterArgs,
+
+    #[command(flatten)]
+    format: FormatArgs,
+}
+
+/// A deadline or event with the thread it belongs to
+#[derive(Serialize)]
+struct AgendaItem {
+    date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time: Option<String>,
+    kind: &'static str,
+    text: String,
+    hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    every: Option<String>,
+    overdue: bool,
+    thread_id: String,
+    thread_title: String,
+    thread_path: String,
+}
+
+pub fn run(args: AgendaArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+    let include_closed = args.filter.include_closed();
+
+    let today = Local::now().date_naive();
+    let today_str = today.format("%Y-%m-%d").to_string();
+    let horizon = (!args.all)
+        .then(|| today.checked_add_days(Days::new(args.days)))
+        .flatten()
+        .map(|d| d.format("%Y-%m-%d").to_string());
+    let in_window = |date: &str| horizon.as_deref().is_none_or(|h| date <= h);
+
+    let mut items: Vec<AgendaItem> = Vec::new();
+    for path in workspace::find_all_threads(ws)? {
+        // A broken thread should not hide everyone else's agenda
+        let Ok(t) = Thread::parse(&path) else {
+            continue;
+        };
+        if !include_closed && thread::is_closed_with_config(t.status(), &config.status.closed) {
+            continue;
+        }
+
+        let thread_title = if t.name().is_empty() {
+            thread::extract_name_from_path(&path).replace('-', " ")
+        } else {
+            t.name().to_string()
+        };
+        let thread_path = workspace::path_relative_to_git_root(ws, &path);
+
+        for d in t.get_deadlines() {
+            let overdue = d.date < today_str;
+            if !overdue && !in_window(&d.date) {
+                continue;
+            }
+            items.push(AgendaItem {
+                date: d.date,
+                time: None,
+                kind: "deadline",
+                text: d.text,
+                hash: d.hash,
+                every: d.every,
+                overdue,
+                thread_id: t.id().to_string(),
+                thread_title: thread_title.clone(),
+                thread_path: thread_path.clone(),
+            });
+        }
+        // Past events are history, not overdue work
+        for e in t.get_events() {
+            if e.date < today_str || !in_window(&e.date) {
+                continue;
+            }
+            items.push(AgendaItem {
+                date: e.date,
+                time: e.time,
+                kind: "event",
+                text: e.text,
+                hash: e.hash,
+                every: None,
+                overdue: false,
+                thread_id: t.id().to_string(),
+                thread_title: thread_title.clone(),
+                thread_path: thread_path.clone(),
+            });
+        }
+    }
+
+    // By day, timed events in order, deadlines ahead of events on the same day
+    items.sort_by(|a, b| {
+        (&a.date, a.kind, &a.time, &a.thread_id).cmp(&(&b.date, b.kind, &b.time, &b.thread_id))
+    });
+
+    if args.notify {
+        let due: Vec<DueItem> = items
+            .iter()
+            .filter(|i| i.date == today_str)
+            .map(|i| DueItem {
+                hash: &i.hash,
+                date: &i.date,
+                text: &i.text,
+                thread_id: &i.thread_id,
+            })
+            .collect();
+        let summary = notify::notify_due(config, &due)?;
+        println!("{}", summary.report("deadlines and events"));
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let json = output::to_json(&items, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&items)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            if items.is_empty() {
+                match &horizon {
+                    Some(_) => println!("Nothing due in the next {} days.", args.days),
+                    None => println!("Nothing due."),
+                }
+                return Ok(());
+            }
+            print_agenda(&items, today, format == OutputFormat::Pretty);
+        }
+    }
+
+    Ok(())
+}
+
+/// One block per day: the date (with "today" marked), then its items.
+fn print_agenda(items: &[AgendaItem], today: NaiveDate, styled: bool) {
+    let mut current_day: Option<&str> = None;
+    for item in items {
+        if current_day != Some(item.date.as_str()) {
+            if current_day.is_some() {
+                println!();
+            }
+            let date = if styled {
+                style_deadline_date(&item.date, today)
+            } else {
+                item.date.clone()
+            };
+            let label = match NaiveDate::parse_from_str(&item.date, "%Y-%m-%d") {
+                Ok(d) if d == today => " (today)".to_string(),
+                Ok(d) if d < today => " (overdue)".to_string(),
+                Ok(d) => format!(" {}", d.format("%a")),
+                Err(_) => String::new(),
+            };
+            if styled {
+                println!("{}{}", date, label.dimmed());
+            } else {
+                println!("{}{}", date, label);
+            }
+            current_day = Some(&item.date);
+        }
+
+        let what = match (&item.time, item.kind) {
+            (Some(time), _) => time.clone(),
+            (None, "event") => "event".to_string(),
+            _ => format!("due{}", recurrence_marker(item.every.as_deref())),
+        };
+        if styled {
+            let text = if item.overdue {
+                item.text.red().to_string()
+            } else {
+                item.text.clone()
+            };
+            println!(
+                "  {}  {}  {} {}",
+                what.dimmed(),
+                text,
+                output::style_id(&item.thread_id),
+                item.thread_title.dimmed()
+            );
+        } else {
+            println!(
+                "  {}  {}  {} {}",
+                what, item.text, item.thread_id, item.thread_title
+            );
+        }
+    }
+}
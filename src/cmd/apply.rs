@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use clap::Args;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::args::FormatArgs;
+use crate::config::{Config, auto_commit_enabled, is_quiet};
+use crate::git;
+use crate::input;
+use crate::output::{self, OutputFormat};
+use crate::thread::Thread;
+use crate::workspace;
+
+#[derive(Args)]
+pub struct ApplyArgs {
+    /// Apply the remaining operations after one fails
+    #[arg(long)]
+    keep_going: bool,
+
+    #[command(flatten)]
+    format: FormatArgs,
+
+    /// Commit the changed threads after applying
+    #[arg(long)]
+    commit: bool,
+
+    /// Commit message
+    #[arg(short = 'm', long)]
+    message: Option<String>,
+}
+
+/// One JSONL record: `{"ref": "abc123", "op": "note.add", "args": {"text": "..."}}`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Operation {
+    #[serde(rename = "ref")]
+    thread_ref: String,
+    op: String,
+    #[serde(default)]
+    args: OperationArgs,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct OperationArgs {
+    text: Option<String>,
+    hash: Option<String>,
+    under: Option<String>,
+    due: Option<String>,
+}
+
+#[derive(Serialize)]
+struct OperationResult {
+    line: usize,
+    #[serde(rename = "ref")]
+    thread_ref: Option<String>,
+    op: Option<String>,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+    message: String,
+}
+
+/// What a successful operation did.
+struct Applied {
+    message: String,
+    hash: Option<String>,
+}
+
+const OPERATIONS: &str =
+    "note.add, note.edit, note.remove, todo.add, todo.check, todo.uncheck, todo.remove, log.add";
+
+pub fn run(args: ApplyArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+
+    let input = input::read_stdin(false);
+    if input.trim().is_empty() {
+        return Err("no operations on stdin; pipe one JSON object per line".to_string());
+    }
+
+    // Threads are parsed once, mutated in memory, and written once at the end
+    let mut refs: HashMap<String, PathBuf> = HashMap::new();
+    let mut threads: HashMap<PathBuf, Thread> = HashMap::new();
+    let mut touched: Vec<PathBuf> = Vec::new();
+    let mut results: Vec<OperationResult> = Vec::new();
+    let mut stopped = false;
+
+    for (i, raw) in input.lines().enumerate() {
+        let line = i + 1;
+        if raw.trim().is_empty() {
+            continue;
+        }
+
+        let op: Operation = match serde_json::from_str(raw) {
+            Ok(op) => op,
+            Err(e) => {
+                results.push(OperationResult {
+                    line,
+                    thread_ref: None,
+                    op: None,
+                    ok: false,
+                    hash: None,
+                    message: format!("invalid operation: {}", e),
+                });
+                if !args.keep_going {
+                    stopped = true;
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let outcome = resolve(ws, &op.thread_ref, &mut refs, &mut threads).and_then(|file| {
+            let t = threads.get_mut(&file).expect("resolved thread is loaded");
+            // Apply to a copy so a failing operation leaves the thread untouched
+            let mut draft = t.clone();
+            let applied = apply_operation(&mut draft, &op)?;
+            *t = draft;
+            if !touched.contains(&file) {
+                touched.push(file);
+            }
+            Ok(applied)
+        });
+
+        let failed = outcome.is_err();
+        results.push(match outcome {
+            Ok(applied) => OperationResult {
+                line,
+                thread_ref: Some(op.thread_ref),
+                op: Some(op.op),
+                ok: true,
+                hash: applied.hash,
+                message: applied.message,
+            },
+            Err(e) => OperationResult {
+                line,
+                thread_ref: Some(op.thread_ref),
+                op: Some(op.op),
+                ok: false,
+                hash: None,
+                message: e,
+            },
+        });
+        if failed && !args.keep_going {
+            stopped = true;
+            break;
+        }
+    }
+
+    for file in &touched {
+        threads[file].write()?;
+    }
+
+    print_results(&results, format)?;
+
+    if !touched.is_empty() {
+        let committed = if args.commit || auto_commit_enabled(config) {
+            let repo = workspace::open()?;
+            let rel_paths: Vec<&Path> = touched
+                .iter()
+                .map(|f| f.strip_prefix(ws).unwrap_or(f))
+                .collect();
+            let msg = args
+                .message
+                .unwrap_or_else(|| git::generate_commit_message(&repo, &rel_paths));
+            git::commit_threads(&repo, &rel_paths, &msg)?
+        } else {
+            false
+        };
+        if !committed && !is_quiet(config) {
+            for file in &touched {
+                output::print_uncommitted_hint(threads[file].id());
+            }
+        }
+    }
+
+    let failures = results.iter().filter(|r| !r.ok).count();
+    if stopped {
+        let line = results.last().map(|r| r.line).unwrap_or_default();
+        return Err(format!(
+            "stopped at line {}; use --keep-going to apply the remaining operations",
+            line
+        ));
+    }
+    if failures > 0 {
+        return Err(format!(
+            "{} of {} operations failed",
+            failures,
+            results.len()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Resolve a thread reference, parsing each thread file only once.
+fn resolve(
+    ws: &Path,
+    thread_ref: &str,
+    refs: &mut HashMap<String, PathBuf>,
+    threads: &mut HashMap<PathBuf, Thread>,
+) -> Result<PathBuf, String> {
+    let file = match refs.get(thread_ref) {
+        Some(file) => file.clone(),
+        None => {
+            let file = workspace::find_by_ref(ws, thread_ref)?;
+            refs.insert(thread_ref.to_string(), file.clone());
+            file
+        }
+    };
+    if !threads.contains_key(&file) {
+        threads.insert(file.clone(), Thread::parse(&file)?);
+    }
+    Ok(file)
+}
+
+fn apply_operation(t: &mut Thread, op: &Operation) -> Result<Applied, String> {
+    let a = &op.args;
+    match op.op.as_str() {
+        "note.add" => {
+            let text = required(&a.text, "text", &op.op)?;
+            let hash = t.add_note(text)?;
+            let summary = text.lines().next().unwrap_or("");
+            t.insert_log_entry(&format!("Added note: {}", summary))?;
+            Ok(Applied {
+                message: format!("Added note: {} (id: {})", summary, hash),
+                hash: Some(hash),
+            })
+        }
+        "note.edit" => {
+            let hash = required(&a.hash, "hash", &op.op)?;
+            let text = required(&a.text, "text", &op.op)?;
+            expect_one(t, "Notes", hash, "note")?;
+            t.edit_by_hash("Notes", hash, text)?;
+            t.insert_log_entry(&format!("Edited note {}", hash))?;
+            Ok(applied(format!("Edited note {}", hash)))
+        }
+        "note.remove" => {
+            let hash = required(&a.hash, "hash", &op.op)?;
+            expect_one(t, "Notes", hash, "note")?;
+            t.remove_by_hash("Notes", hash)?;
+            t.insert_log_entry(&format!("Removed note {}", hash))?;
+            Ok(applied(format!("Removed note {}", hash)))
+        }
+        "todo.add" => {
+            let text = required(&a.text, "text", &op.op)?;
+            if let Some(due) = &a.due {
+                NaiveDate::parse_from_str(due, "%Y-%m-%d")
+                    .map_err(|_| format!("invalid due date '{}': expected YYYY-MM-DD", due))?;
+            }
+            // Resolve the parent before adding, so the new item cannot match its prefix
+            let parent = match &a.under {
+                Some(under) => {
+                    expect_one(t, "Todo", under, "parent item")?;
+                    t.get_todo_items()
+                        .into_iter()
+                        .find(|i| i.hash.starts_with(under.as_str()))
+                        .map(|i| i.hash)
+                }
+                None => None,
+            };
+            let hash = t.add_todo_item(text)?;
+            if let Some(parent) = &parent {
+                t.set_todo_parent(&hash, Some(parent))?;
+            }
+            if let Some(due) = &a.due {
+                t.set_todo_due(&hash, Some(due))?;
+            }
+            Ok(Applied {
+                message: format!("Added to Todo: {} (id: {})", text, hash),
+                hash: Some(hash),
+            })
+        }
+        "todo.check" | "todo.uncheck" => {
+            let hash = required(&a.hash, "hash", &op.op)?;
+            let check = op.op == "todo.check";
+            expect_one(t, "Todo", hash, "item")?;
+            t.set_todo_checked(hash, check)?;
+            let verb = if check { "Checked" } else { "Unchecked" };
+            Ok(applied(format!("{} item {}", verb, hash)))
+        }
+        "todo.remove" => {
+            let hash = required(&a.hash, "hash", &op.op)?;
+            expect_one(t, "Todo", hash, "item")?;
+            t.remove_by_hash("Todo", hash)?;
+            Ok(applied(format!("Removed item {}", hash)))
+        }
+        "log.add" => {
+            let text = required(&a.text, "text", &op.op)?;
+            t.insert_log_entry(text)?;
+            Ok(applied(format!("Logged: {}", text)))
+        }
+        other => Err(format!("unknown op '{}'. Use: {}", other, OPERATIONS)),
+    }
+}
+
+fn applied(message: String) -> Applied {
+    Applied {
+        message,
+        hash: None,
+    }
+}
+
+fn required<'a>(value: &'a Option<String>, name: &str, op: &str) -> Result<&'a str, String> {
+    match value.as_deref() {
+        Some(v) if !v.is_empty() => Ok(v),
+        _ => Err(format!("{} needs args.{}", op, name)),
+    }
+}
+
+/// Require exactly one item in `section` to match the hash prefix.
+fn expect_one(t: &Thread, section: &str, hash: &str, what: &str) -> Result<(), String> {
+    match t.count_matching_items(section, hash) {
+        0 => Err(format!("no {} with hash '{}' found", what, hash)),
+        1 => Ok(()),
+        n => Err(format!("ambiguous hash '{}' matches {} items", hash, n)),
+    }
+}
+
+fn print_results(results: &[OperationResult], format: OutputFormat) -> Result<(), String> {
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let json = output::to_json(results, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(results)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            for r in results {
+                let status = match (r.ok, format) {
+                    (true, OutputFormat::Pretty) => "ok".green().to_string(),
+                    (false, OutputFormat::Pretty) => "error".red().to_string(),
+                    (true, _) => "ok".to_string(),
+                    (false, _) => "error".to_string(),
+                };
+                println!(
+                    "{}: {} {} {}: {}",
+                    r.line,
+                    status,
+                    r.thread_ref.as_deref().unwrap_or("-"),
+                    r.op.as_deref().unwrap_or("-"),
+                    r.message
+                );
+            }
+        }
+    }
+    Ok(())
+}
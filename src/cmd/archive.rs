@@ -0,0 +1,255 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::args::FormatArgs;
+use crate::config::{Config, auto_commit_enabled, is_quiet};
+use crate::git;
+use crate::output::{self, OutputFormat};
+use crate::thread::{self, Thread};
+use crate::workspace;
+
+#[derive(Args)]
+pub struct ArchiveArgs {
+    /// Thread ID or name reference
+    #[arg(
+        required_unless_present_any = ["list", "restore"],
+        add = ArgValueCompleter::new(crate::workspace::complete_thread_ids)
+    )]
+    id: Option<String>,
+
+    /// Archive even if the thread is not closed
+    #[arg(long)]
+    force: bool,
+
+    /// List archived threads
+    #[arg(long, conflicts_with_all = ["id", "restore", "force"])]
+    list: bool,
+
+    /// Move an archived thread back out of the archive
+    #[arg(long, value_name = "REF", conflicts_with_all = ["id", "force"])]
+    restore: Option<String>,
+
+    /// Commit after moving
+    #[arg(long)]
+    commit: bool,
+
+    /// Commit message
+    #[arg(short = 'm', long)]
+    message: Option<String>,
+
+    #[command(flatten)]
+    format: FormatArgs,
+}
+
+#[derive(Serialize)]
+struct ArchiveOutput {
+    id: String,
+    source: String,
+    dest: String,
+    committed: bool,
+}
+
+#[derive(Serialize)]
+struct ArchivedThread {
+    id: String,
+    title: String,
+    status: String,
+    path: String,
+}
+
+pub fn run(args: ArchiveArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+
+    if args.list {
+        return list_archived(ws, format);
+    }
+
+    let (src_file, dest_dir, action) = match (&args.restore, &args.id) {
+        (Some(ref_str), _) => {
+            let archived = workspace::find_archived_threads(ws)?;
+            let src_file = workspace::match_ref(&archived, ref_str)
+                .map_err(|e| e.replace("thread not found", "archived thread not found"))?;
+            let dest_dir = threads_dir_of(&src_file)?.to_path_buf();
+            (src_file, dest_dir, "restore")
+        }
+        (None, Some(ref_str)) => {
+            let src_file = workspace::find_by_ref(ws, ref_str)?;
+            let t = Thread::parse(&src_file)?;
+            if !args.force && !thread::is_closed_with_config(t.status(), &config.status.closed) {
+                return Err(format!(
+                    "thread {} is not closed (status: {}); use --force to archive it anyway",
+                    t.id(),
+                    t.base_status()
+                ));
+            }
+            let dest_dir = src_file
+                .parent()
+                .ok_or_else(|| "invalid source file".to_string())?
+                .join("archive");
+            fs::create_dir_all(&dest_dir)
+                .map_err(|e| format!("creating archive directory: {}", e))?;
+            (src_file, dest_dir, "archive")
+        }
+        (None, None) => unreachable!("clap requires an id, --list or --restore"),
+    };
+
+    let id = Thread::parse(&src_file)?.id().to_string();
+    let filename = src_file
+        .file_name()
+        .ok_or_else(|| "invalid source file".to_string())?;
+
+    let dest_file = if args.restore.is_some() {
+        let dest_file = dest_dir.join(filename);
+        if dest_file.exists() {
+            return Err(format!(
+                "thread already exists at destination: {}",
+                workspace::path_relative_to_git_root(ws, &dest_file)
+            ));
+        }
+        dest_file
+    } else {
+        free_path(&dest_dir, Path::new(filename))
+    };
+
+    let repo = workspace::open()?;
+    let rel_src_path = src_file.strip_prefix(ws).unwrap_or(&src_file).to_path_buf();
+    let rel_dest_path = dest_file
+        .strip_prefix(ws)
+        .unwrap_or(&dest_file)
+        .to_path_buf();
+
+    git::move_file(&repo, ws, &src_file, &dest_file)?;
+
+    let rel_src = workspace::path_relative_to_git_root(ws, &src_file);
+    let rel_dest = workspace::path_relative_to_git_root(ws, &dest_file);
+
+    let committed = if args.commit || auto_commit_enabled(config) {
+        let msg = args
+            .message
+            .unwrap_or_else(|| format!("threads: {} {}", action, filename.to_string_lossy()));
+        git::commit_threads(
+            &repo,
+            &[rel_src_path.as_path(), rel_dest_path.as_path()],
+            &msg,
+        )?
+    } else {
+        false
+    };
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            let verb = if action == "restore" {
+                "Restored"
+            } else {
+                "Archived"
+            };
+            println!("{}: {} → {}", verb, rel_src, rel_dest);
+            if !committed && !is_quiet(config) {
+                output::print_uncommitted_hint(&id);
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let output = ArchiveOutput {
+                id,
+                source: rel_src,
+                dest: rel_dest,
+                committed,
+            };
+            let json = output::to_json(&output, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let output = ArchiveOutput {
+                id,
+                source: rel_src,
+                dest: rel_dest,
+                committed,
+            };
+            let yaml = serde_yaml::to_string(&output)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+    }
+
+    Ok(())
+}
+
+fn list_archived(ws: &Path, format: OutputFormat) -> Result<(), String> {
+    let archived: Vec<ArchivedThread> = workspace::find_archived_threads(ws)?
+        .iter()
+        .filter_map(|path| {
+            let t = Thread::parse(path).ok()?;
+            let title = if t.name().is_empty() {
+                thread::extract_name_from_path(path).replace('-', " ")
+            } else {
+                t.name().to_string()
+            };
+            Some(ArchivedThread {
+                id: t.id().to_string(),
+                title,
+                status: t.base_status(),
+                path: workspace::path_relative_to_git_root(ws, path),
+            })
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            if archived.is_empty() {
+                println!("No archived threads.");
+            }
+            let styled = format == OutputFormat::Pretty;
+            for a in &archived {
+                if styled {
+                    println!(
+                        "{} {} {}  {}",
+                        output::style_id(&a.id),
+                        output::style_status(&a.status),
+                        a.title,
+                        a.path.dimmed()
+                    );
+                } else {
+                    println!("{} [{}] {}  {}", a.id, a.status, a.title, a.path);
+                }
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let json = output::to_json(&archived, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&archived)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+    }
+    Ok(())
+}
+
+/// The `.threads/` directory an archived file is restored to.
+fn threads_dir_of(archived: &Path) -> Result<&Path, String> {
+    archived
+        .parent()
+        .and_then(|archive| archive.parent())
+        .ok_or_else(|| format!("cannot resolve directory of {}", archived.display()))
+}
+
+/// `dir/filename`, or `dir/<stem>-2.md`, `-3`, ... if that is already taken.
+fn free_path(dir: &Path, filename: &Path) -> PathBuf {
+    let candidate = dir.join(filename);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let stem = filename.file_stem().unwrap_or_default().to_string_lossy();
+    (2..)
+        .map(|n| dir.join(format!("{}-{}.md", stem, n)))
+        .find(|p| !p.exists())
+        .expect("unbounded suffix search")
+}
@@ -1,33 +1,73 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
 use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
+use colored::Colorize;
+use regex::Regex;
 
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::input;
 use crate::output;
 use crate::thread::Thread;
 use crate::workspace;
+use crate::wrap;
 
 /// Read or edit the Body section of a thread.
 ///
 /// Without flags and from an interactive terminal, displays the current body.
-/// With piped input, writes to the body (--set by default, --append to add).
+/// With piped input, writes to the body (--set by default, --append or
+/// --prepend to add, --section to append under a `## Heading`).
+/// With --text or --from-clipboard, writes that instead of stdin.
+/// With --wrap, reflows body prose to a canonical width.
+/// With --render-check, reports markdown that will not render cleanly.
 #[derive(Args)]
 pub struct BodyArgs {
     /// Thread ID or name reference
-    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
-    id: String,
+    #[arg(required_unless_present = "all", add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    id: Option<String>,
 
     /// Replace body content (default when content is piped)
-    #[arg(long)]
+    #[arg(long, conflicts_with_all = ["append", "prepend", "section"])]
     set: bool,
 
-    /// Append to existing body content
-    #[arg(long)]
+    /// Append to existing body content, separated by a blank line
+    #[arg(long, conflicts_with = "prepend")]
     append: bool,
 
+    /// Prepend to existing body content, separated by a blank line
+    #[arg(long, conflicts_with = "section")]
+    prepend: bool,
+
+    /// Append under this `## Heading`, creating it at the end if missing
+    #[arg(long, value_name = "HEADING")]
+    section: Option<String>,
+
+    /// Use this text instead of reading stdin
+    #[arg(long, conflicts_with = "from_clipboard")]
+    text: Option<String>,
+
+    /// Read new body content from the system clipboard (requires the `clipboard` feature)
+    #[arg(long)]
+    from_clipboard: bool,
+
+    /// Reflow body paragraphs to this width (code, tables, lists, headers untouched)
+    #[arg(long, value_name = "WIDTH", conflicts_with_all = ["set", "append", "prepend", "section", "text", "from_clipboard"])]
+    wrap: Option<usize>,
+
+    /// Report unclosed code fences, malformed tables, and empty link targets
+    #[arg(long, conflicts_with_all = ["set", "append", "prepend", "section", "text", "from_clipboard", "wrap"])]
+    render_check: bool,
+
+    /// With --wrap or --render-check: cover every thread in the workspace
+    #[arg(long, conflicts_with = "id")]
+    all: bool,
+
+    /// With --wrap: show a diff instead of writing
+    #[arg(long, requires = "wrap")]
+    dry_run: bool,
+
     /// Commit after editing
     #[arg(long)]
     commit: bool,
@@ -38,14 +78,33 @@ pub struct BodyArgs {
 }
 
 pub fn run(args: BodyArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    if let Some(width) = args.wrap {
+        return run_wrap(&args, width, ws, config);
+    }
+    if args.render_check {
+        return run_render_check(&args, ws);
+    }
+    if args.all {
+        return Err("--all applies to --wrap and --render-check".to_string());
+    }
+
+    let id = args.id.clone().unwrap_or_default();
+
     // Check TTY state before reading - this distinguishes interactive use from empty pipe
     let stdin_is_tty = input::stdin_is_tty();
-    let content = input::read_stdin(false);
+    let content = if let Some(text) = &args.text {
+        text.clone()
+    } else if args.from_clipboard {
+        input::read_clipboard()?
+    } else {
+        input::read_stdin(false)
+    };
 
     // Read mode: no flags AND stdin is a terminal (interactive use)
     // This prevents `printf '' | threads body <id>` from silently succeeding
-    if !args.set && !args.append && stdin_is_tty {
-        let file = workspace::find_by_ref(ws, &args.id)?;
+    let merge = args.append || args.prepend || args.section.is_some();
+    if !args.set && !merge && args.text.is_none() && !args.from_clipboard && stdin_is_tty {
+        let file = workspace::find_by_ref(ws, &id)?;
         let t = Thread::parse(&file)?;
         let body = t.content[t.body_start..].trim();
         if !body.is_empty() {
@@ -56,46 +115,298 @@ pub fn run(args: BodyArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     // Write mode: require content
     if content.is_empty() {
-        return Err("no content provided (use stdin)".to_string());
+        if args.from_clipboard {
+            return Err("clipboard is empty".to_string());
+        }
+        return Err("no content provided (use stdin or --text)".to_string());
     }
 
-    // Default to set mode for writes
-    let set_mode = args.set || !args.append;
-
-    let file = workspace::find_by_ref(ws, &args.id)?;
+    let file = workspace::find_by_ref(ws, &id)?;
 
     let mut t = Thread::parse(&file)?;
 
-    if set_mode {
-        let new_body = format!("\n{}\n", content.trim_end());
-        t.content = format!("{}{}", &t.content[..t.body_start], new_body);
-    } else {
-        let existing = t.content[t.body_start..].trim().to_string();
-        let combined = if existing.is_empty() {
-            content.trim_end().to_string()
-        } else {
-            format!("{}\n{}", existing, content.trim_end())
+    let mode = if let Some(section) = &args.section {
+        let heading = section.trim().trim_start_matches("## ");
+        t.append_to_body_section(heading, &content)?;
+        "append"
+    } else if merge {
+        // Keep exactly one blank line between the old and new content
+        let existing = t.content[t.body_start..].trim();
+        let added = content.trim();
+        let combined = match (existing.is_empty(), args.prepend) {
+            (true, _) => added.to_string(),
+            (false, false) => format!("{}\n\n{}", existing, added),
+            (false, true) => format!("{}\n\n{}", added, existing),
         };
-        let new_body = format!("\n{}\n", combined);
+        t.content = format!("{}\n{}\n", &t.content[..t.body_start], combined);
+        if args.prepend { "prepend" } else { "append" }
+    } else {
+        let new_body = format!("\n{}\n", content.trim_end());
         t.content = format!("{}{}", &t.content[..t.body_start], new_body);
-    }
+        "set"
+    };
 
     t.write()?;
 
-    let mode = if set_mode { "set" } else { "append" };
     println!("Body {}: {}", mode, file.display());
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
+        let repo = workspace::open()?;
+        let msg = args.m.unwrap_or_else(|| format!("threads: body {}", id));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
+        output::print_uncommitted_hint(&id);
+    }
+
+    Ok(())
+}
+
+/// Reflow the body of one thread (or all threads with --all) to `width` columns.
+fn run_wrap(args: &BodyArgs, width: usize, ws: &Path, config: &Config) -> Result<(), String> {
+    if width == 0 {
+        return Err("wrap width must be greater than 0".to_string());
+    }
+
+    let files = if args.all {
+        workspace::find_all_threads(ws)?
+    } else {
+        let id = args.id.as_deref().unwrap_or_default();
+        vec![workspace::find_by_ref(ws, id)?]
+    };
+
+    let mut changed: Vec<PathBuf> = Vec::new();
+
+    for file in &files {
+        let mut t = match Thread::parse(file) {
+            Ok(t) => t,
+            Err(e) if args.all => {
+                eprintln!("Skipping {}: {}", file.display(), e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        let body = t.body().to_string();
+        let reflowed = wrap::reflow_markdown(&body, width);
+        if reflowed == body {
+            continue;
+        }
+
+        let rel_path = file.strip_prefix(ws).unwrap_or(file);
+        if args.dry_run {
+            print_diff(&rel_path.display().to_string(), &body, &reflowed);
+        } else {
+            t.content = format!("{}{}", &t.content[..t.body_start], reflowed);
+            t.write()?;
+            println!("Body wrapped: {}", rel_path.display());
+        }
+        changed.push(file.clone());
+    }
+
+    if changed.is_empty() {
+        println!("No bodies to wrap at width {}.", width);
+        return Ok(());
+    }
+    if args.dry_run {
+        return Ok(());
+    }
+
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
+        let rel_paths: Vec<&Path> = changed
+            .iter()
+            .map(|f| f.strip_prefix(ws).unwrap_or(f))
+            .collect();
         let msg = args
             .m
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-    } else if !is_quiet(config) {
-        output::print_uncommitted_hint(&args.id);
+            .clone()
+            .unwrap_or_else(|| git::generate_commit_message(&repo, &rel_paths));
+        git::commit_threads(&repo, &rel_paths, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
+        match &args.id {
+            Some(id) => output::print_uncommitted_hint(id),
+            None => eprintln!(
+                "Note: {} threads have uncommitted changes. Use 'threads git commit --pending' when ready.",
+                changed.len()
+            ),
+        }
     }
 
     Ok(())
 }
+
+/// Lint the body of one thread (or all threads with --all) for markdown that
+/// will not render cleanly. Fails if anything is reported.
+fn run_render_check(args: &BodyArgs, ws: &Path) -> Result<(), String> {
+    let files = if args.all {
+        workspace::find_all_threads(ws)?
+    } else {
+        let id = args.id.as_deref().unwrap_or_default();
+        vec![workspace::find_by_ref(ws, id)?]
+    };
+
+    let mut warnings = 0;
+    let mut flagged = 0;
+    for file in &files {
+        let t = match Thread::parse(file) {
+            Ok(t) => t,
+            Err(e) if args.all => {
+                eprintln!("Skipping {}: {}", file.display(), e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        // Report file line numbers: the body starts after the frontmatter
+        let offset = t.content[..t.body_start].matches('\n').count();
+        let found = render_warnings(&t.content[t.body_start..]);
+        if found.is_empty() {
+            continue;
+        }
+
+        let rel_path = file.strip_prefix(ws).unwrap_or(file);
+        for (line, message) in &found {
+            println!(
+                "{}:{}: {} {}",
+                rel_path.display(),
+                offset + line,
+                "warning:".yellow(),
+                message
+            );
+        }
+        warnings += found.len();
+        flagged += 1;
+    }
+
+    if warnings == 0 {
+        match files.len() {
+            1 => println!("Body renders cleanly."),
+            n => println!("All {} bodies render cleanly.", n),
+        }
+        return Ok(());
+    }
+    Err(format!("{} warnings in {} bodies", warnings, flagged))
+}
+
+static EMPTY_LINK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(\s*\)").unwrap());
+
+static INLINE_CODE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`[^`]*`").unwrap());
+
+/// Markdown problems in `body` as `(1-based line, message)` pairs: unclosed
+/// code fences, tables with a missing separator or uneven rows, and links with
+/// empty targets.
+fn render_warnings(body: &str) -> Vec<(usize, String)> {
+    let mut warnings = Vec::new();
+    let lines: Vec<&str> = body.lines().collect();
+    let mut fence: Option<(usize, &str)> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+
+        // Code fences: ``` or ~~~, closed by the same marker
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (Some((_, open)), Some(m)) if m == open => fence = None,
+            (Some(_), _) => {}
+            (None, Some(m)) => fence = Some((i + 1, m)),
+            (None, None) if trimmed.starts_with('|') => {
+                let end = lines[i..]
+                    .iter()
+                    .position(|l| !l.trim_start().starts_with('|'))
+                    .map_or(lines.len(), |n| i + n);
+                check_table(&lines[i..end], i + 1, &mut warnings);
+                i = end;
+                continue;
+            }
+            (None, None) => {
+                let prose = INLINE_CODE_RE.replace_all(line, "");
+                for caps in EMPTY_LINK_RE.captures_iter(&prose) {
+                    warnings.push((i + 1, format!("link '{}' has an empty target", &caps[1])));
+                }
+            }
+        }
+        i += 1;
+    }
+
+    if let Some((line, marker)) = fence {
+        warnings.push((line, format!("code fence {} is never closed", marker)));
+    }
+    warnings
+}
+
+/// Check one table block starting at body line `first`.
+fn check_table(rows: &[&str], first: usize, warnings: &mut Vec<(usize, String)>) {
+    let cells = |row: &str| -> Vec<String> {
+        let row = row.trim();
+        let row = row.strip_prefix('|').unwrap_or(row);
+        let row = row.strip_suffix('|').unwrap_or(row);
+        row.split('|').map(|c| c.trim().to_string()).collect()
+    };
+    let is_separator = |row: &str| {
+        cells(row)
+            .iter()
+            .all(|c| !c.is_empty() && c.chars().all(|ch| matches!(ch, '-' | ':')))
+    };
+
+    let columns = cells(rows[0]).len();
+    if rows.len() < 2 || !is_separator(rows[1]) {
+        warnings.push((
+            first,
+            "table has no separator row (e.g. '| --- | --- |') under its header".to_string(),
+        ));
+        return;
+    }
+    for (n, row) in rows.iter().enumerate().skip(1) {
+        let count = cells(row).len();
+        if count != columns {
+            warnings.push((
+                first + n,
+                format!("table row has {} cells, header has {}", count, columns),
+            ));
+        }
+    }
+}
+
+/// Print a minimal line diff between two texts (LCS-based, no context trimming).
+fn print_diff(label: &str, old: &str, new: &str) {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] = length of the longest common subsequence of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    println!("{}", format!("--- {}", label).bold());
+    println!("{}", format!("+++ {} (wrapped)", label).bold());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            println!(" {}", a[i]);
+            i += 1;
+            j += 1;
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            println!("{}", format!("+{}", b[j]).green());
+            j += 1;
+        } else {
+            println!("{}", format!("-{}", a[i]).red());
+            i += 1;
+        }
+    }
+}
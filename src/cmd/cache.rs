@@ -1,12 +1,13 @@
 use std::fs;
 use std::path::Path;
+use std::time::Instant;
 
 use clap::{Args, Subcommand};
 use colored::Colorize;
 
 use crate::args::FormatArgs;
-use crate::cache::TimestampCache;
-use crate::output::OutputFormat;
+use crate::cache::{SearchIndex, TimestampCache};
+use crate::output::{self, OutputFormat};
 use crate::workspace;
 
 #[derive(Args)]
@@ -23,14 +24,18 @@ enum CacheAction {
         format: FormatArgs,
     },
 
-    /// Clear the timestamp cache
+    /// Clear the timestamp cache and search index
     Clear,
+
+    /// Re-index every thread for `threads search`
+    RebuildIndex,
 }
 
 pub fn run(args: CacheArgs, ws: &Path) -> Result<(), String> {
     match args.action {
         CacheAction::Status { format } => status(ws, format),
         CacheAction::Clear => clear(ws),
+        CacheAction::RebuildIndex => rebuild_index(ws),
     }
 }
 
@@ -52,6 +57,11 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
         (0, String::new(), 0)
     };
 
+    let index_path = SearchIndex::index_path(ws);
+    let indexed = index_path
+        .exists()
+        .then(|| SearchIndex::load(ws).files.len());
+
     // Check if cache is current
     let is_current = if exists {
         let repo = workspace::open()?;
@@ -85,6 +95,10 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
                     }
                 );
             }
+            match indexed {
+                Some(n) => println!("Search index: {} threads", n),
+                None => println!("Search index: {}", "not present".dimmed()),
+            }
         }
         OutputFormat::Plain => {
             if !exists {
@@ -97,8 +111,9 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
                 println!("size: {}", size_bytes);
                 println!("head: {}", head);
             }
+            println!("index_files: {}", indexed.unwrap_or(0));
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = serde_json::json!({
                 "exists": exists,
                 "path": path.to_string_lossy(),
@@ -106,8 +121,9 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
                 "files": file_count,
                 "size_bytes": size_bytes,
                 "head": head,
+                "index_files": indexed,
             });
-            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+            println!("{}", output::to_json(&output, format).unwrap());
         }
         OutputFormat::Yaml => {
             let output = serde_json::json!({
@@ -117,6 +133,7 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
                 "files": file_count,
                 "size_bytes": size_bytes,
                 "head": head,
+                "index_files": indexed,
             });
             println!("{}", serde_yaml::to_string(&output).unwrap());
         }
@@ -127,18 +144,44 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
 
 fn clear(ws: &Path) -> Result<(), String> {
     let path = cache_path(ws);
+    let index_path = SearchIndex::index_path(ws);
 
-    if !path.exists() {
+    if !path.exists() && !index_path.exists() {
         println!("Cache not present");
         return Ok(());
     }
 
-    let cache = TimestampCache::load(ws);
-    let file_count = cache.files.len();
+    if path.exists() {
+        let cache = TimestampCache::load(ws);
+        let file_count = cache.files.len();
+
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove cache: {}", e))?;
+
+        println!("Cleared cache ({} entries)", file_count);
+    }
+
+    if index_path.exists() {
+        let indexed = SearchIndex::load(ws).files.len();
+        fs::remove_file(&index_path)
+            .map_err(|e| format!("Failed to remove search index: {}", e))?;
+        println!("Cleared search index ({} threads)", indexed);
+    }
+
+    Ok(())
+}
+
+fn rebuild_index(ws: &Path) -> Result<(), String> {
+    let threads = workspace::find_all_threads(ws)?;
 
-    fs::remove_file(&path).map_err(|e| format!("Failed to remove cache: {}", e))?;
+    let start = Instant::now();
+    let mut index = SearchIndex::rebuild(ws, &threads);
+    index.save(ws)?;
 
-    println!("Cleared cache ({} entries)", file_count);
+    println!(
+        "Indexed {} threads in {} ms",
+        index.files.len(),
+        start.elapsed().as_millis()
+    );
 
     Ok(())
 }
@@ -5,16 +5,23 @@
 //! - env: List environment variables
 //! - schema: Output JSON schema
 //! - init: Create template manifest
+//! - dump: Write resolved configuration as a self-contained manifest
+//! - colors / set-color: Show and edit status colors
+//! - get / set: Read or write a single value by dotted key
+//! - validate: Check manifests for syntax, type, and unknown-key problems
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process;
 
 use clap::{Args, Subcommand};
 
 use crate::config::{
-    self, CONFIG_DIR, Config, ConfigSource, ENV_VARS, MANIFEST_FILE, load_config,
-    template_manifest, user_config_path,
+    self, CONFIG_DIR, Config, ConfigSource, ENV_VARS, MANIFEST_FILE, StatusColors, dump_manifest,
+    load_config, template_manifest, user_config_path,
 };
+use crate::output;
+use crate::validate::{self, Severity};
 
 #[derive(Args)]
 pub struct ConfigArgs {
@@ -35,6 +42,24 @@ enum ConfigCommand {
 
     /// Create template manifest file
     Init(InitArgs),
+
+    /// Dump resolved configuration as a complete manifest
+    Dump(DumpArgs),
+
+    /// Show status colors with a styled preview
+    Colors,
+
+    /// Set a status color in the project manifest
+    SetColor(SetColorArgs),
+
+    /// Print one resolved value and where it came from
+    Get(GetArgs),
+
+    /// Set one value in the nearest project manifest
+    Set(SetArgs),
+
+    /// Check manifests for YAML, type, and unknown-key problems
+    Validate(ValidateArgs),
 }
 
 #[derive(Args)]
@@ -55,19 +80,70 @@ struct InitArgs {
     force: bool,
 }
 
-pub fn run(args: ConfigArgs, ws: &Path) -> Result<(), String> {
+#[derive(Args)]
+struct DumpArgs {
+    /// Output JSON instead of YAML (no source header)
+    #[arg(long)]
+    json: bool,
+
+    /// Write to file instead of stdout
+    #[arg(short, long)]
+    output: Option<String>,
+}
+
+#[derive(Args)]
+struct SetColorArgs {
+    /// Status to recolor (e.g. blocked)
+    status: String,
+
+    /// Color name (red, bright blue, dim, ...)
+    color: String,
+}
+
+#[derive(Args)]
+struct GetArgs {
+    /// Dotted config key (e.g. defaults.new)
+    path: String,
+}
+
+#[derive(Args)]
+struct SetArgs {
+    /// Dotted config key (e.g. display.root_name)
+    path: String,
+
+    /// New value, parsed as YAML (true, 3, [a, b], or plain text)
+    value: String,
+
+    /// Write to the user config instead of the project manifest
+    #[arg(long)]
+    global: bool,
+}
+
+#[derive(Args)]
+struct ValidateArgs {
+    /// Manifest file or directory (default: every manifest that applies here, plus the user config)
+    path: Option<String>,
+}
+
+pub fn run(args: ConfigArgs, ws: &Path, profile: Option<&str>) -> Result<(), String> {
     let cwd = std::env::current_dir().map_err(|e| format!("cannot get cwd: {}", e))?;
 
     match args.command {
-        ConfigCommand::Show(show_args) => run_show(ws, &cwd, show_args.effective),
+        ConfigCommand::Show(show_args) => run_show(ws, &cwd, profile, show_args.effective),
         ConfigCommand::Env => run_env(),
         ConfigCommand::Schema => run_schema(),
         ConfigCommand::Init(init_args) => run_init(&cwd, init_args),
+        ConfigCommand::Dump(dump_args) => run_dump(ws, &cwd, profile, dump_args),
+        ConfigCommand::Colors => run_colors(ws, &cwd, profile),
+        ConfigCommand::SetColor(set_args) => run_set_color(ws, &cwd, profile, set_args),
+        ConfigCommand::Get(get_args) => run_get(ws, &cwd, profile, get_args),
+        ConfigCommand::Set(set_args) => run_set(ws, &cwd, set_args),
+        ConfigCommand::Validate(validate_args) => run_validate(ws, &cwd, validate_args),
     }
 }
 
-fn run_show(ws: &Path, cwd: &Path, effective: bool) -> Result<(), String> {
-    let loaded = load_config(ws, cwd);
+fn run_show(ws: &Path, cwd: &Path, profile: Option<&str>, effective: bool) -> Result<(), String> {
+    let loaded = load_config(ws, cwd, profile)?;
 
     if effective {
         print_effective(&loaded.config, &loaded.sources);
@@ -93,6 +169,436 @@ fn print_effective(config: &Config, sources: &[ConfigSource]) {
     print!("{}", yaml);
 }
 
+fn run_dump(ws: &Path, cwd: &Path, profile: Option<&str>, args: DumpArgs) -> Result<(), String> {
+    let loaded = load_config(ws, cwd, profile)?;
+
+    let content = if args.json {
+        let json = serde_json::to_string_pretty(&loaded.config)
+            .map_err(|e| format!("failed to serialize config: {}", e))?;
+        format!("{}\n", json)
+    } else {
+        dump_manifest(&loaded)?
+    };
+
+    match args.output {
+        Some(path) => {
+            let target = cwd.join(&path);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+            }
+            fs::write(&target, content)
+                .map_err(|e| format!("failed to write {}: {}", target.display(), e))?;
+            println!("Wrote: {}", target.display());
+        }
+        None => print!("{}", content),
+    }
+
+    Ok(())
+}
+
+fn run_colors(ws: &Path, cwd: &Path, profile: Option<&str>) -> Result<(), String> {
+    let loaded = load_config(ws, cwd, profile)?;
+    let colors = loaded.config.display.status_colors.unwrap_or_default();
+
+    for status in StatusColors::STATUSES {
+        println!(
+            "  {:<12} {:<14} {}",
+            status,
+            colors.get(status).unwrap_or("default"),
+            output::style_status_with_config(status, Some(&colors))
+        );
+    }
+
+    Ok(())
+}
+
+/// Update `display.status_colors.<status>` in the repo-root manifest, creating it if needed.
+///
+/// The manifest is rewritten from parsed YAML, so comments in it are not preserved.
+fn run_set_color(
+    ws: &Path,
+    cwd: &Path,
+    profile: Option<&str>,
+    args: SetColorArgs,
+) -> Result<(), String> {
+    let status = args.status.to_lowercase();
+    if !StatusColors::STATUSES.contains(&status.as_str()) {
+        return Err(format!(
+            "unknown status '{}' (expected: {})",
+            args.status,
+            StatusColors::STATUSES.join(", ")
+        ));
+    }
+    let color = args.color.to_lowercase();
+    if !output::is_supported_color(&color) {
+        return Err(format!(
+            "unknown color '{}' (expected a terminal color such as red, bright blue, or dim)",
+            args.color
+        ));
+    }
+
+    let manifest_path = ws.join(CONFIG_DIR).join(MANIFEST_FILE);
+    let original = if manifest_path.exists() {
+        Some(
+            fs::read_to_string(&manifest_path)
+                .map_err(|e| format!("failed to read {}: {}", manifest_path.display(), e))?,
+        )
+    } else {
+        None
+    };
+    let mut doc: serde_yaml::Value = match original.as_deref() {
+        Some(content) => serde_yaml::from_str(content)
+            .map_err(|e| format!("failed to parse {}: {}", manifest_path.display(), e))?,
+        None => serde_yaml::Value::Null,
+    };
+
+    let colors = yaml_mapping_entry(yaml_mapping_entry(&mut doc, "display")?, "status_colors")?;
+    colors
+        .as_mapping_mut()
+        .ok_or("display.status_colors is not a mapping")?
+        .insert(status.clone().into(), color.clone().into());
+
+    // Keep comments and layout when the color is already set
+    let keys = ["display", "status_colors", status.as_str()];
+    let value = serde_yaml::Value::from(color.clone());
+    let yaml = match original
+        .as_deref()
+        .and_then(|text| replace_scalar_in_place(text, &keys, &value, &doc))
+    {
+        Some(yaml) => yaml,
+        None => serde_yaml::to_string(&doc)
+            .map_err(|e| format!("failed to serialize manifest: {}", e))?,
+    };
+    fs::create_dir_all(ws.join(CONFIG_DIR))
+        .map_err(|e| format!("failed to create {}: {}", CONFIG_DIR, e))?;
+    fs::write(&manifest_path, yaml)
+        .map_err(|e| format!("failed to write {}: {}", manifest_path.display(), e))?;
+
+    let loaded = load_config(ws, cwd, profile)?;
+    println!(
+        "Set {} color to {}: {}",
+        status,
+        color,
+        output::style_status_with_config(&status, loaded.config.display.status_colors.as_ref())
+    );
+
+    Ok(())
+}
+
+/// Get (or create) the mapping stored under `key`, turning a null document into a mapping.
+fn yaml_mapping_entry<'a>(
+    value: &'a mut serde_yaml::Value,
+    key: &str,
+) -> Result<&'a mut serde_yaml::Value, String> {
+    if value.is_null() {
+        *value = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+    }
+    let map = value
+        .as_mapping_mut()
+        .ok_or_else(|| format!("cannot set '{}': parent is not a mapping", key))?;
+    let entry = map.entry(key.into()).or_insert(serde_yaml::Value::Null);
+    if entry.is_null() {
+        *entry = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+    }
+    Ok(entry)
+}
+
+/// Print the resolved value at a dotted key, then its source as a YAML comment.
+///
+/// An environment variable mapped to the key overrides the file-based value,
+/// as it would at point of use.
+fn run_get(ws: &Path, cwd: &Path, profile: Option<&str>, args: GetArgs) -> Result<(), String> {
+    let loaded = load_config(ws, cwd, profile)?;
+    let resolved = serde_yaml::to_value(&loaded.config)
+        .map_err(|e| format!("failed to serialize config: {}", e))?;
+    let mut value = lookup_key(&resolved, &args.path)?;
+    let mut source = config::value_source(ws, cwd, profile, &args.path);
+
+    if let Some(var) = ENV_VARS
+        .iter()
+        .find(|v| v.config_path == args.path && config::env_is_set(v.name))
+    {
+        let env_value = if value.is_bool() {
+            config::env_bool(var.name).map(serde_yaml::Value::Bool)
+        } else {
+            config::env_string(var.name).map(serde_yaml::Value::String)
+        };
+        if let Some(env_value) = env_value {
+            value = env_value;
+            source = ConfigSource::EnvVar(var.name.to_string());
+        }
+    }
+
+    let yaml =
+        serde_yaml::to_string(&value).map_err(|e| format!("failed to serialize value: {}", e))?;
+    print!("{}", yaml);
+    println!("# source: {}", source);
+    Ok(())
+}
+
+/// Resolve a dotted key in the serialized config. Keys under an unset
+/// optional section (e.g. `display.status_colors.open`) read as null.
+fn lookup_key(doc: &serde_yaml::Value, path: &str) -> Result<serde_yaml::Value, String> {
+    let mut node = doc;
+    for key in path.split('.') {
+        node = match node {
+            serde_yaml::Value::Null => return Ok(serde_yaml::Value::Null),
+            serde_yaml::Value::Mapping(map) => map.get(key).ok_or_else(|| unknown_key(path))?,
+            _ => return Err(unknown_key(path)),
+        };
+    }
+    Ok(node.clone())
+}
+
+fn unknown_key(path: &str) -> String {
+    format!(
+        "unknown config key '{}' (see 'threads config show' for available keys)",
+        path
+    )
+}
+
+/// Set a dotted key in the nearest project manifest, or the user config with `--global`.
+///
+/// The value is parsed as YAML (falling back to plain text) and checked against
+/// the config schema before anything is written. A scalar already on its own
+/// line is replaced in place so comments survive; otherwise the file is
+/// rewritten from parsed YAML.
+fn run_set(ws: &Path, cwd: &Path, args: SetArgs) -> Result<(), String> {
+    let target = if args.global {
+        user_config_path().ok_or("cannot determine user config directory")?
+    } else {
+        config::collect_manifest_paths(ws, cwd)
+            .pop()
+            .unwrap_or_else(|| ws.join(CONFIG_DIR).join(MANIFEST_FILE))
+    };
+    let display_path = if args.global {
+        target.display().to_string()
+    } else {
+        target
+            .strip_prefix(ws)
+            .unwrap_or(&target)
+            .display()
+            .to_string()
+    };
+
+    let original = if target.exists() {
+        Some(
+            fs::read_to_string(&target)
+                .map_err(|e| format!("failed to read {}: {}", display_path, e))?,
+        )
+    } else {
+        None
+    };
+    let doc: serde_yaml::Value = match &original {
+        Some(content) => serde_yaml::from_str(content)
+            .map_err(|e| format!("failed to parse {}: {}", display_path, e))?,
+        None => serde_yaml::Value::Null,
+    };
+
+    let keys: Vec<&str> = args.path.split('.').collect();
+    if keys.iter().any(|k| k.is_empty()) {
+        return Err(unknown_key(&args.path));
+    }
+
+    // `display.root_name 2024` is text, not a number: retry as a string before failing
+    let parsed = serde_yaml::from_str(&args.value)
+        .unwrap_or_else(|_| serde_yaml::Value::String(args.value.clone()));
+    let mut candidates = vec![parsed.clone()];
+    if !parsed.is_string() {
+        candidates.push(serde_yaml::Value::String(args.value.clone()));
+    }
+
+    let mut first_error = None;
+    let mut updated = None;
+    for candidate in candidates {
+        let mut next = doc.clone();
+        set_key(&mut next, &keys, candidate.clone())?;
+        match serde_yaml::from_value::<Config>(next.clone()) {
+            Ok(checked) => {
+                // Unknown keys deserialize fine but vanish on the way back out
+                let resolved = serde_yaml::to_value(&checked)
+                    .map_err(|e| format!("failed to serialize config: {}", e))?;
+                if config::yaml_lookup(&resolved, &args.path) != Some(&candidate) {
+                    return Err(unknown_key(&args.path));
+                }
+                updated = Some((next, candidate));
+                break;
+            }
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    let Some((updated, value)) = updated else {
+        return Err(format!(
+            "invalid value '{}' for {}: {}",
+            args.value,
+            args.path,
+            first_error.map(|e| e.to_string()).unwrap_or_default()
+        ));
+    };
+
+    let content = match original
+        .as_deref()
+        .and_then(|text| replace_scalar_in_place(text, &keys, &value, &updated))
+    {
+        Some(content) => content,
+        None => serde_yaml::to_string(&updated)
+            .map_err(|e| format!("failed to serialize manifest: {}", e))?,
+    };
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(&target, content).map_err(|e| format!("failed to write {}: {}", display_path, e))?;
+
+    println!("Set {} to {} in {}", args.path, args.value, display_path);
+    Ok(())
+}
+
+/// Store `value` at the dotted `keys`, creating intermediate mappings.
+fn set_key(
+    doc: &mut serde_yaml::Value,
+    keys: &[&str],
+    value: serde_yaml::Value,
+) -> Result<(), String> {
+    let (last, parents) = keys.split_last().ok_or("empty config key")?;
+    let mut node = doc;
+    for key in parents {
+        node = yaml_mapping_entry(node, key)?;
+    }
+    if node.is_null() {
+        *node = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+    }
+    node.as_mapping_mut()
+        .ok_or_else(|| format!("cannot set '{}': parent is not a mapping", last))?
+        .insert((*last).into(), value);
+    Ok(())
+}
+
+/// Rewrite the single line holding `keys` with the new scalar, leaving every
+/// other line alone. Returns None when the key is not on a line of its own or
+/// the edited text would not parse back to `expected`.
+fn replace_scalar_in_place(
+    text: &str,
+    keys: &[&str],
+    value: &serde_yaml::Value,
+    expected: &serde_yaml::Value,
+) -> Option<String> {
+    let rendered = serde_yaml::to_string(value).ok()?;
+    let rendered = rendered.trim_end();
+    if rendered.contains('\n') {
+        return None;
+    }
+
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    let mut found = None;
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('-') {
+            continue;
+        }
+        let Some((key, rest)) = trimmed.split_once(':') else {
+            continue;
+        };
+        let indent = line.len() - trimmed.len();
+        while stack.last().is_some_and(|(depth, _)| *depth >= indent) {
+            stack.pop();
+        }
+        stack.push((indent, key.trim().to_string()));
+        if !rest.trim().is_empty()
+            && stack
+                .iter()
+                .map(|(_, k)| k.as_str())
+                .eq(keys.iter().copied())
+        {
+            let comment = rest
+                .find(" #")
+                .map(|at| &rest[rest[..at].trim_end().len()..])
+                .unwrap_or("");
+            found = Some((
+                i,
+                format!("{}: {}{}", &line[..indent + key.len()], rendered, comment),
+            ));
+            break;
+        }
+    }
+
+    let (i, new_line) = found?;
+    lines[i] = new_line;
+    let mut edited = lines.join("\n");
+    if text.ends_with('\n') {
+        edited.push('\n');
+    }
+    let reparsed: serde_yaml::Value = serde_yaml::from_str(&edited).ok()?;
+    (&reparsed == expected).then_some(edited)
+}
+
+/// Report manifest problems that loading would otherwise swallow; exit 1 on errors.
+fn run_validate(ws: &Path, cwd: &Path, args: ValidateArgs) -> Result<(), String> {
+    let paths: Vec<PathBuf> = match &args.path {
+        Some(p) => {
+            let path = cwd.join(p);
+            if !path.is_dir() {
+                vec![path]
+            } else if path.ends_with(CONFIG_DIR) {
+                vec![path.join(MANIFEST_FILE)]
+            } else {
+                vec![path.join(CONFIG_DIR).join(MANIFEST_FILE)]
+            }
+        }
+        None => user_config_path()
+            .filter(|p| p.exists())
+            .into_iter()
+            .chain(config::collect_manifest_paths(ws, cwd))
+            .collect(),
+    };
+    if paths.is_empty() {
+        println!("No manifests found to validate");
+        return Ok(());
+    }
+
+    let (mut errors, mut warnings) = (0, 0);
+    for path in &paths {
+        let display_path = path.strip_prefix(ws).unwrap_or(path).display().to_string();
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", display_path, e))?;
+        let issues = validate::validate_manifest(&content);
+        if issues.is_empty() {
+            println!("OK: {}", display_path);
+        }
+        for issue in &issues {
+            match issue.severity {
+                Severity::Error => errors += 1,
+                Severity::Warning => warnings += 1,
+            }
+            let location = issue.line.map(|l| format!(":{}", l)).unwrap_or_default();
+            println!(
+                "{}: {}{}: [{}] {}",
+                issue.severity.to_string().to_uppercase(),
+                display_path,
+                location,
+                issue.code,
+                issue.message
+            );
+        }
+    }
+
+    println!(
+        "Validated {} manifest{}: {} errors, {} warnings",
+        paths.len(),
+        if paths.len() == 1 { "" } else { "s" },
+        errors,
+        warnings
+    );
+    if errors > 0 {
+        process::exit(1);
+    }
+    Ok(())
+}
+
 fn run_env() -> Result<(), String> {
     println!("Environment Variables:");
     println!();
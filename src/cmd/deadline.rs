@@ -6,8 +6,9 @@ use clap_complete::engine::ArgValueCompleter;
 use colored::Colorize;
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
+use crate::notify::{self, DueItem};
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, DeadlineItem, Thread};
 use crate::workspace;
@@ -18,11 +19,11 @@ pub struct DeadlineArgs {
     #[arg(default_value = "", add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
     id: String,
 
-    /// Action: list, add, remove (default: list)
+    /// Action: list, add, remove, done (default: list)
     #[arg(default_value = "list")]
     action: String,
 
-    /// Date (YYYY-MM-DD) for add, or hash prefix for remove
+    /// Date (YYYY-MM-DD) for add, or hash prefix for remove/done
     #[arg(default_value = "")]
     date_or_hash: String,
 
@@ -30,6 +31,10 @@ pub struct DeadlineArgs {
     #[arg(default_value = "")]
     text: Vec<String>,
 
+    /// Add: repeat the deadline (daily, weekly, monthly); `done` advances it
+    #[arg(long, value_name = "INTERVAL")]
+    every: Option<String>,
+
     #[command(flatten)]
     direction: DirectionArgs,
 
@@ -39,6 +44,10 @@ pub struct DeadlineArgs {
     #[command(flatten)]
     format: FormatArgs,
 
+    /// Agenda: notify deadlines due today once (see behavior.notify_command)
+    #[arg(long)]
+    notify: bool,
+
     /// Commit after editing
     #[arg(long)]
     commit: bool,
@@ -57,10 +66,15 @@ pub fn run(args: DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String>
     // Single-thread mode requires an id
     if args.id.is_empty() {
         return Err(
-            "usage: threads deadline <id> [add <date> <text...> | remove <hash>]".to_string(),
+            "usage: threads deadline <id> [add <date> <text...> | remove <hash> | done <hash>]"
+                .to_string(),
         );
     }
 
+    if args.notify {
+        return Err("--notify applies to the agenda view; omit the thread id".to_string());
+    }
+
     let file = workspace::find_by_ref(ws, &args.id)?;
     let mut t = Thread::parse(&file)?;
 
@@ -69,10 +83,10 @@ pub fn run(args: DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String>
             let format = args.format.resolve();
             let items = t.get_deadlines();
             match format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Ndjson => {
                     println!(
                         "{}",
-                        serde_json::to_string_pretty(&items).map_err(|e| e.to_string())?
+                        output::to_json(&items, format).map_err(|e| e.to_string())?
                     );
                 }
                 OutputFormat::Yaml => {
@@ -106,10 +120,15 @@ pub fn run(args: DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String>
                 return Err("usage: threads deadline <id> add <YYYY-MM-DD> <text...>".to_string());
             }
 
-            let hash = t.add_deadline(date, &text)?;
-            let log_entry = format!("Added deadline: {} {}", date, text);
+            let hash = t.add_deadline(date, &text, args.every.as_deref())?;
+            let every = args
+                .every
+                .as_ref()
+                .map(|e| format!(" (every {})", e.to_lowercase()))
+                .unwrap_or_default();
+            let log_entry = format!("Added deadline: {} {}{}", date, text, every);
             t.insert_log_entry(&log_entry)?;
-            println!("Added deadline: {} {} (id: {})", date, text, hash);
+            println!("Added deadline: {} {}{} (id: {})", date, text, every, hash);
         }
         "remove" | "rm" => {
             let hash = &args.date_or_hash;
@@ -121,9 +140,25 @@ pub fn run(args: DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String>
             t.insert_log_entry(&log_entry)?;
             println!("Removed deadline {}", hash);
         }
+        "done" => {
+            let hash = &args.date_or_hash;
+            if hash.is_empty() {
+                return Err("usage: threads deadline <id> done <hash>".to_string());
+            }
+            let (item, next) = t.complete_deadline(hash)?;
+            let log_entry = match &next {
+                Some(next) => format!("Completed deadline: {} (next: {})", item.text, next),
+                None => format!("Completed deadline: {}", item.text),
+            };
+            t.insert_log_entry(&log_entry)?;
+            match &next {
+                Some(next) => println!("Completed deadline: {} (next: {})", item.text, next),
+                None => println!("Completed deadline: {}", item.text),
+            }
+        }
         _ => {
             return Err(format!(
-                "unknown action '{}'. Use: list, add, remove",
+                "unknown action '{}'. Use: list, add, remove, done",
                 args.action
             ));
         }
@@ -131,15 +166,16 @@ pub fn run(args: DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String>
 
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
         let msg = args
             .message
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-    } else if !is_quiet(config) {
+            .unwrap_or_else(|| format!("threads: deadline {}", t.id()));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
         output::print_uncommitted_hint(&args.id);
     }
 
@@ -147,7 +183,7 @@ pub fn run(args: DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String>
 }
 
 /// Agenda: collect deadlines from all threads in scope, sorted by date.
-fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), String> {
+fn run_agenda(args: &DeadlineArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
     let scope = workspace::infer_scope(ws, None)?;
@@ -161,6 +197,7 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
         date: String,
         text: String,
         hash: String,
+        every: Option<String>,
         thread_id: String,
         thread_name: String,
         thread_path: String,
@@ -192,6 +229,7 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
                 date: d.date,
                 text: d.text,
                 hash: d.hash,
+                every: d.every,
                 thread_id: thread_id.clone(),
                 thread_name: thread_name.clone(),
                 thread_path: rel_path.clone(),
@@ -199,6 +237,23 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
         }
     }
 
+    if args.notify {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        let due: Vec<DueItem> = agenda
+            .iter()
+            .filter(|a| a.date == today)
+            .map(|a| DueItem {
+                hash: &a.hash,
+                date: &a.date,
+                text: &a.text,
+                thread_id: &a.thread_id,
+            })
+            .collect();
+        let summary = notify::notify_due(config, &due)?;
+        println!("{}", summary.report("deadlines"));
+        return Ok(());
+    }
+
     if agenda.is_empty() {
         println!("No deadlines found.");
         return Ok(());
@@ -208,7 +263,7 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
     agenda.sort_by(|a, b| a.date.cmp(&b.date));
 
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             use serde::Serialize;
             #[derive(Serialize)]
             struct JsonItem<'a> {
@@ -232,7 +287,7 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
                 .collect();
             println!(
                 "{}",
-                serde_json::to_string_pretty(&items).map_err(|e| format!("JSON error: {}", e))?
+                output::to_json(&items, format).map_err(|e| format!("JSON error: {}", e))?
             );
         }
         OutputFormat::Yaml => {
@@ -276,8 +331,9 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
             for a in &agenda {
                 let date_styled = style_deadline_date(&a.date, today);
                 println!(
-                    "{}  {}  {}  {}",
+                    "{}{}  {}  {}  {}",
                     date_styled,
+                    recurrence_marker(a.every.as_deref()),
                     a.text,
                     a.hash.dimmed(),
                     format!("[{}]", a.thread_id).dimmed()
@@ -293,10 +349,21 @@ fn run_agenda(args: &DeadlineArgs, ws: &Path, _config: &Config) -> Result<(), St
 fn print_deadline_list(items: &[DeadlineItem], today: NaiveDate) {
     for item in items {
         let date_styled = style_deadline_date(&item.date, today);
-        println!("{}  {}  ({})", date_styled, item.text, item.hash.dimmed());
+        println!(
+            "{}{}  {}  ({})",
+            date_styled,
+            recurrence_marker(item.every.as_deref()),
+            item.text,
+            item.hash.dimmed()
+        );
     }
 }
 
+/// " ⟳ weekly" after a recurring deadline's date, empty otherwise.
+pub fn recurrence_marker(every: Option<&str>) -> String {
+    every.map(|e| format!(" ⟳ {}", e)).unwrap_or_default()
+}
+
 /// Style a date string based on proximity to today.
 pub fn style_deadline_date(date: &str, today: NaiveDate) -> String {
     match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
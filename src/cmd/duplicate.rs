@@ -0,0 +1,141 @@
+use std::path::Path;
+
+use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
+use serde::Serialize;
+
+use crate::args::FormatArgs;
+use crate::config::{Config, auto_commit_enabled, env_string, is_quiet};
+use crate::git;
+use crate::output::{self, OutputFormat};
+use crate::thread::Thread;
+use crate::workspace;
+
+#[derive(Args)]
+pub struct DuplicateArgs {
+    /// Thread ID or name reference to copy
+    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    id: String,
+
+    /// Title for the copy (default: "<title> (copy)")
+    title: Option<String>,
+
+    /// Keep the source's todos (dropped by default)
+    #[arg(long)]
+    with_todos: bool,
+
+    /// Commit after creating
+    #[arg(long)]
+    commit: bool,
+
+    /// Commit message
+    #[arg(short = 'm', long)]
+    m: Option<String>,
+
+    #[command(flatten)]
+    format: FormatArgs,
+}
+
+#[derive(Serialize)]
+struct DuplicateOutput {
+    id: String,
+    source: String,
+    path: String,
+    path_absolute: String,
+}
+
+pub fn run(args: DuplicateArgs, git_root: &Path, config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+
+    let src_file = workspace::find_by_ref(git_root, &args.id)?;
+    let src = Thread::parse(&src_file)?;
+
+    let title = args
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("{} (copy)", src.name()));
+    let slug = workspace::slugify(&title);
+    if slug.is_empty() {
+        return Err("title produces empty slug".to_string());
+    }
+
+    // Same status resolution as `new`
+    let status =
+        env_string("THREADS_DEFAULT_STATUS").unwrap_or_else(|| config.defaults.new.clone());
+
+    let id = workspace::generate_id(git_root)?;
+    let threads_dir = src_file
+        .parent()
+        .ok_or_else(|| "invalid source file".to_string())?;
+    let thread_path = threads_dir.join(format!("{}-{}.md", id, slug));
+    if thread_path.exists() {
+        return Err(format!("thread already exists: {}", thread_path.display()));
+    }
+
+    // Fresh identity, status and log; notes, tags, links and the body carry over.
+    // Deadlines and events belong to the original piece of work and are dropped.
+    let mut t = Thread::new(&id, &title, &src.frontmatter.desc, &status, src.body())?;
+    t.path = thread_path.to_string_lossy().to_string();
+    t.frontmatter.priority = src.frontmatter.priority.clone();
+    t.frontmatter.tags = src.frontmatter.tags.clone();
+    t.frontmatter.links = src.frontmatter.links.clone();
+    t.frontmatter.parent = src.frontmatter.parent.clone();
+    t.frontmatter.notes = src.frontmatter.notes.clone();
+    if args.with_todos {
+        t.frontmatter.todo = src.frontmatter.todo.clone();
+    }
+    if let Some(entry) = t.frontmatter.log.first_mut() {
+        entry.text = format!("Created thread (cloned from {}).", src.id());
+    }
+    t.rebuild_content()?;
+    t.write()?;
+
+    let rel_path = workspace::path_relative_to_git_root(git_root, &thread_path);
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            println!("Duplicated {} as {}", src.id(), id);
+            println!("  → {}", rel_path);
+        }
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let output = DuplicateOutput {
+                id: id.clone(),
+                source: src.id().to_string(),
+                path: rel_path,
+                path_absolute: thread_path.to_string_lossy().to_string(),
+            };
+            let json = output::to_json(&output, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let output = DuplicateOutput {
+                id: id.clone(),
+                source: src.id().to_string(),
+                path: rel_path,
+                path_absolute: thread_path.to_string_lossy().to_string(),
+            };
+            let yaml = serde_yaml::to_string(&output)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+    }
+
+    let committed = if args.commit || auto_commit_enabled(config) {
+        let repo = workspace::open()?;
+        let msg = args
+            .m
+            .unwrap_or_else(|| format!("threads: duplicate {}", id));
+        git::commit_thread(&repo, &thread_path, &msg)?
+    } else {
+        false
+    };
+    if !committed
+        && matches!(format, OutputFormat::Pretty | OutputFormat::Plain)
+        && !is_quiet(config)
+    {
+        output::print_uncommitted_hint(&id);
+    }
+
+    Ok(())
+}
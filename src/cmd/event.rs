@@ -8,8 +8,9 @@ use regex::Regex;
 use std::sync::LazyLock;
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
+use crate::notify::{self, DueItem};
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, EventItem, Thread};
 use crate::workspace;
@@ -43,6 +44,10 @@ pub struct EventArgs {
     #[command(flatten)]
     format: FormatArgs,
 
+    /// Agenda: notify events due today once (see behavior.notify_command)
+    #[arg(long)]
+    notify: bool,
+
     /// Commit after editing
     #[arg(long)]
     commit: bool,
@@ -64,6 +69,10 @@ pub fn run(args: EventArgs, ws: &Path, config: &Config) -> Result<(), String> {
         );
     }
 
+    if args.notify {
+        return Err("--notify applies to the agenda view; omit the thread id".to_string());
+    }
+
     let file = workspace::find_by_ref(ws, &args.id)?;
     let mut t = Thread::parse(&file)?;
 
@@ -72,10 +81,10 @@ pub fn run(args: EventArgs, ws: &Path, config: &Config) -> Result<(), String> {
             let format = args.format.resolve();
             let items = t.get_events();
             match format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Ndjson => {
                     println!(
                         "{}",
-                        serde_json::to_string_pretty(&items).map_err(|e| e.to_string())?
+                        output::to_json(&items, format).map_err(|e| e.to_string())?
                     );
                 }
                 OutputFormat::Yaml => {
@@ -144,15 +153,16 @@ pub fn run(args: EventArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
         let msg = args
             .message
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-    } else if !is_quiet(config) {
+            .unwrap_or_else(|| format!("threads: event {}", t.id()));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
         output::print_uncommitted_hint(&args.id);
     }
 
@@ -172,7 +182,7 @@ fn parse_time_and_text(tokens: &[String]) -> (Option<String>, String) {
 }
 
 /// Agenda: collect events from all threads in scope, sorted by date then time.
-fn run_agenda(args: &EventArgs, ws: &Path, _config: &Config) -> Result<(), String> {
+fn run_agenda(args: &EventArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
     let scope = workspace::infer_scope(ws, None)?;
@@ -225,6 +235,23 @@ fn run_agenda(args: &EventArgs, ws: &Path, _config: &Config) -> Result<(), Strin
         }
     }
 
+    if args.notify {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        let due: Vec<DueItem> = agenda
+            .iter()
+            .filter(|a| a.date == today)
+            .map(|a| DueItem {
+                hash: &a.hash,
+                date: &a.date,
+                text: &a.text,
+                thread_id: &a.thread_id,
+            })
+            .collect();
+        let summary = notify::notify_due(config, &due)?;
+        println!("{}", summary.report("events"));
+        return Ok(());
+    }
+
     if agenda.is_empty() {
         println!("No events found.");
         return Ok(());
@@ -243,7 +270,7 @@ fn run_agenda(args: &EventArgs, ws: &Path, _config: &Config) -> Result<(), Strin
     let has_time = agenda.iter().any(|a| a.time.is_some());
 
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             use serde::Serialize;
             #[derive(Serialize)]
             struct JsonItem<'a> {
@@ -270,7 +297,7 @@ fn run_agenda(args: &EventArgs, ws: &Path, _config: &Config) -> Result<(), Strin
                 .collect();
             println!(
                 "{}",
-                serde_json::to_string_pretty(&items).map_err(|e| format!("JSON error: {}", e))?
+                output::to_json(&items, format).map_err(|e| format!("JSON error: {}", e))?
             );
         }
         OutputFormat::Yaml => {
@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Datelike, Days, NaiveDate, NaiveTime, Utc};
+use clap::{Args, Subcommand};
+
+use crate::args::FilterArgs;
+use crate::config::Config;
+use crate::ics::{self, Calendar};
+use crate::thread::{self, Thread};
+use crate::workspace;
+
+#[derive(Args)]
+pub struct ExportArgs {
+    #[command(subcommand)]
+    format: ExportFormat,
+}
+
+#[derive(Subcommand)]
+enum ExportFormat {
+    /// Deadlines and events as an iCalendar feed
+    Ics {
+        /// Write to this file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        #[command(flatten)]
+        filter: FilterArgs,
+    },
+}
+
+pub fn run(args: ExportArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    match args.format {
+        ExportFormat::Ics { output, filter } => {
+            let (calendar, count) = build_calendar(ws, config, filter.include_closed())?;
+            match output {
+                Some(path) => {
+                    fs::write(&path, calendar)
+                        .map_err(|e| format!("writing {}: {}", path.display(), e))?;
+                    println!(
+                        "Exported {} item{} to {}",
+                        count,
+                        if count == 1 { "" } else { "s" },
+                        path.display()
+                    );
+                }
+                None => print!("{}", calendar),
+            }
+            Ok(())
+        }
+    }
+}
+
+/// One VEVENT per deadline (all-day) and event (timed when it has a time).
+fn build_calendar(
+    ws: &Path,
+    config: &Config,
+    include_closed: bool,
+) -> Result<(String, usize), String> {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut cal = Calendar::new();
+    let mut count = 0;
+
+    for path in workspace::find_all_threads(ws)? {
+        let Ok(t) = Thread::parse(&path) else {
+            continue;
+        };
+        if !include_closed && thread::is_closed_with_config(t.status(), &config.status.closed) {
+            continue;
+        }
+        let title = if t.name().is_empty() {
+            thread::extract_name_from_path(&path).replace('-', " ")
+        } else {
+            t.name().to_string()
+        };
+
+        for d in t.get_deadlines() {
+            let Ok(date) = NaiveDate::parse_from_str(&d.date, "%Y-%m-%d") else {
+                continue;
+            };
+            let rrule = d.every.as_deref().and_then(|every| match every {
+                "daily" => Some("DAILY".to_string()),
+                "weekly" => Some("WEEKLY".to_string()),
+                "monthly" => Some(monthly_rrule(d.day.unwrap_or(date.day()))),
+                _ => None,
+            });
+            cal.line("BEGIN:VEVENT");
+            cal.line(format!("UID:{}-{}@threads", t.id(), d.hash));
+            cal.line(format!("DTSTAMP:{}", stamp));
+            cal.line(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+            if let Some(next) = date.checked_add_days(Days::new(1)) {
+                cal.line(format!("DTEND;VALUE=DATE:{}", next.format("%Y%m%d")));
+            }
+            if let Some(freq) = rrule {
+                cal.line(format!("RRULE:FREQ={}", freq));
+            }
+            cal.line(format!("SUMMARY:{}", ics::escape_text(&title)));
+            cal.line(format!(
+                "DESCRIPTION:{}",
+                ics::escape_text(&format!("Deadline: {} ({})", d.text, t.id()))
+            ));
+            cal.line("END:VEVENT");
+            count += 1;
+        }
+
+        for e in t.get_events() {
+            let Ok(date) = NaiveDate::parse_from_str(&e.date, "%Y-%m-%d") else {
+                continue;
+            };
+            let time = e
+                .time
+                .as_deref()
+                .and_then(|tm| NaiveTime::parse_from_str(tm, "%H:%M").ok());
+            cal.line("BEGIN:VEVENT");
+            cal.line(format!("UID:{}-{}@threads", t.id(), e.hash));
+            cal.line(format!("DTSTAMP:{}", stamp));
+            match time {
+                // Floating local time: the calendar client's zone applies
+                Some(tm) => cal.line(format!(
+                    "DTSTART:{}T{}",
+                    date.format("%Y%m%d"),
+                    tm.format("%H%M%S")
+                )),
+                None => {
+                    cal.line(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+                    if let Some(next) = date.checked_add_days(Days::new(1)) {
+                        cal.line(format!("DTEND;VALUE=DATE:{}", next.format("%Y%m%d")));
+                    }
+                }
+            }
+            cal.line(format!("SUMMARY:{}", ics::escape_text(&title)));
+            cal.line(format!(
+                "DESCRIPTION:{}",
+                ics::escape_text(&format!("{} ({})", e.text, t.id()))
+            ));
+            cal.line("END:VEVENT");
+            count += 1;
+        }
+    }
+
+    Ok((cal.finish(), count))
+}
+
+/// RRULE frequency for a monthly deadline anchored on `day`. Days past the 28th
+/// clamp to the end of shorter months, matching `deadline done`, instead of
+/// skipping them as a bare FREQ=MONTHLY would.
+fn monthly_rrule(day: u32) -> String {
+    if day <= 28 {
+        return "MONTHLY".to_string();
+    }
+    let days: Vec<String> = (28..=day).map(|d| d.to_string()).collect();
+    format!("MONTHLY;BYMONTHDAY={};BYSETPOS=-1", days.join(","))
+}
@@ -8,7 +8,7 @@ use serde::Serialize;
 
 use crate::args::FormatArgs;
 use crate::git;
-use crate::output::OutputFormat;
+use crate::output::{self, OutputFormat};
 use crate::thread;
 use crate::workspace;
 
@@ -26,14 +26,14 @@ enum GitAction {
         format: FormatArgs,
     },
 
-    /// Commit thread changes
+    /// Commit thread changes (all pending threads when no IDs are given)
     Commit {
         /// Thread IDs to commit
         #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
         ids: Vec<String>,
 
-        /// Commit all modified threads
-        #[arg(long)]
+        /// Commit all modified threads (the default without IDs)
+        #[arg(long, conflicts_with = "ids")]
         pending: bool,
 
         /// Commit message
@@ -43,6 +43,10 @@ enum GitAction {
         /// Auto-accept generated message
         #[arg(long)]
         auto: bool,
+
+        /// Commit even if non-thread files are staged (they stay staged, uncommitted)
+        #[arg(long)]
+        allow_mixed: bool,
     },
 }
 
@@ -55,7 +59,8 @@ pub fn run(args: GitArgs, ws: &Path) -> Result<(), String> {
             pending,
             m,
             auto,
-        }) => commit(ws, ids, pending, m, auto),
+            allow_mixed,
+        }) => commit(ws, ids, pending, m, auto, allow_mixed),
     }
 }
 
@@ -120,7 +125,7 @@ fn status(ws: &Path, format_args: FormatArgs) -> Result<(), String> {
     match format {
         OutputFormat::Pretty => output_status_pretty(&pending),
         OutputFormat::Plain => output_status_plain(&pending),
-        OutputFormat::Json => output_status_json(&pending)?,
+        OutputFormat::Json | OutputFormat::Ndjson => output_status_json(&pending, format)?,
         OutputFormat::Yaml => output_status_yaml(&pending)?,
     }
 
@@ -171,7 +176,7 @@ fn output_status_plain(pending: &[PendingThread]) {
     }
 }
 
-fn output_status_json(pending: &[PendingThread]) -> Result<(), String> {
+fn output_status_json(pending: &[PendingThread], format: OutputFormat) -> Result<(), String> {
     #[derive(Serialize)]
     struct Output {
         count: usize,
@@ -183,7 +188,7 @@ fn output_status_json(pending: &[PendingThread]) -> Result<(), String> {
         pending: pending.to_vec(),
     };
 
-    let json = serde_json::to_string_pretty(&output)
+    let json = output::to_json(&output, format)
         .map_err(|e| format!("JSON serialization failed: {}", e))?;
     println!("{}", json);
     Ok(())
@@ -217,12 +222,23 @@ fn commit(
     pending: bool,
     m: Option<String>,
     auto: bool,
+    allow_mixed: bool,
 ) -> Result<(), String> {
     let repo = workspace::open()?;
 
+    // Thread commits leave the index alone, so staged files elsewhere would
+    // silently miss the commit the user probably expects them in
+    let mixed = git::staged_non_thread_files(&repo);
+    if !mixed.is_empty() && !allow_mixed {
+        return Err(format!(
+            "non-thread files are staged: {}; commit them with git, or pass --allow-mixed to commit only the threads",
+            mixed.join(", ")
+        ));
+    }
+
     let mut files: Vec<PathBuf> = Vec::new();
 
-    if pending {
+    if pending || ids.is_empty() {
         // Collect all thread files with uncommitted changes
         let threads = workspace::find_all_threads(ws)?;
 
@@ -241,11 +257,6 @@ fn commit(
         let deleted = git::find_deleted_thread_files(&repo);
         files.extend(deleted);
     } else {
-        // Resolve provided IDs to files
-        if ids.is_empty() {
-            return Err("provide thread IDs or use --pending".to_string());
-        }
-
         for id in &ids {
             let file = workspace::find_by_ref(ws, id)?;
             let rel_path = file
@@ -281,7 +292,11 @@ fn commit(
         m
     } else {
         let path_refs: Vec<&Path> = rel_paths.iter().map(|p| p.as_path()).collect();
-        let generated = git::generate_commit_message(&repo, &path_refs);
+        let generated = format!(
+            "{}\n\n{}",
+            git::generate_commit_message(&repo, &path_refs),
+            summarize_changes(&repo, ws, &rel_paths)
+        );
         println!("Generated message: {}", generated);
 
         if !auto && is_terminal() {
@@ -306,6 +321,9 @@ fn commit(
     git::commit(&repo, &path_refs, &msg)?;
 
     println!("Committed {} thread(s)", files.len());
+    if !mixed.is_empty() {
+        println!("Left {} staged non-thread file(s) uncommitted", mixed.len());
+    }
     eprintln!(
         "{}",
         "Note: Changes are local. Push with 'git push' when ready.".dimmed()
@@ -313,6 +331,31 @@ fn commit(
     Ok(())
 }
 
+/// One "action id title" line per committed thread, for the message body.
+fn summarize_changes(repo: &git2::Repository, ws: &Path, rel_paths: &[PathBuf]) -> String {
+    rel_paths
+        .iter()
+        .map(|rel| {
+            let full = ws.join(rel);
+            let action = if !full.exists() {
+                "remove"
+            } else if git::exists_in_head(repo, rel) {
+                "update"
+            } else {
+                "add"
+            };
+            let id = thread::extract_id_from_path(rel).unwrap_or_default();
+            let title = thread::Thread::parse(&full)
+                .ok()
+                .map(|t| t.name().to_string())
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| thread::extract_name_from_path(rel).replace('-', " "));
+            format!("- {} {} {}", action, id, title)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn is_terminal() -> bool {
     use std::io::IsTerminal;
     io::stdin().is_terminal() && io::stderr().is_terminal()
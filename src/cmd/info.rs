@@ -2,7 +2,7 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
 use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
 use colored::Colorize;
@@ -14,9 +14,12 @@ use tabled::settings::style::HorizontalLine;
 use tabled::settings::{Alignment, Modify, Padding, Style};
 
 use crate::args::FormatArgs;
+use crate::cmd::link::{self, ResolvedLink};
+use crate::cmd::read::timestamp_to_relative;
+use crate::config::Config;
 use crate::git;
 use crate::output::{self, OutputFormat};
-use crate::thread::Thread;
+use crate::thread::{self, LogEntry, Thread};
 use crate::workspace;
 
 #[derive(Args)]
@@ -25,6 +28,16 @@ pub struct InfoArgs {
     #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
     id: String,
 
+    /// Append the last N log entries and commits as one timeline (default 10)
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "10"
+    )]
+    log: Option<usize>,
+
     #[command(flatten)]
     format: FormatArgs,
 }
@@ -47,6 +60,53 @@ impl std::fmt::Display for GitLogEntry {
     }
 }
 
+/// Structured commit for JSON/YAML output
+#[derive(Serialize)]
+struct CommitOutput {
+    hash: String,
+    /// ISO 8601 commit time
+    timestamp: String,
+    relative_time: String,
+    message: String,
+    insertions: usize,
+    deletions: usize,
+}
+
+impl From<&GitLogEntry> for CommitOutput {
+    fn from(entry: &GitLogEntry) -> Self {
+        Self {
+            hash: entry.hash.clone(),
+            timestamp: DateTime::from_timestamp(entry.timestamp, 0)
+                .map(|dt| dt.to_rfc3339())
+                .unwrap_or_default(),
+            relative_time: entry.relative_time.clone(),
+            message: entry.message.clone(),
+            insertions: entry.insertions,
+            deletions: entry.deletions,
+        }
+    }
+}
+
+/// One item of the `--log` timeline
+enum Activity<'a> {
+    Log(&'a LogEntry),
+    Commit(&'a GitLogEntry),
+}
+
+impl Activity<'_> {
+    /// Unix seconds for ordering; untimed log entries sort first
+    fn timestamp(&self) -> i64 {
+        match self {
+            Activity::Log(entry) => NaiveDateTime::parse_from_str(&entry.ts, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .and_then(|dt| dt.and_local_timezone(Local).earliest())
+                .map(|dt| dt.timestamp())
+                .unwrap_or(i64::MIN),
+            Activity::Commit(entry) => entry.timestamp,
+        }
+    }
+}
+
 /// Thread info data
 struct ThreadInfoData {
     id: String,
@@ -65,7 +125,10 @@ struct ThreadInfoData {
     todo_done: usize,
     deadline_count: usize,
     event_count: usize,
+    links: Vec<ResolvedLink>,
     git_history: Vec<GitLogEntry>,
+    /// With `--log N`: the last N log entries, oldest first
+    log: Option<Vec<LogEntry>>,
 }
 
 impl ThreadInfoData {
@@ -92,9 +155,20 @@ impl ThreadInfoData {
             .map(|dt| dt.with_timezone(&Utc).to_rfc3339())
             .unwrap_or_default()
     }
+
+    /// Log entries and commits merged chronologically, keeping the last `limit`.
+    fn activity(&self, limit: usize) -> Vec<Activity<'_>> {
+        let mut items: Vec<Activity> = self.log.iter().flatten().map(Activity::Log).collect();
+        // History is newest first; oldest first keeps same-second commits in order
+        items.extend(self.git_history.iter().rev().map(Activity::Commit));
+        // Stable: equal times keep log entries ahead of commits
+        items.sort_by_key(Activity::timestamp);
+        let skip = items.len().saturating_sub(limit);
+        items.split_off(skip)
+    }
 }
 
-pub fn run(args: InfoArgs, ws: &Path) -> Result<(), String> {
+pub fn run(args: InfoArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
     // Open repository for git operations
@@ -115,17 +189,18 @@ pub fn run(args: InfoArgs, ws: &Path) -> Result<(), String> {
     };
 
     let git_status = get_git_status(&repo, &rel_path);
-    let log_count = thread.get_log_entries().len();
+    let log_entries = thread.get_log_entries();
+    let log_count = log_entries.len();
     let note_count = thread.get_notes().len();
     let todo_items = thread.get_todo_items();
-    let todo_count = todo_items.len();
-    let todo_done = todo_items.iter().filter(|t| t.done).count();
+    let (todo_done, todo_count) = thread::todo_progress(&todo_items, config.display.todo_rollup);
     let deadline_count = thread.get_deadlines().len();
     let event_count = thread.get_events().len();
+    let links = link::resolve_links(ws, thread.links());
     let git_history = get_git_history(ws, &rel_path);
 
     // Get timestamps from git history (created = initial commit, updated = most recent)
-    let (created_dt, updated_dt) = get_timestamps_from_history(&git_history, &file);
+    let (created_dt, updated_dt) = get_timestamps_from_history(&git_history, &thread, &file);
 
     let info = ThreadInfoData {
         id: thread.id().to_string(),
@@ -144,18 +219,27 @@ pub fn run(args: InfoArgs, ws: &Path) -> Result<(), String> {
         todo_done,
         deadline_count,
         event_count,
+        links,
         git_history,
+        log: args.log.map(|n| {
+            // Stored newest first; reverse before the stable sort so
+            // same-second entries stay in order (timestamps sort as strings)
+            let mut entries = log_entries;
+            entries.reverse();
+            entries.sort_by(|a, b| a.ts.cmp(&b.ts));
+            entries.split_off(entries.len().saturating_sub(n))
+        }),
     };
 
     match format {
-        OutputFormat::Pretty => output_pretty(&info),
-        OutputFormat::Plain => output_plain(&info),
-        OutputFormat::Json => output_json(&info),
+        OutputFormat::Pretty => output_pretty(&info, args.log),
+        OutputFormat::Plain => output_plain(&info, args.log),
+        OutputFormat::Json | OutputFormat::Ndjson => output_json(&info, format),
         OutputFormat::Yaml => output_yaml(&info),
     }
 }
 
-fn output_pretty(info: &ThreadInfoData) -> Result<(), String> {
+fn output_pretty(info: &ThreadInfoData, log: Option<usize>) -> Result<(), String> {
     let term_width = output::terminal_width().min(80).saturating_sub(4); // account for box borders + padding
 
     // Right side stats: log · todos · status (no symbols)
@@ -261,19 +345,30 @@ fn output_pretty(info: &ThreadInfoData) -> Result<(), String> {
     // Path line (grey, no separator before)
     let path_line = info.path.dimmed().to_string();
 
-    // Construct table: header, history+path (combined to avoid separator before path)
+    // Construct table: header, links, history+path (combined to avoid separator before path)
     let history_and_path = format!("{}\n\n{}", history_content, path_line);
 
-    let rows: Vec<Vec<String>> = vec![vec![header_content], vec![history_and_path]];
+    let mut rows: Vec<Vec<String>> = vec![vec![header_content]];
+    if !info.links.is_empty() {
+        let links_lines = link::format_links(&info.links, true);
+        rows.push(vec![format!(
+            "{}\n{}",
+            "Links".bold(),
+            links_lines.join("\n")
+        )]);
+    }
+    rows.push(vec![history_and_path]);
 
+    let rows_len = rows.len();
     let mut table = Table::from_iter(rows);
 
-    // Single horizontal line after header
+    // Horizontal line after the header (and links)
     let hline = HorizontalLine::new('─')
         .left('├')
         .right('┤')
         .intersection('─');
-    let style = Style::rounded().horizontals([(1, hline)]);
+    // With two rows both entries name the same line
+    let style = Style::rounded().horizontals([(1, hline), (rows_len - 1, hline)]);
 
     table
         .with(style)
@@ -282,9 +377,50 @@ fn output_pretty(info: &ThreadInfoData) -> Result<(), String> {
 
     println!("{}", table);
 
+    if let Some(limit) = log {
+        print_activity_pretty(info, limit, term_width);
+    }
+
     Ok(())
 }
 
+/// `--log`: ⬡ log entries and ● commits, oldest first, below the box.
+fn print_activity_pretty(info: &ThreadInfoData, limit: usize, max_width: usize) {
+    let items = info.activity(limit);
+    println!();
+    println!("{}", "Activity".bold());
+    if items.is_empty() {
+        println!("  {}", "No log entries or commits".dimmed());
+        return;
+    }
+
+    let now = Local::now().naive_local();
+    for item in items {
+        match item {
+            Activity::Log(entry) => {
+                let time = if entry.ts.is_empty() {
+                    "·".to_string()
+                } else {
+                    timestamp_to_relative(&entry.ts, &now)
+                };
+                println!(
+                    "  {} {:>3} {}",
+                    "⬡".cyan(),
+                    time.dimmed(),
+                    output::truncate_back(&entry.text, max_width.saturating_sub(8))
+                );
+            }
+            Activity::Commit(entry) => {
+                println!(
+                    "  {} {}",
+                    "●".dimmed(),
+                    format_git_entry(entry, max_width.saturating_sub(4))
+                );
+            }
+        }
+    }
+}
+
 /// Format a git log entry: "3h  abc1234 +5 -2  commit message"
 fn format_git_entry(entry: &GitLogEntry, max_width: usize) -> String {
     let time_str = format!("{:>3}", entry.relative_time);
@@ -351,7 +487,7 @@ fn format_git_entry(entry: &GitLogEntry, max_width: usize) -> String {
     }
 }
 
-fn output_plain(info: &ThreadInfoData) -> Result<(), String> {
+fn output_plain(info: &ThreadInfoData, log: Option<usize>) -> Result<(), String> {
     // Title with status
     println!("{} [{}]", info.title, info.status);
     if !info.desc.is_empty() {
@@ -414,6 +550,14 @@ fn output_plain(info: &ThreadInfoData) -> Result<(), String> {
     println!("{}", stat_parts.join(" | "));
     println!();
 
+    if !info.links.is_empty() {
+        println!("Links:");
+        for line in link::format_links(&info.links, false) {
+            println!("  {}", line);
+        }
+        println!();
+    }
+
     // History
     if info.git_history.is_empty() {
         println!("No history (untracked)");
@@ -425,15 +569,31 @@ fn output_plain(info: &ThreadInfoData) -> Result<(), String> {
     }
     println!();
 
+    if let Some(limit) = log {
+        let items = info.activity(limit);
+        if items.is_empty() {
+            println!("No activity");
+        } else {
+            println!("Activity:");
+            for item in items {
+                match item {
+                    Activity::Log(entry) => println!("  log {} {}", entry.ts, entry.text),
+                    Activity::Commit(entry) => println!("  commit {}", entry),
+                }
+            }
+        }
+        println!();
+    }
+
     // Path last (with ID for reference)
     println!("{} | {}", info.id, info.path);
 
     Ok(())
 }
 
-fn output_json(info: &ThreadInfoData) -> Result<(), String> {
+fn output_json(info: &ThreadInfoData, format: OutputFormat) -> Result<(), String> {
     #[derive(Serialize)]
-    struct JsonInfo {
+    struct JsonInfo<'a> {
         id: String,
         status: String,
         path: String,
@@ -450,11 +610,13 @@ fn output_json(info: &ThreadInfoData) -> Result<(), String> {
         todo_done: usize,
         deadline_count: usize,
         event_count: usize,
-        git_history: Vec<String>,
+        links: &'a [ResolvedLink],
+        git_history: Vec<CommitOutput>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        log: Option<&'a [LogEntry]>,
     }
 
-    // Convert GitLogEntry to strings for JSON output
-    let history_strings: Vec<String> = info.git_history.iter().map(|e| e.to_string()).collect();
+    let history: Vec<CommitOutput> = info.git_history.iter().map(CommitOutput::from).collect();
 
     let output = JsonInfo {
         id: info.id.clone(),
@@ -473,10 +635,12 @@ fn output_json(info: &ThreadInfoData) -> Result<(), String> {
         todo_done: info.todo_done,
         deadline_count: info.deadline_count,
         event_count: info.event_count,
-        git_history: history_strings,
+        links: &info.links,
+        git_history: history,
+        log: info.log.as_deref(),
     };
 
-    let json = serde_json::to_string_pretty(&output)
+    let json = output::to_json(&output, format)
         .map_err(|e| format!("JSON serialization failed: {}", e))?;
     println!("{}", json);
     Ok(())
@@ -484,7 +648,7 @@ fn output_json(info: &ThreadInfoData) -> Result<(), String> {
 
 fn output_yaml(info: &ThreadInfoData) -> Result<(), String> {
     #[derive(Serialize)]
-    struct YamlInfo {
+    struct YamlInfo<'a> {
         id: String,
         status: String,
         path: String,
@@ -501,11 +665,13 @@ fn output_yaml(info: &ThreadInfoData) -> Result<(), String> {
         todo_done: usize,
         deadline_count: usize,
         event_count: usize,
-        git_history: Vec<String>,
+        links: &'a [ResolvedLink],
+        git_history: Vec<CommitOutput>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        log: Option<&'a [LogEntry]>,
     }
 
-    // Convert GitLogEntry to strings for YAML output
-    let history_strings: Vec<String> = info.git_history.iter().map(|e| e.to_string()).collect();
+    let history: Vec<CommitOutput> = info.git_history.iter().map(CommitOutput::from).collect();
 
     let output = YamlInfo {
         id: info.id.clone(),
@@ -524,7 +690,9 @@ fn output_yaml(info: &ThreadInfoData) -> Result<(), String> {
         todo_done: info.todo_done,
         deadline_count: info.deadline_count,
         event_count: info.event_count,
-        git_history: history_strings,
+        links: &info.links,
+        git_history: history,
+        log: info.log.as_deref(),
     };
 
     let yaml =
@@ -535,21 +703,26 @@ fn output_yaml(info: &ThreadInfoData) -> Result<(), String> {
 
 /// Get timestamps from git history.
 /// Created = initial commit (last in history), Updated = most recent commit (first in history).
-/// Falls back to filesystem times for uncommitted files.
+/// Uncommitted files use the frontmatter `created`/`updated` fields, then filesystem times.
 fn get_timestamps_from_history(
     history: &[GitLogEntry],
+    thread: &Thread,
     path: &Path,
 ) -> (Option<DateTime<Local>>, Option<DateTime<Local>>) {
     if history.is_empty() {
-        // No git history - use filesystem times
-        let metadata = match fs::metadata(path) {
-            Ok(m) => m,
-            Err(_) => return (None, None),
-        };
-        let updated: Option<DateTime<Local>> = metadata.modified().ok().map(|t| t.into());
-        let created: Option<DateTime<Local>> =
-            metadata.created().ok().map(|t| t.into()).or(updated);
-        return (created, updated);
+        // No git history - use frontmatter, then filesystem times
+        let metadata = fs::metadata(path).ok();
+        let updated: Option<DateTime<Local>> = thread.updated_at().or_else(|| {
+            metadata
+                .as_ref()
+                .and_then(|m| m.modified().ok())
+                .map(|t| t.into())
+        });
+        let created: Option<DateTime<Local>> = thread
+            .created_at()
+            .or_else(|| metadata.and_then(|m| m.created().ok()).map(|t| t.into()))
+            .or(updated);
+        return (created, updated.or(created));
     }
 
     // Most recent commit = first entry (updated)
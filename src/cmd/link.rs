@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::args::FormatArgs;
+use crate::config::{Config, auto_commit_enabled, is_quiet};
+use crate::git;
+use crate::output::{self, OutputFormat};
+use crate::thread::{self, Link, Thread};
+use crate::workspace;
+
+#[derive(Args)]
+pub struct LinkArgs {
+    /// Thread ID or name reference
+    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    id: String,
+
+    /// Record that this thread is blocked by another
+    #[arg(long, value_name = "REF", conflicts_with_all = ["relates_to", "rm"])]
+    blocked_by: Option<String>,
+
+    /// Record that this thread relates to another
+    #[arg(long, value_name = "REF", conflicts_with = "rm")]
+    relates_to: Option<String>,
+
+    /// Remove all links to a thread
+    #[arg(long, value_name = "REF")]
+    rm: Option<String>,
+
+    #[command(flatten)]
+    format: FormatArgs,
+
+    /// Commit after editing
+    #[arg(long)]
+    commit: bool,
+
+    /// Commit message
+    #[arg(short = 'm', long)]
+    message: Option<String>,
+}
+
+/// A link with its target's current name and status (None when the target is gone)
+#[derive(Serialize)]
+pub struct ResolvedLink {
+    pub kind: String,
+    pub target: String,
+    pub name: Option<String>,
+    pub status: Option<String>,
+}
+
+pub fn run(args: LinkArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    let file = workspace::find_by_ref(ws, &args.id)?;
+    let mut t = Thread::parse(&file)?;
+
+    let change = match (&args.blocked_by, &args.relates_to, &args.rm) {
+        (Some(target), _, _) => Some(("blocked-by", target)),
+        (None, Some(target), _) => Some(("relates-to", target)),
+        _ => None,
+    };
+
+    if let Some((kind, target_ref)) = change {
+        let target = target_id(ws, target_ref)?;
+        if !t.add_link(kind, &target)? {
+            println!("Already linked: {} {}", kind, target);
+            return Ok(());
+        }
+        println!("Linked: {} {}", kind, target);
+    } else if let Some(target_ref) = &args.rm {
+        // A dangling target no longer resolves, so fall back to the raw ID
+        let target = match target_id(ws, target_ref) {
+            Ok(id) if t.links().iter().any(|l| l.target == id) => id,
+            _ => target_ref.clone(),
+        };
+        let removed = t.remove_links_to(&target)?;
+        println!(
+            "Removed {} link{} to {}",
+            removed,
+            if removed == 1 { "" } else { "s" },
+            target
+        );
+    } else {
+        return list_links(&t, ws, args.format.resolve());
+    }
+
+    t.write()?;
+
+    let committed = if args.commit || auto_commit_enabled(config) {
+        let repo = workspace::open()?;
+        let msg = args
+            .message
+            .unwrap_or_else(|| format!("threads: link {}", t.id()));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
+        output::print_uncommitted_hint(t.id());
+    }
+
+    Ok(())
+}
+
+/// Resolve a thread reference to the ID stored in a link.
+fn target_id(ws: &Path, target_ref: &str) -> Result<String, String> {
+    let path = workspace::find_by_ref(ws, target_ref).map_err(|e| format!("link target: {}", e))?;
+    let target = Thread::parse(&path)?;
+    Ok(target.id().to_string())
+}
+
+fn list_links(t: &Thread, ws: &Path, format: OutputFormat) -> Result<(), String> {
+    let links = resolve_links(ws, t.links());
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            println!(
+                "{}",
+                output::to_json(&links, format).map_err(|e| e.to_string())?
+            );
+        }
+        OutputFormat::Yaml => {
+            print!(
+                "{}",
+                serde_yaml::to_string(&links).map_err(|e| e.to_string())?
+            );
+        }
+        _ => {
+            if links.is_empty() {
+                println!("No links.");
+            }
+            for line in format_links(&links, format == OutputFormat::Pretty) {
+                println!("{}", line);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Look up each link target's current name and status.
+pub fn resolve_links(ws: &Path, links: &[Link]) -> Vec<ResolvedLink> {
+    if links.is_empty() {
+        return Vec::new();
+    }
+    let paths: HashMap<String, _> = workspace::find_all_threads(ws)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|p| thread::extract_id_from_path(&p).map(|id| (id, p)))
+        .collect();
+
+    links
+        .iter()
+        .map(|link| {
+            let target = paths.get(&link.target).and_then(|p| Thread::parse(p).ok());
+            ResolvedLink {
+                kind: link.kind.clone(),
+                target: link.target.clone(),
+                name: target.as_ref().map(|t| t.name().to_string()),
+                status: target.as_ref().map(|t| t.base_status()),
+            }
+        })
+        .collect()
+}
+
+/// One line per link: "blocked-by abc123 Title [status]", or "[missing]" for a dangling target.
+pub fn format_links(links: &[ResolvedLink], styled: bool) -> Vec<String> {
+    links
+        .iter()
+        .map(|l| {
+            let (name, status) = match (&l.name, &l.status) {
+                (Some(name), Some(status)) if styled => {
+                    (name.clone(), output::style_status(status).to_string())
+                }
+                (Some(name), Some(status)) => (name.clone(), status.clone()),
+                _ if styled => (String::new(), "missing".red().to_string()),
+                _ => (String::new(), "missing".to_string()),
+            };
+            let target = if styled {
+                output::style_id(&l.target).to_string()
+            } else {
+                l.target.clone()
+            };
+            let name = if name.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", name)
+            };
+            format!("{} {}{} [{}]", l.kind, target, name, status)
+        })
+        .collect()
+}
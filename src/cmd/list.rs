@@ -1,12 +1,16 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Local, NaiveDate, Utc};
-use clap::Args;
+use clap::{Args, ValueEnum};
 use colored::Colorize;
 use serde::Serialize;
+use serde::ser::SerializeMap;
+use tabled::builder::Builder;
 use tabled::settings::Style;
-use tabled::{Table, Tabled};
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
 use crate::cache::TimestampCache;
@@ -14,6 +18,7 @@ use crate::config::{Config, is_quiet, root_name};
 use crate::git;
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, Thread};
+use crate::timeutil;
 use crate::workspace;
 
 #[derive(Args)]
@@ -22,6 +27,10 @@ pub struct ListArgs {
     #[arg(default_value = "")]
     path: String,
 
+    /// List every thread in the repo, from git root down, regardless of PWD
+    #[arg(long, visible_alias = "whole-repo", conflicts_with_all = ["path", "down", "up"])]
+    flat: bool,
+
     #[command(flatten)]
     direction: DirectionArgs,
 
@@ -36,8 +45,254 @@ pub struct ListArgs {
     #[arg(long)]
     status: Option<String>,
 
+    /// Only threads carrying all of these tags (comma-separated)
+    #[arg(long, value_name = "TAGS")]
+    tag: Option<String>,
+
+    /// Only threads whose next deadline is on or before DATE (YYYY-MM-DD, 7d, 2w)
+    #[arg(long, value_name = "DATE")]
+    due_before: Option<String>,
+
+    /// Only threads whose next deadline is on or after DATE (YYYY-MM-DD, 7d, 2w)
+    #[arg(long, value_name = "DATE")]
+    due_after: Option<String>,
+
     #[command(flatten)]
     format: FormatArgs,
+
+    /// Show created/modified as YYYY-MM-DD instead of relative times
+    #[arg(long)]
+    absolute_dates: bool,
+
+    /// Tab-separated output: header plus one line per thread, same columns as plain.
+    /// Tabs and newlines inside fields are replaced with spaces.
+    #[arg(long)]
+    tsv: bool,
+
+    /// RFC 4180 CSV output: a header of JSON key names (id, status, ...) plus
+    /// one record per thread, with ISO 8601 created/updated
+    #[arg(long, conflicts_with = "tsv")]
+    csv: bool,
+
+    /// Show threads as a directory tree instead of a table (pretty output only)
+    #[arg(long, conflicts_with_all = ["tsv", "csv"])]
+    tree: bool,
+
+    /// Sort keys, comma-chained, each optionally suffixed with :asc or :desc
+    /// (e.g. `status,updated:desc`). Keys: created, updated, status, title,
+    /// due, id, priority. Dates default to newest first, the rest ascending
+    #[arg(
+        long,
+        value_name = "KEYS",
+        value_delimiter = ',',
+        value_parser = parse_sort_key,
+        default_value = "updated:desc"
+    )]
+    sort: Vec<SortKey>,
+
+    /// Columns to show, in order (e.g. `id,title,due`), for every format.
+    /// Fields: id, status, priority, created, updated, path, git, due, title
+    #[arg(
+        long,
+        value_name = "FIELDS",
+        value_delimiter = ',',
+        value_parser = parse_field,
+        conflicts_with = "tree"
+    )]
+    fields: Option<Vec<ListField>>,
+
+    /// Print pretty output directly instead of through the pager
+    #[arg(long)]
+    no_pager: bool,
+}
+
+/// Fields `list` can sort on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ListSort {
+    Created,
+    Updated,
+    /// In the configured status.open then status.closed order
+    Status,
+    Title,
+    /// Nearest upcoming deadline; threads without one last
+    Due,
+    Id,
+    /// Most urgent priority first; threads without one last
+    Priority,
+}
+
+/// One `--sort` key and its direction.
+#[derive(Clone, Copy, Debug)]
+struct SortKey {
+    field: ListSort,
+    descending: bool,
+}
+
+fn parse_sort_key(s: &str) -> Result<SortKey, String> {
+    let (name, dir) = match s.split_once(':') {
+        Some((name, dir)) => (name, Some(dir)),
+        None => (s, None),
+    };
+    let field = ListSort::from_str(name.trim(), true).map_err(|_| {
+        format!(
+            "unknown sort key '{}': use created, updated, status, title, due, id, priority",
+            name
+        )
+    })?;
+    let descending = match dir.map(str::trim) {
+        None => matches!(field, ListSort::Created | ListSort::Updated),
+        Some("asc") => false,
+        Some("desc") => true,
+        Some(other) => {
+            return Err(format!(
+                "invalid sort direction '{}': use asc or desc",
+                other
+            ));
+        }
+    };
+    Ok(SortKey { field, descending })
+}
+
+impl SortKey {
+    fn compare(&self, a: &ThreadInfo, b: &ThreadInfo, statuses: &[String]) -> Ordering {
+        let status_rank = |t: &ThreadInfo| {
+            statuses
+                .iter()
+                .position(|s| *s == t.status)
+                .unwrap_or(statuses.len())
+        };
+        let ord = match self.field {
+            ListSort::Created => a.created_ts().cmp(&b.created_ts()),
+            ListSort::Updated => a.updated_ts().cmp(&b.updated_ts()),
+            ListSort::Status => status_rank(a)
+                .cmp(&status_rank(b))
+                .then_with(|| a.status.cmp(&b.status)),
+            ListSort::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            ListSort::Due => match (&a.due, &b.due) {
+                (Some(x), Some(y)) => x.cmp(y),
+                // Undated threads stay last in either direction
+                (Some(_), None) => return Ordering::Less,
+                (None, Some(_)) => return Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            ListSort::Id => a.id.cmp(&b.id),
+            ListSort::Priority => a.priority_rank().cmp(&b.priority_rank()),
+        };
+        if self.descending { ord.reverse() } else { ord }
+    }
+}
+
+/// Columns `--fields` can select.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum ListField {
+    Id,
+    Status,
+    Priority,
+    Created,
+    #[value(alias = "modified")]
+    Updated,
+    Path,
+    #[value(alias = "git_status")]
+    Git,
+    Due,
+    Title,
+}
+
+/// Pretty table columns without `--fields`.
+const TABLE_FIELDS: &[ListField] = &[
+    ListField::Id,
+    ListField::Status,
+    ListField::Priority,
+    ListField::Created,
+    ListField::Updated,
+    ListField::Path,
+    ListField::Git,
+    ListField::Due,
+    ListField::Title,
+];
+
+/// Plain and TSV columns without `--fields`.
+const PLAIN_FIELDS: &[ListField] = &[
+    ListField::Id,
+    ListField::Status,
+    ListField::Created,
+    ListField::Updated,
+    ListField::Path,
+    ListField::Git,
+    ListField::Due,
+    ListField::Title,
+];
+
+fn parse_field(s: &str) -> Result<ListField, String> {
+    ListField::from_str(s.trim(), true).map_err(|_| {
+        format!(
+            "unknown field '{}': use id, status, priority, created, updated, path, git, due, title",
+            s
+        )
+    })
+}
+
+impl ListField {
+    fn table_header(self) -> &'static str {
+        match self {
+            ListField::Id => "ID",
+            ListField::Status => "STATUS",
+            ListField::Priority => "PRIO",
+            ListField::Created => "NEW",
+            ListField::Updated => "MOD",
+            ListField::Path => "PATH",
+            ListField::Git => "GIT",
+            ListField::Due => "DUE",
+            ListField::Title => "TITLE",
+        }
+    }
+
+    fn plain_header(self) -> &'static str {
+        match self {
+            ListField::Priority => "PRIORITY",
+            ListField::Created => "CREATED",
+            ListField::Updated => "UPDATED",
+            _ => self.table_header(),
+        }
+    }
+
+    /// The matching key in the JSON/YAML thread object
+    fn json_key(self) -> &'static str {
+        match self {
+            ListField::Id => "id",
+            ListField::Status => "status",
+            ListField::Priority => "priority",
+            ListField::Created => "created",
+            ListField::Updated => "updated",
+            ListField::Path => "path",
+            ListField::Git => "git_status",
+            ListField::Due => "due",
+            ListField::Title => "title",
+        }
+    }
+
+    fn plain_value(self, t: &ThreadInfo) -> String {
+        match self {
+            ListField::Id => t.id.clone(),
+            ListField::Status => t.status.clone(),
+            ListField::Priority => t.priority.clone().unwrap_or_default(),
+            ListField::Created => t.created_plain(),
+            ListField::Updated => t.updated_plain(),
+            ListField::Path => t.path.clone(),
+            ListField::Git => t.git_status.clone().unwrap_or_default(),
+            ListField::Due => t.due.clone().unwrap_or_default(),
+            ListField::Title => t.title.clone(),
+        }
+    }
+
+    /// Like `plain_value`, but with the JSON output's ISO 8601 dates
+    fn csv_value(self, t: &ThreadInfo) -> String {
+        match self {
+            ListField::Created => t.created_iso(),
+            ListField::Updated => t.updated_iso(),
+            _ => self.plain_value(t),
+        }
+    }
 }
 
 #[derive(Serialize, Clone)]
@@ -48,6 +303,9 @@ struct ThreadInfo {
     name: String,
     title: String,
     desc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    tags: Vec<String>,
     #[serde(skip)]
     created_dt: Option<DateTime<Local>>,
     #[serde(skip)]
@@ -62,6 +320,9 @@ struct ThreadInfo {
     /// Nearest upcoming deadline date (YYYY-MM-DD), or None
     #[serde(skip_serializing_if = "Option::is_none")]
     due: Option<String>,
+    /// Whether that deadline recurs
+    #[serde(skip)]
+    due_recurring: bool,
 }
 
 impl ThreadInfo {
@@ -104,17 +365,38 @@ impl ThreadInfo {
             .unwrap_or_default()
     }
 
+    fn created_ts(&self) -> i64 {
+        self.created_dt.map(|dt| dt.timestamp()).unwrap_or(0)
+    }
+
     /// Get timestamp for sorting (most recent first)
     fn updated_ts(&self) -> i64 {
         self.updated_dt.map(|dt| dt.timestamp()).unwrap_or(0)
     }
+
+    /// Priority rank for sorting; missing or unknown priorities sort last
+    fn priority_rank(&self) -> usize {
+        self.priority
+            .as_deref()
+            .and_then(thread::priority_rank)
+            .unwrap_or(thread::PRIORITIES.len())
+    }
 }
 
-pub fn run(args: ListArgs, git_root: &Path, config: &Config) -> Result<(), String> {
+pub fn run(mut args: ListArgs, git_root: &Path, config: &Config) -> Result<(), String> {
     // Open repository for git-based timestamps
     let repo = workspace::open()?;
 
     let format = args.format.resolve();
+    if args.tree && format != OutputFormat::Pretty {
+        return Err("--tree is only available with pretty output".to_string());
+    }
+
+    // --flat: search the whole repo from git root, as if `list <root> --down` were given
+    if args.flat {
+        args.path = git_root.to_string_lossy().to_string();
+        args.direction.down = Some(None);
+    }
 
     // Parse path filter if provided
     let path_filter = if args.path.is_empty() {
@@ -123,23 +405,30 @@ pub fn run(args: ListArgs, git_root: &Path, config: &Config) -> Result<(), Strin
         Some(args.path.as_str())
     };
 
-    // Resolve the scope to understand current context
-    let scope = workspace::infer_scope(git_root, path_filter)?;
-    let filter_path = scope.path.clone();
-    let start_path = scope.threads_dir.parent().unwrap_or(git_root);
+    let (filter_path, threads) = find_in_scope(git_root, path_filter, &args.direction)?;
 
-    // Convert direction args to find options
-    let options = args.direction.to_find_options();
+    let tag_filter = match args.tag.as_deref() {
+        Some(tags) => thread::parse_tag_filter(tags)?,
+        None => Vec::new(),
+    };
+
+    // Deadline window, as YYYY-MM-DD strings to compare against `due`
+    let today = Local::now().date_naive();
+    let due_bound = |arg: &Option<String>| -> Result<Option<String>, String> {
+        arg.as_deref()
+            .map(|s| timeutil::resolve_date(s, today).map(|d| d.format("%Y-%m-%d").to_string()))
+            .transpose()
+    };
+    let due_before = due_bound(&args.due_before)?;
+    let due_after = due_bound(&args.due_after)?;
 
-    // Find threads using options
-    let threads = workspace::find_threads_with_options(start_path, git_root, &options)?;
     let mut results = Vec::new();
 
     // Get PWD relative path for comparison
     let pwd_rel = workspace::pwd_relative_to_git_root(git_root).unwrap_or_else(|_| ".".to_string());
 
     // Determine if we need absolute paths (for json/yaml)
-    let include_absolute = matches!(format, OutputFormat::Json | OutputFormat::Yaml);
+    let include_absolute = format.is_structured();
 
     // Load and update timestamp cache
     let mut cache = TimestampCache::load(git_root);
@@ -148,31 +437,26 @@ pub fn run(args: ListArgs, git_root: &Path, config: &Config) -> Result<(), Strin
     // Save cache (ignore errors - cache is optional)
     let _ = cache.save(git_root);
 
-    for thread_path in threads {
-        let t = match Thread::parse(&thread_path) {
-            Ok(t) => t,
-            Err(_) => continue,
-        };
-
-        let rel_path = workspace::parse_thread_path(git_root, &thread_path);
-        let status = t.status().to_string();
-        let base_status = thread::base_status(&status);
+    let selected = select_threads(
+        &threads,
+        git_root,
+        &filter_path,
+        &args.direction,
+        &args.filter,
+        args.status.as_deref(),
+        false,
+    )?;
+    for Selected {
+        path: thread_path,
+        rel_path,
+        thread: t,
+    } in selected
+    {
+        let base_status = thread::base_status(t.status());
         let name = thread::extract_name_from_path(&thread_path);
 
-        // Path filter: if not searching, only show threads at the specified level
-        if !args.direction.is_searching() && rel_path != filter_path {
-            continue;
-        }
-        // Note: find_threads_with_options already handles direction/depth filtering
-
-        // Status filter
-        let include_closed = args.filter.include_closed();
-        if let Some(ref status_filter) = args.status {
-            let filter_statuses: Vec<&str> = status_filter.split(',').collect();
-            if !filter_statuses.contains(&base_status.as_str()) {
-                continue;
-            }
-        } else if !include_closed && thread::is_closed(&status) {
+        // Tag filter: every requested tag must be present
+        if !t.has_tags(&tag_filter) {
             continue;
         }
 
@@ -203,22 +487,34 @@ pub fn run(args: ListArgs, git_root: &Path, config: &Config) -> Result<(), Strin
         // Get timestamps from cache, with fallback for uncommitted files
         let thread_rel_path = thread_path.strip_prefix(git_root).unwrap_or(&thread_path);
         let thread_rel_str = thread_rel_path.to_string_lossy();
-        let (created_dt, updated_dt) = get_timestamps(&repo, &cache, &thread_path, &thread_rel_str);
+        let (created_dt, updated_dt) =
+            get_timestamps(&repo, &cache, &t, &thread_path, &thread_rel_str);
 
         // Get git file status
         let file_status = git::file_status(&repo, thread_rel_path);
         let git_status_str = format_git_status(&file_status);
 
         // Nearest upcoming deadline
-        let today_str = Local::now().date_naive().format("%Y-%m-%d").to_string();
-        let due = {
-            let deadlines = t.get_deadlines();
-            deadlines
-                .into_iter()
-                .map(|d| d.date)
-                .filter(|date| date.as_str() >= today_str.as_str())
-                .min()
-        };
+        let today_str = today.format("%Y-%m-%d").to_string();
+        let next_deadline = t
+            .get_deadlines()
+            .into_iter()
+            .filter(|d| d.date.as_str() >= today_str.as_str())
+            .min_by(|a, b| a.date.cmp(&b.date));
+        let due_recurring = next_deadline.as_ref().is_some_and(|d| d.every.is_some());
+        let due = next_deadline.map(|d| d.date);
+
+        // --due-before/--due-after: threads without an upcoming deadline never match
+        if due_before.is_some() || due_after.is_some() {
+            let Some(d) = due.as_deref() else {
+                continue;
+            };
+            if due_before.as_deref().is_some_and(|b| d > b)
+                || due_after.as_deref().is_some_and(|a| d < a)
+            {
+                continue;
+            }
+        }
 
         results.push(ThreadInfo {
             id: t.id().to_string(),
@@ -227,6 +523,8 @@ pub fn run(args: ListArgs, git_root: &Path, config: &Config) -> Result<(), Strin
             name,
             title,
             desc: t.frontmatter.desc.clone(),
+            priority: t.get_priority().map(str::to_string),
+            tags: t.tags().to_vec(),
             created_dt,
             updated_dt,
             path_absolute: if include_absolute {
@@ -241,61 +539,189 @@ pub fn run(args: ListArgs, git_root: &Path, config: &Config) -> Result<(), Strin
                 Some(git_status_str.to_string())
             },
             due,
+            due_recurring,
         });
     }
 
-    // Sort by updated timestamp, most recent first
+    // Most recently updated first, then a stable sort on the --sort keys, so
+    // threads that tie on every key keep that order
     results.sort_by_key(|t| std::cmp::Reverse(t.updated_ts()));
+    let statuses: Vec<String> = config
+        .status
+        .open
+        .iter()
+        .chain(&config.status.closed)
+        .cloned()
+        .collect();
+    results.sort_by(|a, b| {
+        args.sort.iter().fold(Ordering::Equal, |ord, key| {
+            ord.then_with(|| key.compare(a, b, &statuses))
+        })
+    });
 
     let include_closed = args.filter.include_closed();
 
+    if let Some(fields) = &mut args.fields {
+        let mut seen = Vec::new();
+        fields.retain(|f| {
+            let new = !seen.contains(f);
+            seen.push(*f);
+            new
+        });
+    }
+    let fields = args.fields.as_deref();
+
+    if args.tsv {
+        return output_tsv(&results, fields);
+    }
+    if args.csv {
+        return output_csv(&results, fields);
+    }
+
     match format {
-        OutputFormat::Pretty => output_pretty(
-            &results,
-            git_root,
-            &filter_path,
-            &pwd_rel,
-            &args.direction,
-            include_closed,
-            args.status.as_deref(),
-            config,
-        ),
+        OutputFormat::Pretty => {
+            let out = render_pretty(
+                &results,
+                git_root,
+                &filter_path,
+                &pwd_rel,
+                &args.direction,
+                args.flat,
+                include_closed,
+                args.status.as_deref(),
+                args.absolute_dates || config.display.absolute_dates,
+                args.tree,
+                fields,
+                config,
+            );
+            output::page(&out, !args.no_pager);
+            Ok(())
+        }
         OutputFormat::Plain => output_plain(
             &results,
             git_root,
             &filter_path,
             &pwd_rel,
             &args.direction,
+            args.flat,
             include_closed,
             args.status.as_deref(),
+            fields,
             config,
         ),
-        OutputFormat::Json => output_json(&results, git_root, &pwd_rel),
-        OutputFormat::Yaml => output_yaml(&results, git_root, &pwd_rel),
+        OutputFormat::Json => output_json(&results, git_root, &pwd_rel, fields),
+        OutputFormat::Ndjson => output_ndjson(&results, git_root, &pwd_rel, fields),
+        OutputFormat::Yaml => output_yaml(&results, git_root, &pwd_rel, fields),
     }
 }
 
-/// Row data for tabled output
-#[derive(Tabled)]
+/// Thread files that `list` would consider for `path` (PWD when None) and
+/// the direction flags, plus the scope's git-root-relative path.
+pub(crate) fn find_in_scope(
+    git_root: &Path,
+    path: Option<&str>,
+    direction: &DirectionArgs,
+) -> Result<(String, Vec<PathBuf>), String> {
+    let scope = workspace::infer_scope(git_root, path)?;
+    let start_path = scope.threads_dir.parent().unwrap_or(git_root);
+    let threads =
+        workspace::find_threads_with_options(start_path, git_root, &direction.to_find_options())?;
+    Ok((scope.path, threads))
+}
+
+/// A parsed thread picked by `select_threads`.
+pub(crate) struct Selected {
+    pub path: PathBuf,
+    /// Git-root-relative directory holding the thread's `.threads/`
+    pub rel_path: String,
+    pub thread: Thread,
+}
+
+/// Parse `threads` and keep the ones at the scope level (unless searching up
+/// or down) whose status matches `status` (comma-separated), or that are open
+/// unless closed threads are included.
+///
+/// Unparseable files are skipped, or with `strict` fail the whole selection.
+pub(crate) fn select_threads(
+    threads: &[PathBuf],
+    git_root: &Path,
+    filter_path: &str,
+    direction: &DirectionArgs,
+    filter: &FilterArgs,
+    status: Option<&str>,
+    strict: bool,
+) -> Result<Vec<Selected>, String> {
+    let include_closed = filter.include_closed();
+    let mut selected = Vec::new();
+
+    for thread_path in threads {
+        let t = match Thread::parse(thread_path) {
+            Ok(t) => t,
+            Err(e) if strict => {
+                return Err(format!(
+                    "{}: {}",
+                    workspace::path_relative_to_git_root(git_root, thread_path),
+                    e
+                ));
+            }
+            Err(_) => continue,
+        };
+
+        // Path filter: if not searching, only keep threads at the specified level
+        // (find_threads_with_options already handles direction/depth filtering)
+        let rel_path = workspace::parse_thread_path(git_root, thread_path);
+        if !direction.is_searching() && rel_path != filter_path {
+            continue;
+        }
+
+        let base_status = thread::base_status(t.status());
+        if let Some(status_filter) = status {
+            if !status_filter.split(',').any(|s| s == base_status) {
+                continue;
+            }
+        } else if !include_closed && thread::is_closed(t.status()) {
+            continue;
+        }
+
+        selected.push(Selected {
+            path: thread_path.clone(),
+            rel_path,
+            thread: t,
+        });
+    }
+
+    Ok(selected)
+}
+
+/// Styled cells of one pretty table row
 struct TableRow {
-    #[tabled(rename = "ID")]
     id: String,
-    #[tabled(rename = "STATUS")]
     status: String,
-    #[tabled(rename = "NEW")]
+    priority: String,
     created: String,
-    #[tabled(rename = "MOD")]
     modified: String,
-    #[tabled(rename = "PATH")]
     path: String,
-    #[tabled(rename = "GIT")]
     git_status: String,
-    #[tabled(rename = "DUE")]
     due: String,
-    #[tabled(rename = "TITLE")]
     title: String,
 }
 
+impl TableRow {
+    fn cell(&self, field: ListField) -> &str {
+        match field {
+            ListField::Id => &self.id,
+            ListField::Status => &self.status,
+            ListField::Priority => &self.priority,
+            ListField::Created => &self.created,
+            ListField::Updated => &self.modified,
+            ListField::Path => &self.path,
+            ListField::Git => &self.git_status,
+            ListField::Due => &self.due,
+            ListField::Title => &self.title,
+        }
+    }
+}
+
 /// Format git file status as short code for list display
 fn format_git_status(status: &git::FileStatus) -> &'static str {
     match status {
@@ -338,6 +764,7 @@ fn build_filter_desc(
     status_filter: Option<&str>,
     search: Option<&str>,
     direction: &DirectionArgs,
+    flat: bool,
 ) -> String {
     let mut parts = Vec::new();
 
@@ -356,25 +783,36 @@ fn build_filter_desc(
     }
 
     // Direction
-    let dir_desc = direction.description();
-    if !dir_desc.is_empty() {
-        parts.push(dir_desc);
+    if flat {
+        parts.push("whole repo, ignoring PWD".to_string());
+    } else {
+        let dir_desc = direction.description();
+        if !dir_desc.is_empty() {
+            parts.push(dir_desc);
+        }
     }
 
     parts.join(", ")
 }
 
+/// Render the header and table (or tree), for printing through the pager.
 #[allow(clippy::too_many_arguments)]
-fn output_pretty(
+fn render_pretty(
     results: &[ThreadInfo],
     git_root: &Path,
     filter_path: &str,
     pwd_rel: &str,
     direction: &DirectionArgs,
+    flat: bool,
     include_closed: bool,
     status_filter: Option<&str>,
+    absolute_dates: bool,
+    tree: bool,
+    fields: Option<&[ListField]>,
     config: &Config,
-) -> Result<(), String> {
+) -> String {
+    let mut out = String::new();
+
     // Header: repo-name (path) with PWD marker
     let repo_name = git_root
         .file_name()
@@ -394,30 +832,39 @@ fn output_pretty(
         String::new()
     };
 
-    println!("{}{}{}", repo_name.bold(), path_desc.dimmed(), pwd_marker);
+    out.push_str(&format!(
+        "{}{}{}\n",
+        repo_name.bold(),
+        path_desc.dimmed(),
+        pwd_marker
+    ));
 
     // Filter disclosure - always show what filters are active
-    let filter_desc = build_filter_desc(include_closed, status_filter, None, direction);
-    println!(
-        "{} threads ({})",
+    let filter_desc = build_filter_desc(include_closed, status_filter, None, direction, flat);
+    out.push_str(&format!(
+        "{} threads ({})\n\n",
         results.len().to_string().bold(),
         filter_desc.dimmed()
-    );
-    println!();
+    ));
 
     if results.is_empty() {
         if !direction.is_searching() && !is_quiet(config) {
-            println!(
-                "{}",
-                "Hint: use --down to include nested directories, --up to search parents".dimmed()
-            );
+            out.push_str(&format!(
+                "{}\n",
+                "Hint: use --down to include nested directories, --up to search parents, --flat for the whole repo".dimmed()
+            ));
         }
-        return Ok(());
+        return out;
+    }
+
+    if tree {
+        out.push_str(&render_tree(results, pwd_rel));
+        return out;
     }
 
     // Build table rows
     let term_width = output::terminal_width();
-    let title_max = term_width.saturating_sub(70).max(20); // Leave room for other columns (added NEW, DUE columns)
+    let title_max = term_width.saturating_sub(79).max(20); // Leave room for other columns (added NEW, DUE, PRIO columns)
     let today = Local::now().date_naive();
 
     let rows: Vec<TableRow> = results
@@ -428,27 +875,129 @@ fn output_pretty(
             // PWD paths are bold, others dimmed
             let path_styled = output::style_path(&path_display, t.is_pwd);
 
-            let due_styled = style_due_date(t.due.as_deref(), today);
+            let mut due_styled = style_due_date(t.due.as_deref(), today);
+            if t.due_recurring {
+                due_styled.push_str(" ⟳");
+            }
 
             TableRow {
                 id: output::style_id(&t.id).to_string(),
                 status: output::style_status(&t.status).to_string(),
-                created: t.created_short(),
-                modified: t.updated_short(),
+                priority: t.priority.clone().unwrap_or_default(),
+                created: if absolute_dates {
+                    t.created_plain()
+                } else {
+                    t.created_short()
+                },
+                modified: if absolute_dates {
+                    t.updated_plain()
+                } else {
+                    t.updated_short()
+                },
                 path: path_styled,
                 git_status: t.git_status.clone().unwrap_or_default(),
                 due: due_styled,
-                title: output::truncate_back(&t.title, title_max),
+                title: title_with_tags(&t.title, &t.tags, title_max),
             }
         })
         .collect();
 
-    let mut table = Table::new(rows);
+    let fields = fields.unwrap_or(TABLE_FIELDS);
+    let mut builder = Builder::default();
+    builder.push_record(fields.iter().map(|f| f.table_header()));
+    for row in &rows {
+        builder.push_record(fields.iter().map(|f| row.cell(*f)));
+    }
+    let mut table = builder.build();
     table.with(Style::rounded());
 
-    println!("{}", table);
+    out.push_str(&format!("{}\n", table));
+    out
+}
 
-    Ok(())
+/// Title for the table, followed by `#tag` markers when they fit in `max`.
+fn title_with_tags(title: &str, tags: &[String], max: usize) -> String {
+    if !tags.is_empty() {
+        let suffix: Vec<String> = tags.iter().map(|t| format!("#{}", t)).collect();
+        let full = format!("{} {}", title, suffix.join(" "));
+        if full.chars().count() <= max {
+            return format!("{} {}", title, suffix.join(" ").dimmed());
+        }
+    }
+    output::truncate_back(title, max)
+}
+
+/// Directory node for `list --tree`: threads at this level plus subdirectories.
+#[derive(Default)]
+struct TreeDir<'a> {
+    threads: Vec<&'a ThreadInfo>,
+    dirs: BTreeMap<String, TreeDir<'a>>,
+}
+
+/// Render threads grouped by directory, threads before subdirectories.
+fn render_tree(results: &[ThreadInfo], pwd_rel: &str) -> String {
+    let mut root = TreeDir::default();
+    for t in results {
+        let mut node = &mut root;
+        if t.path != "." {
+            for part in t.path.split('/') {
+                node = node.dirs.entry(part.to_string()).or_default();
+            }
+        }
+        node.threads.push(t);
+    }
+
+    let mut out = format!("{}\n", output::style_path(".", pwd_rel == "."));
+    render_tree_dir(&mut out, &root, "", ".", pwd_rel);
+    out
+}
+
+fn render_tree_dir(out: &mut String, dir: &TreeDir, prefix: &str, path: &str, pwd_rel: &str) {
+    let count = dir.threads.len() + dir.dirs.len();
+    let mut index = 0;
+    let branch = |index: usize| {
+        if index + 1 == count {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        }
+    };
+
+    for t in &dir.threads {
+        let (connector, _) = branch(index);
+        index += 1;
+        out.push_str(&format!(
+            "{}{}{} {} {}\n",
+            prefix.dimmed(),
+            connector.dimmed(),
+            output::style_id(&t.id),
+            output::style_status(&t.status),
+            t.title
+        ));
+    }
+
+    for (name, sub) in &dir.dirs {
+        let (connector, indent) = branch(index);
+        index += 1;
+        let sub_path = if path == "." {
+            name.clone()
+        } else {
+            format!("{}/{}", path, name)
+        };
+        out.push_str(&format!(
+            "{}{}{}\n",
+            prefix.dimmed(),
+            connector.dimmed(),
+            output::style_path(&format!("{}/", name), sub_path == pwd_rel)
+        ));
+        render_tree_dir(
+            out,
+            sub,
+            &format!("{}{}", prefix, indent),
+            &sub_path,
+            pwd_rel,
+        );
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -458,8 +1007,10 @@ fn output_plain(
     filter_path: &str,
     pwd_rel: &str,
     direction: &DirectionArgs,
+    flat: bool,
     include_closed: bool,
     status_filter: Option<&str>,
+    fields: Option<&[ListField]>,
     config: &Config,
 ) -> Result<(), String> {
     // Plain header: explicit context
@@ -485,7 +1036,7 @@ fn output_plain(
     };
 
     // Full filter disclosure
-    let filter_desc = build_filter_desc(include_closed, status_filter, None, direction);
+    let filter_desc = build_filter_desc(include_closed, status_filter, None, direction, flat);
     println!(
         "Showing {} threads in {}{} ({})",
         results.len(),
@@ -497,31 +1048,59 @@ fn output_plain(
 
     if results.is_empty() {
         if !direction.is_searching() && !is_quiet(config) {
-            println!("Hint: use --down to include nested directories, --up to search parents");
+            println!(
+                "Hint: use --down to include nested directories, --up to search parents, --flat for the whole repo"
+            );
         }
         return Ok(());
     }
 
     // Pipe-delimited format, no truncation, full paths
-    println!("ID | STATUS | CREATED | UPDATED | PATH | GIT | DUE | TITLE");
+    let fields = fields.unwrap_or(PLAIN_FIELDS);
+    let header: Vec<&str> = fields.iter().map(|f| f.plain_header()).collect();
+    println!("{}", header.join(" | "));
 
     for t in results {
-        println!(
-            "{} | {} | {} | {} | {} | {} | {} | {}",
-            t.id,
-            t.status,
-            t.created_plain(),
-            t.updated_plain(),
-            t.path,
-            t.git_status.as_deref().unwrap_or(""),
-            t.due.as_deref().unwrap_or(""),
-            t.title
-        );
+        let values: Vec<String> = fields.iter().map(|f| f.plain_value(t)).collect();
+        println!("{}", values.join(" | "));
+    }
+
+    Ok(())
+}
+
+/// Tab-separated output: no context preamble, one record per line.
+fn output_tsv(results: &[ThreadInfo], fields: Option<&[ListField]>) -> Result<(), String> {
+    let fields = fields.unwrap_or(PLAIN_FIELDS);
+    let header: Vec<&str> = fields.iter().map(|f| f.plain_header()).collect();
+    println!("{}", header.join("\t"));
+
+    for t in results {
+        let escaped: Vec<String> = fields
+            .iter()
+            .map(|f| output::tsv_field(&f.plain_value(t)))
+            .collect();
+        println!("{}", escaped.join("\t"));
     }
 
     Ok(())
 }
 
+/// CSV output, quoted per RFC 4180 where fields hold commas, quotes or newlines.
+fn output_csv(results: &[ThreadInfo], fields: Option<&[ListField]>) -> Result<(), String> {
+    let fields = fields.unwrap_or(PLAIN_FIELDS);
+    let mut writer = csv::Writer::from_writer(io::stdout());
+    let to_err = |e: csv::Error| format!("writing CSV: {}", e);
+    writer
+        .write_record(fields.iter().map(|f| f.json_key()))
+        .map_err(to_err)?;
+    for t in results {
+        writer
+            .write_record(fields.iter().map(|f| f.csv_value(t)))
+            .map_err(to_err)?;
+    }
+    writer.flush().map_err(|e| format!("writing CSV: {}", e))
+}
+
 /// Serializable thread info with ISO 8601 dates for JSON/YAML
 #[derive(Serialize)]
 struct ThreadInfoJson {
@@ -531,6 +1110,9 @@ struct ThreadInfoJson {
     name: String,
     title: String,
     desc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    tags: Vec<String>,
     created: String,
     updated: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -552,6 +1134,8 @@ impl From<&ThreadInfo> for ThreadInfoJson {
             name: t.name.clone(),
             title: t.title.clone(),
             desc: t.desc.clone(),
+            priority: t.priority.clone(),
+            tags: t.tags.clone(),
             created: t.created_iso(),
             updated: t.updated_iso(),
             path_absolute: t.path_absolute.clone(),
@@ -562,19 +1146,67 @@ impl From<&ThreadInfo> for ThreadInfoJson {
     }
 }
 
-fn output_json(results: &[ThreadInfo], git_root: &Path, pwd_rel: &str) -> Result<(), String> {
+/// A thread in JSON/YAML output: every key, or just the `--fields` ones.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ThreadEntry {
+    Full(Box<ThreadInfoJson>),
+    Selected(SelectedFields),
+}
+
+/// Requested keys in the requested order; unset values are null, not omitted.
+struct SelectedFields(Vec<(&'static str, serde_json::Value)>);
+
+impl Serialize for SelectedFields {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, value) in &self.0 {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl ThreadEntry {
+    fn new(t: &ThreadInfo, fields: Option<&[ListField]>) -> Self {
+        let full = ThreadInfoJson::from(t);
+        let Some(fields) = fields else {
+            return ThreadEntry::Full(Box::new(full));
+        };
+        let value = serde_json::to_value(&full).unwrap_or_default();
+        ThreadEntry::Selected(SelectedFields(
+            fields
+                .iter()
+                .map(|f| {
+                    let key = f.json_key();
+                    (key, value.get(key).cloned().unwrap_or_default())
+                })
+                .collect(),
+        ))
+    }
+}
+
+fn output_json(
+    results: &[ThreadInfo],
+    git_root: &Path,
+    pwd_rel: &str,
+    fields: Option<&[ListField]>,
+) -> Result<(), String> {
     let pwd = std::env::current_dir()
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| String::new());
 
-    let threads: Vec<ThreadInfoJson> = results.iter().map(ThreadInfoJson::from).collect();
+    let threads: Vec<ThreadEntry> = results
+        .iter()
+        .map(|t| ThreadEntry::new(t, fields))
+        .collect();
 
     #[derive(Serialize)]
     struct JsonOutput {
         pwd: String,
         git_root: String,
         pwd_relative: String,
-        threads: Vec<ThreadInfoJson>,
+        threads: Vec<ThreadEntry>,
     }
 
     let output = JsonOutput {
@@ -590,19 +1222,56 @@ fn output_json(results: &[ThreadInfo], git_root: &Path, pwd_rel: &str) -> Result
     Ok(())
 }
 
-fn output_yaml(results: &[ThreadInfo], git_root: &Path, pwd_rel: &str) -> Result<(), String> {
+/// The JSON envelope's metadata on the first line, then one thread per line.
+fn output_ndjson(
+    results: &[ThreadInfo],
+    git_root: &Path,
+    pwd_rel: &str,
+    fields: Option<&[ListField]>,
+) -> Result<(), String> {
+    let pwd = std::env::current_dir()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| String::new());
+
+    #[derive(Serialize)]
+    struct Metadata {
+        pwd: String,
+        git_root: String,
+        pwd_relative: String,
+    }
+
+    output::print_ndjson(&Metadata {
+        pwd,
+        git_root: git_root.to_string_lossy().to_string(),
+        pwd_relative: pwd_rel.to_string(),
+    })?;
+    for t in results {
+        output::print_ndjson(&ThreadEntry::new(t, fields))?;
+    }
+    Ok(())
+}
+
+fn output_yaml(
+    results: &[ThreadInfo],
+    git_root: &Path,
+    pwd_rel: &str,
+    fields: Option<&[ListField]>,
+) -> Result<(), String> {
     let pwd = std::env::current_dir()
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| String::new());
 
-    let threads: Vec<ThreadInfoJson> = results.iter().map(ThreadInfoJson::from).collect();
+    let threads: Vec<ThreadEntry> = results
+        .iter()
+        .map(|t| ThreadEntry::new(t, fields))
+        .collect();
 
     #[derive(Serialize)]
     struct YamlOutput {
         pwd: String,
         git_root: String,
         pwd_relative: String,
-        threads: Vec<ThreadInfoJson>,
+        threads: Vec<ThreadEntry>,
     }
 
     let output = YamlOutput {
@@ -619,9 +1288,17 @@ fn output_yaml(results: &[ThreadInfo], git_root: &Path, pwd_rel: &str) -> Result
 }
 
 /// Get timestamps from cache, handling uncommitted modifications.
-fn get_timestamps(
+///
+/// Committed, clean files use the last commit date. Files with uncommitted
+/// changes use the filesystem mtime instead, so mutations that are not yet
+/// committed (e.g. a `close` + `reopen`, which both write a log entry) still
+/// move the thread to the top of the default updated-first sort. Files that
+/// were never committed prefer the frontmatter `created`/`updated` fields over
+/// filesystem times, which copies and checkouts reset.
+pub(crate) fn get_timestamps(
     repo: &git2::Repository,
     cache: &TimestampCache,
+    t: &Thread,
     abs_path: &Path,
     rel_path: &str,
 ) -> (Option<DateTime<Local>>, Option<DateTime<Local>>) {
@@ -646,17 +1323,20 @@ fn get_timestamps(
 
         (created_dt, modified_dt)
     } else {
-        // File not in cache (never committed) - use filesystem times
+        // File not in cache (never committed) - use frontmatter, then filesystem times
         let metadata = fs::metadata(abs_path).ok();
 
-        let created_dt: Option<DateTime<Local>> = metadata
-            .as_ref()
-            .and_then(|m| m.created().ok())
-            .map(|t| t.into());
+        let created_dt: Option<DateTime<Local>> = t.created_at().or_else(|| {
+            metadata
+                .as_ref()
+                .and_then(|m| m.created().ok())
+                .map(|t| t.into())
+        });
 
-        let modified_dt: Option<DateTime<Local>> =
-            metadata.and_then(|m| m.modified().ok()).map(|t| t.into());
+        let modified_dt: Option<DateTime<Local>> = t
+            .updated_at()
+            .or_else(|| metadata.and_then(|m| m.modified().ok()).map(|t| t.into()));
 
-        (created_dt.or(modified_dt), modified_dt)
+        (created_dt.or(modified_dt), modified_dt.or(created_dt))
     }
 }
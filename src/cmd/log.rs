@@ -1,16 +1,18 @@
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 
-use chrono::Local;
+use chrono::{Local, NaiveDate};
 use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
 use colored::Colorize;
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::input;
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, Thread};
+use crate::timeutil;
 use crate::workspace;
 
 #[derive(Args)]
@@ -32,6 +34,34 @@ pub struct LogArgs {
     #[command(flatten)]
     format: FormatArgs,
 
+    /// Print the number of log entries in the thread
+    #[arg(long, conflicts_with_all = ["entry", "archive_before"])]
+    count: bool,
+
+    /// Move entries older than this date (YYYY-MM-DD) into an '## Archived Log' body section
+    #[arg(long, value_name = "DATE", conflicts_with = "entry")]
+    archive_before: Option<String>,
+
+    /// Export entries as CSV rows (timestamp,thread_id,text), oldest first
+    #[arg(long, conflicts_with_all = ["entry", "count", "archive_before"])]
+    csv: bool,
+
+    /// With --csv: export every thread in the repo (closed ones included)
+    #[arg(long, requires = "csv")]
+    all: bool,
+
+    /// Show only entries on or after this date (YYYY-MM-DD, or 7d/2w ago)
+    #[arg(long, value_name = "DATE", conflicts_with_all = ["count", "archive_before", "csv"])]
+    since: Option<String>,
+
+    /// Show only entries on or before this date (YYYY-MM-DD, or 7d/2w ago)
+    #[arg(long, value_name = "DATE", conflicts_with_all = ["count", "archive_before", "csv"])]
+    until: Option<String>,
+
+    /// Show only the N most recent entries
+    #[arg(short = 'n', long, value_name = "COUNT", conflicts_with_all = ["count", "archive_before", "csv"])]
+    tail: Option<usize>,
+
     /// Commit after adding
     #[arg(long)]
     commit: bool,
@@ -42,10 +72,62 @@ pub struct LogArgs {
 }
 
 pub fn run(args: LogArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    if args.csv {
+        let files = match (args.all, args.id.is_empty()) {
+            (true, true) => workspace::find_all_threads(ws)?,
+            (false, false) => vec![workspace::find_by_ref(ws, &args.id)?],
+            _ => return Err("usage: threads log <id> --csv | threads log --csv --all".to_string()),
+        };
+        return export_csv(&files);
+    }
+
     if args.id.is_empty() {
+        if args.count || args.archive_before.is_some() {
+            return Err(
+                "usage: threads log <id> --count | threads log <id> --archive-before <date>"
+                    .to_string(),
+            );
+        }
         return run_agenda(&args, ws, config);
     }
 
+    // Reading one thread's log: any read filter without an entry to add
+    let reading = args.since.is_some() || args.until.is_some() || args.tail.is_some();
+    if reading && args.entry.is_empty() {
+        return run_agenda(&args, ws, config);
+    }
+
+    if args.count {
+        let file = workspace::find_by_ref(ws, &args.id)?;
+        let t = Thread::parse(&file)?;
+        println!("{}", t.get_log_entries().len());
+        return Ok(());
+    }
+
+    if let Some(ref date) = args.archive_before {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("invalid date '{}': expected YYYY-MM-DD", date))?;
+
+        let file = workspace::find_by_ref(ws, &args.id)?;
+        let mut t = Thread::parse(&file)?;
+
+        let moved = t.archive_log_before(date)?;
+        if moved == 0 {
+            println!("No log entries before {}.", date);
+            return Ok(());
+        }
+        t.insert_log_entry(&format!("Archived {} log entries before {}", moved, date))?;
+        t.write()?;
+
+        println!(
+            "Archived {} log entries before {} into '## {}'",
+            moved,
+            date,
+            thread::ARCHIVED_LOG_SECTION
+        );
+        return commit_or_hint(&args, t.id(), &file, config);
+    }
+
     let mut entry = args.entry.clone();
 
     // Read entry from stdin if not provided
@@ -67,31 +149,95 @@ pub fn run(args: LogArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     println!("Logged to: {}", file.display());
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    // Suggest archiving once the frontmatter log grows past the configured threshold
+    if let Some(limit) = config.behavior.log_archive_after
+        && t.frontmatter.log.len() > limit
+        && !is_quiet(config)
+    {
+        println!(
+            "Hint: {} log entries (threshold {}). Use 'threads log {} --archive-before <date>' to archive older ones.",
+            t.frontmatter.log.len(),
+            limit,
+            args.id
+        );
+    }
+
+    commit_or_hint(&args, t.id(), &file, config)
+}
+
+/// Write log entries from the given threads to stdout as CSV, sorted chronologically.
+///
+/// Entries without a timestamp keep a blank ts column and sort first.
+fn export_csv(files: &[PathBuf]) -> Result<(), String> {
+    let mut rows: Vec<(String, String, String)> = Vec::new();
+    for path in files {
+        let t = match Thread::parse(path) {
+            Ok(t) => t,
+            Err(_) => continue,
+        };
+        for entry in t.get_log_entries() {
+            rows.push((entry.ts, t.id().to_string(), entry.text));
+        }
+    }
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut writer = csv::Writer::from_writer(io::stdout());
+    let to_err = |e: csv::Error| format!("writing CSV: {}", e);
+    writer
+        .write_record(["timestamp", "thread_id", "text"])
+        .map_err(to_err)?;
+    for (ts, id, text) in &rows {
+        writer.write_record([ts, id, text]).map_err(to_err)?;
+    }
+    writer.flush().map_err(|e| format!("writing CSV: {}", e))
+}
+
+/// Commit the modified thread, or print the uncommitted hint.
+fn commit_or_hint(args: &LogArgs, id: &str, file: &Path, config: &Config) -> Result<(), String> {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
         let msg = args
             .message
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-    } else if !is_quiet(config) {
+            .clone()
+            .unwrap_or_else(|| format!("threads: log {}", id));
+        git::commit_thread(&repo, file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
         output::print_uncommitted_hint(&args.id);
     }
 
     Ok(())
 }
 
-/// Agenda: collect log entries from all threads in scope, sorted by timestamp descending.
+/// Agenda: collect log entries from all threads in scope (or the one thread
+/// named by `args.id`), filtered by --since/--until/--tail and sorted by
+/// timestamp descending.
 fn run_agenda(args: &LogArgs, ws: &Path, _config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
-    let scope = workspace::infer_scope(ws, None)?;
-    let start_path = scope.threads_dir.parent().unwrap_or(ws);
-    let options = args.direction.to_find_options();
-    let thread_files = workspace::find_threads_with_options(start_path, ws, &options)?;
-
-    let include_closed = args.filter.include_closed();
+    let today = Local::now().date_naive();
+    let since = args
+        .since
+        .as_deref()
+        .map(|s| timeutil::resolve_past_date(s, today))
+        .transpose()?;
+    let until = args
+        .until
+        .as_deref()
+        .map(|s| timeutil::resolve_past_date(s, today))
+        .transpose()?;
+
+    let (thread_files, include_closed) = if args.id.is_empty() {
+        let scope = workspace::infer_scope(ws, None)?;
+        let start_path = scope.threads_dir.parent().unwrap_or(ws);
+        let options = args.direction.to_find_options();
+        let files = workspace::find_threads_with_options(start_path, ws, &options)?;
+        (files, args.filter.include_closed())
+    } else {
+        (vec![workspace::find_by_ref(ws, &args.id)?], true)
+    };
 
     struct AgendaItem {
         ts: String,
@@ -132,7 +278,17 @@ fn run_agenda(args: &LogArgs, ws: &Path, _config: &Config) -> Result<(), String>
         }
     }
 
-    if agenda.is_empty() {
+    // Undated entries cannot be placed in a date window, so only show them unfiltered
+    if since.is_some() || until.is_some() {
+        agenda.retain(|a| {
+            let date =
+                a.ts.get(..10)
+                    .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+            date.is_some_and(|d| since.is_none_or(|s| d >= s) && until.is_none_or(|u| d <= u))
+        });
+    }
+
+    if agenda.is_empty() && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
         println!("No log entries found.");
         return Ok(());
     }
@@ -144,9 +300,12 @@ fn run_agenda(args: &LogArgs, ws: &Path, _config: &Config) -> Result<(), String>
         (false, true) => std::cmp::Ordering::Less,
         (false, false) => b.ts.cmp(&a.ts),
     });
+    if let Some(n) = args.tail {
+        agenda.truncate(n);
+    }
 
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             use serde::Serialize;
             #[derive(Serialize)]
             struct JsonItem<'a> {
@@ -168,7 +327,7 @@ fn run_agenda(args: &LogArgs, ws: &Path, _config: &Config) -> Result<(), String>
                 .collect();
             println!(
                 "{}",
-                serde_json::to_string_pretty(&items).map_err(|e| format!("JSON error: {}", e))?
+                output::to_json(&items, format).map_err(|e| format!("JSON error: {}", e))?
             );
         }
         OutputFormat::Yaml => {
@@ -1,10 +1,16 @@
+pub mod agenda;
+pub mod apply;
+pub mod archive;
 pub mod body;
 pub mod cache;
 pub mod config_cmd;
 pub mod deadline;
+pub mod duplicate;
 pub mod event;
+pub mod export;
 pub mod git_cmd;
 pub mod info;
+pub mod link;
 pub mod list;
 pub mod log;
 pub mod migrate;
@@ -14,11 +20,14 @@ pub mod note;
 pub mod path;
 pub mod read;
 pub mod remove;
+pub mod rename;
 pub mod reopen;
 pub mod resolve;
 pub mod search;
 pub mod stats;
 pub mod status;
+pub mod tag;
 pub mod todo;
+pub mod tree;
 pub mod update;
 pub mod validate;
@@ -1,13 +1,15 @@
 use std::fs;
-use std::path::Path;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
 
 use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
+use crate::input;
 use crate::output::{self, OutputFormat};
 use crate::thread::Thread;
 use crate::workspace;
@@ -19,7 +21,25 @@ pub struct MoveArgs {
     id: String,
 
     /// New path (git-root-relative, ./pwd-relative, or absolute)
-    new_path: String,
+    #[arg(required_unless_present_any = ["interactive", "up", "into_subdir"])]
+    new_path: Option<String>,
+
+    /// Move to the parent of the thread's current directory
+    #[arg(long, conflicts_with_all = ["new_path", "interactive", "into_subdir"])]
+    up: bool,
+
+    /// Move into the named child of the thread's current directory
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["new_path", "interactive"])]
+    into_subdir: Option<String>,
+
+    /// Pick the destination from a list of thread directories (needs a terminal
+    /// and the `interactive` feature)
+    #[arg(short = 'i', long, conflicts_with = "new_path")]
+    interactive: bool,
+
+    /// Show the destination and referencing threads without moving
+    #[arg(long, conflicts_with = "commit")]
+    dry_run: bool,
 
     /// Commit after moving
     #[arg(long)]
@@ -40,6 +60,17 @@ struct MoveOutput {
     dest: String,
     scope: String,
     committed: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    dry_run: bool,
+    references: Vec<Reference>,
+}
+
+/// A thread whose `parent` or `links` point at the moved thread
+#[derive(Serialize)]
+struct Reference {
+    id: String,
+    path: String,
+    kind: String,
 }
 
 pub fn run(args: MoveArgs, git_root: &Path, config: &Config) -> Result<(), String> {
@@ -52,15 +83,17 @@ pub fn run(args: MoveArgs, git_root: &Path, config: &Config) -> Result<(), Strin
     let t = Thread::parse(&src_file)?;
     let id = t.id().to_string();
 
-    // Resolve destination scope
-    let scope = workspace::infer_scope(git_root, Some(&args.new_path))
-        .map_err(|e| format!("invalid path '{}': {}", args.new_path, e))?;
+    let new_path = match (args.new_path.clone(), args.into_subdir.as_deref()) {
+        (Some(path), _) => path,
+        (None, Some(name)) => child_destination(git_root, &src_file, name)?,
+        (None, None) if args.up => parent_destination(git_root, &src_file)?,
+        (None, None) => pick_destination(git_root, &src_file)?,
+    };
 
-    // Ensure dest .threads/ exists
-    fs::create_dir_all(&scope.threads_dir)
-        .map_err(|e| format!("creating threads directory: {}", e))?;
+    // Resolve destination scope
+    let scope = workspace::infer_scope(git_root, Some(&new_path))
+        .map_err(|e| format!("invalid path '{}': {}", new_path, e))?;
 
-    // Move file
     let filename = src_file
         .file_name()
         .ok_or_else(|| "invalid source file".to_string())?;
@@ -74,20 +107,26 @@ pub fn run(args: MoveArgs, git_root: &Path, config: &Config) -> Result<(), Strin
     }
 
     let rel_src = workspace::path_relative_to_git_root(git_root, &src_file);
+    // Built from the scope because the destination may not exist yet (--dry-run)
+    let rel_dest = match scope.path.as_str() {
+        "." => format!(".threads/{}", filename.to_string_lossy()),
+        dir => format!("{}/.threads/{}", dir, filename.to_string_lossy()),
+    };
 
-    fs::rename(&src_file, &dest_file).map_err(|e| format!("moving file: {}", e))?;
+    if !args.dry_run {
+        fs::create_dir_all(&scope.threads_dir)
+            .map_err(|e| format!("creating threads directory: {}", e))?;
+        // git mv keeps history attached when the file is clean
+        let repo = workspace::open()?;
+        git::move_file(&repo, git_root, &src_file, &dest_file)?;
+    }
 
-    let rel_dest = workspace::path_relative_to_git_root(git_root, &dest_file);
+    // IDs are stable, so references survive the move; report them anyway
+    let references = find_references(git_root, &id)?;
 
     // Commit if requested or auto-commit enabled
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    let committed = if should_commit {
+    let committed = if !args.dry_run && (args.commit || auto_commit_enabled(config)) {
         let repo = workspace::open()?;
-        let rel_src_path = src_file.strip_prefix(git_root).unwrap_or(&src_file);
-        let rel_dest_path = dest_file.strip_prefix(git_root).unwrap_or(&dest_file);
-
-        git::add(&repo, &[rel_src_path, rel_dest_path])?;
-
         let msg = args.m.unwrap_or_else(|| {
             format!(
                 "threads: move {} to {}",
@@ -96,28 +135,36 @@ pub fn run(args: MoveArgs, git_root: &Path, config: &Config) -> Result<(), Strin
             )
         });
 
-        git::commit(&repo, &[rel_src_path, rel_dest_path], &msg)?;
-        true
+        git::commit_threads(&repo, &[src_file.as_path(), dest_file.as_path()], &msg)?
     } else {
         false
     };
 
     match format {
         OutputFormat::Pretty | OutputFormat::Plain => {
-            println!("Moved: {} → {}", rel_src, rel_dest);
-            if !committed && !is_quiet(config) {
+            let verb = if args.dry_run { "Would move" } else { "Moved" };
+            println!("{}: {} → {}", verb, rel_src, rel_dest);
+            if !references.is_empty() {
+                println!("Referenced by {} thread(s):", references.len());
+                for r in &references {
+                    println!("  {}  {}  ({})", r.id, r.path, r.kind);
+                }
+            }
+            if !args.dry_run && !committed && !is_quiet(config) {
                 output::print_uncommitted_hint(&id);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = MoveOutput {
                 id,
                 source: rel_src,
                 dest: rel_dest,
                 scope: scope.level_desc,
                 committed,
+                dry_run: args.dry_run,
+                references,
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
@@ -128,6 +175,8 @@ pub fn run(args: MoveArgs, git_root: &Path, config: &Config) -> Result<(), Strin
                 dest: rel_dest,
                 scope: scope.level_desc,
                 committed,
+                dry_run: args.dry_run,
+                references,
             };
             let yaml = serde_yaml::to_string(&output)
                 .map_err(|e| format!("YAML serialization failed: {}", e))?;
@@ -137,3 +186,152 @@ pub fn run(args: MoveArgs, git_root: &Path, config: &Config) -> Result<(), Strin
 
     Ok(())
 }
+
+/// Threads whose `parent` or `links` name `id`, one entry per reference.
+/// Threads that fail to parse are skipped.
+fn find_references(git_root: &Path, id: &str) -> Result<Vec<Reference>, String> {
+    let mut refs = Vec::new();
+    for path in workspace::find_all_threads(git_root)? {
+        let Ok(t) = Thread::parse(&path) else {
+            continue;
+        };
+        if t.id() == id {
+            continue;
+        }
+        let kinds = t
+            .parent()
+            .filter(|p| *p == id)
+            .map(|_| "parent".to_string())
+            .into_iter()
+            .chain(
+                t.links()
+                    .iter()
+                    .filter(|l| l.target == id)
+                    .map(|l| l.kind.clone()),
+            );
+        for kind in kinds {
+            refs.push(Reference {
+                id: t.id().to_string(),
+                path: workspace::path_relative_to_git_root(git_root, &path),
+                kind,
+            });
+        }
+    }
+    refs.sort_by(|a, b| (&a.id, &a.kind).cmp(&(&b.id, &b.kind)));
+    Ok(refs)
+}
+
+/// Directory holding the thread's `.threads/`, canonicalized.
+fn current_dir_of(src_file: &Path) -> Result<PathBuf, String> {
+    src_file
+        .parent()
+        .and_then(|p| p.parent())
+        .and_then(|p| p.canonicalize().ok())
+        .ok_or_else(|| format!("cannot resolve directory of {}", src_file.display()))
+}
+
+/// Destination for `--up`: the parent of the thread's current directory.
+/// Returns the directory as an absolute path.
+fn parent_destination(git_root: &Path, src_file: &Path) -> Result<String, String> {
+    let root = git_root
+        .canonicalize()
+        .unwrap_or_else(|_| git_root.to_path_buf());
+    let current = current_dir_of(src_file)?;
+    if current == root {
+        return Err("thread is already at the repo root; --up has nowhere to go".to_string());
+    }
+    let parent = current
+        .parent()
+        .ok_or_else(|| format!("{} has no parent directory", current.display()))?;
+    Ok(parent.to_string_lossy().to_string())
+}
+
+/// Destination for `--into-subdir`: an existing child of the thread's current
+/// directory. Returns the directory as an absolute path.
+fn child_destination(git_root: &Path, src_file: &Path, name: &str) -> Result<String, String> {
+    let single = Path::new(name).components().count() == 1;
+    if !single || name.is_empty() || name == "." || name == ".." || name.starts_with('.') {
+        return Err(format!(
+            "--into-subdir takes a single directory name, got '{}'",
+            name
+        ));
+    }
+    let current = current_dir_of(src_file)?;
+    let child = current.join(name);
+    if !child.is_dir() {
+        return Err(format!(
+            "no directory '{}' in {}",
+            name,
+            workspace::path_relative_to_git_root(git_root, &current)
+        ));
+    }
+    Ok(child.to_string_lossy().to_string())
+}
+
+/// Prompt for a destination among directories that hold or sit next to a `.threads/`.
+/// Returns the chosen directory as an absolute path.
+fn pick_destination(git_root: &Path, src_file: &Path) -> Result<String, String> {
+    if !input::stdin_is_tty() || !io::stderr().is_terminal() {
+        return Err(
+            "--interactive needs a terminal; pass an explicit destination path instead".to_string(),
+        );
+    }
+
+    let root = git_root
+        .canonicalize()
+        .unwrap_or_else(|_| git_root.to_path_buf());
+    let current = src_file
+        .parent()
+        .and_then(|p| p.parent())
+        .and_then(|p| p.canonicalize().ok());
+
+    let mut candidates = candidate_dirs(&root)?;
+    candidates.retain(|d| Some(d) != current.as_ref());
+    if candidates.is_empty() {
+        return Err("no other thread directories to move to; pass an explicit path".to_string());
+    }
+
+    let labels: Vec<String> = candidates
+        .iter()
+        .map(|d| match d.strip_prefix(&root) {
+            Ok(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+            Ok(rel) => rel.to_string_lossy().to_string(),
+            Err(_) => d.to_string_lossy().to_string(),
+        })
+        .collect();
+
+    let choice = input::select("Move to", &labels)
+        .map_err(|e| format!("{}; pass an explicit destination path instead", e))?;
+    Ok(candidates[choice].to_string_lossy().to_string())
+}
+
+/// Directories containing a `.threads/` with threads, the git root, and their
+/// immediate non-hidden subdirectories (stopping at nested git repos).
+fn candidate_dirs(root: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut holders: Vec<PathBuf> = workspace::find_all_threads(root)?
+        .iter()
+        .filter_map(|t| t.parent().and_then(|p| p.parent()))
+        .map(|p| p.to_path_buf())
+        .collect();
+    holders.push(root.to_path_buf());
+    holders.sort();
+    holders.dedup();
+
+    let mut dirs = holders.clone();
+    for dir in &holders {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if path.is_dir() && !hidden && !workspace::is_git_root(&path) {
+                dirs.push(path);
+            }
+        }
+    }
+
+    dirs.sort();
+    dirs.dedup();
+    Ok(dirs)
+}
@@ -1,32 +1,36 @@
 use std::fs;
-use std::path::Path;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
 
+use chrono::Local;
 use clap::Args;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, env_string, is_quiet};
+use crate::config::{Config, auto_commit_enabled, env_string, is_quiet, load_config};
+use crate::fuzzy;
 use crate::git;
 use crate::input;
 use crate::output::{self, OutputFormat};
+use crate::template::{self, TemplateInfo};
 use crate::thread::{self, Thread};
 use crate::workspace;
 
 #[derive(Args)]
 pub struct NewArgs {
-    /// [path] title - Path is optional, title is required
+    /// [path] title - Path is optional, title is required (prompted for at a terminal)
     /// Path resolution:
     ///   (none)  → PWD (current directory)
     ///   .       → PWD (explicit)
     ///   ./X/Y   → PWD-relative
     ///   /X/Y    → Absolute
     ///   X/Y     → Git-root-relative
-    #[arg(required = true, num_args = 1..=2)]
+    #[arg(num_args = 0..=2)]
     args: Vec<String>,
 
-    /// Initial status
-    #[arg(long, default_value = "idea")]
-    status: String,
+    /// Initial status (default: THREADS_DEFAULT_STATUS or config defaults.new)
+    #[arg(long)]
+    status: Option<String>,
 
     /// One-line description
     #[arg(long, default_value = "")]
@@ -36,6 +40,30 @@ pub struct NewArgs {
     #[arg(long, default_value = "")]
     body: String,
 
+    /// Tag to add (repeatable)
+    #[arg(long = "tag", value_name = "TAG")]
+    tags: Vec<String>,
+
+    /// Start the body from a template ({{title}}, {{name}}, {{date}} and {{id}} are filled in)
+    #[arg(long, value_name = "NAME", conflicts_with = "body")]
+    template: Option<String>,
+
+    /// Fill `{{key}}` in the template with value (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE", requires = "template")]
+    vars: Vec<String>,
+
+    /// List available templates and exit
+    #[arg(long, conflicts_with = "template")]
+    list_templates: bool,
+
+    /// Create the thread even if a similarly named one already exists
+    #[arg(long)]
+    force: bool,
+
+    /// Skip the check for similarly named threads
+    #[arg(long, conflicts_with = "force")]
+    no_dup_check: bool,
+
     /// Commit after creating
     #[arg(long)]
     commit: bool,
@@ -55,19 +83,57 @@ struct NewOutput {
     path_absolute: String,
 }
 
-pub fn run(args: NewArgs, git_root: &Path, config: &Config) -> Result<(), String> {
+pub fn run(
+    args: NewArgs,
+    git_root: &Path,
+    config: &Config,
+    profile: Option<&str>,
+) -> Result<(), String> {
     let format = args.format.resolve();
 
+    if args.list_templates {
+        let cwd = std::env::current_dir().map_err(|e| format!("cannot get cwd: {}", e))?;
+        return list_templates(&template::search_dirs(git_root, &cwd), format);
+    }
+
+    // Parse positional args: [title] or [path, title]; none means prompt
+    let (path_arg, title) = match args.args.as_slice() {
+        [path, title] => (Some(path.as_str()), Some(title.clone())),
+        [title] => (None, Some(title.clone())),
+        _ => (None, None),
+    };
+
+    // Determine scope using new path resolution
+    let scope = workspace::infer_scope(git_root, path_arg)?;
+
+    // Defaults come from the manifests that apply where the thread lands,
+    // which differ from the cwd's when a path is given
+    let scoped_config;
+    let config = match (path_arg, scope.threads_dir.parent()) {
+        (Some(_), Some(dir)) => {
+            scoped_config = load_config(git_root, dir, profile)?.config;
+            &scoped_config
+        }
+        _ => config,
+    };
+
     // Resolve status: CLI flag > THREADS_DEFAULT_STATUS env > config default > hardcoded default
-    let default_status = &config.defaults.new;
-    let status = if args.status != "idea" {
-        // User explicitly set --status
-        args.status.clone()
-    } else if let Some(env_status) = env_string("THREADS_DEFAULT_STATUS") {
-        env_status
-    } else {
-        default_status.clone()
+    let default_status =
+        env_string("THREADS_DEFAULT_STATUS").unwrap_or_else(|| config.defaults.new.clone());
+
+    // A bare `threads new` at a terminal asks for what is missing; scripts
+    // and structured output keep the strict argument behavior
+    let (title, desc, status) = match title {
+        Some(title) => (title, args.desc.clone(), args.status.clone()),
+        None if input::stdin_is_tty()
+            && io::stderr().is_terminal()
+            && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) =>
+        {
+            prompt_missing(&args, config, &default_status)?
+        }
+        None => return Err("title is required".to_string()),
     };
+    let status = status.unwrap_or_else(|| default_status.clone());
 
     // Validate status early using config status lists
     if !thread::is_valid_status_with_config(&status, &config.status.open, &config.status.closed) {
@@ -85,22 +151,12 @@ pub fn run(args: NewArgs, git_root: &Path, config: &Config) -> Result<(), String
         ));
     }
 
-    // Parse positional args: either [title] or [path, title]
-    let (path_arg, title) = if args.args.len() == 2 {
-        (Some(args.args[0].as_str()), args.args[1].clone())
-    } else if args.args.len() == 1 {
-        // Single arg is title, no path specified (will use PWD)
-        (None, args.args[0].clone())
-    } else {
-        return Err("title is required".to_string());
-    };
-
     if title.is_empty() {
         return Err("title is required".to_string());
     }
 
     // Warn if no description provided (unless quiet mode)
-    if args.desc.is_empty() && !is_quiet(config) {
+    if desc.is_empty() && !is_quiet(config) {
         eprintln!("Warning: No --desc provided. Add one with: threads update <id> --desc \"...\"");
     }
 
@@ -110,19 +166,60 @@ pub fn run(args: NewArgs, git_root: &Path, config: &Config) -> Result<(), String
         return Err("title produces empty slug".to_string());
     }
 
+    let vars = args
+        .vars
+        .iter()
+        .map(|v| match v.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => Ok((key.trim(), value)),
+            _ => Err(format!("invalid --var '{}': expected key=value", v)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Templates are looked up from where the thread lands, like the config
+    let template_text = match &args.template {
+        Some(name) => {
+            let dir = scope.threads_dir.parent().unwrap_or(git_root);
+            Some(template::load(name, &template::search_dirs(git_root, dir))?)
+        }
+        None => None,
+    };
+
     // Read body from stdin if available and not provided via flag
-    let body = if args.body.is_empty() {
-        input::read_stdin(false)
-    } else {
+    let mut body = if template_text.is_some() || !args.body.is_empty() {
         args.body.clone()
+    } else {
+        input::read_stdin(false)
     };
 
-    // Determine scope using new path resolution
-    let scope = workspace::infer_scope(git_root, path_arg)?;
+    if !args.force && !args.no_dup_check {
+        check_duplicates(git_root, &title, config)?;
+    }
 
     // Generate ID
     let id = workspace::generate_id(git_root)?;
 
+    if let Some(text) = &template_text {
+        let date = Local::now().format("%Y-%m-%d").to_string();
+        // --var values come first, so they can override the built-ins
+        let mut values = vars.clone();
+        values.extend([
+            ("title", title.as_str()),
+            ("name", title.as_str()),
+            ("date", date.as_str()),
+            ("id", id.as_str()),
+        ]);
+        body = template::render(text, &values);
+
+        let unfilled = template::placeholders(&body);
+        if !unfilled.is_empty() {
+            let tokens: Vec<String> = unfilled.iter().map(|k| format!("{{{{{}}}}}", k)).collect();
+            eprintln!(
+                "Warning: template placeholders left unfilled: {} (pass --var key=value)",
+                tokens.join(", ")
+            );
+        }
+    }
+
     // Ensure threads directory exists
     fs::create_dir_all(&scope.threads_dir)
         .map_err(|e| format!("creating threads directory: {}", e))?;
@@ -137,9 +234,42 @@ pub fn run(args: NewArgs, git_root: &Path, config: &Config) -> Result<(), String
     }
 
     // Build thread using the canonical constructor (initial log entry in frontmatter, no legacy sections)
-    let mut t = Thread::new(&id, &title, &args.desc, &status, &body)
+    let mut t = Thread::new(&id, &title, &desc, &status, &body)
         .map_err(|e| format!("creating thread: {}", e))?;
     t.path = thread_path.to_string_lossy().to_string();
+
+    for tag in &config.defaults.tags {
+        t.add_tag(tag)
+            .map_err(|e| format!("invalid default tag '{}': {}", tag, e))?;
+    }
+    let mut tags = Vec::new();
+    for tag in &args.tags {
+        let (slug, _) = t
+            .add_tag(tag)
+            .map_err(|e| format!("invalid tag '{}': {}", tag, e))?;
+        if !tags.contains(&slug) {
+            tags.push(slug);
+        }
+    }
+
+    // Record a non-default starting status and any --tag tags in the initial log entry
+    let as_status = if thread::base_status(&status) != thread::base_status(&default_status) {
+        format!(" as {}", status)
+    } else {
+        String::new()
+    };
+    let with_tags = if tags.is_empty() {
+        String::new()
+    } else {
+        format!(" with tags {}", tags.join(", "))
+    };
+    if !(as_status.is_empty() && with_tags.is_empty())
+        && let Some(entry) = t.frontmatter.log.first_mut()
+    {
+        entry.text = format!("Created thread{}{}.", as_status, with_tags);
+        t.rebuild_content()?;
+    }
+
     t.write()?;
 
     // Display path relative to git root
@@ -157,13 +287,13 @@ pub fn run(args: NewArgs, git_root: &Path, config: &Config) -> Result<(), String
                 );
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = NewOutput {
                 id: id.clone(),
                 path: rel_path.clone(),
                 path_absolute: thread_path.to_string_lossy().to_string(),
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
@@ -179,18 +309,137 @@ pub fn run(args: NewArgs, git_root: &Path, config: &Config) -> Result<(), String
         }
     }
 
-    // Commit if requested or THREADS_AUTO_COMMIT is set
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    // Commit if requested or auto-commit is enabled
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = thread_path.strip_prefix(git_root).unwrap_or(&thread_path);
-        let msg = args
-            .m
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &thread_path, &msg)?;
-    } else if matches!(format, OutputFormat::Pretty | OutputFormat::Plain) && !is_quiet(config) {
+        let msg = args.m.unwrap_or_else(|| format!("threads: new {}", id));
+        git::commit_thread(&repo, &thread_path, &msg)?
+    } else {
+        false
+    };
+    if !committed
+        && matches!(format, OutputFormat::Pretty | OutputFormat::Plain)
+        && !is_quiet(config)
+    {
         output::print_uncommitted_hint(&id);
     }
 
     Ok(())
 }
+
+/// Print the templates `--template` can use, with where each one comes from.
+fn list_templates(dirs: &[PathBuf], format: OutputFormat) -> Result<(), String> {
+    let templates: Vec<TemplateInfo> = template::list(dirs);
+    match format {
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let json = output::to_json(&templates, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&templates)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            let width = templates.iter().map(|t| t.name.len()).max().unwrap_or(0);
+            for t in &templates {
+                println!("{:width$}  {}", t.name, t.source, width = width);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Ask for the title, then the description and status unless given as flags.
+///
+/// Returns `(title, desc, status)`; a `None` status means the default. EOF
+/// at any prompt aborts before anything is written.
+fn prompt_missing(
+    args: &NewArgs,
+    config: &Config,
+    default_status: &str,
+) -> Result<(String, String, Option<String>), String> {
+    let title = input::prompt_line("Title: ")?;
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+
+    let desc = if args.desc.is_empty() {
+        input::prompt_line("Description (optional): ")?
+    } else {
+        args.desc.clone()
+    };
+
+    if args.status.is_some() {
+        return Ok((title, desc, args.status.clone()));
+    }
+    let choices = &config.status.open;
+    eprintln!("Status:");
+    for (i, choice) in choices.iter().enumerate() {
+        let marker = if choice == default_status {
+            " (default)"
+        } else {
+            ""
+        };
+        eprintln!("  {}) {}{}", i + 1, choice, marker);
+    }
+    let answer = input::prompt_line(&format!(
+        "Choose [1-{}, default {}]: ",
+        choices.len(),
+        default_status
+    ))?;
+    let status = if answer.is_empty() {
+        None
+    } else if let Ok(n) = answer.parse::<usize>() {
+        let choice = n
+            .checked_sub(1)
+            .and_then(|i| choices.get(i))
+            .ok_or_else(|| format!("no status numbered {}", n))?;
+        Some(choice.clone())
+    } else {
+        // A typed name is checked with the other statuses below
+        Some(answer)
+    };
+    Ok((title, desc, status))
+}
+
+/// Titles at least this similar (see `fuzzy::similarity`) count as likely duplicates.
+const DUPLICATE_THRESHOLD: f64 = 0.8;
+
+/// Refuse to create a thread whose title closely matches an open thread's name,
+/// unless the user confirms at a terminal.
+fn check_duplicates(git_root: &Path, title: &str, config: &Config) -> Result<(), String> {
+    let mut similar: Vec<(f64, String, String)> = Vec::new();
+    for path in workspace::find_all_threads(git_root)? {
+        let Ok(t) = Thread::parse(&path) else {
+            continue;
+        };
+        if thread::is_closed_with_config(t.status(), &config.status.closed) {
+            continue;
+        }
+        let similarity = fuzzy::similarity(title, t.name());
+        if similarity >= DUPLICATE_THRESHOLD {
+            similar.push((similarity, t.id().to_string(), t.name().to_string()));
+        }
+    }
+    if similar.is_empty() {
+        return Ok(());
+    }
+
+    similar.sort_by(|a, b| b.0.total_cmp(&a.0));
+    eprintln!("Warning: similar threads already exist:");
+    for (_, id, name) in &similar {
+        eprintln!("  {}  {}", id, name);
+    }
+
+    if io::stdin().is_terminal() && io::stderr().is_terminal() {
+        let response = input::prompt_line(&format!("Create '{}' anyway? [y/N] ", title))?;
+        if matches!(response.to_lowercase().as_str(), "y" | "yes") {
+            return Ok(());
+        }
+        return Err("aborted: not creating a likely duplicate".to_string());
+    }
+
+    Err("not creating a likely duplicate; pass --force to create it anyway".to_string())
+}
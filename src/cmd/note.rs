@@ -5,8 +5,9 @@ use clap_complete::engine::ArgValueCompleter;
 use colored::Colorize;
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
+use crate::input;
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, Thread};
 use crate::workspace;
@@ -17,7 +18,7 @@ pub struct NoteArgs {
     #[arg(default_value = "", add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
     id: String,
 
-    /// Action: list, add, edit, remove (default: list)
+    /// Action: list, add, edit, remove, promote (default: list)
     #[arg(default_value = "list")]
     action: String,
 
@@ -25,10 +26,14 @@ pub struct NoteArgs {
     #[arg(default_value = "")]
     text: String,
 
-    /// New text when editing (edit action only)
+    /// New text when editing; omit to open the note in $EDITOR (edit action only)
     #[arg(default_value = "")]
     new_text: String,
 
+    /// Body heading to move the note under (promote action only)
+    #[arg(long, visible_alias = "move-to-body")]
+    heading: Option<String>,
+
     #[command(flatten)]
     direction: DirectionArgs,
 
@@ -54,7 +59,7 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     if args.id.is_empty() {
         return Err(
-            "usage: threads note <id> [list | add <text> | edit <hash> <text> | remove <hash>]"
+            "usage: threads note <id> [list | add <text> | edit <hash> [text] | remove <hash> | promote <hash> --heading <heading>]"
                 .to_string(),
         );
     }
@@ -68,10 +73,10 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
             let format = args.format.resolve();
             let items = t.get_notes();
             match format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Ndjson => {
                     println!(
                         "{}",
-                        serde_json::to_string_pretty(&items).map_err(|e| e.to_string())?
+                        output::to_json(&items, format).map_err(|e| e.to_string())?
                     );
                 }
                 OutputFormat::Yaml => {
@@ -85,7 +90,11 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
                         println!("No notes.");
                     } else {
                         for item in &items {
-                            println!("- {} ({})", item.text, item.hash);
+                            let mut lines = item.text.lines();
+                            println!("- {} ({})", lines.next().unwrap_or(""), item.hash);
+                            for line in lines {
+                                println!("  {}", line);
+                            }
                         }
                     }
                 }
@@ -101,17 +110,17 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
             let hash = t.add_note(text)?;
 
             // Add log entry
-            let log_entry = format!("Added note: {}", text);
+            let summary = text.lines().next().unwrap_or("");
+            let log_entry = format!("Added note: {}", summary);
             t.insert_log_entry(&log_entry)?;
 
-            println!("Added note: {} (id: {})", text, hash);
+            println!("Added note: {} (id: {})", summary, hash);
         }
         "edit" => {
-            if args.text.is_empty() || args.new_text.is_empty() {
-                return Err("usage: threads note <id> edit <hash> \"new text\"".to_string());
+            if args.text.is_empty() {
+                return Err("usage: threads note <id> edit <hash> [\"new text\"]".to_string());
             }
             let hash = &args.text;
-            let new_text = &args.new_text;
 
             // Check for ambiguous hash
             let count = t.count_matching_items("Notes", hash);
@@ -122,7 +131,29 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
                 return Err(format!("ambiguous hash '{}' matches {} notes", hash, count));
             }
 
-            t.edit_by_hash("Notes", hash, new_text)?;
+            let new_text = if args.new_text.is_empty() {
+                let current = t
+                    .get_notes()
+                    .into_iter()
+                    .find(|n| n.hash.starts_with(hash.as_str()))
+                    .map(|n| n.text)
+                    .unwrap_or_default();
+                let edited = input::edit_in_editor(&format!("{}\n", current))?;
+                let edited = edited.trim();
+                if edited.is_empty() {
+                    println!("Empty note, edit cancelled");
+                    return Ok(());
+                }
+                if edited == current {
+                    println!("Note {} unchanged", hash);
+                    return Ok(());
+                }
+                edited.to_string()
+            } else {
+                args.new_text.clone()
+            };
+
+            t.edit_by_hash("Notes", hash, &new_text)?;
 
             let log_entry = format!("Edited note {}", hash);
             t.insert_log_entry(&log_entry)?;
@@ -151,9 +182,42 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
             println!("Removed note {}", hash);
         }
+        "promote" => {
+            let heading = match (&args.heading, args.text.is_empty()) {
+                (Some(heading), false) => heading,
+                _ => {
+                    return Err(
+                        "usage: threads note <id> promote <hash> --heading \"Heading\"".to_string(),
+                    );
+                }
+            };
+            let hash = &args.text;
+
+            // Check for ambiguous hash
+            let count = t.count_matching_items("Notes", hash);
+            if count == 0 {
+                return Err(format!("no note with hash '{}' found", hash));
+            }
+            if count > 1 {
+                return Err(format!("ambiguous hash '{}' matches {} notes", hash, count));
+            }
+            let note = t
+                .get_notes()
+                .into_iter()
+                .find(|n| n.hash.starts_with(hash.as_str()))
+                .ok_or_else(|| format!("no note with hash '{}' found", hash))?;
+
+            t.remove_by_hash("Notes", hash)?;
+            t.append_to_body_section(heading, &note.text)?;
+
+            let log_entry = format!("Moved note {} to body under '{}'", note.hash, heading);
+            t.insert_log_entry(&log_entry)?;
+
+            println!("Moved note {} to body under '## {}'", note.hash, heading);
+        }
         _ => {
             return Err(format!(
-                "unknown action '{}'. Use: list, add, edit, remove",
+                "unknown action '{}'. Use: list, add, edit, remove, promote",
                 args.action
             ));
         }
@@ -161,15 +225,16 @@ pub fn run(args: NoteArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
         let msg = args
             .message
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-    } else if !is_quiet(config) {
+            .unwrap_or_else(|| format!("threads: note {}", t.id()));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
         output::print_uncommitted_hint(&args.id);
     }
 
@@ -232,7 +297,7 @@ fn run_agenda(args: &NoteArgs, ws: &Path, _config: &Config) -> Result<(), String
     }
 
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             use serde::Serialize;
             #[derive(Serialize)]
             struct JsonItem<'a> {
@@ -254,7 +319,7 @@ fn run_agenda(args: &NoteArgs, ws: &Path, _config: &Config) -> Result<(), String
                 .collect();
             println!(
                 "{}",
-                serde_json::to_string_pretty(&items).map_err(|e| format!("JSON error: {}", e))?
+                output::to_json(&items, format).map_err(|e| format!("JSON error: {}", e))?
             );
         }
         OutputFormat::Yaml => {
@@ -287,14 +352,27 @@ fn run_agenda(args: &NoteArgs, ws: &Path, _config: &Config) -> Result<(), String
             for a in &agenda {
                 println!(
                     "{} | {} | {} | {} | {}",
-                    a.text, a.hash, a.thread_id, a.thread_name, a.thread_path
+                    a.text.replace('\n', " "),
+                    a.hash,
+                    a.thread_id,
+                    a.thread_name,
+                    a.thread_path
                 );
             }
         }
         _ => {
             for a in &agenda {
                 let bracket_id = format!("[{}]", a.thread_id);
-                println!("• {}  {}  {}", a.text, a.hash.dimmed(), bracket_id.dimmed());
+                let mut lines = a.text.lines();
+                println!(
+                    "• {}  {}  {}",
+                    lines.next().unwrap_or(""),
+                    a.hash.dimmed(),
+                    bracket_id.dimmed()
+                );
+                for line in lines {
+                    println!("  {}", line);
+                }
             }
         }
     }
@@ -5,7 +5,7 @@ use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::output::OutputFormat;
+use crate::output::{self, OutputFormat};
 use crate::workspace;
 
 #[derive(Args)]
@@ -36,12 +36,12 @@ pub fn run(args: PathArgs, ws: &Path) -> Result<(), String> {
         OutputFormat::Pretty | OutputFormat::Plain => {
             println!("{}", abs_path.display());
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = PathOutput {
                 path: rel_path,
                 path_absolute: abs_path.to_string_lossy().to_string(),
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
@@ -2,7 +2,7 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
-use chrono::{Local, NaiveDateTime};
+use chrono::{Local, NaiveDate, NaiveDateTime};
 use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
 use colored::Colorize;
@@ -11,20 +11,55 @@ use termimad::MadSkin;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::args::FormatArgs;
+use crate::cmd::link;
+use crate::config::{Config, READ_SECTIONS};
 use crate::git;
 use crate::output::{self, OutputFormat};
-use crate::thread::{self, DeadlineItem, EventItem, LogEntry, NoteItem, Thread, TodoItem};
+use crate::thread::{self, DeadlineItem, EventItem, Link, LogEntry, NoteItem, Thread, TodoItem};
 use crate::workspace;
 
 #[derive(Args)]
 pub struct ReadArgs {
-    /// Thread ID or name reference
-    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
-    id: String,
+    /// Thread IDs or name references (rendered in order)
+    #[arg(required = true, add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    ids: Vec<String>,
+
+    /// Report unresolvable refs and continue with the rest
+    #[arg(long)]
+    keep_going: bool,
 
     #[command(flatten)]
     format: FormatArgs,
 
+    /// Show only the header, item counts, and history (pretty output)
+    #[arg(long)]
+    stat: bool,
+
+    /// Show log and commit dates as YYYY-MM-DD instead of relative times
+    #[arg(long)]
+    absolute_dates: bool,
+
+    /// Copy to the clipboard instead of printing: the markdown body, or the
+    /// --json/--yaml output (requires the `clipboard` feature)
+    #[arg(long, conflicts_with = "stat")]
+    copy: bool,
+
+    /// Print only these sections (comma-separated: body, notes, todo, deadlines, events, log)
+    #[arg(long, value_delimiter = ',', conflicts_with_all = ["stat", "copy"])]
+    section: Vec<String>,
+
+    /// Print only the frontmatter YAML (structured output drops body and raw)
+    #[arg(long, alias = "raw-frontmatter", conflicts_with_all = ["stat", "copy", "section", "no_body"])]
+    frontmatter_only: bool,
+
+    /// Leave the body out of the output
+    #[arg(long, conflicts_with_all = ["copy", "section"])]
+    no_body: bool,
+
+    /// Print pretty output directly instead of through the pager
+    #[arg(long)]
+    no_pager: bool,
+
     /// Override terminal width (for testing)
     #[arg(long, hide = true)]
     width: Option<usize>,
@@ -34,39 +69,241 @@ pub struct ReadArgs {
     debug_widths: bool,
 }
 
-pub fn run(args: ReadArgs, ws: &Path) -> Result<(), String> {
-    let file = workspace::find_by_ref(ws, &args.id)?;
-    let content = fs::read_to_string(&file).map_err(|e| format!("reading file: {}", e))?;
-
+pub fn run(args: ReadArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
+    let mut order = if format == OutputFormat::Pretty {
+        section_order(config)?
+    } else {
+        Vec::new()
+    };
+    if args.no_body {
+        order.retain(|s| *s != "body");
+    }
+    let absolute = args.absolute_dates || config.display.absolute_dates;
+    let only = args
+        .section
+        .iter()
+        .map(|name| {
+            match_section(name).ok_or_else(|| {
+                format!(
+                    "unknown section '{}' (expected: {})",
+                    name,
+                    READ_SECTIONS.join(", ")
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut structured: Vec<ReadOutput> = Vec::new();
+    let mut bodies: Vec<String> = Vec::new();
+    let mut rendered = 0;
+    let mut printed_sections = 0;
+    let mut failures = 0;
+    // Pretty/plain text, printed (or paged) once every thread is rendered
+    let mut out = String::new();
+
+    for id in &args.ids {
+        let resolved = workspace::find_by_ref(ws, id).and_then(|file| {
+            fs::read_to_string(&file)
+                .map(|content| (file, content))
+                .map_err(|e| format!("reading file: {}", e))
+        });
+        let (file, content) = match resolved {
+            Ok(r) => r,
+            Err(e) if args.keep_going => {
+                eprintln!("{}: {}", id, e);
+                failures += 1;
+                continue;
+            }
+            Err(e) => {
+                print!("{}", out);
+                return Err(e);
+            }
+        };
 
-    match format {
-        OutputFormat::Pretty => {
-            output_pretty(&file, ws, args.width, args.debug_widths)?;
+        if args.copy && !format.is_structured() {
+            bodies.push(Thread::parse(&file)?.body().trim().to_string());
+            continue;
+        }
+
+        // Blank line between consecutive pretty boxes / raw files
+        if rendered > 0
+            && only.is_empty()
+            && matches!(format, OutputFormat::Pretty | OutputFormat::Plain)
+        {
+            out.push('\n');
         }
-        OutputFormat::Plain => {
-            // Plain: raw markdown content
-            print!("{}", content);
+        rendered += 1;
+
+        let rel_path = file
+            .strip_prefix(ws)
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|_| file.to_string_lossy().to_string());
+
+        // A file that fails to parse is shown raw, since that is when it needs reading most
+        let thread = match Thread::from_content(&file, &content) {
+            Ok(thread) => thread,
+            Err(e) => {
+                if format.is_structured() {
+                    structured.push(ReadOutput::Unparsed(UnparsedOutput {
+                        path: rel_path,
+                        error: e,
+                        raw: content,
+                    }));
+                } else {
+                    eprintln!(
+                        "{} {} could not be parsed ({}); showing the raw file",
+                        "Warning:".yellow().bold(),
+                        rel_path,
+                        e
+                    );
+                    out.push_str(&content);
+                }
+                continue;
+            }
+        };
+
+        match format {
+            OutputFormat::Pretty | OutputFormat::Plain if args.frontmatter_only => {
+                out.push_str(&thread.frontmatter_yaml()?);
+            }
+            OutputFormat::Pretty | OutputFormat::Plain if !only.is_empty() => {
+                // Empty sections print nothing, so pipelines see no stray blank lines
+                let text = format_only_sections(&thread, &only, format, absolute, args.width);
+                if !text.is_empty() {
+                    if printed_sections > 0 {
+                        out.push('\n');
+                    }
+                    out.push_str(&text);
+                    out.push('\n');
+                    printed_sections += 1;
+                }
+            }
+            OutputFormat::Pretty => {
+                let view = if args.stat {
+                    PrettyView::Stat {
+                        todo_rollup: config.display.todo_rollup,
+                    }
+                } else {
+                    PrettyView::Full(&order)
+                };
+                out.push_str(&output_pretty(
+                    &file,
+                    ws,
+                    view,
+                    absolute,
+                    args.width,
+                    args.debug_widths,
+                )?);
+            }
+            OutputFormat::Plain if args.no_body => {
+                // Raw file up to and including the closing delimiter
+                out.push_str(&content[..thread.body_start.min(content.len())]);
+                out.push('\n');
+            }
+            OutputFormat::Plain => {
+                // Plain: raw markdown content
+                out.push_str(&content);
+            }
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+                let output = thread_output(&thread, &rel_path, &content);
+                if !only.is_empty() {
+                    structured.push(ReadOutput::Sections(narrow_output(&output, &only)?));
+                } else if args.frontmatter_only {
+                    structured.push(ReadOutput::Sections(drop_fields(
+                        &output,
+                        &["body", "raw"],
+                    )?));
+                } else if args.no_body {
+                    structured.push(ReadOutput::Sections(drop_fields(&output, &["body"])?));
+                } else {
+                    structured.push(ReadOutput::Thread(Box::new(output)));
+                }
+            }
         }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let thread = Thread::parse(&file)?;
-            let rel_path = file
-                .strip_prefix(ws)
-                .map(|p| p.to_string_lossy().to_string())
-                .unwrap_or_else(|_| file.to_string_lossy().to_string());
-
-            output_structured(&thread, &rel_path, &content, format)?;
+    }
+
+    if format == OutputFormat::Pretty {
+        output::page(&out, !args.no_pager);
+    } else {
+        print!("{}", out);
+    }
+
+    if format.is_structured() {
+        // A single id keeps the object shape; several ids give an array, or
+        // one line per thread for ndjson
+        let text = if format == OutputFormat::Ndjson {
+            structured
+                .iter()
+                .map(|output| structured_string(output, format))
+                .collect::<Result<String, String>>()?
+        } else if args.ids.len() == 1 {
+            match structured.first() {
+                Some(output) => structured_string(output, format)?,
+                None => String::new(),
+            }
+        } else {
+            structured_string(&structured, format)?
+        };
+        if args.copy {
+            bodies.push(text);
+        } else {
+            print!("{}", text);
         }
     }
+
+    if args.copy && !bodies.is_empty() {
+        let what = if matches!(format, OutputFormat::Json | OutputFormat::Ndjson) {
+            "JSON output".to_string()
+        } else if format == OutputFormat::Yaml {
+            "YAML output".to_string()
+        } else if bodies.len() == 1 {
+            "body".to_string()
+        } else {
+            format!("{} bodies", bodies.len())
+        };
+        crate::input::write_clipboard(&bodies.join("\n\n"))?;
+        println!("Copied {} to clipboard", what);
+    }
+
+    if failures > 0 {
+        return Err(format!(
+            "{} of {} threads could not be read",
+            failures,
+            args.ids.len()
+        ));
+    }
     Ok(())
 }
 
+/// Structured output for one requested thread
+#[derive(Serialize)]
+#[serde(untagged)]
+enum ReadOutput {
+    Thread(Box<ThreadOutput>),
+    /// `--section`, `--frontmatter-only`, `--no-body`: a subset of the thread's fields
+    Sections(serde_json::Map<String, serde_json::Value>),
+    Unparsed(UnparsedOutput),
+}
+
+/// A thread file that could not be parsed: the parse error plus the raw content
+#[derive(Serialize)]
+struct UnparsedOutput {
+    path: String,
+    error: String,
+    raw: String,
+}
+
 /// Structured output data for JSON/YAML
 #[derive(Serialize)]
 struct ThreadOutput {
     id: String,
     name: String,
     status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    tags: Vec<String>,
+    links: Vec<Link>,
     desc: String,
     path: String,
     body: String,
@@ -78,17 +315,15 @@ struct ThreadOutput {
     raw: String,
 }
 
-/// Output thread as JSON or YAML
-fn output_structured(
-    thread: &Thread,
-    rel_path: &str,
-    raw_content: &str,
-    format: OutputFormat,
-) -> Result<(), String> {
-    let output = ThreadOutput {
+/// Build the JSON/YAML representation of a thread
+fn thread_output(thread: &Thread, rel_path: &str, raw_content: &str) -> ThreadOutput {
+    ThreadOutput {
         id: thread.frontmatter.id.clone(),
         name: thread.name().to_string(),
         status: thread.frontmatter.status.clone(),
+        priority: thread.frontmatter.priority.clone(),
+        tags: thread.tags().to_vec(),
+        links: thread.links().to_vec(),
         desc: thread.frontmatter.desc.clone(),
         path: rel_path.to_string(),
         body: thread.content[thread.body_start..].trim().to_string(),
@@ -98,34 +333,136 @@ fn output_structured(
         deadlines: thread.get_deadlines(),
         events: thread.get_events(),
         raw: raw_content.to_string(),
-    };
+    }
+}
 
+/// Serialize a thread (or list of threads) as JSON or YAML, newline-terminated
+fn structured_string<T: Serialize>(output: &T, format: OutputFormat) -> Result<String, String> {
     match format {
-        OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&output).map_err(|e| format!("JSON error: {}", e))?
-            );
-        }
+        OutputFormat::Json | OutputFormat::Ndjson => output::to_json(&output, format)
+            .map(|json| json + "\n")
+            .map_err(|e| format!("JSON error: {}", e)),
         OutputFormat::Yaml => {
-            print!(
-                "{}",
-                serde_yaml::to_string(&output).map_err(|e| format!("YAML error: {}", e))?
-            );
+            serde_yaml::to_string(&output).map_err(|e| format!("YAML error: {}", e))
         }
         _ => unreachable!(),
     }
+}
 
-    Ok(())
+/// Keep only `id` and the requested section fields of a thread's structured output.
+fn narrow_output(
+    output: &ThreadOutput,
+    sections: &[&str],
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let serde_json::Value::Object(mut all) =
+        serde_json::to_value(output).map_err(|e| format!("JSON error: {}", e))?
+    else {
+        return Err("JSON error: thread output is not an object".to_string());
+    };
+    let mut narrowed = serde_json::Map::new();
+    for key in std::iter::once(&"id").chain(sections) {
+        if let Some(value) = all.remove(*key) {
+            narrowed.insert(key.to_string(), value);
+        }
+    }
+    Ok(narrowed)
+}
+
+/// A thread's structured output without the `dropped` fields.
+fn drop_fields(
+    output: &ThreadOutput,
+    dropped: &[&str],
+) -> Result<serde_json::Map<String, serde_json::Value>, String> {
+    let serde_json::Value::Object(mut all) =
+        serde_json::to_value(output).map_err(|e| format!("JSON error: {}", e))?
+    else {
+        return Err("JSON error: thread output is not an object".to_string());
+    };
+    all.retain(|key, _| !dropped.contains(&key.as_str()));
+    Ok(all)
+}
+
+/// Render only the requested sections for `read --section`, blank-line separated.
+/// Pretty uses the box's formatters; plain strips their styling and gives the body raw.
+fn format_only_sections(
+    thread: &Thread,
+    sections: &[&str],
+    format: OutputFormat,
+    absolute_dates: bool,
+    width_override: Option<usize>,
+) -> String {
+    let width = width_override.unwrap_or_else(|| output::terminal_width().min(100));
+    let plain = format == OutputFormat::Plain;
+    let body = thread.body().trim();
+
+    let mut parts = Vec::new();
+    for name in sections {
+        let text = match *name {
+            "body" if body.is_empty() => continue,
+            "body" if plain => body.to_string(),
+            "body" => format_body(body, width),
+            "notes" => format_notes(&thread.get_notes(), width),
+            "todo" => format_todos(&thread.get_todo_items()),
+            "deadlines" => format_deadlines(&thread.get_deadlines()),
+            "events" => format_events(&thread.get_events()),
+            "log" => format_log(&thread.get_log_entries(), absolute_dates),
+            _ => continue,
+        };
+        if text.is_empty() {
+            continue;
+        }
+        parts.push(if plain {
+            strip_ansi_to_string(&text)
+        } else {
+            text
+        });
+    }
+    parts.join("\n\n")
+}
+
+/// Match a section name (case-insensitive) against `READ_SECTIONS`.
+fn match_section(name: &str) -> Option<&'static str> {
+    let lower = name.trim().to_lowercase();
+    READ_SECTIONS.iter().find(|s| **s == lower).copied()
+}
+
+/// Resolve the pretty-output section order from `display.read_sections`.
+fn section_order(config: &Config) -> Result<Vec<&str>, String> {
+    let Some(ref names) = config.display.read_sections else {
+        return Ok(READ_SECTIONS.to_vec());
+    };
+
+    names
+        .iter()
+        .map(|name| {
+            match_section(name).ok_or_else(|| {
+                format!(
+                    "unknown section '{}' in display.read_sections (expected: {})",
+                    name,
+                    READ_SECTIONS.join(", ")
+                )
+            })
+        })
+        .collect()
+}
+
+/// Which content sections the pretty box shows between header and footer.
+enum PrettyView<'a> {
+    /// Every non-empty section, in the given order
+    Full(&'a [&'a str]),
+    /// A single line of item counts (`--stat`), optionally rolling up subtasks
+    Stat { todo_rollup: bool },
 }
 
 /// Rich pretty output - single box with sections separated by horizontal lines
 fn output_pretty(
     file: &Path,
     ws: &Path,
+    view: PrettyView,
+    absolute_dates: bool,
     width_override: Option<usize>,
     debug: bool,
-) -> Result<(), String> {
+) -> Result<String, String> {
     let thread = Thread::parse(file)?;
     let term_width = width_override.unwrap_or_else(|| output::terminal_width().min(100));
 
@@ -139,7 +476,7 @@ fn output_pretty(
         .unwrap_or_else(|_| file.to_string_lossy().to_string());
 
     // Get git history
-    let git_history = get_git_history(ws, &rel_path);
+    let git_history = get_git_history(ws, &rel_path, absolute_dates);
 
     // === Section 1: Header ===
     let title = if !thread.name().is_empty() {
@@ -148,7 +485,10 @@ fn output_pretty(
         thread::extract_name_from_path(file).replace('-', " ")
     };
 
-    let status_styled = output::style_status(&thread.base_status()).to_string();
+    let mut status_styled = output::style_status(&thread.base_status()).to_string();
+    if let Some(priority) = thread.get_priority() {
+        status_styled.push_str(&format!(" · {}", priority));
+    }
 
     // Get git status with diff stats if dirty
     let git_info = if let Ok(repo) = workspace::open() {
@@ -199,23 +539,43 @@ fn output_pretty(
     // === Build sections dynamically ===
     let mut sections: Vec<String> = vec![header];
 
-    if !body.is_empty() {
-        sections.push(format_body(&body));
-    }
-    if !notes_items.is_empty() {
-        sections.push(format_notes(&notes_items));
-    }
-    if !todo_items.is_empty() {
-        sections.push(format_todos(&todo_items));
-    }
-    if !deadline_items.is_empty() {
-        sections.push(format_deadlines(&deadline_items));
-    }
-    if !event_items.is_empty() {
-        sections.push(format_events(&event_items));
+    let order = match view {
+        PrettyView::Full(order) => order,
+        PrettyView::Stat { todo_rollup } => {
+            sections.push(format_counts(
+                &body,
+                notes_items.len(),
+                thread::todo_progress(&todo_items, todo_rollup),
+                deadline_items.len(),
+                event_items.len(),
+                log_entries.len(),
+            ));
+            &[]
+        }
+    };
+
+    // Links sit under the header, with each target's current status
+    if !order.is_empty() && !thread.links().is_empty() {
+        let links = link::resolve_links(ws, thread.links());
+        sections.push(link::format_links(&links, true).join("\n"));
     }
-    if !log_entries.is_empty() {
-        sections.push(format_log(&log_entries));
+
+    for name in order {
+        match *name {
+            "body" if !body.is_empty() => sections.push(format_body(&body, inner_width)),
+            "notes" if !notes_items.is_empty() => {
+                sections.push(format_notes(&notes_items, inner_width))
+            }
+            "todo" if !todo_items.is_empty() => sections.push(format_todos(&todo_items)),
+            "deadlines" if !deadline_items.is_empty() => {
+                sections.push(format_deadlines(&deadline_items))
+            }
+            "events" if !event_items.is_empty() => sections.push(format_events(&event_items)),
+            "log" if !log_entries.is_empty() => {
+                sections.push(format_log(&log_entries, absolute_dates))
+            }
+            _ => {}
+        }
     }
 
     // Footer: history + path (truncate path from front if too long)
@@ -228,13 +588,11 @@ fn output_pretty(
     ));
 
     // === Render box with sections ===
-    print_boxed_sections(&sections, term_width, debug);
-
-    Ok(())
+    Ok(boxed_sections(&sections, term_width, debug))
 }
 
-/// Print sections in a rounded box with horizontal separators
-fn print_boxed_sections(sections: &[String], max_width: usize, debug: bool) {
+/// Render sections in a rounded box with horizontal separators
+fn boxed_sections(sections: &[String], max_width: usize, debug: bool) -> String {
     let inner_width = max_width.saturating_sub(4); // Account for "│ " and " │"
 
     if debug {
@@ -244,8 +602,10 @@ fn print_boxed_sections(sections: &[String], max_width: usize, debug: bool) {
         );
     }
 
+    let mut out = String::new();
+
     // Top border
-    println!("╭{}╮", "─".repeat(max_width - 2));
+    out.push_str(&format!("╭{}╮\n", "─".repeat(max_width - 2)));
 
     for (i, section) in sections.iter().enumerate() {
         // Print section content with padding
@@ -262,21 +622,23 @@ fn print_boxed_sections(sections: &[String], max_width: usize, debug: bool) {
                         "DEBUG: section={} line={}: visible_width={}, padding={}, total={} (expected {})",
                         i, line_num, visible_width, padding, total, max_width
                     );
-                    eprintln!("DEBUG:   content: {:?}", &wrapped[..wrapped.len().min(50)]);
+                    let head: String = wrapped.chars().take(50).collect();
+                    eprintln!("DEBUG:   content: {:?}", head);
                 }
 
-                println!("│ {}{} │", wrapped, " ".repeat(padding));
+                out.push_str(&format!("│ {}{} │\n", wrapped, " ".repeat(padding)));
             }
         }
 
         // Separator between sections (not after last)
         if i < sections.len() - 1 {
-            println!("├{}┤", "─".repeat(max_width - 2));
+            out.push_str(&format!("├{}┤\n", "─".repeat(max_width - 2)));
         }
     }
 
     // Bottom border
-    println!("╰{}╯", "─".repeat(max_width - 2));
+    out.push_str(&format!("╰{}╯\n", "─".repeat(max_width - 2)));
+    out
 }
 
 /// Wrap a line to fit within max_width (respecting ANSI codes).
@@ -310,9 +672,18 @@ fn detect_prefix(line: &str) -> Option<(String, String)> {
         return split_at_visible_pos(line, 2);
     }
 
-    // Todos: "☐ " or "☑ " (checkbox + space)
-    if stripped.starts_with("☐ ") || stripped.starts_with("☑ ") {
-        return split_at_visible_pos(line, 2);
+    // Todos: "☐ " or "☑ " (checkbox + space), indented two spaces per subtask level
+    let unindented = stripped.trim_start_matches(' ');
+    if unindented.starts_with("☐ ") || unindented.starts_with("☑ ") {
+        return split_at_visible_pos(line, stripped.len() - unindented.len() + 2);
+    }
+
+    // Log with absolute date: "YYYY-MM-DD " = 11 chars
+    if stripped.len() > 11
+        && stripped.as_bytes()[10] == b' '
+        && NaiveDate::parse_from_str(&stripped[..10], "%Y-%m-%d").is_ok()
+    {
+        return split_at_visible_pos(line, 11);
     }
 
     // Log with timestamp: right-aligned to 4 chars + space = 5 chars total
@@ -427,36 +798,69 @@ fn strip_ansi_width(s: &str) -> usize {
     visible.width()
 }
 
-/// Format body section - render markdown
-fn format_body(body: &str) -> String {
+/// One-line item summary for `read --stat`.
+fn format_counts(
+    body: &str,
+    notes: usize,
+    (done, total): (usize, usize),
+    deadlines: usize,
+    events: usize,
+    log: usize,
+) -> String {
+    let mut parts = vec![
+        format!("{} body lines", body.lines().count()),
+        format!("{} notes", notes),
+        format!("{}/{} todos done", done, total),
+    ];
+    if deadlines > 0 {
+        parts.push(format!("{} deadlines", deadlines));
+    }
+    if events > 0 {
+        parts.push(format!("{} events", events));
+    }
+    parts.push(format!("{} log entries", log));
+    parts.join(" · ").dimmed().to_string()
+}
+
+/// Format body section - render markdown sized to the box, so tables fit its width
+fn format_body(body: &str, width: usize) -> String {
     let skin = MadSkin::default();
-    let mut buf = Vec::new();
-    skin.write_text_on(&mut buf, body).ok();
-    String::from_utf8_lossy(&buf).trim().to_string()
+    skin.text(body, Some(width)).to_string().trim().to_string()
 }
 
-/// Format notes items with bullet points
-fn format_notes(notes: &[NoteItem]) -> String {
-    notes
-        .iter()
-        .map(|item| {
-            let rendered = render_inline_markdown(&item.text);
-            format!("{} {}", "•".cyan(), rendered)
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
+/// Format notes items with bullet points. Lines of a multi-line note after the
+/// first continue under the bullet, pre-wrapped to `width`.
+fn format_notes(notes: &[NoteItem], width: usize) -> String {
+    let bullet = format!("{} ", "•".cyan());
+    let mut lines = Vec::new();
+    for item in notes {
+        for (i, line) in item.text.lines().enumerate() {
+            let prefix = if i == 0 { bullet.as_str() } else { "  " };
+            let rendered = render_inline_markdown(line);
+            lines.extend(crate::wrap::wrap_with_prefix(prefix, &rendered, width));
+        }
+    }
+    lines.join("\n")
 }
 
-/// Format todo items with colored checkboxes and markdown
+/// Format todo items with colored checkboxes, markdown, and due dates.
+/// Subtasks are indented under their parent.
 fn format_todos(todos: &[TodoItem]) -> String {
-    todos
-        .iter()
-        .map(|item| {
+    use crate::cmd::deadline::style_deadline_date;
+    let today = Local::now().date_naive();
+    thread::todo_tree(todos)
+        .into_iter()
+        .map(|(depth, item)| {
+            let indent = "  ".repeat(depth);
             let rendered = render_inline_markdown(&item.text);
+            let due = match &item.due {
+                Some(d) if !item.done => format!("  {}", style_deadline_date(d, today)),
+                _ => String::new(),
+            };
             if item.done {
-                format!("{} {}", "☑".green(), rendered)
+                format!("{}{} {}", indent, "☑".green(), rendered)
             } else {
-                format!("{} {}", "☐".yellow(), rendered)
+                format!("{}{} {}{}", indent, "☐".yellow(), rendered, due)
             }
         })
         .collect::<Vec<_>>()
@@ -465,13 +869,19 @@ fn format_todos(todos: &[TodoItem]) -> String {
 
 /// Format deadline items with date styling
 fn format_deadlines(items: &[DeadlineItem]) -> String {
-    use crate::cmd::deadline::style_deadline_date;
+    use crate::cmd::deadline::{recurrence_marker, style_deadline_date};
     let today = Local::now().date_naive();
     items
         .iter()
         .map(|item| {
             let date_styled = style_deadline_date(&item.date, today);
-            format!("{}  {}  {}", date_styled, item.text, item.hash.dimmed())
+            format!(
+                "{}{}  {}  {}",
+                date_styled,
+                recurrence_marker(item.every.as_deref()),
+                item.text,
+                item.hash.dimmed()
+            )
         })
         .collect::<Vec<_>>()
         .join("\n")
@@ -508,8 +918,8 @@ fn render_inline_markdown(text: &str) -> String {
         .to_string()
 }
 
-/// Format log entries with relative timestamps and markdown
-fn format_log(entries: &[LogEntry]) -> String {
+/// Format log entries with relative (or absolute date) timestamps and markdown
+fn format_log(entries: &[LogEntry], absolute_dates: bool) -> String {
     let now = Local::now().naive_local();
 
     entries
@@ -518,6 +928,9 @@ fn format_log(entries: &[LogEntry]) -> String {
             let rendered = render_inline_markdown(&entry.text);
             if entry.ts.is_empty() {
                 format!("   {} {}", "·".dimmed(), rendered)
+            } else if absolute_dates {
+                let date = entry.ts.get(..10).unwrap_or(&entry.ts);
+                format!("{} {}", date.cyan(), rendered)
             } else {
                 let relative = timestamp_to_relative(&entry.ts, &now);
                 format!("{:>4} {}", relative.cyan(), rendered)
@@ -557,20 +970,26 @@ pub(crate) fn timestamp_to_relative(ts_str: &str, now: &NaiveDateTime) -> String
 
 /// Git log entry
 struct GitLogEntry {
-    relative_time: String,
+    /// Short relative time ("3h") or commit date (YYYY-MM-DD)
+    time: String,
     hash: String,
     message: String,
 }
 
 /// Get git history for a file
-fn get_git_history(ws: &Path, rel_path: &str) -> Vec<GitLogEntry> {
+fn get_git_history(ws: &Path, rel_path: &str, absolute_dates: bool) -> Vec<GitLogEntry> {
+    let format = if absolute_dates {
+        "--format=%cs\t%h\t%s"
+    } else {
+        "--format=%cr\t%h\t%s"
+    };
     let output = Command::new("git")
         .args([
             "-C",
             &ws.to_string_lossy(),
             "log",
             "--follow",
-            "--format=%cr\t%h\t%s",
+            format,
             "--",
             rel_path,
         ])
@@ -588,7 +1007,11 @@ fn get_git_history(ws: &Path, rel_path: &str) -> Vec<GitLogEntry> {
             let parts: Vec<&str> = line.split('\t').collect();
             if parts.len() >= 3 {
                 Some(GitLogEntry {
-                    relative_time: shorten_relative_time(parts[0]),
+                    time: if absolute_dates {
+                        parts[0].to_string()
+                    } else {
+                        shorten_relative_time(parts[0])
+                    },
                     hash: parts[1].to_string(),
                     message: parts[2..].join("\t"),
                 })
@@ -661,11 +1084,11 @@ fn format_history(history: &[GitLogEntry], max_width: usize) -> String {
 
 /// Format a single git log entry
 fn format_git_entry(entry: &GitLogEntry, max_width: usize) -> String {
-    let time_str = format!("{:>3}", entry.relative_time);
+    let time_str = format!("{:>3}", entry.time);
     let hash_str = output::style_id(&entry.hash).to_string();
 
     // Calculate remaining space for message
-    let prefix_len = 3 + 1 + 7 + 1; // time + space + hash + space
+    let prefix_len = time_str.len() + 1 + 7 + 1; // time + space + hash + space
     let msg_max = max_width.saturating_sub(prefix_len);
     let message = output::truncate_back(&entry.message, msg_max);
 
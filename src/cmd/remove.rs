@@ -1,14 +1,14 @@
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
-use crate::output::OutputFormat;
+use crate::output::{self, OutputFormat};
 use crate::thread::Thread;
 use crate::workspace;
 
@@ -18,6 +18,10 @@ pub struct RemoveArgs {
     #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
     id: String,
 
+    /// Also drop links to this thread from the threads that have them
+    #[arg(long)]
+    clean_links: bool,
+
     /// Commit after removing
     #[arg(long)]
     commit: bool,
@@ -36,9 +40,21 @@ struct RemoveOutput {
     name: String,
     path: String,
     was_tracked: bool,
+    /// Threads that linked to the removed one
+    linked_from: Vec<LinkRef>,
+    /// Whether those links were removed (--clean-links)
+    links_cleaned: bool,
     committed: bool,
 }
 
+/// A thread whose `links` pointed at the removed thread
+#[derive(Serialize)]
+struct LinkRef {
+    id: String,
+    path: String,
+    kind: String,
+}
+
 pub fn run(args: RemoveArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
@@ -61,23 +77,71 @@ pub fn run(args: RemoveArgs, ws: &Path, config: &Config) -> Result<(), String> {
     // Remove file
     fs::remove_file(&file).map_err(|e| format!("removing file: {}", e))?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    let committed = if was_tracked && should_commit {
-        let msg = args
-            .m
-            .unwrap_or_else(|| format!("threads: remove '{}'", name));
-        git::add(&repo, &[rel_path.as_path()])?;
-        git::commit(&repo, &[rel_path.as_path()], &msg)?;
-        true
-    } else {
-        false
-    };
+    // Links to the removed thread now dangle (E009): clean them up or report them
+    let mut linked_from = Vec::new();
+    let mut cleaned: Vec<PathBuf> = Vec::new();
+    for path in workspace::find_all_threads(ws)? {
+        let Ok(mut other) = Thread::parse(&path) else {
+            continue;
+        };
+        let kinds: Vec<String> = other
+            .links()
+            .iter()
+            .filter(|l| l.target == id)
+            .map(|l| l.kind.clone())
+            .collect();
+        if kinds.is_empty() {
+            continue;
+        }
+        for kind in kinds {
+            linked_from.push(LinkRef {
+                id: other.id().to_string(),
+                path: workspace::path_relative_to_git_root(ws, &path),
+                kind,
+            });
+        }
+        if args.clean_links {
+            other.remove_links_to(&id)?;
+            other.insert_log_entry(&format!("Removed links to deleted thread {}.", id))?;
+            other.write()?;
+            cleaned.push(path);
+        }
+    }
+
+    let committed =
+        if (was_tracked || !cleaned.is_empty()) && (args.commit || auto_commit_enabled(config)) {
+            let msg = args
+                .m
+                .unwrap_or_else(|| format!("threads: remove '{}'", name));
+            let mut files: Vec<&Path> = cleaned.iter().map(PathBuf::as_path).collect();
+            if was_tracked {
+                files.push(&rel_path);
+            }
+            git::commit_threads(&repo, &files, &msg)?
+        } else {
+            false
+        };
 
     let rel_path_str = rel_path.to_string_lossy().to_string();
 
     match format {
         OutputFormat::Pretty | OutputFormat::Plain => {
             println!("Removed: {}", rel_path_str);
+            if args.clean_links && !linked_from.is_empty() {
+                println!("Removed links from {} thread(s):", cleaned.len());
+                for r in &linked_from {
+                    println!("  {}  {}  ({})", r.id, r.path, r.kind);
+                }
+            } else if !linked_from.is_empty() {
+                eprintln!(
+                    "Warning: {} link(s) still point at {} (use --clean-links to remove them):",
+                    linked_from.len(),
+                    id
+                );
+                for r in &linked_from {
+                    eprintln!("  {}  {}  ({})", r.id, r.path, r.kind);
+                }
+            }
             if !is_quiet(config) {
                 if !was_tracked {
                     println!("Note: Thread was never committed to git, no commit needed.");
@@ -89,15 +153,17 @@ pub fn run(args: RemoveArgs, ws: &Path, config: &Config) -> Result<(), String> {
                 }
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = RemoveOutput {
                 id,
                 name,
                 path: rel_path_str,
                 was_tracked,
+                linked_from,
+                links_cleaned: args.clean_links,
                 committed,
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
@@ -107,6 +173,8 @@ pub fn run(args: RemoveArgs, ws: &Path, config: &Config) -> Result<(), String> {
                 name,
                 path: rel_path_str,
                 was_tracked,
+                linked_from,
+                links_cleaned: args.clean_links,
                 committed,
             };
             let yaml = serde_yaml::to_string(&output)
@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
+use serde::Serialize;
+
+use crate::args::FormatArgs;
+use crate::config::{Config, auto_commit_enabled, is_quiet};
+use crate::git;
+use crate::output::{self, OutputFormat};
+use crate::thread::{self, Thread};
+use crate::workspace;
+
+#[derive(Args)]
+pub struct RenameArgs {
+    /// Thread ID or name reference
+    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    id: String,
+
+    /// New title
+    title: String,
+
+    /// Only change the title; keep the current filename
+    #[arg(long)]
+    title_only: bool,
+
+    /// Commit after renaming
+    #[arg(long)]
+    commit: bool,
+
+    /// Commit message
+    #[arg(short = 'm', long)]
+    m: Option<String>,
+
+    #[command(flatten)]
+    format: FormatArgs,
+}
+
+#[derive(Serialize)]
+struct RenameOutput {
+    id: String,
+    title: String,
+    source: String,
+    path: String,
+    committed: bool,
+}
+
+pub fn run(args: RenameArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+
+    let src_file = workspace::find_by_ref(ws, &args.id)?;
+    let mut t = Thread::parse(&src_file)?;
+
+    let title = args.title.trim();
+    if title.is_empty() {
+        return Err("title is required".to_string());
+    }
+    let slug = workspace::slugify(title);
+    if slug.is_empty() {
+        return Err("title produces empty slug".to_string());
+    }
+
+    // Keep the filename's ID prefix, even if frontmatter disagrees (E005)
+    let id = thread::extract_id_from_path(&src_file).unwrap_or_else(|| t.id().to_string());
+    let dest_file = if args.title_only {
+        src_file.clone()
+    } else {
+        src_file.with_file_name(format!("{}-{}.md", id, slug))
+    };
+    if dest_file != src_file && dest_file.exists() {
+        return Err(format!(
+            "cannot rename: {} already exists",
+            workspace::path_relative_to_git_root(ws, &dest_file)
+        ));
+    }
+
+    t.set_frontmatter_field("name", title)?;
+
+    let repo = workspace::open()?;
+    // Move before writing so a clean file still qualifies for git mv
+    if dest_file != src_file {
+        git::move_file(&repo, ws, &src_file, &dest_file)?;
+        t.path = dest_file.to_string_lossy().to_string();
+    }
+    t.write()?;
+
+    let rel_src = workspace::path_relative_to_git_root(ws, &src_file);
+    let rel_dest = workspace::path_relative_to_git_root(ws, &dest_file);
+
+    let committed = if args.commit || auto_commit_enabled(config) {
+        let msg = args
+            .m
+            .unwrap_or_else(|| format!("threads: rename {} to '{}'", id, title));
+        git::commit_threads(&repo, &[src_file.as_path(), dest_file.as_path()], &msg)?
+    } else {
+        false
+    };
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            println!("Renamed: {}", title);
+            if dest_file != src_file {
+                println!("  {} → {}", rel_src, rel_dest);
+            } else {
+                println!("  → {}", rel_dest);
+            }
+            if !committed && !is_quiet(config) {
+                output::print_uncommitted_hint(&id);
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let output = RenameOutput {
+                id,
+                title: title.to_string(),
+                source: rel_src,
+                path: rel_dest,
+                committed,
+            };
+            let json = output::to_json(&output, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let output = RenameOutput {
+                id,
+                title: title.to_string(),
+                source: rel_src,
+                path: rel_dest,
+                committed,
+            };
+            let yaml = serde_yaml::to_string(&output)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+    }
+
+    Ok(())
+}
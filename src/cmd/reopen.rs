@@ -5,7 +5,7 @@ use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, Thread};
@@ -17,9 +17,10 @@ pub struct ReopenArgs {
     #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
     id: String,
 
-    /// Status to reopen to
-    #[arg(long, default_value = "active")]
-    status: String,
+    /// Status to reopen to (default: the last open status in git history,
+    /// else defaults.open)
+    #[arg(long)]
+    status: Option<String>,
 
     /// Commit after reopening
     #[arg(long)]
@@ -48,15 +49,10 @@ pub fn run(args: ReopenArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let file = workspace::find_by_ref(ws, &args.id)?;
 
     // Resolve status: CLI flag > git history > config default
-    let new_status = if args.status != "active" {
-        // User explicitly set --status
-        args.status.clone()
-    } else if let Some(prev) = git::previous_status(ws, &file, &config.status.closed) {
-        // Found previous open status in git history
-        prev
-    } else {
-        // Fall back to config default
-        config.defaults.open.clone()
+    let new_status = match &args.status {
+        Some(status) => status.clone(),
+        None => git::previous_status(ws, &file, &config.status.closed)
+            .unwrap_or_else(|| config.defaults.open.clone()),
     };
 
     // Validate status using config status lists
@@ -82,22 +78,19 @@ pub fn run(args: ReopenArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let id = t.id().to_string();
 
     // Update status
+    let transitions = config.behavior.transitions.as_ref();
+    thread::check_transition(&old_status, &new_status, transitions)?;
     t.set_frontmatter_field("status", &new_status)?;
 
     // Add log entry
-    t.insert_log_entry("Reopened.")?;
+    t.insert_log_entry(&format!("Reopened as {}.", new_status))?;
 
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    let committed = if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
-        let msg = args
-            .m
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-        true
+        let msg = args.m.unwrap_or_else(|| format!("threads: reopen {}", id));
+        git::commit_thread(&repo, &file, &msg)?
     } else {
         false
     };
@@ -111,7 +104,7 @@ pub fn run(args: ReopenArgs, ws: &Path, config: &Config) -> Result<(), String> {
                 output::print_uncommitted_hint(&id);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = ReopenOutput {
                 id,
                 old_status,
@@ -119,7 +112,7 @@ pub fn run(args: ReopenArgs, ws: &Path, config: &Config) -> Result<(), String> {
                 path: rel_path,
                 committed,
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
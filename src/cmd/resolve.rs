@@ -5,10 +5,10 @@ use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::output::{self, OutputFormat};
-use crate::thread::Thread;
+use crate::thread::{self, Thread};
 use crate::workspace;
 
 #[derive(Args)]
@@ -49,6 +49,8 @@ pub fn run(args: ResolveArgs, ws: &Path, config: &Config) -> Result<(), String>
 
     // Update status using config default
     let closed_status = &config.defaults.closed;
+    let transitions = config.behavior.transitions.as_ref();
+    thread::check_transition(&old_status, closed_status, transitions)?;
     t.set_frontmatter_field("status", closed_status)?;
 
     // Add log entry
@@ -61,15 +63,10 @@ pub fn run(args: ResolveArgs, ws: &Path, config: &Config) -> Result<(), String>
 
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    let committed = if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
-        let msg = args
-            .m
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-        true
+        let msg = args.m.unwrap_or_else(|| format!("threads: resolve {}", id));
+        git::commit_thread(&repo, &file, &msg)?
     } else {
         false
     };
@@ -83,14 +80,14 @@ pub fn run(args: ResolveArgs, ws: &Path, config: &Config) -> Result<(), String>
                 output::print_uncommitted_hint(&id);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = ResolveOutput {
                 id,
                 old_status,
                 path: rel_path,
                 committed,
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
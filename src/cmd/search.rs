@@ -1,16 +1,19 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use colored::Colorize;
+use regex::RegexBuilder;
 use serde::Serialize;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
+use crate::cache::{SearchIndex, ThreadDigest};
 use crate::config::{Config, is_quiet, root_name};
 use crate::fuzzy;
 use crate::output::{self, OutputFormat};
-use crate::thread::{self, Thread};
+use crate::thread;
 use crate::workspace;
 
 #[derive(Args)]
@@ -35,16 +38,96 @@ pub struct SearchArgs {
     #[arg(long)]
     status: Option<String>,
 
+    /// Only threads carrying all of these tags (comma-separated)
+    #[arg(long, value_name = "TAGS")]
+    tag: Option<String>,
+
+    /// Restrict matching to one part of each thread
+    #[arg(long = "in", value_enum, default_value_t = SearchScope::All, conflicts_with = "regex")]
+    scope: SearchScope,
+
+    /// Treat the query as a regular expression and list every matching line
+    #[arg(short = 'e', long)]
+    regex: bool,
+
+    /// Match regardless of case (default for --regex; pass =false to match case)
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        default_missing_value = "true",
+        requires = "regex"
+    )]
+    ignore_case: Option<bool>,
+
+    /// Fields a --regex search looks at (comma-separated; default all)
+    #[arg(long, value_enum, value_delimiter = ',', requires = "regex")]
+    section: Vec<Section>,
+
+    /// List matching lines with N lines of body context around each
+    #[arg(short = 'C', long, value_name = "N")]
+    context: Option<usize>,
+
+    /// Matching lines listed per thread (with --regex or --context)
+    #[arg(long, value_name = "N", default_value_t = 3)]
+    max_matches: usize,
+
     #[command(flatten)]
     format: FormatArgs,
 }
 
+/// Which parts of a thread a search matches against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum SearchScope {
+    /// Title, description, path, body, notes, and todos
+    #[default]
+    All,
+    /// Body text only
+    Body,
+    /// Notes only
+    Notes,
+    /// Todo items only
+    Todo,
+    /// Title only
+    Name,
+}
+
+/// Fields a regex search can look at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Section {
+    Body,
+    Notes,
+    Todo,
+    Log,
+}
+
+/// One matching line, listed by --regex and --context searches.
+#[derive(Clone, Serialize)]
+struct LineMatch {
+    section: Section,
+    /// 1-based line in the thread file
+    line: usize,
+    text: String,
+    /// Byte ranges of each match within `text`
+    #[serde(skip)]
+    spans: Vec<(usize, usize)>,
+    /// Ranks fuzzy hits within a thread
+    #[serde(skip)]
+    rank: i64,
+    /// Surrounding body lines as (line, text), for --context
+    #[serde(skip)]
+    context: Vec<(usize, String)>,
+}
+
 #[derive(Clone, Copy, Debug)]
 enum MatchKind {
     Title,
     Desc,
     Path,
     Body,
+    Note,
+    Todo,
 }
 
 impl MatchKind {
@@ -54,6 +137,8 @@ impl MatchKind {
             MatchKind::Desc => "desc",
             MatchKind::Path => "path",
             MatchKind::Body => "body",
+            MatchKind::Note => "note",
+            MatchKind::Todo => "todo",
         }
     }
 }
@@ -76,8 +161,18 @@ struct SearchResult {
     path_absolute: Option<String>,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     is_pwd: bool,
+    tags: Vec<String>,
     matched_in: String,
     snippet: String,
+    /// Matching lines, for --regex and --context searches
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    matches: Vec<LineMatch>,
+    /// Matching lines beyond --max-matches
+    #[serde(skip)]
+    more_matches: usize,
+    /// Thread file, git-root-relative
+    #[serde(skip)]
+    file: String,
 }
 
 pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), String> {
@@ -107,6 +202,22 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
         return Err("query is required".to_string());
     }
 
+    let pattern = if args.regex {
+        Some(
+            RegexBuilder::new(&query)
+                .case_insensitive(args.ignore_case.unwrap_or(true))
+                .build()
+                .map_err(|e| format!("invalid regex '{}': {}", query, e))?,
+        )
+    } else {
+        None
+    };
+    let sections = if args.section.is_empty() {
+        vec![Section::Body, Section::Notes, Section::Todo, Section::Log]
+    } else {
+        args.section.clone()
+    };
+
     // Resolve scope
     let scope = workspace::infer_scope(git_root, path_arg)?;
     let filter_path = scope.path.clone();
@@ -115,6 +226,11 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
     // Convert direction args to find options
     let options = args.direction.to_find_options();
 
+    let tag_filter = match args.tag.as_deref() {
+        Some(tags) => thread::parse_tag_filter(tags)?,
+        None => Vec::new(),
+    };
+
     // Find threads using options
     let threads = workspace::find_threads_with_options(start_path, git_root, &options)?;
 
@@ -122,11 +238,14 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
     let pwd_rel = workspace::pwd_relative_to_git_root(git_root).unwrap_or_else(|_| ".".to_string());
 
     // Determine if we need absolute paths (for json/yaml)
-    let include_absolute = matches!(format, OutputFormat::Json | OutputFormat::Yaml);
+    let include_absolute = format.is_structured();
 
     let mut results = Vec::new();
     let mut skipped_closed_metadata_matches = 0usize;
 
+    // Unchanged files are served from the index instead of being re-parsed
+    let mut index = SearchIndex::load(git_root);
+
     for thread_path in threads {
         let rel_path = workspace::parse_thread_path(git_root, &thread_path);
 
@@ -135,17 +254,20 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
             continue;
         }
 
-        let t = match Thread::parse(&thread_path) {
-            Ok(t) => t,
-            Err(_) => continue,
+        let Some(t) = index.get(git_root, &thread_path) else {
+            continue;
         };
 
-        let status = t.status().to_string();
+        if !tag_filter.iter().all(|tag| t.tags.contains(tag)) {
+            continue;
+        }
+
+        let status = t.status.clone();
         let base_status = thread::base_status(&status);
 
         // Resolve title early (used by filters and hints).
-        let title = if !t.name().is_empty() {
-            t.name().to_string()
+        let title = if !t.name.is_empty() {
+            t.name.clone()
         } else {
             let name = thread::extract_name_from_path(&thread_path);
             name.replace('-', " ")
@@ -159,7 +281,11 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
                 continue;
             }
         } else if !include_closed && thread::is_closed(&status) {
-            if matches_metadata(&tokens, &title, &t.frontmatter.desc, &rel_path) {
+            let searches_metadata = matches!(args.scope, SearchScope::All | SearchScope::Name);
+            if searches_metadata
+                && pattern.is_none()
+                && matches_metadata(&tokens, &title, &t.desc, &rel_path)
+            {
                 skipped_closed_metadata_matches += 1;
             }
             continue;
@@ -167,14 +293,64 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
 
         let is_pwd = rel_path == pwd_rel;
 
-        let Some(best) = best_match(&tokens, &title, &t.frontmatter.desc, &rel_path, t.body())
-        else {
+        let file = workspace::path_relative_to_git_root(git_root, &thread_path);
+        let context = args.context.unwrap_or(0);
+
+        if let Some(re) = &pattern {
+            let mut matches = line_matches(t, &sections, context, |text| {
+                let spans: Vec<(usize, usize)> =
+                    re.find_iter(text).map(|m| (m.start(), m.end())).collect();
+                (!spans.is_empty()).then_some((0, spans))
+            });
+            let total = matches.len();
+            matches.truncate(args.max_matches.max(1));
+            let Some(first) = matches.first() else {
+                continue;
+            };
+            results.push(SearchResult {
+                score: total as i64,
+                id: t.id.clone(),
+                status: base_status,
+                path: rel_path,
+                title,
+                path_absolute: if include_absolute {
+                    Some(thread_path.to_string_lossy().to_string())
+                } else {
+                    None
+                },
+                is_pwd,
+                tags: t.tags.clone(),
+                matched_in: section_name(first.section).to_string(),
+                snippet: format!("{}: {}", section_name(first.section), first.text),
+                more_matches: total - matches.len(),
+                matches,
+                file,
+            });
+            continue;
+        }
+
+        let lines = candidate_lines(args.scope, t, &title, &rel_path);
+        let Some(best) = best_match(&tokens, &lines) else {
             continue;
         };
 
+        // --context lists the best-ranked lines, shown in file order
+        let (matches, more_matches) = if args.context.is_some() {
+            let mut matches = line_matches(t, &scope_sections(args.scope), context, |text| {
+                fuzzy_line_match(&tokens, text)
+            });
+            matches.sort_by(|a, b| b.rank.cmp(&a.rank).then(a.line.cmp(&b.line)));
+            let more = matches.len().saturating_sub(args.max_matches.max(1));
+            matches.truncate(args.max_matches.max(1));
+            matches.sort_by_key(|m| m.line);
+            (matches, more)
+        } else {
+            (Vec::new(), 0)
+        };
+
         results.push(SearchResult {
             score: best.score,
-            id: t.id().to_string(),
+            id: t.id.clone(),
             status: base_status,
             path: rel_path,
             title,
@@ -184,11 +360,19 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
                 None
             },
             is_pwd,
+            tags: t.tags.clone(),
             matched_in: best.kind.as_str().to_string(),
             snippet: best.snippet,
+            matches,
+            more_matches,
+            file,
         });
     }
 
+    // The index is optional: a failed save only costs the next search a re-parse
+    index.prune(git_root);
+    let _ = index.save(git_root);
+
     // Sort by score descending, then by title for stable order
     results.sort_by(|a, b| {
         b.score
@@ -199,16 +383,24 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
 
     let include_closed = args.filter.include_closed();
 
+    // Shown in the summary line; regexes in /pattern/flags form
+    let shown_query = match &pattern {
+        Some(_) if args.ignore_case.unwrap_or(true) => format!("/{}/i", query),
+        Some(_) => format!("/{}/", query),
+        None => query.clone(),
+    };
+
     match format {
         OutputFormat::Pretty => output_pretty(
             &results,
             git_root,
             &filter_path,
             &pwd_rel,
-            &query,
+            &shown_query,
             &args.direction,
             include_closed,
             args.status.as_deref(),
+            args.scope,
             skipped_closed_metadata_matches,
             config,
         ),
@@ -217,14 +409,16 @@ pub fn run(args: SearchArgs, git_root: &Path, config: &Config) -> Result<(), Str
             git_root,
             &filter_path,
             &pwd_rel,
-            &query,
+            &shown_query,
             &args.direction,
             include_closed,
             args.status.as_deref(),
+            args.scope,
             skipped_closed_metadata_matches,
             config,
         ),
         OutputFormat::Json => output_json(&results, git_root, &pwd_rel, &query),
+        OutputFormat::Ndjson => output_ndjson(&results, git_root, &pwd_rel, &query),
         OutputFormat::Yaml => output_yaml(&results, git_root, &pwd_rel, &query),
     }
 }
@@ -241,35 +435,49 @@ fn matches_metadata(tokens: &[String], title: &str, desc: &str, rel_path: &str)
     true
 }
 
-fn best_match(
-    tokens: &[String],
+/// Build the (kind, line) candidates to match against for the chosen scope.
+fn candidate_lines(
+    scope: SearchScope,
+    t: &ThreadDigest,
     title: &str,
-    desc: &str,
     rel_path: &str,
-    body: &str,
-) -> Option<SearchMatch> {
-    // Build candidate lines (trimmed, non-empty).
-    let mut lines: Vec<(MatchKind, &str)> = Vec::new();
-    let title = title.trim();
-    if !title.is_empty() {
-        lines.push((MatchKind::Title, title));
-    }
-    let desc = desc.trim();
-    if !desc.is_empty() {
-        lines.push((MatchKind::Desc, desc));
-    }
-    let rel_path = rel_path.trim();
-    if !rel_path.is_empty() {
-        lines.push((MatchKind::Path, rel_path));
-    }
-    for line in body.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+) -> Vec<(MatchKind, String)> {
+    let mut lines: Vec<(MatchKind, String)> = Vec::new();
+    let mut push = |kind: MatchKind, text: &str| {
+        let text = text.trim();
+        if !text.is_empty() {
+            lines.push((kind, text.to_string()));
+        }
+    };
+
+    let all = scope == SearchScope::All;
+    if all || scope == SearchScope::Name {
+        push(MatchKind::Title, title);
+    }
+    if all {
+        push(MatchKind::Desc, &t.desc);
+        push(MatchKind::Path, rel_path);
+    }
+    if all || scope == SearchScope::Body {
+        for line in &t.body {
+            push(MatchKind::Body, line);
+        }
+    }
+    if all || scope == SearchScope::Notes {
+        for note in &t.notes {
+            push(MatchKind::Note, &note.text);
         }
-        lines.push((MatchKind::Body, line));
     }
+    if all || scope == SearchScope::Todo {
+        for item in &t.todo {
+            push(MatchKind::Todo, &item.text);
+        }
+    }
+
+    lines
+}
 
+fn best_match(tokens: &[String], lines: &[(MatchKind, String)]) -> Option<SearchMatch> {
     if lines.is_empty() {
         return None;
     }
@@ -278,7 +486,7 @@ fn best_match(
     let mut total = 0i64;
     for tok in tokens {
         let mut best_tok: Option<i64> = None;
-        for (_, line) in &lines {
+        for (_, line) in lines {
             if let Some(s) = fuzzy::score(tok, line) {
                 best_tok = Some(best_tok.map_or(s, |cur| cur.max(s)));
             }
@@ -288,7 +496,7 @@ fn best_match(
 
     // Snippet: pick the single line that matches the most tokens, then the highest summed score.
     let mut best_line: Option<(usize, i64, MatchKind, &str)> = None;
-    for (kind, line) in &lines {
+    for (kind, line) in lines {
         let mut matched = 0usize;
         let mut sum = 0i64;
         for tok in tokens {
@@ -302,7 +510,7 @@ fn best_match(
         }
 
         best_line = match best_line {
-            None => Some((matched, sum, *kind, *line)),
+            None => Some((matched, sum, *kind, line.as_str())),
             Some((best_matched, best_sum, best_kind, best_line)) => {
                 if matched > best_matched
                     || (matched == best_matched && sum > best_sum)
@@ -314,7 +522,7 @@ fn best_match(
                         && kind.as_str() == best_kind.as_str()
                         && line.len() < best_line.len())
                 {
-                    Some((matched, sum, *kind, *line))
+                    Some((matched, sum, *kind, line.as_str()))
                 } else {
                     Some((best_matched, best_sum, best_kind, best_line))
                 }
@@ -335,7 +543,106 @@ fn best_match(
     })
 }
 
-/// Row data for pretty output table.
+fn section_name(section: Section) -> &'static str {
+    match section {
+        Section::Body => "body",
+        Section::Notes => "note",
+        Section::Todo => "todo",
+        Section::Log => "log",
+    }
+}
+
+/// Fields of a thread that have lines to list for an `--in` scope.
+fn scope_sections(scope: SearchScope) -> Vec<Section> {
+    match scope {
+        SearchScope::All => vec![Section::Body, Section::Notes, Section::Todo],
+        SearchScope::Body => vec![Section::Body],
+        SearchScope::Notes => vec![Section::Notes],
+        SearchScope::Todo => vec![Section::Todo],
+        SearchScope::Name => Vec::new(),
+    }
+}
+
+/// Rank a line by its fuzzy token scores; highlight exact (ASCII
+/// case-insensitive) occurrences of the tokens.
+fn fuzzy_line_match(tokens: &[String], text: &str) -> Option<(i64, Vec<(usize, usize)>)> {
+    let mut rank = None;
+    for tok in tokens {
+        if let Some(s) = fuzzy::score(tok, text) {
+            rank = Some(rank.unwrap_or(0) + s);
+        }
+    }
+    let rank = rank?;
+
+    // ASCII folding keeps byte offsets, and a match of a whole token always
+    // starts and ends on a char boundary
+    let folded = text.to_ascii_lowercase();
+    let mut spans = Vec::new();
+    for tok in tokens {
+        let tok = tok.to_ascii_lowercase();
+        spans.extend(folded.match_indices(&tok).map(|(i, m)| (i, i + m.len())));
+    }
+    Some((rank, spans))
+}
+
+/// Lines in the chosen sections accepted by `matcher`, in file order.
+///
+/// `matcher` returns a rank and the byte spans to highlight. Body hits carry
+/// `context` lines on either side.
+fn line_matches(
+    t: &ThreadDigest,
+    sections: &[Section],
+    context: usize,
+    matcher: impl Fn(&str) -> Option<(i64, Vec<(usize, usize)>)>,
+) -> Vec<LineMatch> {
+    let mut found = Vec::new();
+    let mut check = |section: Section, line: usize, text: &str| {
+        if let Some((rank, spans)) = matcher(text) {
+            found.push(LineMatch {
+                section,
+                line,
+                text: text.to_string(),
+                spans,
+                rank,
+                context: Vec::new(),
+            });
+        }
+    };
+
+    // Items live in the frontmatter; each one is reported at its `text:` line
+    for (section, items) in [
+        (Section::Notes, &t.notes),
+        (Section::Todo, &t.todo),
+        (Section::Log, &t.log),
+    ] {
+        if !sections.contains(&section) {
+            continue;
+        }
+        for item in items {
+            for part in item.text.lines() {
+                check(section, item.line, part);
+            }
+        }
+    }
+
+    if sections.contains(&Section::Body) {
+        let offset = t.body_line;
+        for (i, text) in t.body.iter().enumerate() {
+            check(Section::Body, offset + i, text);
+        }
+        for m in found.iter_mut().filter(|m| m.section == Section::Body) {
+            let i = m.line - offset;
+            m.context = (i.saturating_sub(context)..(i + context + 1).min(t.body.len()))
+                .filter(|&j| j != i)
+                .map(|j| (offset + j, t.body[j].clone()))
+                .collect();
+        }
+    }
+
+    found.sort_by_key(|m| m.line);
+    found
+}
+
 #[derive(Tabled)]
 struct TableRow {
     #[tabled(rename = "SCORE")]
@@ -354,6 +661,7 @@ fn build_filter_desc(
     include_closed: bool,
     status_filter: Option<&str>,
     query: &str,
+    scope: SearchScope,
     direction: &DirectionArgs,
 ) -> String {
     let mut parts = Vec::new();
@@ -368,6 +676,12 @@ fn build_filter_desc(
 
     parts.push(format!("query=\"{}\"", query));
 
+    if scope != SearchScope::All
+        && let Some(value) = scope.to_possible_value()
+    {
+        parts.push(format!("in={}", value.get_name()));
+    }
+
     let dir_desc = direction.description();
     if !dir_desc.is_empty() {
         parts.push(dir_desc);
@@ -386,6 +700,7 @@ fn output_pretty(
     direction: &DirectionArgs,
     include_closed: bool,
     status_filter: Option<&str>,
+    scope: SearchScope,
     skipped_closed_metadata_matches: usize,
     config: &Config,
 ) -> Result<(), String> {
@@ -408,7 +723,7 @@ fn output_pretty(
 
     println!("{}{}{}", repo_name.bold(), path_desc.dimmed(), pwd_marker);
 
-    let filter_desc = build_filter_desc(include_closed, status_filter, query, direction);
+    let filter_desc = build_filter_desc(include_closed, status_filter, query, scope, direction);
     println!(
         "{} matches ({})",
         results.len().to_string().bold(),
@@ -437,6 +752,11 @@ fn output_pretty(
         return Ok(());
     }
 
+    if results.iter().any(|r| !r.matches.is_empty()) {
+        print_line_matches(results, pwd_rel);
+        return Ok(());
+    }
+
     let term_width = output::terminal_width();
     let title_max = 28usize;
     let _unused = term_width; // keep width calc pattern consistent with list/stats
@@ -475,6 +795,7 @@ fn output_plain(
     direction: &DirectionArgs,
     include_closed: bool,
     status_filter: Option<&str>,
+    scope: SearchScope,
     skipped_closed_metadata_matches: usize,
     config: &Config,
 ) -> Result<(), String> {
@@ -499,7 +820,7 @@ fn output_plain(
         ""
     };
 
-    let filter_desc = build_filter_desc(include_closed, status_filter, query, direction);
+    let filter_desc = build_filter_desc(include_closed, status_filter, query, scope, direction);
     println!(
         "Showing {} matches in {}{} ({})",
         results.len(),
@@ -522,6 +843,11 @@ fn output_plain(
         return Ok(());
     }
 
+    if results.iter().any(|r| !r.matches.is_empty()) {
+        print_grep_lines(results);
+        return Ok(());
+    }
+
     println!("SCORE | ID | STATUS | PATH | TITLE");
     for r in results {
         println!(
@@ -533,6 +859,121 @@ fn output_plain(
     Ok(())
 }
 
+/// Listing for --regex and --context: a header per thread, then its matching
+/// lines (and any context) with the matched text highlighted.
+fn print_line_matches(results: &[SearchResult], pwd_rel: &str) {
+    for (i, r) in results.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!(
+            "{} {} {}  {}",
+            output::style_id(&r.id),
+            output::style_status(&r.status),
+            r.title.bold(),
+            output::shortest_path(&r.path, pwd_rel).dimmed()
+        );
+
+        for (gap, line, entry) in listing(&r.matches) {
+            if gap {
+                println!("  {}", "--".dimmed());
+            }
+            match entry {
+                Entry::Match(m) => println!(
+                    "  {:>4}  {:<4}  {}",
+                    line.to_string().green(),
+                    section_name(m.section).dimmed(),
+                    highlight(&m.text, &m.spans)
+                ),
+                Entry::Context(text) => {
+                    println!(
+                        "  {:>4}  {:<4}  {}",
+                        line.to_string().dimmed(),
+                        "",
+                        text.dimmed()
+                    )
+                }
+            }
+        }
+        if r.more_matches > 0 {
+            println!(
+                "  {}",
+                format!("… {} more (see --max-matches)", r.more_matches).dimmed()
+            );
+        }
+    }
+}
+
+/// grep-style `file:line: text` lines; context lines use `-` like `grep -C`.
+fn print_grep_lines(results: &[SearchResult]) {
+    let has_context = results
+        .iter()
+        .any(|r| r.matches.iter().any(|m| !m.context.is_empty()));
+    for (i, r) in results.iter().enumerate() {
+        if i > 0 && has_context {
+            println!("--");
+        }
+        for (gap, line, entry) in listing(&r.matches) {
+            if gap {
+                println!("--");
+            }
+            match entry {
+                Entry::Match(m) => println!("{}:{}: {}", r.file, line, m.text),
+                Entry::Context(text) => println!("{}-{}- {}", r.file, line, text),
+            }
+        }
+    }
+}
+
+enum Entry<'a> {
+    Match(&'a LineMatch),
+    Context(&'a str),
+}
+
+/// Matches and their context merged into file order as `(gap, line, entry)`,
+/// where `gap` marks a jump past lines that are not shown.
+fn listing(matches: &[LineMatch]) -> Vec<(bool, usize, Entry<'_>)> {
+    let mut lines: BTreeMap<usize, Entry<'_>> = BTreeMap::new();
+    for m in matches {
+        for (line, text) in &m.context {
+            lines.entry(*line).or_insert(Entry::Context(text));
+        }
+    }
+    for m in matches {
+        lines.insert(m.line, Entry::Match(m));
+    }
+
+    let has_context = matches.iter().any(|m| !m.context.is_empty());
+    let mut prev: Option<usize> = None;
+    lines
+        .into_iter()
+        .map(|(line, entry)| {
+            let gap = has_context && prev.is_some_and(|p| line > p + 1);
+            prev = Some(line);
+            (gap, line, entry)
+        })
+        .collect()
+}
+
+/// Render `text` with each matched span in bold red. Overlapping spans merge.
+fn highlight(text: &str, spans: &[(usize, usize)]) -> String {
+    let mut spans = spans.to_vec();
+    spans.sort();
+    let mut out = String::with_capacity(text.len());
+    let mut pos = 0;
+    for (start, end) in spans {
+        let start = start.max(pos);
+        if start >= end || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+            continue;
+        }
+        out.push_str(&text[pos..start]);
+        out.push_str(&text[start..end].red().bold().to_string());
+        pos = end;
+    }
+    out.push_str(&text[pos..]);
+    out
+}
+
 #[derive(Serialize)]
 struct SearchResultJson<'a> {
     score: i64,
@@ -540,12 +981,33 @@ struct SearchResultJson<'a> {
     status: &'a str,
     path: &'a str,
     title: &'a str,
+    tags: &'a [String],
     matched_in: &'a str,
     snippet: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
     path_absolute: Option<&'a str>,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     is_pwd: bool,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    matches: &'a [LineMatch],
+}
+
+impl<'a> From<&'a SearchResult> for SearchResultJson<'a> {
+    fn from(r: &'a SearchResult) -> Self {
+        SearchResultJson {
+            score: r.score,
+            id: &r.id,
+            status: &r.status,
+            path: &r.path,
+            title: &r.title,
+            tags: &r.tags,
+            matched_in: &r.matched_in,
+            snippet: &r.snippet,
+            path_absolute: r.path_absolute.as_deref(),
+            is_pwd: r.is_pwd,
+            matches: &r.matches,
+        }
+    }
 }
 
 fn output_json(
@@ -558,20 +1020,7 @@ fn output_json(
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| String::new());
 
-    let matches: Vec<SearchResultJson<'_>> = results
-        .iter()
-        .map(|r| SearchResultJson {
-            score: r.score,
-            id: &r.id,
-            status: &r.status,
-            path: &r.path,
-            title: &r.title,
-            matched_in: &r.matched_in,
-            snippet: &r.snippet,
-            path_absolute: r.path_absolute.as_deref(),
-            is_pwd: r.is_pwd,
-        })
-        .collect();
+    let matches: Vec<SearchResultJson<'_>> = results.iter().map(SearchResultJson::from).collect();
 
     #[derive(Serialize)]
     struct JsonOutput<'a> {
@@ -596,6 +1045,37 @@ fn output_json(
     Ok(())
 }
 
+/// The JSON envelope's metadata on the first line, then one match per line.
+fn output_ndjson(
+    results: &[SearchResult],
+    git_root: &Path,
+    pwd_rel: &str,
+    query: &str,
+) -> Result<(), String> {
+    let pwd = std::env::current_dir()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| String::new());
+
+    #[derive(Serialize)]
+    struct Metadata<'a> {
+        pwd: String,
+        git_root: String,
+        pwd_relative: String,
+        query: &'a str,
+    }
+
+    output::print_ndjson(&Metadata {
+        pwd,
+        git_root: git_root.to_string_lossy().to_string(),
+        pwd_relative: pwd_rel.to_string(),
+        query,
+    })?;
+    for r in results {
+        output::print_ndjson(&SearchResultJson::from(r))?;
+    }
+    Ok(())
+}
+
 fn output_yaml(
     results: &[SearchResult],
     git_root: &Path,
@@ -606,20 +1086,7 @@ fn output_yaml(
         .map(|p| p.to_string_lossy().to_string())
         .unwrap_or_else(|_| String::new());
 
-    let matches: Vec<SearchResultJson<'_>> = results
-        .iter()
-        .map(|r| SearchResultJson {
-            score: r.score,
-            id: &r.id,
-            status: &r.status,
-            path: &r.path,
-            title: &r.title,
-            matched_in: &r.matched_in,
-            snippet: &r.snippet,
-            path_absolute: r.path_absolute.as_deref(),
-            is_pwd: r.is_pwd,
-        })
-        .collect();
+    let matches: Vec<SearchResultJson<'_>> = results.iter().map(SearchResultJson::from).collect();
 
     #[derive(Serialize)]
     struct YamlOutput<'a> {
@@ -1,17 +1,21 @@
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-use clap::Args;
+use chrono::{DateTime, Local};
+use clap::{Args, ValueEnum};
 use colored::Colorize;
 use serde::Serialize;
+use tabled::builder::Builder;
 use tabled::settings::Style;
-use tabled::{Table, Tabled};
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
+use crate::cache::TimestampCache;
+use crate::cmd::list::get_timestamps;
 use crate::config::{Config, is_quiet, root_name};
+use crate::git;
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, Thread};
-use crate::workspace;
+use crate::workspace::{self, FindOptions};
 
 #[derive(Args)]
 pub struct StatsArgs {
@@ -27,6 +31,72 @@ pub struct StatsArgs {
 
     #[command(flatten)]
     format: FormatArgs,
+
+    /// Group counts by this dimension; a thread counts once per tag with `tag`
+    #[arg(
+        long,
+        value_enum,
+        value_name = "DIMENSION",
+        default_value_t = StatsBy::Status,
+        conflicts_with_all = ["compare_ref", "stale"]
+    )]
+    by: StatsBy,
+
+    /// Print only `key<TAB>count` lines (no header, summary, or total)
+    #[arg(long, visible_alias = "porcelain")]
+    machine: bool,
+
+    /// Compare counts committed at REF with those at HEAD, per status
+    #[arg(long, value_name = "REF", conflicts_with = "machine")]
+    compare_ref: Option<String>,
+
+    /// Bucket threads by time since last modification; DAYS (default 30) marks them stale
+    #[arg(
+        long,
+        value_name = "DAYS",
+        num_args = 0..=1,
+        default_missing_value = "30",
+        conflicts_with = "compare_ref"
+    )]
+    stale: Option<i64>,
+}
+
+/// What `stats --by` groups threads on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum StatsBy {
+    Status,
+    /// Git-relative directory holding the thread
+    Path,
+    Tag,
+    Priority,
+}
+
+impl StatsBy {
+    fn header(self) -> &'static str {
+        match self {
+            StatsBy::Status => "STATUS",
+            StatsBy::Path => "PATH",
+            StatsBy::Tag => "TAG",
+            StatsBy::Priority => "PRIORITY",
+        }
+    }
+
+    /// The keys `t` counts under; threads without one count as "(none)".
+    fn keys(self, t: &Thread, rel_path: &str) -> Vec<String> {
+        let keys = match self {
+            StatsBy::Status => vec![t.base_status()],
+            StatsBy::Path => vec![rel_path.to_string()],
+            StatsBy::Tag => t.tags().to_vec(),
+            StatsBy::Priority => t.get_priority().map(str::to_string).into_iter().collect(),
+        };
+        let keys: Vec<String> = keys.into_iter().filter(|k| !k.is_empty()).collect();
+        if keys.is_empty() {
+            vec!["(none)".to_string()]
+        } else {
+            keys
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -35,6 +105,13 @@ struct StatusCount {
     count: usize,
 }
 
+/// A `--by` group other than status.
+#[derive(Serialize)]
+struct KeyCount {
+    key: String,
+    count: usize,
+}
+
 pub fn run(args: StatsArgs, git_root: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
@@ -48,58 +125,52 @@ pub fn run(args: StatsArgs, git_root: &Path, config: &Config) -> Result<(), Stri
     // Resolve the scope
     let scope = workspace::infer_scope(git_root, path_filter)?;
     let filter_path = scope.path.clone();
+
+    if let Some(ref rev) = args.compare_ref {
+        return run_compare(&args, rev, git_root, &filter_path, format, config);
+    }
+
     let start_path = scope.threads_dir.parent().unwrap_or(git_root);
 
+    if let Some(days) = args.stale {
+        return run_stale(
+            &args,
+            days,
+            start_path,
+            git_root,
+            &filter_path,
+            format,
+            config,
+        );
+    }
+
     // Convert direction args to find options
     let options = args.direction.to_find_options();
 
     // Find threads using options
     let threads = workspace::find_threads_with_options(start_path, git_root, &options)?;
 
-    let mut counts: HashMap<String, usize> = HashMap::new();
-    let mut total = 0;
-
-    for path in threads {
-        let rel_path = workspace::parse_thread_path(git_root, &path);
-
-        // Path filter: if not searching, only count threads at the specified level
-        if !args.direction.is_searching() && rel_path != filter_path {
-            continue;
-        }
-        // Note: find_threads_with_options already handles direction/depth filtering
-
-        let t = match Thread::parse(&path) {
-            Ok(t) => t,
-            Err(_) => continue,
-        };
-
-        let status = t.base_status();
-
-        // Filter out closed threads unless --include-closed
-        if !args.filter.include_closed() && thread::is_closed(&status) {
-            continue;
-        }
+    let by = args.by;
+    let (sorted, total) = count_threads(
+        &threads,
+        git_root,
+        &filter_path,
+        &args.direction,
+        args.filter.include_closed(),
+        |t, rel_path| by.keys(t, rel_path),
+    );
 
-        let status = if status.is_empty() {
-            "(none)".to_string()
-        } else {
-            status
-        };
+    let include_closed = args.filter.include_closed();
 
-        *counts.entry(status).or_insert(0) += 1;
-        total += 1;
+    if args.machine {
+        return output_machine(&sorted);
     }
 
-    // Sort by count descending
-    let mut sorted: Vec<_> = counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
-
-    let include_closed = args.filter.include_closed();
-
     match format {
         OutputFormat::Pretty => output_pretty(
             &sorted,
             total,
+            by,
             &filter_path,
             &args.direction,
             include_closed,
@@ -108,24 +179,99 @@ pub fn run(args: StatsArgs, git_root: &Path, config: &Config) -> Result<(), Stri
         OutputFormat::Plain => output_plain(
             &sorted,
             total,
+            by,
             git_root,
             &filter_path,
             &args.direction,
             include_closed,
             config,
         ),
-        OutputFormat::Json => output_json(&sorted, total, git_root, &filter_path),
-        OutputFormat::Yaml => output_yaml(&sorted, total, git_root, &filter_path),
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            if by == StatsBy::Status {
+                let envelope = build_envelope(
+                    &sorted,
+                    total,
+                    git_root,
+                    &filter_path,
+                    &args.direction,
+                    include_closed,
+                );
+                print_structured(&envelope, format)
+            } else {
+                let counts = sorted
+                    .iter()
+                    .map(|(key, count)| KeyCount {
+                        key: key.clone(),
+                        count: *count,
+                    })
+                    .collect();
+                let envelope = wrap_envelope(
+                    GroupData { by, counts, total },
+                    git_root,
+                    &filter_path,
+                    &args.direction,
+                    include_closed,
+                );
+                print_structured(&envelope, format)
+            }
+        }
     }
 }
 
-/// Row data for stats table
-#[derive(Tabled)]
-struct StatsRow {
-    #[tabled(rename = "STATUS")]
-    status: String,
-    #[tabled(rename = "COUNT")]
-    count: String,
+/// Count the threads in scope under the keys `key` gives each one, sorted by
+/// count (descending), then key. The total counts threads, not keys.
+fn count_threads(
+    threads: &[PathBuf],
+    git_root: &Path,
+    filter_path: &str,
+    direction: &DirectionArgs,
+    include_closed: bool,
+    key: impl Fn(&Thread, &str) -> Vec<String>,
+) -> (Vec<(String, usize)>, usize) {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut total = 0;
+
+    for path in threads {
+        let rel_path = workspace::parse_thread_path(git_root, path);
+
+        // Path filter: if not searching, only count threads at the specified level
+        if !direction.is_searching() && rel_path != filter_path {
+            continue;
+        }
+        // Note: find_threads_with_options already handles direction/depth filtering
+
+        let t = match Thread::parse(path) {
+            Ok(t) => t,
+            Err(_) => continue,
+        };
+
+        // Filter out closed threads unless --include-closed
+        if !include_closed && thread::is_closed(&t.base_status()) {
+            continue;
+        }
+
+        for k in key(&t, &rel_path) {
+            *counts.entry(k).or_insert(0) += 1;
+        }
+        total += 1;
+    }
+
+    let mut sorted: Vec<_> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    (sorted, total)
+}
+
+fn print_structured<T: Serialize>(value: &T, format: OutputFormat) -> Result<(), String> {
+    if matches!(format, OutputFormat::Json | OutputFormat::Ndjson) {
+        let json = output::to_json(value, format)
+            .map_err(|e| format!("JSON serialization failed: {}", e))?;
+        println!("{}", json);
+    } else {
+        let yaml = serde_yaml::to_string(value)
+            .map_err(|e| format!("YAML serialization failed: {}", e))?;
+        print!("{}", yaml);
+    }
+    Ok(())
 }
 
 /// Build filter description for summary line
@@ -149,6 +295,7 @@ fn build_filter_desc(include_closed: bool, direction: &DirectionArgs) -> String
 fn output_pretty(
     sorted: &[(String, usize)],
     total: usize,
+    by: StatsBy,
     filter_path: &str,
     direction: &DirectionArgs,
     include_closed: bool,
@@ -181,22 +328,25 @@ fn output_pretty(
         return Ok(());
     }
 
-    // Build table rows with styled status
-    let mut rows: Vec<StatsRow> = sorted
-        .iter()
-        .map(|(status, count)| StatsRow {
-            status: output::style_status(status).to_string(),
-            count: count.to_string(),
-        })
-        .collect();
+    // Build table rows, statuses styled
+    let mut builder = Builder::default();
+    builder.push_record([by.header(), "COUNT"]);
+    for (key, count) in sorted {
+        let key = if by == StatsBy::Status {
+            output::style_status(key).to_string()
+        } else {
+            key.clone()
+        };
+        builder.push_record([key, count.to_string()]);
+    }
 
     // Add total row
-    rows.push(StatsRow {
-        status: "Total".bold().to_string(),
-        count: total.to_string().bold().to_string(),
-    });
+    builder.push_record([
+        "Total".bold().to_string(),
+        total.to_string().bold().to_string(),
+    ]);
 
-    let mut table = Table::new(rows);
+    let mut table = builder.build();
     table.with(Style::rounded());
 
     println!("{}", table);
@@ -204,9 +354,11 @@ fn output_pretty(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn output_plain(
     sorted: &[(String, usize)],
     total: usize,
+    by: StatsBy,
     git_root: &Path,
     filter_path: &str,
     direction: &DirectionArgs,
@@ -241,81 +393,463 @@ fn output_plain(
     }
 
     // Plain pipe-delimited format
-    println!("STATUS | COUNT");
-    for (status, count) in sorted {
-        println!("{} | {}", status, count);
+    println!("{} | COUNT", by.header());
+    for (key, count) in sorted {
+        println!("{} | {}", key, count);
     }
     println!("Total | {}", total);
 
     Ok(())
 }
 
-fn output_json(
+/// Line-oriented output for scripts: one `key<TAB>count` per line.
+fn output_machine(sorted: &[(String, usize)]) -> Result<(), String> {
+    for (key, count) in sorted {
+        println!("{}\t{}", key, count);
+    }
+    Ok(())
+}
+
+/// Common envelope for structured stats output; `data` holds the counts.
+#[derive(Serialize)]
+struct StatsEnvelope<D> {
+    generated_at: String,
+    scope: StatsScope,
+    filters: StatsFilters,
+    data: D,
+}
+
+#[derive(Serialize)]
+struct StatsScope {
+    git_root: String,
+    path: String,
+}
+
+#[derive(Serialize)]
+struct StatsFilters {
+    include_closed: bool,
+    /// Active search direction, e.g. "down 2" (null when local only)
+    direction: Option<String>,
+}
+
+#[derive(Serialize)]
+struct StatsData {
+    counts: Vec<StatusCount>,
+    total: usize,
+}
+
+/// `data` for `--by` path, tag or priority
+#[derive(Serialize)]
+struct GroupData {
+    by: StatsBy,
+    counts: Vec<KeyCount>,
+    /// Threads counted; with `--by tag` the counts can add up to more
+    total: usize,
+}
+
+fn build_envelope(
     sorted: &[(String, usize)],
     total: usize,
     git_root: &Path,
     filter_path: &str,
-) -> Result<(), String> {
-    #[derive(Serialize)]
-    struct JsonOutput {
-        git_root: String,
-        path: String,
-        counts: Vec<StatusCount>,
-        total: usize,
-    }
-
-    let counts: Vec<StatusCount> = sorted
+    direction: &DirectionArgs,
+    include_closed: bool,
+) -> StatsEnvelope<StatsData> {
+    let counts = sorted
         .iter()
         .map(|(status, count)| StatusCount {
             status: status.clone(),
             count: *count,
         })
         .collect();
+    wrap_envelope(
+        StatsData { counts, total },
+        git_root,
+        filter_path,
+        direction,
+        include_closed,
+    )
+}
 
-    let output = JsonOutput {
-        git_root: git_root.to_string_lossy().to_string(),
-        path: filter_path.to_string(),
-        counts,
-        total,
+fn wrap_envelope<D>(
+    data: D,
+    git_root: &Path,
+    filter_path: &str,
+    direction: &DirectionArgs,
+    include_closed: bool,
+) -> StatsEnvelope<D> {
+    let direction = direction.description();
+    let direction = direction.trim_start_matches('(').trim_end_matches(')');
+
+    StatsEnvelope {
+        generated_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        scope: StatsScope {
+            git_root: git_root.to_string_lossy().to_string(),
+            path: filter_path.to_string(),
+        },
+        filters: StatsFilters {
+            include_closed,
+            direction: (!direction.is_empty()).then(|| direction.to_string()),
+        },
+        data,
+    }
+}
+
+// ============================================================================
+// Compare
+// ============================================================================
+
+#[derive(Serialize)]
+struct CompareData {
+    #[serde(rename = "ref")]
+    rev: String,
+    counts: Vec<StatusDelta>,
+    total: StatusDelta,
+    /// Threads at HEAD that did not exist at the ref
+    new_threads: usize,
+    /// Threads at the ref that no longer exist at HEAD
+    removed_threads: usize,
+}
+
+#[derive(Serialize)]
+struct StatusDelta {
+    status: String,
+    before: usize,
+    after: usize,
+    delta: i64,
+}
+
+impl StatusDelta {
+    fn new(status: &str, before: usize, after: usize) -> Self {
+        StatusDelta {
+            status: status.to_string(),
+            before,
+            after,
+            delta: after as i64 - before as i64,
+        }
+    }
+
+    /// e.g. "active: 12 → 9 (-3)"
+    fn line(&self) -> String {
+        format!(
+            "{}: {} → {} ({:+})",
+            self.status, self.before, self.after, self.delta
+        )
+    }
+}
+
+/// Status counts and thread ids committed at `rev`, within the scope.
+struct CountsAt {
+    counts: HashMap<String, usize>,
+    ids: HashSet<String>,
+}
+
+fn counts_at(
+    repo: &git2::Repository,
+    rev: &str,
+    filter_path: &str,
+    options: &FindOptions,
+    include_closed: bool,
+) -> Result<CountsAt, String> {
+    let mut at = CountsAt {
+        counts: HashMap::new(),
+        ids: HashSet::new(),
     };
 
-    let json = serde_json::to_string_pretty(&output)
-        .map_err(|e| format!("JSON serialization failed: {}", e))?;
-    println!("{}", json);
-    Ok(())
+    for (path, content) in git::thread_files_at(repo, rev)? {
+        let dir = Path::new(&path)
+            .parent()
+            .and_then(|p| p.parent())
+            .map(|p| p.to_string_lossy().to_string())
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| ".".to_string());
+        if !dir_in_scope(&dir, filter_path, options) {
+            continue;
+        }
+
+        let Ok(t) = Thread::from_content(Path::new(&path), &content) else {
+            continue;
+        };
+        let status = t.base_status();
+        if !include_closed && thread::is_closed(&status) {
+            continue;
+        }
+        let status = if status.is_empty() {
+            "(none)".to_string()
+        } else {
+            status
+        };
+
+        *at.counts.entry(status).or_insert(0) += 1;
+        at.ids.insert(t.id().to_string());
+    }
+
+    Ok(at)
 }
 
-fn output_yaml(
-    sorted: &[(String, usize)],
-    total: usize,
+/// Whether threads in `dir` fall within the search around `scope`, mirroring
+/// the working-tree search. Both are git-root-relative ("." for the root).
+fn dir_in_scope(dir: &str, scope: &str, options: &FindOptions) -> bool {
+    if dir == scope {
+        return true;
+    }
+    let parts = |p: &str| -> Vec<String> {
+        if p == "." {
+            Vec::new()
+        } else {
+            p.split('/').map(str::to_string).collect()
+        }
+    };
+    let (dir, scope) = (parts(dir), parts(scope));
+    let within = |levels: usize, limit: Option<usize>| limit.is_none_or(|n| n == 0 || levels <= n);
+
+    let below = options
+        .down
+        .is_some_and(|limit| dir.starts_with(&scope) && within(dir.len() - scope.len(), limit));
+    let above = options
+        .up
+        .is_some_and(|limit| scope.starts_with(&dir) && within(scope.len() - dir.len(), limit));
+    below || above
+}
+
+fn run_compare(
+    args: &StatsArgs,
+    rev: &str,
     git_root: &Path,
     filter_path: &str,
+    format: OutputFormat,
+    config: &Config,
 ) -> Result<(), String> {
-    #[derive(Serialize)]
-    struct YamlOutput {
-        git_root: String,
-        path: String,
-        counts: Vec<StatusCount>,
-        total: usize,
-    }
+    let repo = workspace::open()?;
+    let options = args.direction.to_find_options();
+    let include_closed = args.filter.include_closed();
+
+    let before = counts_at(&repo, rev, filter_path, &options, include_closed)?;
+    let after = counts_at(&repo, "HEAD", filter_path, &options, include_closed)?;
+
+    let mut statuses: Vec<&String> = before.counts.keys().chain(after.counts.keys()).collect();
+    statuses.sort();
+    statuses.dedup();
 
-    let counts: Vec<StatusCount> = sorted
+    let count = |at: &CountsAt, status: &str| at.counts.get(status).copied().unwrap_or(0);
+    let mut counts: Vec<StatusDelta> = statuses
         .iter()
-        .map(|(status, count)| StatusCount {
-            status: status.clone(),
-            count: *count,
-        })
+        .map(|s| StatusDelta::new(s, count(&before, s), count(&after, s)))
         .collect();
-
-    let output = YamlOutput {
-        git_root: git_root.to_string_lossy().to_string(),
-        path: filter_path.to_string(),
+    counts.sort_by(|a, b| b.after.cmp(&a.after).then(b.before.cmp(&a.before)));
+
+    let data = CompareData {
+        rev: rev.to_string(),
+        total: StatusDelta::new(
+            "Total",
+            before.counts.values().sum(),
+            after.counts.values().sum(),
+        ),
         counts,
-        total,
+        new_threads: after.ids.difference(&before.ids).count(),
+        removed_threads: before.ids.difference(&after.ids).count(),
     };
 
-    let yaml =
-        serde_yaml::to_string(&output).map_err(|e| format!("YAML serialization failed: {}", e))?;
-    print!("{}", yaml);
-    Ok(())
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            let path_desc = if filter_path == "." {
+                root_name(config).to_string()
+            } else {
+                filter_path.to_string()
+            };
+            let filter_desc = build_filter_desc(include_closed, &args.direction);
+            let header = format!("Status changes in {} since {}", path_desc, rev);
+
+            if format == OutputFormat::Pretty {
+                println!("{} ({})", header.bold(), filter_desc.dimmed());
+            } else {
+                println!("{} ({})", header, filter_desc);
+            }
+            println!();
+
+            if data.counts.is_empty() {
+                println!("No threads found at {} or HEAD.", rev);
+                return Ok(());
+            }
+            for delta in &data.counts {
+                println!("{}", delta.line());
+            }
+            println!("{}", data.total.line());
+            if data.new_threads > 0 || data.removed_threads > 0 {
+                println!();
+                println!(
+                    "{} new, {} removed since {}",
+                    data.new_threads, data.removed_threads, rev
+                );
+            }
+            Ok(())
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let envelope =
+                wrap_envelope(data, git_root, filter_path, &args.direction, include_closed);
+            print_structured(&envelope, format)
+        }
+    }
+}
+
+// ============================================================================
+// Stale
+// ============================================================================
+
+/// Age buckets, as (label, upper bound in days); the last one is open-ended.
+const STALE_BUCKETS: [(&str, i64); 4] =
+    [("≤7d", 7), ("≤30d", 30), ("≤90d", 90), (">90d", i64::MAX)];
+
+#[derive(Serialize)]
+struct StaleData {
+    threshold_days: i64,
+    /// Threads not modified in `threshold_days` or more
+    stale: usize,
+    total: usize,
+    buckets: Vec<BucketCount>,
+}
+
+#[derive(Serialize)]
+struct BucketCount {
+    bucket: &'static str,
+    count: usize,
+}
+
+fn run_stale(
+    args: &StatsArgs,
+    threshold: i64,
+    start_path: &Path,
+    git_root: &Path,
+    filter_path: &str,
+    format: OutputFormat,
+    config: &Config,
+) -> Result<(), String> {
+    let repo = workspace::open()?;
+    let include_closed = args.filter.include_closed();
+    let threads = workspace::find_threads_with_options(
+        start_path,
+        git_root,
+        &args.direction.to_find_options(),
+    )?;
+
+    let mut cache = TimestampCache::load(git_root);
+    cache.update(&repo, &threads, git_root);
+    let _ = cache.save(git_root);
+
+    let now = Local::now();
+    let mut data = StaleData {
+        threshold_days: threshold,
+        stale: 0,
+        total: 0,
+        buckets: STALE_BUCKETS
+            .iter()
+            .map(|(bucket, _)| BucketCount { bucket, count: 0 })
+            .collect(),
+    };
+
+    for path in threads {
+        if !args.direction.is_searching()
+            && workspace::parse_thread_path(git_root, &path) != filter_path
+        {
+            continue;
+        }
+        let Ok(t) = Thread::parse(&path) else {
+            continue;
+        };
+        if !include_closed && thread::is_closed(&t.base_status()) {
+            continue;
+        }
+
+        let rel = path
+            .strip_prefix(git_root)
+            .unwrap_or(&path)
+            .to_string_lossy();
+        let (_, updated) = get_timestamps(&repo, &cache, &t, &path, &rel);
+        let Some(updated): Option<DateTime<Local>> = updated else {
+            continue;
+        };
+        let age = (now - updated).num_days();
+
+        let slot = STALE_BUCKETS
+            .iter()
+            .position(|(_, max)| age <= *max)
+            .unwrap_or(STALE_BUCKETS.len() - 1);
+        data.buckets[slot].count += 1;
+        data.total += 1;
+        if age >= threshold {
+            data.stale += 1;
+        }
+    }
+
+    if args.machine {
+        for b in &data.buckets {
+            println!("{}\t{}", b.bucket, b.count);
+        }
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            let styled = format == OutputFormat::Pretty;
+            let path_desc = if filter_path == "." {
+                root_name(config).to_string()
+            } else {
+                filter_path.to_string()
+            };
+            let filter_desc = build_filter_desc(include_closed, &args.direction);
+            let header = format!("Time since last change for threads in {}", path_desc);
+
+            if styled {
+                println!("{} ({})", header.bold(), filter_desc.dimmed());
+            } else {
+                println!("{} ({})", header, filter_desc);
+            }
+            println!();
+
+            if data.total == 0 {
+                println!("No threads found.");
+                return Ok(());
+            }
+
+            if styled {
+                print_histogram(&data.buckets);
+            } else {
+                println!("BUCKET | COUNT");
+                for b in &data.buckets {
+                    println!("{} | {}", b.bucket, b.count);
+                }
+            }
+            println!();
+            let summary = format!(
+                "{} of {} not modified in {}+ days",
+                data.stale, data.total, threshold
+            );
+            if styled && data.stale > 0 {
+                println!("{}", summary.yellow());
+            } else {
+                println!("{}", summary);
+            }
+            Ok(())
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let envelope =
+                wrap_envelope(data, git_root, filter_path, &args.direction, include_closed);
+            print_structured(&envelope, format)
+        }
+    }
+}
+
+/// One bar per bucket, scaled so the largest fills 30 columns.
+fn print_histogram(buckets: &[BucketCount]) {
+    const WIDTH: usize = 30;
+    let max = buckets.iter().map(|b| b.count).max().unwrap_or(0).max(1);
+    for b in buckets {
+        let len = (b.count * WIDTH).div_ceil(max);
+        println!(
+            "  {:<5} {} {}",
+            b.bucket,
+            "█".repeat(len).cyan(),
+            b.count.to_string().dimmed()
+        );
+    }
 }
@@ -4,8 +4,9 @@ use clap::Args;
 use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
-use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, is_quiet};
+use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
+use crate::cmd::list::{self, Selected};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::output::{self, OutputFormat};
 use crate::thread::{self, Thread};
@@ -14,11 +15,34 @@ use crate::workspace;
 #[derive(Args)]
 pub struct StatusArgs {
     /// Thread ID or name reference
-    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
-    id: String,
+    #[arg(
+        required_unless_present = "all",
+        add = ArgValueCompleter::new(crate::workspace::complete_thread_ids)
+    )]
+    id: Option<String>,
 
     /// New status
-    new_status: String,
+    #[arg(required_unless_present = "all")]
+    new_status: Option<String>,
+
+    /// Set STATUS on every thread in scope (narrow with --status, --down/--up,
+    /// --include-closed, as for list)
+    #[arg(long, value_name = "STATUS", conflicts_with_all = ["id", "new_status"])]
+    all: Option<String>,
+
+    /// With --all: only threads currently in these statuses (comma-separated)
+    #[arg(long, value_name = "STATUSES", requires = "all")]
+    status: Option<String>,
+
+    /// With --all: list the threads that would change without writing
+    #[arg(long, requires = "all", conflicts_with = "commit")]
+    dry_run: bool,
+
+    #[command(flatten)]
+    direction: DirectionArgs,
+
+    #[command(flatten)]
+    filter: FilterArgs,
 
     /// Commit after changing
     #[arg(long)]
@@ -41,47 +65,51 @@ struct StatusOutput {
     committed: bool,
 }
 
+#[derive(Serialize)]
+struct BulkStatusOutput {
+    new_status: String,
+    changed: Vec<StatusChange>,
+    committed: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    dry_run: bool,
+}
+
+#[derive(Serialize)]
+struct StatusChange {
+    id: String,
+    old_status: String,
+    path: String,
+}
+
 pub fn run(args: StatusArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
-    // Validate status using config status lists
-    if !thread::is_valid_status_with_config(
-        &args.new_status,
-        &config.status.open,
-        &config.status.closed,
-    ) {
-        let all_statuses: Vec<&str> = config
-            .status
-            .open
-            .iter()
-            .chain(config.status.closed.iter())
-            .map(|s| s.as_str())
-            .collect();
-        return Err(format!(
-            "Invalid status '{}'. Must be one of: {}",
-            args.new_status,
-            all_statuses.join(", ")
-        ));
+    let new_status = match (&args.all, &args.new_status) {
+        (Some(status), _) | (None, Some(status)) => status.clone(),
+        (None, None) => return Err("usage: threads status <id> <status>".to_string()),
+    };
+    validate_status(&new_status, config)?;
+
+    if args.all.is_some() {
+        return run_all(args, new_status, ws, config);
     }
+    let thread_ref = args.id.clone().unwrap_or_default();
 
-    let file = workspace::find_by_ref(ws, &args.id)?;
+    let file = workspace::find_by_ref(ws, &thread_ref)?;
 
     let mut t = Thread::parse(&file)?;
     let old_status = t.status().to_string();
     let id = t.id().to_string();
+    let transitions = config.behavior.transitions.as_ref();
+    thread::check_transition(&old_status, &new_status, transitions)?;
 
-    t.set_frontmatter_field("status", &args.new_status)?;
+    t.set_frontmatter_field("status", &new_status)?;
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    let committed = if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
-        let msg = args
-            .m
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-        true
+        let msg = args.m.unwrap_or_else(|| format!("threads: status {}", id));
+        git::commit_thread(&repo, &file, &msg)?
     } else {
         false
     };
@@ -90,23 +118,20 @@ pub fn run(args: StatusArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     match format {
         OutputFormat::Pretty | OutputFormat::Plain => {
-            println!(
-                "Changed: {} → {} ({})",
-                old_status, args.new_status, rel_path
-            );
+            println!("Changed: {} → {} ({})", old_status, new_status, rel_path);
             if !committed && !is_quiet(config) {
                 output::print_uncommitted_hint(&id);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = StatusOutput {
                 id,
                 old_status,
-                new_status: args.new_status,
+                new_status,
                 path: rel_path,
                 committed,
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
@@ -114,7 +139,7 @@ pub fn run(args: StatusArgs, ws: &Path, config: &Config) -> Result<(), String> {
             let output = StatusOutput {
                 id,
                 old_status,
-                new_status: args.new_status,
+                new_status,
                 path: rel_path,
                 committed,
             };
@@ -126,3 +151,122 @@ pub fn run(args: StatusArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     Ok(())
 }
+
+/// `status --all`: set one status on every selected thread.
+///
+/// Every thread in scope is parsed and updated in memory before any file is
+/// written, so a broken file aborts the batch untouched.
+fn run_all(args: StatusArgs, new_status: String, ws: &Path, config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+
+    let (filter_path, threads) = list::find_in_scope(ws, None, &args.direction)?;
+    let selected = list::select_threads(
+        &threads,
+        ws,
+        &filter_path,
+        &args.direction,
+        &args.filter,
+        args.status.as_deref(),
+        true,
+    )?;
+
+    // Threads already in the target status are left alone
+    let mut changed = Vec::new();
+    let mut updated = Vec::new();
+    for Selected {
+        path, mut thread, ..
+    } in selected
+    {
+        if thread::base_status(thread.status()) == new_status {
+            continue;
+        }
+        let transitions = config.behavior.transitions.as_ref();
+        thread::check_transition(thread.status(), &new_status, transitions)
+            .map_err(|e| format!("{}: {}", thread.id(), e))?;
+        changed.push(StatusChange {
+            id: thread.id().to_string(),
+            old_status: thread.status().to_string(),
+            path: workspace::path_relative_to_git_root(ws, &path),
+        });
+        thread.set_frontmatter_field("status", &new_status)?;
+        updated.push((path, thread));
+    }
+
+    let committed = if args.dry_run {
+        false
+    } else {
+        for (_, thread) in &updated {
+            thread.write()?;
+        }
+        if !updated.is_empty() && (args.commit || auto_commit_enabled(config)) {
+            let repo = workspace::open()?;
+            let files: Vec<&Path> = updated.iter().map(|(p, _)| p.as_path()).collect();
+            let msg = args.m.clone().unwrap_or_else(|| {
+                format!("threads: status {} for {} threads", new_status, files.len())
+            });
+            git::commit_threads(&repo, &files, &msg)?
+        } else {
+            false
+        }
+    };
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            for c in &changed {
+                println!("{}  {} → {}  {}", c.id, c.old_status, new_status, c.path);
+            }
+            let count = changed.len();
+            let noun = if count == 1 { "thread" } else { "threads" };
+            if args.dry_run {
+                println!("Would change {} {}", count, noun);
+            } else {
+                println!("Changed {} {}", count, noun);
+                if count > 0 && !committed && !is_quiet(config) {
+                    eprintln!(
+                        "Note: {} {} have uncommitted changes. Use 'threads git commit --pending' when ready.",
+                        count, noun
+                    );
+                }
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            let output = BulkStatusOutput {
+                new_status,
+                changed,
+                committed,
+                dry_run: args.dry_run,
+            };
+            if matches!(format, OutputFormat::Json | OutputFormat::Ndjson) {
+                let json = output::to_json(&output, format)
+                    .map_err(|e| format!("JSON serialization failed: {}", e))?;
+                println!("{}", json);
+            } else {
+                let yaml = serde_yaml::to_string(&output)
+                    .map_err(|e| format!("YAML serialization failed: {}", e))?;
+                print!("{}", yaml);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check a status against the configured open and closed lists.
+fn validate_status(status: &str, config: &Config) -> Result<(), String> {
+    if !thread::is_valid_status_with_config(status, &config.status.open, &config.status.closed) {
+        let all_statuses: Vec<&str> = config
+            .status
+            .open
+            .iter()
+            .chain(config.status.closed.iter())
+            .map(|s| s.as_str())
+            .collect();
+        return Err(format!(
+            "Invalid status '{}'. Must be one of: {}",
+            status,
+            all_statuses.join(", ")
+        ));
+    }
+
+    Ok(())
+}
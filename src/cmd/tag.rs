@@ -0,0 +1,123 @@
+use std::path::Path;
+
+use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
+
+use crate::args::FormatArgs;
+use crate::config::{Config, auto_commit_enabled, is_quiet};
+use crate::git;
+use crate::output::{self, OutputFormat};
+use crate::thread::Thread;
+use crate::workspace;
+
+#[derive(Args)]
+pub struct TagArgs {
+    /// Thread ID or name reference
+    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    id: String,
+
+    /// Action: ls, add, rm (default: ls)
+    #[arg(default_value = "ls")]
+    action: String,
+
+    /// Tags to add or remove (stored lowercase, spaces become dashes)
+    tags: Vec<String>,
+
+    #[command(flatten)]
+    format: FormatArgs,
+
+    /// Commit after editing
+    #[arg(long)]
+    commit: bool,
+
+    /// Commit message
+    #[arg(short = 'm', long)]
+    message: Option<String>,
+}
+
+pub fn run(args: TagArgs, ws: &Path, config: &Config) -> Result<(), String> {
+    let file = workspace::find_by_ref(ws, &args.id)?;
+
+    let mut t = Thread::parse(&file)?;
+
+    match args.action.as_str() {
+        "ls" | "list" => {
+            let format = args.format.resolve();
+            let tags = t.tags();
+            match format {
+                OutputFormat::Json | OutputFormat::Ndjson => {
+                    println!(
+                        "{}",
+                        output::to_json(tags, format).map_err(|e| e.to_string())?
+                    );
+                }
+                OutputFormat::Yaml => {
+                    print!(
+                        "{}",
+                        serde_yaml::to_string(tags).map_err(|e| e.to_string())?
+                    );
+                }
+                _ => {
+                    if tags.is_empty() {
+                        println!("No tags.");
+                    } else {
+                        for tag in tags {
+                            println!("#{}", tag);
+                        }
+                    }
+                }
+            }
+            return Ok(());
+        }
+        "add" => {
+            if args.tags.is_empty() {
+                return Err("usage: threads tag <id> add <tag>...".to_string());
+            }
+            let mut changed = false;
+            for tag in &args.tags {
+                let (slug, added) = t.add_tag(tag)?;
+                if added {
+                    println!("Added tag: {}", slug);
+                    changed = true;
+                } else {
+                    println!("Already tagged: {}", slug);
+                }
+            }
+            if !changed {
+                return Ok(());
+            }
+        }
+        "rm" | "remove" => {
+            if args.tags.is_empty() {
+                return Err("usage: threads tag <id> rm <tag>...".to_string());
+            }
+            for tag in &args.tags {
+                let slug = t.remove_tag(tag)?;
+                println!("Removed tag: {}", slug);
+            }
+        }
+        _ => {
+            return Err(format!(
+                "unknown action '{}'. Use: ls, add, rm",
+                args.action
+            ));
+        }
+    }
+
+    t.write()?;
+
+    let committed = if args.commit || auto_commit_enabled(config) {
+        let repo = workspace::open()?;
+        let msg = args
+            .message
+            .unwrap_or_else(|| format!("threads: tag {}", t.id()));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
+        output::print_uncommitted_hint(t.id());
+    }
+
+    Ok(())
+}
@@ -1,14 +1,17 @@
 use std::path::Path;
 
-use clap::Args;
+use chrono::{Local, NaiveDate};
+use clap::{Args, ValueEnum};
 use clap_complete::engine::ArgValueCompleter;
 use colored::Colorize;
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
-use crate::config::{Config, env_bool, is_quiet};
+use crate::cmd::deadline::style_deadline_date;
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::output::{self, OutputFormat};
-use crate::thread::{self, Thread};
+use crate::thread::{self, Thread, TodoItem, TodoPlacement};
+use crate::timeutil;
 use crate::workspace;
 
 #[derive(Args)]
@@ -17,7 +20,7 @@ pub struct TodoArgs {
     #[arg(default_value = "", add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
     id: String,
 
-    /// Action: list, add, check, uncheck, remove (default: list)
+    /// Action: list, add, check, uncheck, remove, promote, nest, move, sort (default: list)
     #[arg(default_value = "list")]
     action: String,
 
@@ -25,6 +28,11 @@ pub struct TodoArgs {
     #[arg(default_value = "")]
     item: String,
 
+    /// Pick the item by case-insensitive text instead of hash (check, uncheck,
+    /// remove, promote, nest, move)
+    #[arg(long = "match", value_name = "TEXT", conflicts_with = "item")]
+    match_text: Option<String>,
+
     #[command(flatten)]
     direction: DirectionArgs,
 
@@ -38,6 +46,54 @@ pub struct TodoArgs {
     #[arg(long)]
     include_done: bool,
 
+    /// Body heading to move the item under (promote action only)
+    #[arg(long, visible_alias = "move-to-body")]
+    heading: Option<String>,
+
+    /// Parent todo hash: add as a subtask, or nest an existing item under it
+    #[arg(long, value_name = "HASH")]
+    under: Option<String>,
+
+    /// Move the item just before this one (move action only)
+    #[arg(long, value_name = "HASH", conflicts_with_all = ["after", "top", "bottom"])]
+    before: Option<String>,
+
+    /// Move the item just after this one (move action only)
+    #[arg(long, value_name = "HASH", conflicts_with_all = ["top", "bottom"])]
+    after: Option<String>,
+
+    /// Move the item to the top of the list (move action only)
+    #[arg(long, conflicts_with = "bottom")]
+    top: bool,
+
+    /// Move the item to the bottom of the list (move action only)
+    #[arg(long)]
+    bottom: bool,
+
+    /// Put completed items after open ones (sort action only)
+    #[arg(long)]
+    done_last: bool,
+
+    /// Due date for add (YYYY-MM-DD)
+    #[arg(long)]
+    due: Option<String>,
+
+    /// Agenda: only open todos due within --within (overdue included), sorted by due date
+    #[arg(long)]
+    due_soon: bool,
+
+    /// Window for --due-soon: days (3d), weeks (2w), or an end date (YYYY-MM-DD)
+    #[arg(long, default_value = "3d", requires = "due_soon")]
+    within: String,
+
+    /// Order the list view (display only unless --reorder-persist)
+    #[arg(long, value_enum)]
+    sort: Option<TodoSort>,
+
+    /// Write the --sort order back into the thread file
+    #[arg(long, requires = "sort")]
+    reorder_persist: bool,
+
     /// Commit after editing
     #[arg(long)]
     commit: bool,
@@ -47,6 +103,31 @@ pub struct TodoArgs {
     message: Option<String>,
 }
 
+/// Sort keys for `todo <id> list --sort`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum TodoSort {
+    /// Open items first, done items last
+    Done,
+    /// Earliest due date first, undated items last
+    Due,
+    /// Alphabetical by item text
+    Text,
+}
+
+/// Stable sort, so items that compare equal keep their file order.
+fn sort_todo_items(items: &mut [TodoItem], key: TodoSort) {
+    match key {
+        TodoSort::Done => items.sort_by_key(|i| i.done),
+        TodoSort::Due => items.sort_by(|a, b| match (&a.due, &b.due) {
+            (Some(x), Some(y)) => x.cmp(y),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }),
+        TodoSort::Text => items.sort_by_key(|i| i.text.to_lowercase()),
+    }
+}
+
 pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
     if args.id.is_empty() && args.action == "list" {
         return run_agenda(&args, ws, config);
@@ -54,7 +135,7 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     if args.id.is_empty() {
         return Err(
-            "usage: threads todo <id> [add <text> | check <hash> | uncheck <hash> | remove <hash>]"
+            "usage: threads todo <id> [add <text> | check <hash> | uncheck <hash> | remove <hash> | promote <hash> --heading <heading> | nest <hash> [--under <hash>] | move <hash> (--before <hash> | --after <hash> | --top | --bottom) | sort --done-last]"
                 .to_string(),
         );
     }
@@ -63,15 +144,46 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     let mut t = Thread::parse(&file)?;
 
+    // Hash of the item to act on: the positional argument, or resolved from --match
+    let item_ref = match &args.match_text {
+        Some(text) => {
+            let done = match args.action.as_str() {
+                "check" | "complete" | "done" => Some(false),
+                "uncheck" => Some(true),
+                "remove" | "promote" | "nest" | "move" => None,
+                _ => {
+                    return Err(
+                        "--match applies to check, uncheck, remove, promote, nest, and move"
+                            .to_string(),
+                    );
+                }
+            };
+            find_by_text(&t.get_todo_items(), text, done)?
+        }
+        None => args.item.clone(),
+    };
+
     match args.action.as_str() {
+        "list" | "ls" if args.reorder_persist => {
+            let mut items = t.get_todo_items();
+            if let Some(key) = args.sort {
+                sort_todo_items(&mut items, key);
+            }
+            let count = items.len();
+            t.set_todo_items(items)?;
+            println!("Reordered {} todo items", count);
+        }
         "list" | "ls" => {
             let format = args.format.resolve();
-            let items = t.get_todo_items();
+            let mut items = t.get_todo_items();
+            if let Some(key) = args.sort {
+                sort_todo_items(&mut items, key);
+            }
             match format {
-                OutputFormat::Json => {
+                OutputFormat::Json | OutputFormat::Ndjson => {
                     println!(
                         "{}",
-                        serde_json::to_string_pretty(&items).map_err(|e| e.to_string())?
+                        output::to_json(&items, format).map_err(|e| e.to_string())?
                     );
                 }
                 OutputFormat::Yaml => {
@@ -84,9 +196,23 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
                     if items.is_empty() {
                         println!("No todo items.");
                     } else {
-                        for item in &items {
-                            let mark = if item.done { "[x]" } else { "[ ]" };
-                            println!("{} {} ({})", mark, item.text, item.hash);
+                        let today = Local::now().date_naive();
+                        for (depth, item) in thread::todo_tree(&items) {
+                            let mark = format!(
+                                "{}{}",
+                                "  ".repeat(depth),
+                                if item.done { "[x]" } else { "[ ]" }
+                            );
+                            match &item.due {
+                                Some(due) => println!(
+                                    "{} {} ({}) due {}",
+                                    mark,
+                                    item.text,
+                                    item.hash,
+                                    style_deadline_date(due, today)
+                                ),
+                                None => println!("{} {} ({})", mark, item.text, item.hash),
+                            }
                         }
                     }
                 }
@@ -99,15 +225,116 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
             }
             let text = &args.item;
 
+            if let Some(due) = &args.due {
+                NaiveDate::parse_from_str(due, "%Y-%m-%d")
+                    .map_err(|_| format!("invalid due date '{}': expected YYYY-MM-DD", due))?;
+            }
+
+            // Resolve the parent before adding, so the new item cannot match its prefix
+            let parent = match &args.under {
+                Some(under) => {
+                    let count = t.count_matching_items("Todo", under);
+                    if count > 1 {
+                        return Err(format!(
+                            "ambiguous hash '{}' matches {} items",
+                            under, count
+                        ));
+                    }
+                    let item = t
+                        .get_todo_items()
+                        .into_iter()
+                        .find(|i| i.hash.starts_with(under.as_str()))
+                        .ok_or_else(|| format!("no parent item with hash '{}' found", under))?;
+                    Some(item.hash)
+                }
+                None => None,
+            };
+
             let hash = t.add_todo_item(text)?;
+            if let Some(parent) = &parent {
+                t.set_todo_parent(&hash, Some(parent))?;
+            }
 
-            println!("Added to Todo: {} (id: {})", text, hash);
+            match &args.due {
+                Some(due) => {
+                    t.set_todo_due(&hash, Some(due))?;
+                    println!("Added to Todo: {} due {} (id: {})", text, due, hash);
+                }
+                None => println!("Added to Todo: {} (id: {})", text, hash),
+            }
+        }
+        "nest" => {
+            if item_ref.is_empty() {
+                return Err("usage: threads todo <id> nest <hash> [--under <hash>]".to_string());
+            }
+            let hash = &item_ref;
+
+            // Check for ambiguous hashes
+            for h in std::iter::once(hash).chain(args.under.as_ref()) {
+                let count = t.count_matching_items("Todo", h);
+                if count > 1 {
+                    return Err(format!("ambiguous hash '{}' matches {} items", h, count));
+                }
+            }
+
+            t.set_todo_parent(hash, args.under.as_deref())?;
+
+            match &args.under {
+                Some(parent) => println!("Nested item {} under {}", hash, parent),
+                None => println!("Moved item {} to the top level", hash),
+            }
+        }
+        "move" => {
+            let placement = match (&args.before, &args.after) {
+                (Some(h), _) => Some((TodoPlacement::Before(h), format!("before {}", h))),
+                (_, Some(h)) => Some((TodoPlacement::After(h), format!("after {}", h))),
+                _ if args.top => Some((TodoPlacement::Top, "to the top".to_string())),
+                _ if args.bottom => Some((TodoPlacement::Bottom, "to the bottom".to_string())),
+                _ => None,
+            };
+            let Some((placement, desc)) = placement.filter(|_| !item_ref.is_empty()) else {
+                return Err(
+                    "usage: threads todo <id> move <hash> (--before <hash> | --after <hash> | --top | --bottom)"
+                        .to_string(),
+                );
+            };
+            let hash = &item_ref;
+
+            // Check for missing or ambiguous hashes
+            for h in std::iter::once(hash).chain(args.before.as_ref().or(args.after.as_ref())) {
+                let count = t.count_matching_items("Todo", h);
+                if count == 0 {
+                    return Err(format!("no item with hash '{}' found", h));
+                }
+                if count > 1 {
+                    return Err(format!("ambiguous hash '{}' matches {} items", h, count));
+                }
+            }
+
+            t.move_todo_item(hash, placement)?;
+
+            println!("Moved item {} {}", hash, desc);
+        }
+        "sort" => {
+            if !args.done_last {
+                return Err("usage: threads todo <id> sort --done-last".to_string());
+            }
+            let mut items = t.get_todo_items();
+            let before: Vec<String> = items.iter().map(|i| i.hash.clone()).collect();
+            sort_todo_items(&mut items, TodoSort::Done);
+            let moved = items
+                .iter()
+                .zip(&before)
+                .filter(|(item, hash)| item.hash != **hash)
+                .count();
+            t.set_todo_items(items)?;
+            println!("Moved {} item(s) to put done items last", moved);
         }
         "check" | "complete" | "done" => {
-            if args.item.is_empty() {
+            if item_ref.is_empty() {
                 return Err("usage: threads todo <id> check <hash>".to_string());
             }
-            let hash = &args.item;
+            let hash = &item_ref;
 
             // Check for ambiguous hash
             let count = t.count_matching_items("Todo", hash);
@@ -123,10 +350,10 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
             println!("Checked item {}", hash);
         }
         "uncheck" => {
-            if args.item.is_empty() {
+            if item_ref.is_empty() {
                 return Err("usage: threads todo <id> uncheck <hash>".to_string());
             }
-            let hash = &args.item;
+            let hash = &item_ref;
 
             // Check for ambiguous hash
             let count = t.count_matching_items("Todo", hash);
@@ -142,10 +369,10 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
             println!("Unchecked item {}", hash);
         }
         "remove" => {
-            if args.item.is_empty() {
+            if item_ref.is_empty() {
                 return Err("usage: threads todo <id> remove <hash>".to_string());
             }
-            let hash = &args.item;
+            let hash = &item_ref;
 
             // Check for ambiguous hash
             let count = t.count_matching_items("Todo", hash);
@@ -160,9 +387,43 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
             println!("Removed item {}", hash);
         }
+        "promote" => {
+            let heading = match (&args.heading, item_ref.is_empty()) {
+                (Some(heading), false) => heading,
+                _ => {
+                    return Err(
+                        "usage: threads todo <id> promote <hash> --heading \"Heading\"".to_string(),
+                    );
+                }
+            };
+            let hash = &item_ref;
+
+            // Check for ambiguous hash
+            let count = t.count_matching_items("Todo", hash);
+            if count == 0 {
+                return Err(format!("no item with hash '{}' found", hash));
+            }
+            if count > 1 {
+                return Err(format!("ambiguous hash '{}' matches {} items", hash, count));
+            }
+            let item = t
+                .get_todo_items()
+                .into_iter()
+                .find(|i| i.hash.starts_with(hash.as_str()))
+                .ok_or_else(|| format!("no item with hash '{}' found", hash))?;
+
+            t.remove_by_hash("Todo", hash)?;
+            let mark = if item.done { "[x]" } else { "[ ]" };
+            t.append_to_body_section(heading, &format!("- {} {}", mark, item.text))?;
+
+            let log_entry = format!("Moved todo {} to body under '{}'", item.hash, heading);
+            t.insert_log_entry(&log_entry)?;
+
+            println!("Moved item {} to body under '## {}'", item.hash, heading);
+        }
         _ => {
             return Err(format!(
-                "unknown action '{}'. Use: list, add, check, uncheck, remove",
+                "unknown action '{}'. Use: list, add, check, uncheck, remove, promote, nest, move, sort",
                 args.action
             ));
         }
@@ -170,25 +431,70 @@ pub fn run(args: TodoArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
         let msg = args
             .message
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-    } else if !is_quiet(config) {
+            .unwrap_or_else(|| format!("threads: todo {}", t.id()));
+        git::commit_thread(&repo, &file, &msg)?
+    } else {
+        false
+    };
+    if !committed && !is_quiet(config) {
         output::print_uncommitted_hint(&args.id);
     }
 
     Ok(())
 }
 
+/// Resolve `--match`: the one item whose text contains `text` (case-insensitive).
+/// `done` restricts the search to checked (`Some(true)`) or open (`Some(false)`) items.
+fn find_by_text(items: &[TodoItem], text: &str, done: Option<bool>) -> Result<String, String> {
+    let needle = text.to_lowercase();
+    let matches: Vec<&TodoItem> = items
+        .iter()
+        .filter(|i| done.is_none_or(|d| i.done == d))
+        .filter(|i| i.text.to_lowercase().contains(&needle))
+        .collect();
+
+    let kind = match done {
+        Some(true) => "checked item",
+        Some(false) => "unchecked item",
+        None => "item",
+    };
+    match matches.as_slice() {
+        [] => Err(format!("no {} matches '{}'", kind, text)),
+        [item] => Ok(item.hash.clone()),
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .map(|i| format!("  {}  {}", i.hash, i.text))
+                .collect();
+            Err(format!(
+                "'{}' matches {} {}s; use a longer match or a hash:\n{}",
+                text,
+                matches.len(),
+                kind,
+                candidates.join("\n")
+            ))
+        }
+    }
+}
+
 /// Agenda: collect todos from all threads in scope.
+///
+/// With `--due-soon`, only open todos due within the window (or overdue) are shown,
+/// sorted by due date.
 fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
+    let today = Local::now().date_naive();
+    let horizon = if args.due_soon {
+        Some(timeutil::resolve_date(&args.within, today)?)
+    } else {
+        None
+    };
+
     let scope = workspace::infer_scope(ws, None)?;
     let start_path = scope.threads_dir.parent().unwrap_or(ws);
     let options = args.direction.to_find_options();
@@ -200,6 +506,8 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
         text: String,
         hash: String,
         done: bool,
+        due: Option<String>,
+        overdue: bool,
         thread_id: String,
         thread_name: String,
         thread_path: String,
@@ -226,13 +534,24 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
         let thread_id = t.id().to_string();
 
         for item in t.get_todo_items() {
-            if item.done && !args.include_done {
+            let due_date = item
+                .due
+                .as_deref()
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+            if let Some(horizon) = horizon {
+                // Due-soon view: open todos with a due date inside the window
+                if item.done || due_date.is_none_or(|d| d > horizon) {
+                    continue;
+                }
+            } else if item.done && !args.include_done {
                 continue;
             }
             agenda.push(AgendaItem {
                 text: item.text,
                 hash: item.hash,
                 done: item.done,
+                due: item.due,
+                overdue: !item.done && due_date.is_some_and(|d| d < today),
                 thread_id: thread_id.clone(),
                 thread_name: thread_name.clone(),
                 thread_path: rel_path.clone(),
@@ -241,7 +560,9 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
     }
 
     if agenda.is_empty() {
-        if args.include_done {
+        if args.due_soon {
+            println!("No todos due within {}.", args.within);
+        } else if args.include_done {
             println!("No todos found.");
         } else {
             println!("No open todos found.");
@@ -249,14 +570,22 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
         return Ok(());
     }
 
+    if args.due_soon {
+        // Sort by due date ascending (all items have a valid due date here)
+        agenda.sort_by(|a, b| a.due.cmp(&b.due));
+    }
+
     match format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             use serde::Serialize;
             #[derive(Serialize)]
             struct JsonItem<'a> {
                 done: bool,
                 text: &'a str,
                 hash: &'a str,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                due: Option<&'a str>,
+                overdue: bool,
                 thread_id: &'a str,
                 thread_name: &'a str,
                 thread_path: &'a str,
@@ -267,6 +596,8 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
                     done: a.done,
                     text: &a.text,
                     hash: &a.hash,
+                    due: a.due.as_deref(),
+                    overdue: a.overdue,
                     thread_id: &a.thread_id,
                     thread_name: &a.thread_name,
                     thread_path: &a.thread_path,
@@ -274,7 +605,7 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
                 .collect();
             println!(
                 "{}",
-                serde_json::to_string_pretty(&items).map_err(|e| format!("JSON error: {}", e))?
+                output::to_json(&items, format).map_err(|e| format!("JSON error: {}", e))?
             );
         }
         OutputFormat::Yaml => {
@@ -284,6 +615,9 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
                 done: bool,
                 text: &'a str,
                 hash: &'a str,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                due: Option<&'a str>,
+                overdue: bool,
                 thread_id: &'a str,
                 thread_name: &'a str,
                 thread_path: &'a str,
@@ -294,6 +628,8 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
                     done: a.done,
                     text: &a.text,
                     hash: &a.hash,
+                    due: a.due.as_deref(),
+                    overdue: a.overdue,
                     thread_id: &a.thread_id,
                     thread_name: &a.thread_name,
                     thread_path: &a.thread_path,
@@ -305,21 +641,52 @@ fn run_agenda(args: &TodoArgs, ws: &Path, _config: &Config) -> Result<(), String
             );
         }
         OutputFormat::Plain => {
-            println!("DONE | TEXT | HASH | THREAD_ID | NAME | PATH");
-            for a in &agenda {
-                println!(
-                    "{} | {} | {} | {} | {} | {}",
-                    a.done, a.text, a.hash, a.thread_id, a.thread_name, a.thread_path
-                );
+            if args.due_soon {
+                println!("DUE | TEXT | HASH | THREAD_ID | NAME | PATH");
+                for a in &agenda {
+                    println!(
+                        "{} | {} | {} | {} | {} | {}",
+                        a.due.as_deref().unwrap_or(""),
+                        a.text,
+                        a.hash,
+                        a.thread_id,
+                        a.thread_name,
+                        a.thread_path
+                    );
+                }
+            } else {
+                println!("DONE | TEXT | HASH | THREAD_ID | NAME | PATH");
+                for a in &agenda {
+                    println!(
+                        "{} | {} | {} | {} | {} | {}",
+                        a.done, a.text, a.hash, a.thread_id, a.thread_name, a.thread_path
+                    );
+                }
             }
         }
         _ => {
             for a in &agenda {
+                if args.due_soon {
+                    let due = a.due.as_deref().unwrap_or("");
+                    println!(
+                        "{}  {}  {}  {}",
+                        style_deadline_date(due, today),
+                        a.text,
+                        a.hash.dimmed(),
+                        format!("[{}]", a.thread_id).dimmed()
+                    );
+                    continue;
+                }
                 let mark = if a.done { "[x]" } else { "[ ]" };
+                let due = match &a.due {
+                    Some(d) => format!("  due {}", style_deadline_date(d, today)),
+                    None => String::new(),
+                };
                 println!(
-                    "{}  {}  {}  {}",
+                    "{}  {}{}  {}  {}",
                     mark,
                     a.text,
+                    due,
                     a.hash.dimmed(),
                     format!("[{}]", a.thread_id).dimmed()
                 );
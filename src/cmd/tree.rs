@@ -0,0 +1,242 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use clap::Args;
+use clap_complete::engine::ArgValueCompleter;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::args::{FilterArgs, FormatArgs};
+use crate::config::Config;
+use crate::output::{self, OutputFormat};
+use crate::thread::{self, Thread};
+use crate::workspace;
+
+#[derive(Args)]
+pub struct TreeArgs {
+    /// Thread to show the subtree of (omit for every thread)
+    #[arg(add = ArgValueCompleter::new(crate::workspace::complete_thread_ids))]
+    id: Option<String>,
+
+    #[command(flatten)]
+    filter: FilterArgs,
+
+    #[command(flatten)]
+    format: FormatArgs,
+}
+
+/// A thread as it appears in the hierarchy
+struct Entry {
+    id: String,
+    title: String,
+    status: String,
+    path: String,
+    parent: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TreeNode {
+    id: String,
+    title: String,
+    status: String,
+    path: String,
+    /// Parent ID that names no existing or archived thread (W012)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing_parent: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<TreeNode>,
+}
+
+pub fn run(args: TreeArgs, ws: &Path, _config: &Config) -> Result<(), String> {
+    let format = args.format.resolve();
+    let include_closed = args.filter.include_closed();
+
+    let mut entries = Vec::new();
+    for path in workspace::find_all_threads(ws)? {
+        let Ok(t) = Thread::parse(&path) else {
+            continue;
+        };
+        let title = if t.name().is_empty() {
+            thread::extract_name_from_path(&path).replace('-', " ")
+        } else {
+            t.name().to_string()
+        };
+        entries.push(Entry {
+            id: t.id().to_string(),
+            title,
+            status: t.base_status(),
+            path: workspace::path_relative_to_git_root(ws, &path),
+            parent: t.parent().map(str::to_string),
+        });
+    }
+
+    // Children of an archived thread become roots, but their parent is not missing
+    let archived: HashSet<String> = workspace::find_archived_threads(ws)?
+        .iter()
+        .filter_map(|p| thread::extract_id_from_path(p))
+        .collect();
+
+    let index: HashMap<&str, usize> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.id.as_str(), i))
+        .collect();
+    let mut children: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, e) in entries.iter().enumerate() {
+        if let Some(parent) = e.parent.as_deref()
+            && index.contains_key(parent)
+        {
+            children.entry(parent).or_default().push(i);
+        }
+    }
+
+    let builder = Builder {
+        entries: &entries,
+        children: &children,
+        index: &index,
+        archived: &archived,
+        include_closed,
+    };
+    let mut visited = HashSet::new();
+
+    let roots: Vec<TreeNode> = match &args.id {
+        Some(id_ref) => {
+            let file = workspace::find_by_ref(ws, id_ref)?;
+            let id = Thread::parse(&file)?.id().to_string();
+            let root = *index
+                .get(id.as_str())
+                .ok_or_else(|| format!("thread '{}' not found", id))?;
+            builder
+                .build(root, true, &mut visited)
+                .into_iter()
+                .collect()
+        }
+        None => {
+            // Roots: no parent, or a parent that does not exist
+            let mut roots: Vec<TreeNode> = (0..entries.len())
+                .filter(|&i| {
+                    entries[i]
+                        .parent
+                        .as_deref()
+                        .is_none_or(|p| !index.contains_key(p))
+                })
+                .filter_map(|i| builder.build(i, false, &mut visited))
+                .collect();
+            // Threads caught in a hand-edited parent cycle are unreachable from any root
+            for i in 0..entries.len() {
+                if !visited.contains(&i)
+                    && let Some(node) = builder.build(i, false, &mut visited)
+                {
+                    roots.push(node);
+                }
+            }
+            roots
+        }
+    };
+
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => {
+            if roots.is_empty() {
+                println!("No threads.");
+            }
+            let styled = format == OutputFormat::Pretty;
+            for root in &roots {
+                print_node(root, "", "", styled);
+            }
+        }
+        OutputFormat::Json | OutputFormat::Ndjson => {
+            let json = output::to_json(&roots, format)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&roots)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+    }
+
+    Ok(())
+}
+
+struct Builder<'a> {
+    entries: &'a [Entry],
+    children: &'a HashMap<&'a str, Vec<usize>>,
+    index: &'a HashMap<&'a str, usize>,
+    archived: &'a HashSet<String>,
+    include_closed: bool,
+}
+
+impl Builder<'_> {
+    /// Build the subtree at `i`. Closed threads are dropped unless included,
+    /// or kept because a descendant is shown.
+    fn build(&self, i: usize, keep: bool, visited: &mut HashSet<usize>) -> Option<TreeNode> {
+        if !visited.insert(i) {
+            return None;
+        }
+        let e = &self.entries[i];
+        let children: Vec<TreeNode> = self
+            .children
+            .get(e.id.as_str())
+            .into_iter()
+            .flatten()
+            .filter_map(|&c| self.build(c, false, visited))
+            .collect();
+
+        let hidden = !self.include_closed && thread::is_closed(&e.status);
+        if hidden && children.is_empty() && !keep {
+            return None;
+        }
+
+        Some(TreeNode {
+            id: e.id.clone(),
+            title: e.title.clone(),
+            status: e.status.clone(),
+            path: e.path.clone(),
+            missing_parent: e
+                .parent
+                .clone()
+                .filter(|p| !self.index.contains_key(p.as_str()) && !self.archived.contains(p)),
+            children,
+        })
+    }
+}
+
+fn print_node(node: &TreeNode, prefix: &str, connector: &str, styled: bool) {
+    let missing = node
+        .missing_parent
+        .as_ref()
+        .map(|p| format!(" (parent {} missing)", p))
+        .unwrap_or_default();
+    if styled {
+        println!(
+            "{}{}{} {} {}{}",
+            prefix.dimmed(),
+            connector.dimmed(),
+            output::style_id(&node.id),
+            output::style_status(&node.status),
+            node.title,
+            missing.dimmed()
+        );
+    } else {
+        println!(
+            "{}{}{} [{}] {}{}",
+            prefix, connector, node.id, node.status, node.title, missing
+        );
+    }
+
+    // Children of a root line up under it; deeper levels continue the rails
+    let child_prefix = match connector {
+        "" => prefix.to_string(),
+        "└── " => format!("{}    ", prefix),
+        _ => format!("{}│   ", prefix),
+    };
+    for (i, child) in node.children.iter().enumerate() {
+        let connector = if i + 1 == node.children.len() {
+            "└── "
+        } else {
+            "├── "
+        };
+        print_node(child, &child_prefix, connector, styled);
+    }
+}
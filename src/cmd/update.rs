@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use clap::Args;
@@ -5,10 +6,10 @@ use clap_complete::engine::ArgValueCompleter;
 use serde::Serialize;
 
 use crate::args::FormatArgs;
-use crate::config::{Config, env_bool, is_quiet};
+use crate::config::{Config, auto_commit_enabled, is_quiet};
 use crate::git;
 use crate::output::{self, OutputFormat};
-use crate::thread::Thread;
+use crate::thread::{self, Thread};
 use crate::workspace;
 
 #[derive(Args)]
@@ -25,6 +26,26 @@ pub struct UpdateArgs {
     #[arg(long)]
     desc: Option<String>,
 
+    /// Clear the description
+    #[arg(long, conflicts_with = "desc")]
+    clear_desc: bool,
+
+    /// Set the priority (critical, high, medium, low)
+    #[arg(long, value_name = "LEVEL")]
+    priority: Option<String>,
+
+    /// Remove the priority
+    #[arg(long, conflicts_with = "priority")]
+    clear_priority: bool,
+
+    /// Set the parent thread (ID or name reference)
+    #[arg(long, value_name = "REF")]
+    parent: Option<String>,
+
+    /// Remove the parent thread
+    #[arg(long, conflicts_with = "parent")]
+    clear_parent: bool,
+
     /// Commit after updating
     #[arg(long)]
     commit: bool,
@@ -44,6 +65,10 @@ struct UpdateOutput {
     title: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     desc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
     path: String,
     committed: bool,
 }
@@ -51,8 +76,18 @@ struct UpdateOutput {
 pub fn run(args: UpdateArgs, ws: &Path, config: &Config) -> Result<(), String> {
     let format = args.format.resolve();
 
-    if args.title.is_none() && args.desc.is_none() {
-        return Err("specify --title and/or --desc".to_string());
+    if args.title.is_none()
+        && args.desc.is_none()
+        && !args.clear_desc
+        && args.priority.is_none()
+        && !args.clear_priority
+        && args.parent.is_none()
+        && !args.clear_parent
+    {
+        return Err(
+            "specify --title, --desc, --clear-desc, --priority, --clear-priority, --parent, or --clear-parent"
+                .to_string(),
+        );
     }
 
     let file = workspace::find_by_ref(ws, &args.id)?;
@@ -68,22 +103,55 @@ pub fn run(args: UpdateArgs, ws: &Path, config: &Config) -> Result<(), String> {
         t.set_frontmatter_field("desc", desc)?;
     }
 
+    if args.clear_desc {
+        t.set_frontmatter_field("desc", "")?;
+        t.insert_log_entry("Cleared description.")?;
+    }
+
+    if let Some(ref priority) = args.priority {
+        t.set_priority(Some(priority))?;
+    }
+
+    if args.clear_priority {
+        t.set_priority(None)?;
+    }
+
+    // Parent ID as written, or empty when cleared
+    let parent = if let Some(ref parent_ref) = args.parent {
+        let parent_id = resolve_parent(ws, &id, parent_ref)?;
+        t.set_parent(Some(&parent_id))?;
+        Some(parent_id)
+    } else if args.clear_parent {
+        t.set_parent(None)?;
+        Some(String::new())
+    } else {
+        None
+    };
+
     t.write()?;
 
-    let should_commit = args.commit || env_bool("THREADS_AUTO_COMMIT").unwrap_or(false);
-    let committed = if should_commit {
+    let committed = if args.commit || auto_commit_enabled(config) {
         let repo = workspace::open()?;
-        let rel_path = file.strip_prefix(ws).unwrap_or(&file);
-        let msg = args
-            .m
-            .unwrap_or_else(|| git::generate_commit_message(&repo, &[rel_path]));
-        git::auto_commit(&repo, &file, &msg)?;
-        true
+        let msg = args.m.unwrap_or_else(|| format!("threads: update {}", id));
+        git::commit_thread(&repo, &file, &msg)?
     } else {
         false
     };
 
     let rel_path = workspace::path_relative_to_git_root(ws, &file);
+    let desc = if args.clear_desc {
+        Some(String::new())
+    } else {
+        args.desc.clone()
+    };
+    // Normalized level as written, or empty when cleared
+    let priority = if args.clear_priority {
+        Some(String::new())
+    } else if args.priority.is_some() {
+        t.get_priority().map(str::to_string)
+    } else {
+        None
+    };
 
     match format {
         OutputFormat::Pretty | OutputFormat::Plain => {
@@ -93,20 +161,35 @@ pub fn run(args: UpdateArgs, ws: &Path, config: &Config) -> Result<(), String> {
             if let Some(ref desc) = args.desc {
                 println!("Updated desc: {}", desc);
             }
+            if args.clear_desc {
+                println!("Cleared desc");
+            }
+            match priority.as_deref() {
+                Some("") => println!("Cleared priority"),
+                Some(level) => println!("Updated priority: {}", level),
+                None => {}
+            }
+            match parent.as_deref() {
+                Some("") => println!("Cleared parent"),
+                Some(parent_id) => println!("Updated parent: {}", parent_id),
+                None => {}
+            }
             println!("  → {}", rel_path);
             if !committed && !is_quiet(config) {
                 output::print_uncommitted_hint(&id);
             }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = UpdateOutput {
                 id,
                 title: args.title,
-                desc: args.desc,
+                desc,
+                priority,
+                parent,
                 path: rel_path,
                 committed,
             };
-            let json = serde_json::to_string_pretty(&output)
+            let json = output::to_json(&output, format)
                 .map_err(|e| format!("JSON serialization failed: {}", e))?;
             println!("{}", json);
         }
@@ -114,7 +197,9 @@ pub fn run(args: UpdateArgs, ws: &Path, config: &Config) -> Result<(), String> {
             let output = UpdateOutput {
                 id,
                 title: args.title,
-                desc: args.desc,
+                desc,
+                priority,
+                parent,
                 path: rel_path,
                 committed,
             };
@@ -126,3 +211,28 @@ pub fn run(args: UpdateArgs, ws: &Path, config: &Config) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Resolve the --parent reference to an ID, refusing a parent that would
+/// close a cycle in the thread hierarchy.
+fn resolve_parent(ws: &Path, id: &str, parent_ref: &str) -> Result<String, String> {
+    let parent_file = workspace::find_by_ref(ws, parent_ref)?;
+    let parent_id = Thread::parse(&parent_file)?.id().to_string();
+
+    let mut parents = HashMap::new();
+    for path in workspace::find_all_threads(ws)? {
+        if let Ok(t) = Thread::parse(&path)
+            && let Some(p) = t.parent()
+        {
+            parents.insert(t.id().to_string(), p.to_string());
+        }
+    }
+
+    if let Some(cycle) = thread::parent_cycle(&parents, id, &parent_id) {
+        return Err(format!(
+            "setting parent {} would create a cycle: {}",
+            parent_id,
+            cycle.join(" → ")
+        ));
+    }
+    Ok(parent_id)
+}
@@ -1,80 +1,27 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process;
-use std::sync::LazyLock;
 
-use clap::{Args, Subcommand};
+use chrono::{DateTime, Local};
+use clap::{Args, Subcommand, ValueEnum};
 use colored::Colorize;
-use regex::Regex;
 use serde::Serialize;
 
 use crate::args::{DirectionArgs, FilterArgs, FormatArgs};
 use crate::cmd::migrate::migrate_file_for_validate;
 use crate::config::Config;
-use crate::output::OutputFormat;
-use crate::thread::{self, Frontmatter, extract_id_from_path};
+use crate::git;
+use crate::output::{self, OutputFormat};
+use crate::thread::{self, Frontmatter, Thread};
+use crate::validate::{
+    BOLD_LOG_FORMAT_RE, BRACKET_LOG_FORMAT_RE, Issue, LOG_DATE_HEADER_RE, MALFORMED_CHECKBOX_RE,
+    Severity, TIME_ONLY_FORMAT_RE, TODO_CHECKBOX_RE, apply_severity_overrides, expected_slug,
+    is_non_log_list_item, issue_description, validate_body, validate_encoding,
+    validate_frontmatter,
+};
 use crate::workspace;
 
-// ============================================================================
-// Regexes for validation
-// ============================================================================
-
-/// Matches a valid 6-character hex ID
-static VALID_ID_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9a-f]{6}$").unwrap());
-
-/// Matches section headers (## Name)
-static SECTION_HEADER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^## (.+)$").unwrap());
-
-/// Legacy section names that must not appear in fully migrated threads.
-/// Finding any of these triggers W010.
-static LEGACY_SECTIONS: &[&str] = &["Body", "Notes", "Todo", "Log"];
-
-/// Matches log date headers (### YYYY-MM-DD) - legacy format to be removed
-static LOG_DATE_HEADER_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^### (\d{4}-\d{2}-\d{2})$").unwrap());
-
-/// Matches current log format: - [YYYY-MM-DD HH:MM:SS] text
-static BRACKET_LOG_FORMAT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^- \[(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})\]").unwrap());
-
-/// Matches legacy bold log format: - **YYYY-MM-DD HH:MM:SS** text
-static BOLD_LOG_FORMAT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^- \*\*(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})\*\*").unwrap());
-
-/// Matches legacy time-only format: - **HH:MM** text (under date header)
-static TIME_ONLY_FORMAT_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^- \*\*(\d{2}:\d{2})\*\*").unwrap());
-
-/// Matches todo checkbox line
-static TODO_CHECKBOX_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^- \[([ xX])\]").unwrap());
-
-/// Matches malformed checkbox (common mistakes)
-static MALFORMED_CHECKBOX_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^- \[([^\] ]|.{2,})\]").unwrap());
-
-/// Issue code descriptions
-fn issue_description(code: &str) -> &'static str {
-    match code {
-        "E000" => "Cannot read file",
-        "E001" => "Missing frontmatter",
-        "E002" => "Invalid YAML syntax",
-        "E003" => "Missing required field",
-        "E004" => "Invalid ID format",
-        "E005" => "ID mismatch with filename",
-        "E006" => "Invalid status value",
-        "E007" => "Duplicate ID across threads",
-        "W004" => "Old log format",
-        "W005" => "Invalid timestamp",
-        "W006" => "Malformed checkbox",
-        "W007" => "Log entry missing or legacy timestamp",
-        "W008" => "Legacy date header",
-        "W009" => "Filename missing ID prefix",
-        "W010" => "Legacy markdown section found",
-        _ => "Unknown issue",
-    }
-}
-
 // ============================================================================
 // CLI Arguments
 // ============================================================================
@@ -92,6 +39,22 @@ pub struct ValidateArgs {
     #[arg(short = 'a', long, global = true)]
     all: bool,
 
+    /// Check duplicate names (W023) across the whole repo, not just per directory
+    #[arg(long, global = true)]
+    global_names: bool,
+
+    /// Print only the counts (total, valid, errors, warnings), no per-file detail
+    #[arg(long, global = true)]
+    summary_only: bool,
+
+    /// Severity that makes check exit 1: only errors (default), or warnings too
+    #[arg(long, value_enum, default_value_t = ErrorLevel::Error, global = true)]
+    error_level: ErrorLevel,
+
+    /// Make check exit 1 when there are more than N warnings
+    #[arg(long, value_name = "N", global = true)]
+    max_warnings: Option<usize>,
+
     #[command(flatten)]
     direction: DirectionArgs,
 
@@ -102,6 +65,16 @@ pub struct ValidateArgs {
     format: FormatArgs,
 }
 
+/// Lowest issue severity that fails `validate check`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum ErrorLevel {
+    /// Exit 1 on errors only
+    #[default]
+    Error,
+    /// Exit 1 on errors or warnings
+    Warning,
+}
+
 #[derive(Subcommand)]
 enum ValidateAction {
     /// Run validation checks (default)
@@ -120,6 +93,14 @@ enum ValidateAction {
         #[arg(long)]
         e002: bool,
 
+        /// Fix E010: Regenerate duplicate item hashes
+        #[arg(long)]
+        e010: bool,
+
+        /// Fix W006: Rewrite malformed todo checkboxes as '- [ ]' or '- [x]'
+        #[arg(long)]
+        w006: bool,
+
         /// Fix W007: Add timestamps to log entries (from git blame)
         #[arg(long)]
         w007: bool,
@@ -128,6 +109,30 @@ enum ValidateAction {
         #[arg(long)]
         w010: bool,
 
+        /// Fix W013: Backfill 'created' from the first git commit
+        #[arg(long)]
+        w013: bool,
+
+        /// Fix W015: Merge hidden legacy section items into frontmatter
+        #[arg(long)]
+        w015: bool,
+
+        /// Fix W022: Rename files to match the thread name
+        #[arg(long)]
+        w022: bool,
+
+        /// Fix W024: Re-sort the log newest first
+        #[arg(long)]
+        w024: bool,
+
+        /// Fix W025: Strip orphan hash comments from the body
+        #[arg(long)]
+        w025: bool,
+
+        /// Fix W026: Insert the space in "status(reason)"
+        #[arg(long)]
+        w026: bool,
+
         /// Show what would be fixed without making changes
         #[arg(long)]
         dry_run: bool,
@@ -138,70 +143,6 @@ enum ValidateAction {
 // Data Structures
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Severity {
-    Error,
-    Warning,
-}
-
-impl std::fmt::Display for Severity {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Severity::Error => write!(f, "error"),
-            Severity::Warning => write!(f, "warning"),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize)]
-pub struct Issue {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub line: Option<usize>,
-    pub severity: Severity,
-    pub code: String,
-    pub message: String,
-}
-
-impl Issue {
-    fn error(code: &str, message: impl Into<String>) -> Self {
-        Self {
-            line: None,
-            severity: Severity::Error,
-            code: code.to_string(),
-            message: message.into(),
-        }
-    }
-
-    fn error_at(code: &str, line: usize, message: impl Into<String>) -> Self {
-        Self {
-            line: Some(line),
-            severity: Severity::Error,
-            code: code.to_string(),
-            message: message.into(),
-        }
-    }
-
-    #[allow(dead_code)]
-    fn warning(code: &str, message: impl Into<String>) -> Self {
-        Self {
-            line: None,
-            severity: Severity::Warning,
-            code: code.to_string(),
-            message: message.into(),
-        }
-    }
-
-    fn warning_at(code: &str, line: usize, message: impl Into<String>) -> Self {
-        Self {
-            line: Some(line),
-            severity: Severity::Warning,
-            code: code.to_string(),
-            message: message.into(),
-        }
-    }
-}
-
 #[derive(Debug, Clone, Serialize)]
 pub struct FileResult {
     pub path: String,
@@ -234,9 +175,42 @@ pub struct ValidationSummary {
     pub valid: usize,
     pub errors: usize,
     pub warnings: usize,
+    /// The process exit code check uses, so CI can assert on it from the output
+    pub exit_code: i32,
     pub files: Vec<FileResult>,
 }
 
+/// The counts of a [`ValidationSummary`], for `--summary-only`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryCounts {
+    pub total: usize,
+    pub valid: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub exit_code: i32,
+}
+
+impl ValidationSummary {
+    fn counts(&self) -> SummaryCounts {
+        SummaryCounts {
+            total: self.total,
+            valid: self.valid,
+            errors: self.errors,
+            warnings: self.warnings,
+            exit_code: self.exit_code,
+        }
+    }
+
+    /// Set `exit_code` from the --error-level and --max-warnings policy.
+    fn with_exit_code(mut self, level: ErrorLevel, max_warnings: Option<usize>) -> Self {
+        let failed = self.errors > 0
+            || (level == ErrorLevel::Warning && self.warnings > 0)
+            || max_warnings.is_some_and(|max| self.warnings > max);
+        self.exit_code = i32::from(failed);
+        self
+    }
+}
+
 // ============================================================================
 // Main Entry Point
 // ============================================================================
@@ -247,18 +221,50 @@ pub fn run(args: ValidateArgs, ws: &Path, config: &Config) -> Result<(), String>
     // Collect thread files to validate
     let files = collect_files(&args, ws)?;
 
+    if args.summary_only
+        && matches!(
+            args.action,
+            Some(ValidateAction::Stats | ValidateAction::Fix { .. })
+        )
+    {
+        return Err("--summary-only only applies to 'validate check'".to_string());
+    }
+    let exit_policy = args.error_level != ErrorLevel::Error || args.max_warnings.is_some();
+    if exit_policy
+        && matches!(
+            args.action,
+            Some(ValidateAction::Stats | ValidateAction::Fix { .. })
+        )
+    {
+        return Err("--error-level and --max-warnings only apply to 'validate check'".to_string());
+    }
+
     if files.is_empty() {
+        if args.summary_only {
+            return output_summary_only(
+                &ValidationSummary {
+                    total: 0,
+                    valid: 0,
+                    errors: 0,
+                    warnings: 0,
+                    exit_code: 0,
+                    files: vec![],
+                },
+                format,
+            );
+        }
         match format {
             OutputFormat::Pretty | OutputFormat::Plain => {
                 println!("No threads found to validate");
             }
-            OutputFormat::Json | OutputFormat::Yaml => {
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
                 output_check_structured(
                     &ValidationSummary {
                         total: 0,
                         valid: 0,
                         errors: 0,
                         warnings: 0,
+                        exit_code: 0,
                         files: vec![],
                     },
                     format,
@@ -269,29 +275,60 @@ pub fn run(args: ValidateArgs, ws: &Path, config: &Config) -> Result<(), String>
     }
 
     let include_closed = args.filter.include_closed();
-
-    // Validate all files
-    let summary = validate_all(&files, ws, config, include_closed);
+    let validator = Validator::new(ws, config, include_closed, args.global_names);
+    let check = |validator| {
+        validate_all(&files, validator).with_exit_code(args.error_level, args.max_warnings)
+    };
 
     // Dispatch to subcommand
     match args.action {
-        None | Some(ValidateAction::Check { verbose: false }) => run_check(&summary, format, false),
-        Some(ValidateAction::Check { verbose: true }) => run_check(&summary, format, true),
-        Some(ValidateAction::Stats) => run_stats(&summary, format),
+        None | Some(ValidateAction::Check { .. }) if args.summary_only => {
+            let summary = check(validator);
+            output_summary_only(&summary, format)?;
+            if summary.exit_code != 0 {
+                process::exit(summary.exit_code);
+            }
+            Ok(())
+        }
+        None | Some(ValidateAction::Check { verbose: false }) => {
+            run_check(&check(validator), format, false, args.max_warnings)
+        }
+        Some(ValidateAction::Check { verbose: true }) => {
+            run_check(&check(validator), format, true, args.max_warnings)
+        }
+        Some(ValidateAction::Stats) => run_stats(&validate_all(&files, validator), format),
         Some(ValidateAction::Fix {
             e002,
+            e010,
+            w006,
             w007,
             w010,
+            w013,
+            w015,
+            w022,
+            w024,
+            w025,
+            w026,
             dry_run,
         }) => run_fix(
             &files,
             ws,
-            e002,
-            w007,
-            w010,
+            FixSelection {
+                e002,
+                e010,
+                w006,
+                w007,
+                w010,
+                w013,
+                w015,
+                w022,
+                w024,
+                w025,
+                w026,
+            },
             dry_run,
             format,
-            include_closed,
+            validator,
         ),
     }
 }
@@ -322,15 +359,26 @@ fn run_check(
     summary: &ValidationSummary,
     format: OutputFormat,
     verbose: bool,
+    max_warnings: Option<usize>,
 ) -> Result<(), String> {
     match format {
         OutputFormat::Pretty => output_check_pretty(summary, verbose),
         OutputFormat::Plain => output_check_plain(summary, verbose),
-        OutputFormat::Json | OutputFormat::Yaml => output_check_structured(summary, format)?,
+        OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml => {
+            output_check_structured(summary, format)?
+        }
     }
 
-    if summary.errors > 0 {
-        process::exit(1);
+    if let Some(max) = max_warnings
+        && summary.warnings > max
+    {
+        eprintln!(
+            "{} warnings exceed --max-warnings {}",
+            summary.warnings, max
+        );
+    }
+    if summary.exit_code != 0 {
+        process::exit(summary.exit_code);
     }
 
     Ok(())
@@ -464,11 +512,53 @@ fn output_check_structured(
                 .map_err(|e| format!("YAML serialization failed: {}", e))?;
             print!("{}", yaml);
         }
+        OutputFormat::Ndjson => {
+            // The counts first, then one line per issue
+            #[derive(Serialize)]
+            struct IssueLine<'a> {
+                path: &'a str,
+                #[serde(flatten)]
+                issue: &'a Issue,
+            }
+
+            output::print_ndjson(&summary.counts())?;
+            for file in &summary.files {
+                for issue in &file.issues {
+                    output::print_ndjson(&IssueLine {
+                        path: &file.path,
+                        issue,
+                    })?;
+                }
+            }
+        }
         _ => {}
     }
     Ok(())
 }
 
+/// `--summary-only`: one `key=value` line, or the bare counts object.
+fn output_summary_only(summary: &ValidationSummary, format: OutputFormat) -> Result<(), String> {
+    let counts = summary.counts();
+    match format {
+        OutputFormat::Pretty | OutputFormat::Plain => println!(
+            "total={} valid={} errors={} warnings={}",
+            counts.total, counts.valid, counts.errors, counts.warnings
+        ),
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(&counts)
+                .map_err(|e| format!("JSON serialization failed: {}", e))?;
+            println!("{}", json);
+        }
+        OutputFormat::Ndjson => output::print_ndjson(&counts)?,
+        OutputFormat::Yaml => {
+            let yaml = serde_yaml::to_string(&counts)
+                .map_err(|e| format!("YAML serialization failed: {}", e))?;
+            print!("{}", yaml);
+        }
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Stats Subcommand
 // ============================================================================
@@ -516,6 +606,7 @@ fn run_stats(summary: &ValidationSummary, format: OutputFormat) -> Result<(), St
         OutputFormat::Pretty => output_stats_pretty(summary, &stats),
         OutputFormat::Plain => output_stats_plain(summary, &stats),
         OutputFormat::Json => output_stats_json(summary, &stats)?,
+        OutputFormat::Ndjson => output_stats_ndjson(summary, &stats)?,
         OutputFormat::Yaml => output_stats_yaml(summary, &stats)?,
     }
 
@@ -611,6 +702,28 @@ fn output_stats_json(summary: &ValidationSummary, stats: &[IssueStat]) -> Result
     Ok(())
 }
 
+/// The counts on the first line, then one issue code per line.
+fn output_stats_ndjson(summary: &ValidationSummary, stats: &[IssueStat]) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Counts {
+        total: usize,
+        valid: usize,
+        errors: usize,
+        warnings: usize,
+    }
+
+    output::print_ndjson(&Counts {
+        total: summary.total,
+        valid: summary.valid,
+        errors: summary.errors,
+        warnings: summary.warnings,
+    })?;
+    for stat in stats {
+        output::print_ndjson(stat)?;
+    }
+    Ok(())
+}
+
 fn output_stats_yaml(summary: &ValidationSummary, stats: &[IssueStat]) -> Result<(), String> {
     #[derive(Serialize)]
     struct Output {
@@ -639,51 +752,103 @@ fn output_stats_yaml(summary: &ValidationSummary, stats: &[IssueStat]) -> Result
 // Validation Logic
 // ============================================================================
 
-fn validate_all(
-    files: &[PathBuf],
-    ws: &Path,
-    config: &Config,
+/// Per-file checks plus the cross-file state (duplicate IDs and names) they accumulate.
+///
+/// `validate` feeds it every file; `validate fix` feeds it each file's content after
+/// fixing, so detection and fixing share a single read per file.
+struct Validator<'a> {
+    ws: &'a Path,
+    config: &'a Config,
     include_closed: bool,
-) -> ValidationSummary {
-    let mut results: Vec<FileResult> = Vec::new();
-    let mut ids_seen: HashMap<String, PathBuf> = HashMap::new();
+    global_names: bool,
+    ids_seen: HashMap<String, PathBuf>,
+    names_seen: HashMap<(Option<PathBuf>, String), (String, PathBuf)>,
+    /// IDs of every thread in the workspace, for link targets (E009); loaded on first use
+    known_ids: Option<HashSet<String>>,
+    results: Vec<FileResult>,
+}
 
-    for path in files {
-        let rel_path = path
-            .strip_prefix(ws)
+impl<'a> Validator<'a> {
+    fn new(ws: &'a Path, config: &'a Config, include_closed: bool, global_names: bool) -> Self {
+        Self {
+            ws,
+            config,
+            include_closed,
+            global_names,
+            ids_seen: HashMap::new(),
+            names_seen: HashMap::new(),
+            known_ids: None,
+            results: Vec::new(),
+        }
+    }
+
+    fn rel_path(&self, path: &Path) -> String {
+        path.strip_prefix(self.ws)
             .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|_| path.to_string_lossy().to_string());
+            .unwrap_or_else(|_| path.to_string_lossy().to_string())
+    }
+
+    /// IDs of every thread in the workspace, archived ones included, scanned on first use.
+    fn known_ids(&mut self) -> &HashSet<String> {
+        let ws = self.ws;
+        self.known_ids.get_or_insert_with(|| {
+            let active = workspace::find_all_threads(ws).unwrap_or_default();
+            let archived = workspace::find_archived_threads(ws).unwrap_or_default();
+            active
+                .iter()
+                .chain(&archived)
+                .filter_map(|p| thread::extract_id_from_path(p))
+                .collect()
+        })
+    }
 
+    /// Read and check a file from disk.
+    fn check_file(&mut self, path: &Path) {
+        match fs::read_to_string(path) {
+            Ok(raw) => self.check_content(path, &raw),
+            Err(e) => self.results.push(FileResult {
+                path: self.rel_path(path),
+                issues: vec![Issue::error("E000", format!("cannot read file: {}", e))],
+            }),
+        }
+    }
+
+    /// Check already-loaded file content.
+    fn check_content(&mut self, path: &Path, raw: &str) {
+        let ws = self.ws;
         let mut issues = Vec::new();
 
-        // Read file content
-        let content = match fs::read_to_string(path) {
-            Ok(c) => c,
-            Err(e) => {
-                issues.push(Issue::error("E000", format!("cannot read file: {}", e)));
-                results.push(FileResult {
-                    path: rel_path,
+        // W021: BOM / CRLF are normalized away before the remaining checks
+        issues.extend(validate_encoding(raw));
+        let content = thread::normalize_content(raw);
+
+        // Frontmatter that does not parse (E001/E002) leaves nothing to cross-check
+        let t = match Thread::from_content(path, &content) {
+            Ok(t) => t,
+            Err(_) => {
+                issues.extend(validate_frontmatter(&content, path, self.config));
+                issues.extend(validate_body(&content, self.config));
+                apply_severity_overrides(&mut issues, self.config);
+                self.results.push(FileResult {
+                    path: self.rel_path(path),
                     issues,
                 });
-                continue;
+                return;
             }
         };
 
-        // Validate frontmatter
-        let fm_result = validate_frontmatter(&content, path, config);
-        issues.extend(fm_result.issues);
-
         // Skip closed threads unless include_closed is set
-        if !include_closed
-            && let Some(ref status) = fm_result.status
-            && thread::is_closed(status)
-        {
-            continue;
+        if !self.include_closed && thread::is_closed(t.status()) {
+            return;
         }
 
+        // Per-file checks: frontmatter, sections, log entries, and todo items
+        issues.extend(t.validate(self.config));
+
         // Check for duplicate IDs (E007)
-        if let Some(ref id) = fm_result.id {
-            if let Some(other_path) = ids_seen.get(id) {
+        if !t.id().is_empty() {
+            let id = t.id();
+            if let Some(other_path) = self.ids_seen.get(id) {
                 let other_rel = other_path
                     .strip_prefix(ws)
                     .map(|p| p.to_string_lossy().to_string())
@@ -693,364 +858,151 @@ fn validate_all(
                     format!("duplicate ID '{}' (also in {})", id, other_rel),
                 ));
             } else {
-                ids_seen.insert(id.clone(), path.clone());
+                self.ids_seen.insert(id.to_string(), path.to_path_buf());
             }
         }
 
-        // Validate sections
-        issues.extend(validate_sections(&content, config));
-
-        // Validate log entries
-        issues.extend(validate_log(&content));
-
-        // Validate todo items
-        issues.extend(validate_todos(&content));
-
-        results.push(FileResult {
-            path: rel_path,
-            issues,
-        });
-    }
-
-    // Compute summary
-    let valid = results.iter().filter(|r| r.is_valid()).count();
-    let errors: usize = results.iter().map(|r| r.error_count()).sum();
-    let warnings: usize = results.iter().map(|r| r.warning_count()).sum();
-
-    ValidationSummary {
-        total: results.len(),
-        valid,
-        errors,
-        warnings,
-        files: results,
-    }
-}
-
-struct FrontmatterResult {
-    id: Option<String>,
-    status: Option<String>,
-    issues: Vec<Issue>,
-}
-
-fn validate_frontmatter(content: &str, path: &Path, config: &Config) -> FrontmatterResult {
-    let mut issues = Vec::new();
-
-    // E001: Check for frontmatter delimiters
-    if !content.starts_with("---\n") {
-        issues.push(Issue::error_at("E001", 1, "missing frontmatter delimiter"));
-        return FrontmatterResult {
-            id: None,
-            status: None,
-            issues,
-        };
-    }
-
-    // Find closing delimiter
-    let rest = &content[4..];
-    let end = match rest.find("\n---") {
-        Some(e) => e,
-        None => {
-            issues.push(Issue::error(
-                "E001",
-                "unclosed frontmatter (missing closing ---)",
-            ));
-            return FrontmatterResult {
-                id: None,
-                status: None,
-                issues,
-            };
-        }
-    };
-
-    let yaml_content = &rest[..end];
-
-    // E002: Parse YAML
-    let fm: Frontmatter = match serde_yaml::from_str(yaml_content) {
-        Ok(fm) => fm,
-        Err(e) => {
-            let line = extract_yaml_error_line(&e);
-            if let Some(l) = line {
-                issues.push(Issue::error_at(
-                    "E002",
-                    l + 1,
-                    format!("invalid YAML: {}", e),
-                ));
+        // Check for duplicate names (W023): ambiguous for name-based lookups
+        if !t.name().is_empty() {
+            let name = t.name();
+            let dir = if self.global_names {
+                None
             } else {
-                issues.push(Issue::error("E002", format!("invalid YAML: {}", e)));
-            }
-            return FrontmatterResult {
-                id: None,
-                status: None,
-                issues,
+                path.parent().map(Path::to_path_buf)
             };
+            let id = t.id().to_string();
+            match self.names_seen.get(&(dir.clone(), name.to_lowercase())) {
+                Some((other_id, other_path)) => {
+                    let other_rel = other_path
+                        .strip_prefix(ws)
+                        .map(|p| p.to_string_lossy().to_string())
+                        .unwrap_or_else(|_| other_path.to_string_lossy().to_string());
+                    issues.push(Issue::warning(
+                        "W023",
+                        format!(
+                            "duplicate name '{}' (also {} in {})",
+                            name, other_id, other_rel
+                        ),
+                    ));
+                }
+                None => {
+                    self.names_seen
+                        .insert((dir, name.to_lowercase()), (id, path.to_path_buf()));
+                }
+            }
         }
-    };
-
-    // E003: Check required fields
-    if fm.id.is_empty() {
-        issues.push(Issue::error("E003", "missing required field: id"));
-    }
-    if fm.name.is_empty() {
-        issues.push(Issue::error("E003", "missing required field: name"));
-    }
-    if fm.status.is_empty() {
-        issues.push(Issue::error("E003", "missing required field: status"));
-    }
-
-    // E004: Validate ID format
-    if !fm.id.is_empty() && !VALID_ID_RE.is_match(&fm.id) {
-        issues.push(Issue::error(
-            "E004",
-            format!("invalid ID format '{}' (expected 6 hex chars)", fm.id),
-        ));
-    }
-
-    // E005: Check ID matches filename
-    if !fm.id.is_empty()
-        && let Some(filename_id) = extract_id_from_path(path)
-        && fm.id != filename_id
-    {
-        issues.push(Issue::error(
-            "E005",
-            format!(
-                "ID mismatch: frontmatter has '{}', filename has '{}'",
-                fm.id, filename_id
-            ),
-        ));
-    }
-
-    // W009: Frontmatter has ID but filename has no ID prefix
-    if !fm.id.is_empty() && extract_id_from_path(path).is_none() {
-        issues.push(Issue::warning(
-            "W009",
-            format!("frontmatter id '{}' not reflected in filename", fm.id),
-        ));
-    }
-
-    // E006: Validate status using config status lists
-    if !fm.status.is_empty()
-        && !thread::is_valid_status_with_config(
-            &fm.status,
-            &config.status.open,
-            &config.status.closed,
-        )
-    {
-        let base = thread::base_status(&fm.status);
-        issues.push(Issue::error("E006", format!("invalid status '{}'", base)));
-    }
 
-    let extracted_id = if fm.id.is_empty() {
-        extract_id_from_path(path)
-    } else {
-        Some(fm.id)
-    };
-
-    let extracted_status = if fm.status.is_empty() {
-        None
-    } else {
-        Some(fm.status)
-    };
-
-    FrontmatterResult {
-        id: extracted_id,
-        status: extracted_status,
-        issues,
-    }
-}
-
-fn extract_yaml_error_line(e: &serde_yaml::Error) -> Option<usize> {
-    e.location().map(|loc| loc.line())
-}
-
-fn validate_sections(content: &str, _config: &Config) -> Vec<Issue> {
-    let mut issues = Vec::new();
-
-    for (line_num, line) in content.lines().enumerate() {
-        if let Some(caps) = SECTION_HEADER_RE.captures(line) {
-            let section = caps.get(1).unwrap().as_str();
-            let line_display = line_num + 1;
-
-            // W010: Any legacy section name found means the file needs migration.
-            // Non-legacy ## headers in body content are fine and are ignored.
-            if LEGACY_SECTIONS.contains(&section) {
-                issues.push(Issue::warning_at(
-                    "W010",
-                    line_display,
-                    format!(
-                        "legacy section '## {}' found — run 'threads migrate'",
-                        section
-                    ),
+        // Check link targets (E009): scoped validation still resolves repo-wide
+        for target in t.frontmatter.links.iter().map(|l| &l.target) {
+            if !self.known_ids().contains(target) {
+                issues.push(Issue::error(
+                    "E009",
+                    format!("link target '{}' does not exist", target),
                 ));
             }
         }
-    }
-
-    issues
-}
-
-fn validate_log(content: &str) -> Vec<Issue> {
-    let mut issues = Vec::new();
-    let mut in_log_section = false;
-    let mut has_date_header = false;
-
-    for (line_num, line) in content.lines().enumerate() {
-        let line_display = line_num + 1;
-
-        if line.starts_with("## ") {
-            in_log_section = line.starts_with("## Log");
-            has_date_header = false;
-            continue;
-        }
-
-        if !in_log_section {
-            continue;
-        }
 
-        // W008: Legacy date headers should be removed (dates go in entries)
-        if LOG_DATE_HEADER_RE.is_match(line) {
-            has_date_header = true;
-            issues.push(Issue::warning_at(
-                "W008",
-                line_display,
-                "legacy date header - run 'validate fix --w007' to migrate",
+        // Check the parent thread exists (W012); `tree` shows such threads at the root
+        if let Some(ref parent) = t.frontmatter.parent
+            && !self.known_ids().contains(parent)
+        {
+            issues.push(Issue::warning(
+                "W012",
+                format!("parent '{}' does not exist", parent),
             ));
-            continue;
         }
 
-        // Check log entries (lines starting with "- ")
-        if line.starts_with("- ") {
-            let entry_content = line.strip_prefix("- ").unwrap_or(line);
-
-            // Skip continuation lines (bold labels, table rows, etc.)
-            if is_non_log_list_item(entry_content) {
-                continue;
-            }
+        // Encoding and cross-file issues; per-file ones were already re-graded
+        apply_severity_overrides(&mut issues, self.config);
 
-            // Current format: - [YYYY-MM-DD HH:MM:SS] text
-            if line.starts_with("- [") {
-                if BRACKET_LOG_FORMAT_RE.is_match(line) {
-                    // Valid current format, check timestamp validity
-                    if let Some(caps) = BRACKET_LOG_FORMAT_RE.captures(line) {
-                        let ts = &caps[1];
-                        if !is_valid_timestamp(ts) {
-                            issues.push(Issue::warning_at(
-                                "W005",
-                                line_display,
-                                format!("invalid timestamp '{}'", ts),
-                            ));
-                        }
-                    }
-                } else {
-                    // Has brackets but not a valid timestamp - might be malformed
-                    issues.push(Issue::warning_at(
-                        "W007",
-                        line_display,
-                        "log entry missing timestamp",
-                    ));
-                }
-            } else if line.starts_with("- **") {
-                // Legacy bold formats
-                if BOLD_LOG_FORMAT_RE.is_match(line) {
-                    issues.push(Issue::warning_at(
-                        "W007",
-                        line_display,
-                        "legacy bold timestamp - run 'validate fix --w007' to migrate",
-                    ));
-                } else if TIME_ONLY_FORMAT_RE.is_match(line) {
-                    if has_date_header {
-                        issues.push(Issue::warning_at(
-                            "W007",
-                            line_display,
-                            "legacy time-only format - run 'validate fix --w007' to migrate",
-                        ));
-                    } else {
-                        issues.push(Issue::warning_at(
-                            "W004",
-                            line_display,
-                            "time-only format without date header",
-                        ));
-                    }
-                }
-                // Note: Bold text that isn't a timestamp is handled by is_non_log_list_item above
-            } else {
-                // Plain list item without any timestamp
-                issues.push(Issue::warning_at(
-                    "W007",
-                    line_display,
-                    "log entry missing timestamp",
-                ));
-            }
-        }
+        self.results.push(FileResult {
+            path: self.rel_path(path),
+            issues,
+        });
     }
 
-    issues
-}
-
-fn validate_todos(content: &str) -> Vec<Issue> {
-    let mut issues = Vec::new();
-    let mut in_todo_section = false;
-
-    for (line_num, line) in content.lines().enumerate() {
-        let line_display = line_num + 1;
-
-        if line.starts_with("## ") {
-            in_todo_section = line.starts_with("## Todo");
-            continue;
-        }
-
-        if !in_todo_section {
-            continue;
-        }
-
-        if line.trim().is_empty() {
-            continue;
-        }
-
-        if line.starts_with("- [") {
-            if TODO_CHECKBOX_RE.is_match(line) {
-                continue;
-            }
+    fn finish(self) -> ValidationSummary {
+        let results = self.results;
+        let valid = results.iter().filter(|r| r.is_valid()).count();
+        let errors: usize = results.iter().map(|r| r.error_count()).sum();
+        let warnings: usize = results.iter().map(|r| r.warning_count()).sum();
 
-            if MALFORMED_CHECKBOX_RE.is_match(line) {
-                issues.push(Issue::warning_at(
-                    "W006",
-                    line_display,
-                    "malformed checkbox (use '- [ ]' or '- [x]')",
-                ));
-            }
+        ValidationSummary {
+            total: results.len(),
+            valid,
+            errors,
+            warnings,
+            exit_code: 0,
+            files: results,
         }
     }
-
-    issues
 }
 
-fn is_valid_timestamp(ts: &str) -> bool {
-    chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S").is_ok()
+fn validate_all(files: &[PathBuf], mut validator: Validator) -> ValidationSummary {
+    for path in files {
+        validator.check_file(path);
+    }
+    validator.finish()
 }
 
 // ============================================================================
 // Fix Subcommand
 // ============================================================================
 
-#[allow(clippy::too_many_arguments)]
+/// Which fixes `validate fix` should apply.
+struct FixSelection {
+    e002: bool,
+    e010: bool,
+    w006: bool,
+    w007: bool,
+    w010: bool,
+    w013: bool,
+    w015: bool,
+    w022: bool,
+    w024: bool,
+    w025: bool,
+    w026: bool,
+}
+
 fn run_fix(
     files: &[PathBuf],
     ws: &Path,
-    fix_e002: bool,
-    fix_w007: bool,
-    fix_w010: bool,
+    fixes: FixSelection,
     dry_run: bool,
     format: OutputFormat,
-    include_closed: bool,
+    mut validator: Validator,
 ) -> Result<(), String> {
-    if !fix_e002 && !fix_w007 && !fix_w010 {
-        return Err("specify at least one fix: --e002, --w007, --w010".to_string());
+    if !fixes.e002
+        && !fixes.e010
+        && !fixes.w006
+        && !fixes.w007
+        && !fixes.w010
+        && !fixes.w013
+        && !fixes.w015
+        && !fixes.w022
+        && !fixes.w024
+        && !fixes.w025
+        && !fixes.w026
+    {
+        return Err(
+            "specify at least one fix: --e002, --e010, --w006, --w007, --w010, --w013, --w015, \
+             --w022, --w024, --w025, --w026"
+                .to_string(),
+        );
     }
 
     let mut frontmatter_fixed = 0;
     let mut log_entries_fixed = 0;
+    let mut checkboxes_fixed = 0;
     let mut headers_removed = 0;
+    let mut logs_sorted = 0;
+    let mut hashes_regenerated = 0;
+    let mut sections_merged = 0;
+    let mut created_backfilled = 0;
+    let mut hash_comments_removed = 0;
+    let mut statuses_spaced = 0;
     let mut legacy_migrated = 0;
+    let mut files_renamed = 0;
     let mut files_modified = 0;
     let mut fix_entries: Vec<FixEntry> = Vec::new();
 
@@ -1062,14 +1014,18 @@ fn run_fix(
 
         let content = match fs::read_to_string(path) {
             Ok(c) => c,
-            Err(_) => continue,
+            Err(_) => {
+                validator.check_file(path);
+                continue;
+            }
         };
 
         // Filter by status unless include_closed
-        if !include_closed
+        if !validator.include_closed
             && let Some(status) = extract_status_from_content(&content)
             && thread::is_closed(&status)
         {
+            validator.check_content(path, &content);
             continue;
         }
 
@@ -1077,11 +1033,18 @@ fn run_fix(
         let mut file_changed = false;
         let mut file_fm_fixed = 0;
         let mut file_log_fixed = 0;
+        let mut file_checkboxes_fixed = 0;
         let mut file_headers_removed = 0;
+        let mut file_log_sorted = false;
+        let mut file_hashes_regenerated = 0;
+        let mut file_section_items = None;
+        let mut file_created_backfilled = false;
+        let mut file_hash_comments = 0;
+        let mut file_status_spaced = false;
         let mut file_legacy_migrated = false;
 
         // E002: Fix frontmatter quoting
-        if fix_e002 {
+        if fixes.e002 {
             let (new_content, fixed) = fix_frontmatter_quoting(
                 &current_content,
                 &rel_path,
@@ -1096,8 +1059,24 @@ fn run_fix(
             }
         }
 
+        // W006: canonical checkboxes; unclear markers are reported and left alone
+        if fixes.w006 {
+            let (new_content, fixed) = fix_checkboxes(
+                &current_content,
+                &rel_path,
+                dry_run,
+                format,
+                &mut fix_entries,
+            );
+            if fixed > 0 {
+                file_checkboxes_fixed = fixed;
+                current_content = new_content;
+                file_changed = true;
+            }
+        }
+
         // W007: Fix log timestamps
-        if fix_w007 {
+        if fixes.w007 {
             let (new_lines, fixes, removed) = fix_log_section(
                 &current_content,
                 path,
@@ -1115,9 +1094,113 @@ fn run_fix(
             }
         }
 
+        // W013: backfill `created` from the commit that added the file
+        if fixes.w013
+            && let Ok(mut t) = Thread::from_content(path, &current_content)
+            && t.frontmatter.created.is_none()
+            && let Some(created) = git::first_commit_time(ws, path)
+                .and_then(|ts| DateTime::from_timestamp(ts, 0))
+                .map(|dt| thread::format_timestamp(dt.with_timezone(&Local)))
+        {
+            if dry_run && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                println!("{}: would set created to {}", rel_path, created);
+            }
+            t.frontmatter.created = Some(created);
+            t.rebuild_content()?;
+            current_content = t.content;
+            file_created_backfilled = true;
+            file_changed = true;
+        }
+
+        // W015: fold legacy section items hidden by frontmatter back in, then strip the sections
+        if fixes.w015
+            && let Ok(mut t) = Thread::from_content(path, &current_content)
+            && !thread::shadowed_sections(&t.frontmatter, &t.content).is_empty()
+        {
+            let merged = t.merge_legacy_sections()?;
+            if dry_run && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                println!(
+                    "{}: would merge {} legacy section items into frontmatter",
+                    rel_path, merged
+                );
+            }
+            current_content = t.content;
+            file_section_items = Some(merged);
+            file_changed = true;
+        }
+
+        // E010: fresh hashes for items that share one with an earlier item
+        if fixes.e010
+            && let Ok(mut t) = Thread::from_content(path, &current_content)
+        {
+            let rehashed = thread::regenerate_duplicate_hashes(&mut t.frontmatter);
+            if rehashed > 0 {
+                t.rebuild_content()?;
+                if dry_run && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                    println!(
+                        "{}: would regenerate {} duplicate hashes",
+                        rel_path, rehashed
+                    );
+                }
+                current_content = t.content;
+                file_hashes_regenerated = rehashed;
+                file_changed = true;
+            }
+        }
+
+        // W024: re-sort the frontmatter log newest first
+        if fixes.w024
+            && let Ok(mut t) = Thread::from_content(path, &current_content)
+            && thread::sort_log_newest_first(&mut t.frontmatter.log)
+        {
+            t.rebuild_content()?;
+            if dry_run && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                println!("{}: would re-sort log newest first", rel_path);
+            }
+            current_content = t.content;
+            file_log_sorted = true;
+            file_changed = true;
+        }
+
+        // W025: strip hash comments that no longer belong to any item
+        if fixes.w025
+            && let Ok(t) = Thread::from_content(path, &current_content)
+        {
+            let (body, removed) = thread::strip_orphan_hash_comments(&t.frontmatter, t.body());
+            if removed > 0 {
+                if dry_run && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                    println!(
+                        "{}: would remove {} orphan hash comments",
+                        rel_path, removed
+                    );
+                }
+                current_content = format!("{}{}", &t.content[..t.body_start], body);
+                file_hash_comments = removed;
+                file_changed = true;
+            }
+        }
+
+        // W026: "blocked(reason)" -> "blocked (reason)"
+        if fixes.w026
+            && let Ok(mut t) = Thread::from_content(path, &current_content)
+            && let Some(spaced) = thread::spaced_status_reason(&t.frontmatter.status)
+        {
+            if dry_run && matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                println!(
+                    "{}: would change status '{}' to '{}'",
+                    rel_path, t.frontmatter.status, spaced
+                );
+            }
+            t.frontmatter.status = spaced;
+            t.rebuild_content()?;
+            current_content = t.content;
+            file_status_spaced = true;
+            file_changed = true;
+        }
+
         // W010: migrate legacy sections.
         // migrate_file_for_validate handles its own file write; we only track the count here.
-        if fix_w010 {
+        if fixes.w010 {
             match migrate_file_for_validate(path, ws, dry_run) {
                 Ok(true) => {
                     file_legacy_migrated = true;
@@ -1133,7 +1216,22 @@ fn run_fix(
         if file_changed {
             frontmatter_fixed += file_fm_fixed;
             log_entries_fixed += file_log_fixed;
+            checkboxes_fixed += file_checkboxes_fixed;
             headers_removed += file_headers_removed;
+            if file_created_backfilled {
+                created_backfilled += 1;
+            }
+            if file_log_sorted {
+                logs_sorted += 1;
+            }
+            hashes_regenerated += file_hashes_regenerated;
+            if file_section_items.is_some() {
+                sections_merged += 1;
+            }
+            hash_comments_removed += file_hash_comments;
+            if file_status_spaced {
+                statuses_spaced += 1;
+            }
             files_modified += 1;
 
             if !dry_run {
@@ -1146,12 +1244,33 @@ fn run_fix(
                         if file_fm_fixed > 0 {
                             parts.push(format!("{} frontmatter fields", file_fm_fixed));
                         }
+                        if file_checkboxes_fixed > 0 {
+                            parts.push(format!("{} checkboxes", file_checkboxes_fixed));
+                        }
                         if file_log_fixed > 0 {
                             parts.push(format!("{} log entries", file_log_fixed));
                         }
                         if file_headers_removed > 0 {
                             parts.push(format!("{} headers removed", file_headers_removed));
                         }
+                        if file_created_backfilled {
+                            parts.push("created timestamp".to_string());
+                        }
+                        if file_log_sorted {
+                            parts.push("log order".to_string());
+                        }
+                        if let Some(items) = file_section_items {
+                            parts.push(format!("{} hidden section items merged", items));
+                        }
+                        if file_hashes_regenerated > 0 {
+                            parts.push(format!("{} duplicate hashes", file_hashes_regenerated));
+                        }
+                        if file_hash_comments > 0 {
+                            parts.push(format!("{} orphan hash comments", file_hash_comments));
+                        }
+                        if file_status_spaced {
+                            parts.push("status spacing".to_string());
+                        }
                         println!("Fixed {} in {}", parts.join(", "), rel_path);
                     }
                     _ => {}
@@ -1165,7 +1284,65 @@ fn run_fix(
                 files_modified += 1;
             }
         }
+
+        // W010 migration writes the file itself; pick up its result unless overwritten above
+        if file_legacy_migrated
+            && !file_changed
+            && !dry_run
+            && let Ok(migrated) = fs::read_to_string(path)
+        {
+            current_content = migrated;
+        }
+
+        // W022: rename last, after all content fixes have been written to the old path
+        let mut final_path = path.clone();
+        if fixes.w022
+            && let Some(new_path) = renamed_path(path, &current_content)
+        {
+            let new_rel = new_path
+                .strip_prefix(ws)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| new_path.to_string_lossy().to_string());
+
+            if new_path.exists() {
+                eprintln!(
+                    "W022 fix skipped for {}: {} already exists",
+                    rel_path, new_rel
+                );
+            } else {
+                if dry_run {
+                    match format {
+                        OutputFormat::Pretty | OutputFormat::Plain => {
+                            println!("Would rename {} -> {}", rel_path, new_rel);
+                        }
+                        _ => fix_entries.push(FixEntry {
+                            path: rel_path.clone(),
+                            line: 0,
+                            old: rel_path.clone(),
+                            new: new_rel.clone(),
+                        }),
+                    }
+                } else {
+                    let repo = workspace::open()?;
+                    git::move_file(&repo, ws, path, &new_path)
+                        .map_err(|e| format!("failed to rename {}: {}", rel_path, e))?;
+                    if matches!(format, OutputFormat::Pretty | OutputFormat::Plain) {
+                        println!("Renamed {} -> {}", rel_path, new_rel);
+                    }
+                    final_path = new_path;
+                }
+
+                files_renamed += 1;
+                if !file_changed && !file_legacy_migrated {
+                    files_modified += 1;
+                }
+            }
+        }
+
+        // Re-check the fixed content in memory: no second read to find what is left
+        validator.check_content(&final_path, &current_content);
     }
+    let remaining = validator.finish();
 
     // Summary
     match format {
@@ -1175,15 +1352,39 @@ fn run_fix(
             if frontmatter_fixed > 0 {
                 parts.push(format!("{} frontmatter fields", frontmatter_fixed));
             }
+            if checkboxes_fixed > 0 {
+                parts.push(format!("{} checkboxes", checkboxes_fixed));
+            }
             if log_entries_fixed > 0 {
                 parts.push(format!("{} log entries", log_entries_fixed));
             }
             if headers_removed > 0 {
                 parts.push(format!("{} headers removed", headers_removed));
             }
+            if created_backfilled > 0 {
+                parts.push(format!("{} created timestamps", created_backfilled));
+            }
+            if logs_sorted > 0 {
+                parts.push(format!("{} logs re-sorted", logs_sorted));
+            }
+            if sections_merged > 0 {
+                parts.push(format!("{} hidden sections merged", sections_merged));
+            }
+            if hashes_regenerated > 0 {
+                parts.push(format!("{} duplicate hashes", hashes_regenerated));
+            }
+            if hash_comments_removed > 0 {
+                parts.push(format!("{} orphan hash comments", hash_comments_removed));
+            }
+            if statuses_spaced > 0 {
+                parts.push(format!("{} statuses spaced", statuses_spaced));
+            }
             if legacy_migrated > 0 {
                 parts.push(format!("{} files migrated", legacy_migrated));
             }
+            if files_renamed > 0 {
+                parts.push(format!("{} files renamed", files_renamed));
+            }
 
             if dry_run {
                 if parts.is_empty() {
@@ -1200,28 +1401,60 @@ fn run_fix(
             } else {
                 println!("Fixed {} in {} files", parts.join(", "), files_modified);
             }
+
+            // Whatever the selected fixers could not resolve
+            if remaining.errors > 0 || remaining.warnings > 0 {
+                println!();
+                if format == OutputFormat::Pretty {
+                    output_check_pretty(&remaining, false);
+                } else {
+                    output_check_plain(&remaining, false);
+                }
+            }
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Ndjson => {
             let output = serde_json::json!({
                 "dry_run": dry_run,
                 "frontmatter_fixed": frontmatter_fixed,
                 "log_entries_fixed": log_entries_fixed,
+                "checkboxes_fixed": checkboxes_fixed,
                 "headers_removed": headers_removed,
+                "created_backfilled": created_backfilled,
+                "logs_sorted": logs_sorted,
+                "hashes_regenerated": hashes_regenerated,
+                "sections_merged": sections_merged,
+                "hash_comments_removed": hash_comments_removed,
+                "statuses_spaced": statuses_spaced,
                 "legacy_migrated": legacy_migrated,
+                "files_renamed": files_renamed,
                 "files_modified": files_modified,
                 "changes": fix_entries,
+                "remaining": remaining,
             });
-            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+            if format == OutputFormat::Ndjson {
+                output::print_ndjson(&output)?;
+            } else {
+                println!("{}", serde_json::to_string_pretty(&output).unwrap());
+            }
         }
         OutputFormat::Yaml => {
             let output = serde_json::json!({
                 "dry_run": dry_run,
                 "frontmatter_fixed": frontmatter_fixed,
                 "log_entries_fixed": log_entries_fixed,
+                "checkboxes_fixed": checkboxes_fixed,
                 "headers_removed": headers_removed,
+                "created_backfilled": created_backfilled,
+                "logs_sorted": logs_sorted,
+                "hashes_regenerated": hashes_regenerated,
+                "sections_merged": sections_merged,
+                "hash_comments_removed": hash_comments_removed,
+                "statuses_spaced": statuses_spaced,
                 "legacy_migrated": legacy_migrated,
+                "files_renamed": files_renamed,
                 "files_modified": files_modified,
                 "changes": fix_entries,
+                "remaining": remaining,
             });
             println!("{}", serde_yaml::to_string(&output).unwrap());
         }
@@ -1230,6 +1463,65 @@ fn run_fix(
     Ok(())
 }
 
+/// Rewrite malformed checkboxes in `## Todo` sections as `- [ ]` or `- [x]`,
+/// keeping the rest of the line (text and `<!-- hash -->`) as is. `[*]`,
+/// `[done]` and stray spaces around an x count as checked, an empty box as
+/// unchecked. Other markers such as `[o]` or `[-]` are reported and left alone.
+fn fix_checkboxes(
+    content: &str,
+    rel_path: &str,
+    dry_run: bool,
+    format: OutputFormat,
+    fix_entries: &mut Vec<FixEntry>,
+) -> (String, usize) {
+    let mut lines: Vec<String> = Vec::new();
+    let mut fixes = 0;
+    let mut in_todo_section = false;
+
+    for (i, line) in content.lines().enumerate() {
+        if line.starts_with("## ") {
+            in_todo_section = line.starts_with("## Todo");
+        }
+        if !in_todo_section
+            || TODO_CHECKBOX_RE.is_match(line)
+            || !MALFORMED_CHECKBOX_RE.is_match(line)
+        {
+            lines.push(line.to_string());
+            continue;
+        }
+
+        // "- [" is ASCII, and the malformed pattern guarantees a closing bracket
+        let close = 3 + line[3..].find(']').unwrap_or(0);
+        let marker = &line[3..close];
+        let mark = match marker.trim().to_lowercase().as_str() {
+            "" => ' ',
+            "x" | "*" | "done" | "✓" | "✔" => 'x',
+            _ => {
+                eprintln!(
+                    "W006 fix skipped for {}:{}: unclear checkbox '[{}]'",
+                    rel_path,
+                    i + 1,
+                    marker
+                );
+                lines.push(line.to_string());
+                continue;
+            }
+        };
+        let fixed_line = format!("- [{}]{}", mark, &line[close + 1..]);
+        if dry_run {
+            print_fix(format, rel_path, i + 1, line, &fixed_line, fix_entries);
+        }
+        lines.push(fixed_line);
+        fixes += 1;
+    }
+
+    let mut fixed = lines.join("\n");
+    if content.ends_with('\n') {
+        fixed.push('\n');
+    }
+    (fixed, fixes)
+}
+
 /// Fix frontmatter quoting: quote values that contain YAML-special characters
 fn fix_frontmatter_quoting(
     content: &str,
@@ -1376,6 +1668,22 @@ fn quote_yaml_value(value: &str) -> String {
     }
 }
 
+/// Target path for a W022 rename, or None when the filename already matches the name.
+fn renamed_path(path: &Path, content: &str) -> Option<PathBuf> {
+    let rest = content.strip_prefix("---\n")?;
+    let end = rest.find("\n---")?;
+    let fm: Frontmatter = serde_yaml::from_str(&rest[..end]).ok()?;
+    if fm.id.is_empty() {
+        return None;
+    }
+
+    let slug = expected_slug(&fm.name)?;
+    if thread::extract_name_from_path(path) == slug {
+        return None;
+    }
+    Some(path.with_file_name(format!("{}-{}.md", fm.id, slug)))
+}
+
 /// Extract status from content using line-by-line parsing (works even with broken YAML)
 fn extract_status_from_content(content: &str) -> Option<String> {
     // Find frontmatter
@@ -1568,59 +1876,6 @@ fn print_fix(
     }
 }
 
-/// Check if a list item content looks like code/formatting rather than a log entry
-fn is_non_log_list_item(content: &str) -> bool {
-    let trimmed = content.trim();
-
-    // Code fence
-    if trimmed.starts_with("```") {
-        return true;
-    }
-
-    // Shell command (likely inside code block)
-    if trimmed.starts_with("$ ") || (trimmed.starts_with("# ") && !trimmed.starts_with("## ")) {
-        return true;
-    }
-
-    // Markdown header inside list item
-    if trimmed.starts_with("### ") || trimmed.starts_with("#### ") {
-        return true;
-    }
-
-    // Lines that are clearly continuations (start with common code patterns)
-    if trimmed.starts_with("git ") || trimmed.starts_with("cd ") || trimmed.starts_with("./") {
-        return true;
-    }
-
-    // Table rows (markdown tables)
-    if trimmed.starts_with('|') {
-        return true;
-    }
-
-    // Bold text that is NOT a timestamp (continuation headers like "**Results:**")
-    // Timestamps look like **YYYY-MM-DD or **HH:MM** - other bold is content
-    if let Some(after_bold) = trimmed.strip_prefix("**") {
-        // Check if it's NOT a timestamp pattern
-        // Timestamp patterns: YYYY-MM-DD or HH:MM
-        let is_date = after_bold.len() >= 10
-            && after_bold.chars().take(4).all(|c| c.is_ascii_digit())
-            && after_bold.chars().nth(4) == Some('-');
-        let is_time = after_bold.len() >= 5
-            && after_bold.chars().take(2).all(|c| c.is_ascii_digit())
-            && after_bold.chars().nth(2) == Some(':');
-        if !is_date && !is_time {
-            return true;
-        }
-    }
-
-    // "See X", "Note:", etc. - common continuation patterns
-    if trimmed.starts_with("See ") || trimmed.starts_with("Note:") {
-        return true;
-    }
-
-    false
-}
-
 /// Get timestamp from git blame for a specific line
 fn get_blame_timestamp(path: &Path, ws: &Path, line_num: usize) -> Option<String> {
     use std::process::Command;
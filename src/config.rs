@@ -3,9 +3,10 @@
 //! Configuration is loaded from multiple sources with the following precedence:
 //! 1. CLI flags (highest priority)
 //! 2. Environment variables (THREADS_*)
-//! 3. Project manifest (.threads-config/manifest.yaml)
-//! 4. User global (~/.config/threads/config.yaml)
-//! 5. Built-in defaults (lowest priority)
+//! 3. Selected profile (--profile / THREADS_PROFILE)
+//! 4. Project manifest (.threads-config/manifest.yaml)
+//! 5. User global (~/.config/threads/config.yaml)
+//! 6. Built-in defaults (lowest priority)
 //!
 //! This module provides:
 //! - `Config` struct with all settings
@@ -13,6 +14,7 @@
 //! - Helper functions for env var parsing
 //! - Config loading and merging
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -35,6 +37,14 @@ pub struct Config {
     pub display: DisplayConfig,
     /// Behavior settings
     pub behavior: BehaviorConfig,
+    /// Validation settings
+    pub validate: ValidateConfig,
+    /// Named overlays applied on top with --profile or THREADS_PROFILE
+    ///
+    /// Kept as raw YAML so a profile applies exactly the keys it sets.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(with = "BTreeMap<String, serde_json::Value>")]
+    pub profiles: BTreeMap<String, serde_yaml::Value>,
 }
 
 /// Status category definitions.
@@ -77,6 +87,8 @@ pub struct DefaultsConfig {
     pub closed: String,
     /// Default status when reopening threads (fallback after git history)
     pub open: String,
+    /// Tags given to new threads
+    pub tags: Vec<String>,
 }
 
 impl Default for DefaultsConfig {
@@ -85,6 +97,7 @@ impl Default for DefaultsConfig {
             new: "idea".to_string(),
             closed: "resolved".to_string(),
             open: "active".to_string(),
+            tags: Vec::new(),
         }
     }
 }
@@ -97,8 +110,17 @@ pub struct DisplayConfig {
     pub root_name: Option<String>,
     /// Status colors (null entries use defaults)
     pub status_colors: Option<StatusColors>,
+    /// Order and inclusion of sections in `threads read` (null = all, default order)
+    pub read_sections: Option<Vec<String>>,
+    /// Show YYYY-MM-DD dates instead of relative times ("3d") in `read` and `list`
+    pub absolute_dates: bool,
+    /// Count a todo with subtasks as done only once all its subtasks are
+    pub todo_rollup: bool,
 }
 
+/// Sections of `threads read` pretty output, in default order.
+pub const READ_SECTIONS: &[&str] = &["body", "notes", "todo", "deadlines", "events", "log"];
+
 /// Custom colors for statuses.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
@@ -114,6 +136,37 @@ pub struct StatusColors {
     pub rejected: Option<String>,
 }
 
+impl StatusColors {
+    /// Statuses that accept a custom color.
+    pub const STATUSES: [&'static str; 9] = [
+        "active",
+        "blocked",
+        "paused",
+        "idea",
+        "planning",
+        "resolved",
+        "superseded",
+        "deferred",
+        "rejected",
+    ];
+
+    /// Configured color for a status, if any.
+    pub fn get(&self, status: &str) -> Option<&str> {
+        match status {
+            "active" => self.active.as_deref(),
+            "blocked" => self.blocked.as_deref(),
+            "paused" => self.paused.as_deref(),
+            "idea" => self.idea.as_deref(),
+            "planning" => self.planning.as_deref(),
+            "resolved" => self.resolved.as_deref(),
+            "superseded" => self.superseded.as_deref(),
+            "deferred" => self.deferred.as_deref(),
+            "rejected" => self.rejected.as_deref(),
+            _ => None,
+        }
+    }
+}
+
 impl Default for StatusColors {
     fn default() -> Self {
         Self {
@@ -131,7 +184,7 @@ impl Default for StatusColors {
 }
 
 /// Behavior defaults.
-#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct BehaviorConfig {
     /// Automatically commit after mutations
@@ -142,6 +195,50 @@ pub struct BehaviorConfig {
     pub default_up: Option<DepthSetting>,
     /// Suppress hints
     pub quiet: bool,
+    /// Warn (W020) about open threads with no body, items, or log beyond creation
+    pub flag_empty_threads: bool,
+    /// Warn (W013) about threads without a `created` frontmatter timestamp
+    pub flag_missing_created: bool,
+    /// Hint to archive the log once a thread has more entries than this (null = never)
+    pub log_archive_after: Option<usize>,
+    /// Shell command for `--notify`, with `{text}`, `{date}`, `{id}` placeholders (null = stderr)
+    pub notify_command: Option<String>,
+    /// Allowed next statuses per status for `threads status` (null = any valid status).
+    /// Statuses without an entry are unrestricted; an empty list allows no change.
+    pub transitions: Option<BTreeMap<String, Vec<String>>>,
+}
+
+impl Default for BehaviorConfig {
+    fn default() -> Self {
+        Self {
+            auto_commit: false,
+            default_down: None,
+            default_up: None,
+            quiet: false,
+            flag_empty_threads: true,
+            flag_missing_created: false,
+            log_archive_after: None,
+            notify_command: None,
+            transitions: None,
+        }
+    }
+}
+
+/// Validation settings.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ValidateConfig {
+    /// Per-code severity overrides for `threads validate` (e.g. `W010: error`)
+    pub severity: BTreeMap<String, SeverityOverride>,
+}
+
+/// Severity to report an issue code with, or `off` to drop it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SeverityOverride {
+    Error,
+    Warning,
+    Off,
 }
 
 /// Depth setting for direction flags.
@@ -168,6 +265,8 @@ pub enum ConfigSource {
     UserGlobal,
     /// Project manifest (.threads-config/manifest.yaml)
     ProjectManifest(String),
+    /// Named profile from `profiles:` in a manifest
+    Profile(String),
     /// Environment variable
     EnvVar(String),
     /// CLI flag
@@ -180,6 +279,7 @@ impl std::fmt::Display for ConfigSource {
             ConfigSource::Default => write!(f, "default"),
             ConfigSource::UserGlobal => write!(f, "~/.config/threads/config.yaml"),
             ConfigSource::ProjectManifest(path) => write!(f, "{}", path),
+            ConfigSource::Profile(name) => write!(f, "profile '{}'", name),
             ConfigSource::EnvVar(name) => write!(f, "${}", name),
             ConfigSource::CliFlag => write!(f, "CLI flag"),
         }
@@ -225,7 +325,7 @@ pub const ENV_VARS: &[EnvVar] = &[
         description: "Default output format",
         default: "pretty (auto-detects TTY)",
         config_path: "display.format",
-        values: Some("pretty, plain, json, yaml"),
+        values: Some("pretty, plain, json, yaml, ndjson"),
     },
     EnvVar {
         name: "THREADS_INCLUDE_CLOSED",
@@ -269,6 +369,27 @@ pub const ENV_VARS: &[EnvVar] = &[
         config_path: "behavior.quiet",
         values: Some("1, true, yes"),
     },
+    EnvVar {
+        name: "THREADS_PAGER",
+        description: "Pager for long pretty output of read/list (overrides PAGER)",
+        default: "$PAGER, then less -R",
+        config_path: "display.pager",
+        values: Some("shell command"),
+    },
+    EnvVar {
+        name: "THREADS_NO_PAGER",
+        description: "Never page output (same as --no-pager)",
+        default: "unset",
+        config_path: "display.pager",
+        values: Some("any non-empty value"),
+    },
+    EnvVar {
+        name: "THREADS_PROFILE",
+        description: "Config profile to apply (same as --profile)",
+        default: "unset",
+        config_path: "profiles.<name>",
+        values: Some("a name under profiles:"),
+    },
     EnvVar {
         name: "THREADS_ROOT",
         description: "Override git root detection",
@@ -337,10 +458,16 @@ pub const CONFIG_DIR: &str = ".threads-config";
 /// 1. Built-in defaults
 /// 2. User global (~/.config/threads/config.yaml)
 /// 3. Project manifests (walk from git_root to cwd)
+/// 4. The named `profile`, if any, from the merged `profiles:` map
 ///
 /// Does not apply CLI flags (those are handled by args resolution).
 /// Does not apply ENV vars (those are checked at point of use).
-pub fn load_config(git_root: &Path, cwd: &Path) -> LoadedConfig {
+/// Fails only if `profile` names no defined profile.
+pub fn load_config(
+    git_root: &Path,
+    cwd: &Path,
+    profile: Option<&str>,
+) -> Result<LoadedConfig, String> {
     let mut config = Config::default();
     let mut sources = vec![ConfigSource::Default];
 
@@ -365,7 +492,26 @@ pub fn load_config(git_root: &Path, cwd: &Path) -> LoadedConfig {
         }
     }
 
-    LoadedConfig { config, sources }
+    // 3. Selected profile, layered over everything above
+    if let Some(name) = profile {
+        let Some(overlay) = config.profiles.get(name).cloned() else {
+            let available: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+            return Err(if available.is_empty() {
+                format!("unknown profile '{}': no profiles are defined", name)
+            } else {
+                format!(
+                    "unknown profile '{}' (available: {})",
+                    name,
+                    available.join(", ")
+                )
+            });
+        };
+        config = apply_profile(&config, &overlay)
+            .map_err(|e| format!("invalid profile '{}': {}", name, e))?;
+        sources.push(ConfigSource::Profile(name.to_string()));
+    }
+
+    Ok(LoadedConfig { config, sources })
 }
 
 /// Result of loading configuration with source tracking.
@@ -391,7 +537,7 @@ pub fn load_manifest(path: &Path) -> Option<Config> {
 /// Collect manifest paths from git_root to cwd (inclusive).
 ///
 /// Returns paths in order from root to cwd (so later ones override earlier).
-fn collect_manifest_paths(git_root: &Path, cwd: &Path) -> Vec<PathBuf> {
+pub fn collect_manifest_paths(git_root: &Path, cwd: &Path) -> Vec<PathBuf> {
     let mut paths = Vec::new();
 
     // Normalize paths
@@ -457,11 +603,23 @@ pub fn merge(base: &mut Config, overlay: &Config) {
     if overlay.defaults.open != default_defaults.open {
         base.defaults.open = overlay.defaults.open.clone();
     }
+    if !overlay.defaults.tags.is_empty() {
+        base.defaults.tags = overlay.defaults.tags.clone();
+    }
 
     // Display: merge Option fields
     if overlay.display.root_name.is_some() {
         base.display.root_name = overlay.display.root_name.clone();
     }
+    if overlay.display.read_sections.is_some() {
+        base.display.read_sections = overlay.display.read_sections.clone();
+    }
+    if overlay.display.absolute_dates {
+        base.display.absolute_dates = true;
+    }
+    if overlay.display.todo_rollup {
+        base.display.todo_rollup = true;
+    }
     if let Some(ref overlay_colors) = overlay.display.status_colors {
         let base_colors = base
             .display
@@ -484,6 +642,74 @@ pub fn merge(base: &mut Config, overlay: &Config) {
     if overlay.behavior.quiet != default_behavior.quiet {
         base.behavior.quiet = overlay.behavior.quiet;
     }
+    if overlay.behavior.flag_empty_threads != default_behavior.flag_empty_threads {
+        base.behavior.flag_empty_threads = overlay.behavior.flag_empty_threads;
+    }
+    if overlay.behavior.flag_missing_created != default_behavior.flag_missing_created {
+        base.behavior.flag_missing_created = overlay.behavior.flag_missing_created;
+    }
+    if overlay.behavior.log_archive_after.is_some() {
+        base.behavior.log_archive_after = overlay.behavior.log_archive_after;
+    }
+    if overlay.behavior.notify_command.is_some() {
+        base.behavior.notify_command = overlay.behavior.notify_command.clone();
+    }
+    if overlay.behavior.transitions.is_some() {
+        base.behavior.transitions = overlay.behavior.transitions.clone();
+    }
+
+    // Validate: overlay entries override per code
+    for (code, level) in &overlay.validate.severity {
+        base.validate.severity.insert(code.to_uppercase(), *level);
+    }
+
+    // Profiles: overlay definitions replace same-named ones
+    for (name, profile) in &overlay.profiles {
+        base.profiles.insert(name.clone(), profile.clone());
+    }
+}
+
+/// Layer a profile over `config`, applying every key the profile sets.
+///
+/// Unlike `merge`, this honours values equal to the built-in default, so a
+/// profile can turn `behavior.auto_commit` back off over a manifest's `true`.
+fn apply_profile(config: &Config, profile: &serde_yaml::Value) -> Result<Config, String> {
+    let mut doc = serde_yaml::to_value(config).map_err(|e| e.to_string())?;
+    let mut profile = profile.clone();
+    if let Some(severity) = profile
+        .get_mut("validate")
+        .and_then(|v| v.get_mut("severity"))
+        .and_then(serde_yaml::Value::as_mapping_mut)
+    {
+        *severity = std::mem::take(severity)
+            .into_iter()
+            .map(|(code, level)| match code.as_str() {
+                Some(c) => (serde_yaml::Value::from(c.to_uppercase()), level),
+                None => (code, level),
+            })
+            .collect();
+    }
+    overlay_yaml(&mut doc, &profile);
+    serde_yaml::from_value(doc).map_err(|e| e.to_string())
+}
+
+/// Recursively overlay `overlay` onto `base`: mappings merge per key, any
+/// other non-null value replaces what was there.
+fn overlay_yaml(base: &mut serde_yaml::Value, overlay: &serde_yaml::Value) {
+    match (base, overlay) {
+        (_, serde_yaml::Value::Null) => {}
+        (serde_yaml::Value::Mapping(base), serde_yaml::Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(key) {
+                    Some(slot) => overlay_yaml(slot, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (slot, value) => *slot = value.clone(),
+    }
 }
 
 /// Merge status colors (overlay wins for non-None values).
@@ -530,6 +756,13 @@ pub fn is_quiet(config: &Config) -> bool {
     config.behavior.quiet || env_bool("THREADS_QUIET").unwrap_or(false)
 }
 
+/// Check if mutations should commit the thread they touched.
+///
+/// THREADS_AUTO_COMMIT, when set, overrides config.behavior.auto_commit.
+pub fn auto_commit_enabled(config: &Config) -> bool {
+    env_bool("THREADS_AUTO_COMMIT").unwrap_or(config.behavior.auto_commit)
+}
+
 /// Get the display name for the repo root.
 ///
 /// Returns config.display.root_name if set, otherwise "repo root".
@@ -552,6 +785,7 @@ pub fn template_manifest() -> String {
 #   new: idea           # threads new
 #   closed: resolved    # threads close
 #   open: active        # threads reopen
+#   tags: []            # tags for threads new
 
 # Display settings
 # display:
@@ -566,6 +800,9 @@ pub fn template_manifest() -> String {
 #     superseded: dim
 #     deferred: dim
 #     rejected: dim
+#   read_sections: [body, notes, todo, deadlines, events, log]  # order/inclusion in 'threads read'
+#   absolute_dates: false  # YYYY-MM-DD instead of relative times in read/list
+#   todo_rollup: false  # todos with subtasks count as done once all subtasks are
 
 # Behavior settings
 # behavior:
@@ -573,10 +810,110 @@ pub fn template_manifest() -> String {
 #   default_down: null  # null = disabled, number = depth, "unlimited" = no limit
 #   default_up: null
 #   quiet: false
+#   flag_empty_threads: true  # validate W020 for open stub threads
+#   flag_missing_created: false  # validate W013 for threads without 'created'
+#   log_archive_after: null   # hint 'log --archive-before' past this many entries
+#   notify_command: null      # e.g. "notify-send {text} {date}" for deadline/event/agenda --notify
+#   transitions:              # allowed next statuses (unset = any valid status)
+#     idea: [planning, active]  # statuses left out may change freely
+#     resolved: []              # an empty list locks the status
+
+# Validation settings
+# validate:
+#   severity:          # per-code overrides: error, warning, or off
+#     W010: error
+#     W004: off
+
+# Named presets, applied with --profile <name> or THREADS_PROFILE
+# profiles:
+#   solo:
+#     behavior:
+#       auto_commit: true
+#       quiet: true
 "#
     .to_string()
 }
 
+/// Serialize a resolved config as a self-contained manifest.
+///
+/// Every field is written out, so the result reproduces the same effective
+/// config when used as the only manifest. The header lists the sources that
+/// contributed to the resolved values.
+pub fn dump_manifest(loaded: &LoadedConfig) -> Result<String, String> {
+    let yaml = serde_yaml::to_string(&loaded.config)
+        .map_err(|e| format!("failed to serialize config: {}", e))?;
+
+    let mut out = String::new();
+    out.push_str("# threads configuration manifest (generated by `threads config dump`)\n");
+    out.push_str("# Resolved from (in order of application):\n");
+    for source in &loaded.sources {
+        out.push_str(&format!("#   - {}\n", source));
+    }
+    out.push('\n');
+    out.push_str(&yaml);
+    Ok(out)
+}
+
+/// Look up a dotted path (e.g. `defaults.new`) in a YAML document.
+pub fn yaml_lookup<'a>(doc: &'a serde_yaml::Value, path: &str) -> Option<&'a serde_yaml::Value> {
+    path.split('.')
+        .try_fold(doc, |node, key| node.as_mapping()?.get(key))
+}
+
+/// Which layer supplies the resolved value at dotted `path`.
+///
+/// Follows `load_config`: a selected profile that sets the path wins, otherwise
+/// the last of user config and project manifests that sets it to a non-default
+/// value. Environment
+/// variables are not considered (they apply at point of use).
+pub fn value_source(
+    git_root: &Path,
+    cwd: &Path,
+    profile: Option<&str>,
+    path: &str,
+) -> ConfigSource {
+    let default = serde_yaml::to_value(Config::default()).unwrap_or_default();
+    let default_value = yaml_lookup(&default, path);
+    let sets = |doc: &serde_yaml::Value, key: &str| {
+        yaml_lookup(doc, key).is_some_and(|v| !v.is_null() && Some(v) != default_value)
+    };
+    let read = |p: &Path| {
+        fs::read_to_string(p)
+            .ok()
+            .and_then(|c| serde_yaml::from_str::<serde_yaml::Value>(&c).ok())
+    };
+
+    let mut layers: Vec<(ConfigSource, serde_yaml::Value)> = Vec::new();
+    if let Some(doc) = user_config_path().as_deref().and_then(read) {
+        layers.push((ConfigSource::UserGlobal, doc));
+    }
+    for manifest in collect_manifest_paths(git_root, cwd) {
+        if let Some(doc) = read(&manifest) {
+            let rel_path = manifest
+                .strip_prefix(git_root)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| manifest.to_string_lossy().to_string());
+            layers.push((ConfigSource::ProjectManifest(rel_path), doc));
+        }
+    }
+
+    if let Some(name) = profile {
+        let profile_key = format!("profiles.{}.{}", name, path);
+        if layers
+            .iter()
+            .any(|(_, doc)| yaml_lookup(doc, &profile_key).is_some_and(|v| !v.is_null()))
+        {
+            return ConfigSource::Profile(name.to_string());
+        }
+    }
+    layers
+        .into_iter()
+        .rev()
+        .find(|(_, doc)| sets(doc, path))
+        .map(|(source, _)| source)
+        .unwrap_or(ConfigSource::Default)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -744,6 +1081,21 @@ mod tests {
         assert_eq!(base.defaults.closed, "resolved");
     }
 
+    #[test]
+    fn test_merge_default_tags() {
+        let mut base = Config::default();
+        base.defaults.tags = vec!["team".to_string()];
+
+        // An overlay without tags keeps the inherited ones
+        merge(&mut base, &Config::default());
+        assert_eq!(base.defaults.tags, vec!["team"]);
+
+        let mut overlay = Config::default();
+        overlay.defaults.tags = vec!["infra".to_string()];
+        merge(&mut base, &overlay);
+        assert_eq!(base.defaults.tags, vec!["infra"]);
+    }
+
     #[test]
     fn test_merge_status_lists() {
         let mut base = Config::default();
@@ -757,6 +1109,63 @@ mod tests {
         assert!(base.status.closed.contains(&"resolved".to_string()));
     }
 
+    #[test]
+    fn test_profile_overlays_base() {
+        let dir = std::env::temp_dir().join(format!("threads-profile-{}", std::process::id()));
+        fs::create_dir_all(dir.join(CONFIG_DIR)).unwrap();
+        fs::write(
+            dir.join(CONFIG_DIR).join(MANIFEST_FILE),
+            "defaults:\n  new: planning\nprofiles:\n  solo:\n    behavior:\n      quiet: true\n    defaults:\n      new: active\n",
+        )
+        .unwrap();
+
+        let base = load_config(&dir, &dir, None).unwrap();
+        assert_eq!(base.config.defaults.new, "planning");
+        assert!(!base.config.behavior.quiet);
+
+        let solo = load_config(&dir, &dir, Some("solo")).unwrap();
+        assert_eq!(solo.config.defaults.new, "active");
+        assert!(solo.config.behavior.quiet);
+        assert_eq!(
+            solo.sources.last(),
+            Some(&ConfigSource::Profile("solo".to_string()))
+        );
+
+        let err = load_config(&dir, &dir, Some("team")).unwrap_err();
+        assert_eq!(err, "unknown profile 'team' (available: solo)");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_profile_can_restore_defaults() {
+        let dir = std::env::temp_dir().join(format!("threads-profile-off-{}", std::process::id()));
+        fs::create_dir_all(dir.join(CONFIG_DIR)).unwrap();
+        fs::write(
+            dir.join(CONFIG_DIR).join(MANIFEST_FILE),
+            "behavior:\n  auto_commit: true\n  quiet: true\nvalidate:\n  severity:\n    W010: error\nprofiles:\n  manual:\n    behavior:\n      auto_commit: false\n    validate:\n      severity:\n        w010: off\n",
+        )
+        .unwrap();
+
+        let base = load_config(&dir, &dir, None).unwrap();
+        assert!(base.config.behavior.auto_commit);
+
+        let manual = load_config(&dir, &dir, Some("manual")).unwrap();
+        assert!(!manual.config.behavior.auto_commit);
+        // Keys the profile leaves alone keep the manifest's value
+        assert!(manual.config.behavior.quiet);
+        assert_eq!(
+            manual.config.validate.severity.get("W010"),
+            Some(&SeverityOverride::Off)
+        );
+        assert_eq!(
+            value_source(&dir, &dir, Some("manual"), "behavior.auto_commit"),
+            ConfigSource::Profile("manual".to_string())
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
     #[test]
     fn test_template_manifest() {
         let template = template_manifest();
@@ -764,4 +1173,45 @@ mod tests {
         assert!(template.contains("status:"));
         assert!(template.contains("defaults:"));
     }
+
+    #[test]
+    fn test_dump_manifest_roundtrip() {
+        let mut config = Config::default();
+        config.defaults.new = "planning".to_string();
+        config.status.closed = vec!["done".to_string(), "dropped".to_string()];
+        config.display.root_name = Some("home".to_string());
+        config.behavior.quiet = true;
+        let loaded = LoadedConfig {
+            config,
+            sources: vec![ConfigSource::Default],
+        };
+
+        let dumped = dump_manifest(&loaded).unwrap();
+        assert!(dumped.starts_with("# threads configuration manifest"));
+        assert!(dumped.contains("#   - default"));
+
+        let parsed: Config = serde_yaml::from_str(&dumped).unwrap();
+        let mut reloaded = Config::default();
+        merge(&mut reloaded, &parsed);
+        assert_eq!(
+            serde_yaml::to_string(&reloaded).unwrap(),
+            serde_yaml::to_string(&loaded.config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_yaml_lookup_dotted_path() {
+        let doc: serde_yaml::Value =
+            serde_yaml::from_str("behavior:\n  quiet: true\ndefaults:\n  new: idea\n").unwrap();
+        assert_eq!(
+            yaml_lookup(&doc, "behavior.quiet"),
+            Some(&serde_yaml::Value::Bool(true))
+        );
+        assert_eq!(
+            yaml_lookup(&doc, "defaults.new").and_then(|v| v.as_str()),
+            Some("idea")
+        );
+        assert!(yaml_lookup(&doc, "defaults.closed").is_none());
+        assert!(yaml_lookup(&doc, "behavior.quiet.deeper").is_none());
+    }
 }
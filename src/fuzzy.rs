@@ -147,6 +147,27 @@ pub fn score_tokens(tokens: &[String], haystack: &str) -> Option<i64> {
     Some(total)
 }
 
+/// Similarity of two titles in `0.0..=1.0`, for spotting near-duplicates.
+///
+/// The shorter string is matched against the longer one, and the score is
+/// scaled by the longer string's self-match, so a short title buried in a long
+/// one stays well below 1.0.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (a.trim(), b.trim());
+    let (short, long) = if a.chars().count() <= b.chars().count() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let (Some(found), Some(perfect)) = (score(short, long), score(long, long)) else {
+        return 0.0;
+    };
+    if perfect <= 0 {
+        return 0.0;
+    }
+    (found as f64 / perfect as f64).clamp(0.0, 1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,4 +211,11 @@ mod tests {
         assert!(score("aldo", "Carola Aldo Olivia").is_some());
         assert!(score("olivia", "Carola Aldo Olivia").is_some());
     }
+
+    #[test]
+    fn similarity_flags_near_duplicates_only() {
+        assert!(similarity("Fix login bug", "fix login bugs") > 0.8);
+        assert!(similarity("API", "API redesign for the billing service") < 0.5);
+        assert_eq!(similarity("Fix login bug", "Write docs"), 0.0);
+    }
 }
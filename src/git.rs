@@ -1,7 +1,7 @@
 use std::path::Path;
 use std::process::Command;
 
-use git2::{Repository, Status, StatusOptions};
+use git2::{Repository, RepositoryState, Status, StatusOptions};
 
 /// Check if a file has uncommitted changes (staged, unstaged, or untracked)
 pub fn has_changes(repo: &Repository, rel_path: &Path) -> bool {
@@ -49,33 +49,33 @@ pub fn exists_in_head(repo: &Repository, rel_path: &Path) -> bool {
     tree.get_path(rel_path).is_ok()
 }
 
-/// Stage files (skips non-existent for deletions, handles both add and remove)
-pub fn add(repo: &Repository, files: &[&Path]) -> Result<(), String> {
-    let mut index = repo
-        .index()
-        .map_err(|e| format!("Failed to get index: {}", e.message()))?;
-
-    let workdir = repo
-        .workdir()
-        .ok_or("Repository has no working directory")?;
-
-    for file in files {
-        let full_path = workdir.join(file);
-        if full_path.exists() {
-            index
-                .add_path(file)
-                .map_err(|e| format!("Failed to stage {}: {}", file.display(), e.message()))?;
-        } else {
-            // File doesn't exist - might be a deletion, try to remove from index
-            let _ = index.remove_path(file);
+/// Thread files as committed at `rev`, as `(git-root-relative path, content)` pairs.
+///
+/// Only markdown files directly inside a `.threads/` directory are returned, so
+/// archived threads are skipped like in the working-tree search.
+pub fn thread_files_at(repo: &Repository, rev: &str) -> Result<Vec<(String, String)>, String> {
+    let tree = repo
+        .revparse_single(rev)
+        .and_then(|obj| obj.peel_to_tree())
+        .map_err(|e| format!("cannot resolve '{}': {}", rev, e.message()))?;
+
+    let mut files = Vec::new();
+    tree.walk(git2::TreeWalkMode::PreOrder, |root, entry| {
+        let name = entry.name().unwrap_or_default();
+        let in_threads_dir = root == ".threads/" || root.ends_with("/.threads/");
+        if in_threads_dir
+            && name.ends_with(".md")
+            && entry.kind() == Some(git2::ObjectType::Blob)
+            && let Ok(blob) = repo.find_blob(entry.id())
+        {
+            let content = String::from_utf8_lossy(blob.content()).to_string();
+            files.push((format!("{}{}", root, name), content));
         }
-    }
-
-    index
-        .write()
-        .map_err(|e| format!("Failed to write index: {}", e.message()))?;
+        git2::TreeWalkResult::Ok
+    })
+    .map_err(|e| format!("reading tree at '{}': {}", rev, e.message()))?;
 
-    Ok(())
+    Ok(files)
 }
 
 /// Create a commit containing only the specified files.
@@ -188,15 +188,53 @@ pub fn commit(repo: &Repository, files: &[&Path], message: &str) -> Result<(), S
     Ok(())
 }
 
-/// Auto-commit: stage and commit (push is opt-in via separate command)
-pub fn auto_commit(repo: &Repository, file: &Path, message: &str) -> Result<(), String> {
+/// Commit one thread file after a mutation (see `commit_threads`).
+pub fn commit_thread(repo: &Repository, file: &Path, message: &str) -> Result<bool, String> {
+    commit_threads(repo, &[file], message)
+}
+
+/// Commit thread files after a mutation (push is opt-in via separate command).
+///
+/// Paths may be absolute or relative to the repo root. Returns false without
+/// committing when HEAD is detached or a merge, rebase or similar is in
+/// progress; the mutation has already been written, so that is a warning,
+/// not an error.
+pub fn commit_threads(repo: &Repository, files: &[&Path], message: &str) -> Result<bool, String> {
     let workdir = repo
         .workdir()
         .ok_or("Repository has no working directory")?;
+    let rel_paths: Vec<&Path> = files
+        .iter()
+        .map(|f| f.strip_prefix(workdir).unwrap_or(f))
+        .collect();
+
+    if let Some(reason) = commit_blocker(repo) {
+        let names: Vec<String> = rel_paths.iter().map(|p| p.display().to_string()).collect();
+        eprintln!("Warning: {}; left {} uncommitted", reason, names.join(", "));
+        return Ok(false);
+    }
 
-    let rel_path = file.strip_prefix(workdir).unwrap_or(file);
+    commit(repo, &rel_paths, message)?;
+    Ok(true)
+}
 
-    commit(repo, &[rel_path], message)
+/// Why committing on top of HEAD would be wrong right now, if it would.
+fn commit_blocker(repo: &Repository) -> Option<&'static str> {
+    if repo.head_detached().unwrap_or(false) {
+        return Some("HEAD is detached");
+    }
+    match repo.state() {
+        RepositoryState::Clean => None,
+        RepositoryState::Merge => Some("a merge is in progress"),
+        RepositoryState::Revert | RepositoryState::RevertSequence => {
+            Some("a revert is in progress")
+        }
+        RepositoryState::CherryPick | RepositoryState::CherryPickSequence => {
+            Some("a cherry-pick is in progress")
+        }
+        RepositoryState::Bisect => Some("a bisect is in progress"),
+        _ => Some("a rebase is in progress"),
+    }
 }
 
 /// Generate a commit message for thread changes
@@ -308,6 +346,30 @@ pub fn find_deleted_thread_files(repo: &Repository) -> Vec<std::path::PathBuf> {
     deleted
 }
 
+/// Paths staged in the index that are not thread files (`.threads/*.md`).
+pub fn staged_non_thread_files(repo: &Repository) -> Vec<String> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(false);
+
+    let statuses = match repo.statuses(Some(&mut opts)) {
+        Ok(s) => s,
+        Err(_) => return Vec::new(),
+    };
+
+    let staged = Status::INDEX_NEW
+        | Status::INDEX_MODIFIED
+        | Status::INDEX_DELETED
+        | Status::INDEX_RENAMED
+        | Status::INDEX_TYPECHANGE;
+
+    statuses
+        .iter()
+        .filter(|entry| entry.status().intersects(staged))
+        .filter_map(|entry| entry.path().map(str::to_string))
+        .filter(|path| !(path.contains(".threads/") && path.ends_with(".md")))
+        .collect()
+}
+
 /// Get the status of a specific file
 pub fn file_status(repo: &Repository, rel_path: &Path) -> FileStatus {
     let mut opts = StatusOptions::new();
@@ -418,20 +480,84 @@ pub fn diff_stats(repo: &Repository, rel_path: &Path) -> Option<(usize, usize)>
     }
 }
 
-/// Find the previous status of a thread file from git history.
+/// Move a file from one absolute path to another. Tracked, unmodified files
+/// go through `git mv`, staging the rename so history follows them.
+pub fn move_file(repo: &Repository, ws: &Path, from: &Path, to: &Path) -> Result<(), String> {
+    let rel_from = from.strip_prefix(ws).unwrap_or(from);
+    let rel_to = to.strip_prefix(ws).unwrap_or(to);
+    if is_tracked(repo, rel_from) && !has_changes(repo, rel_from) {
+        git_mv(ws, rel_from, rel_to)
+    } else {
+        std::fs::rename(from, to).map_err(|e| format!("moving file: {}", e))
+    }
+}
+
+fn git_mv(ws: &Path, from: &Path, to: &Path) -> Result<(), String> {
+    let output = Command::new("git")
+        .args([
+            "-C",
+            &ws.to_string_lossy(),
+            "mv",
+            &from.to_string_lossy(),
+            &to.to_string_lossy(),
+        ])
+        .output()
+        .map_err(|e| format!("running git mv: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "git mv failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+/// Unix time of the first commit that added a file, following renames.
+/// Returns None for files that were never committed.
+pub fn first_commit_time(ws: &Path, file: &Path) -> Option<i64> {
+    let rel_path = file.strip_prefix(ws).unwrap_or(file);
+
+    let output = Command::new("git")
+        .args([
+            "-C",
+            &ws.to_string_lossy(),
+            "log",
+            "--follow",
+            "--format=%ct",
+            "--",
+            &rel_path.to_string_lossy(),
+        ])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    // Newest first: the last line is the commit that added the file
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .last()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Find the most recent open status a thread file had in git history.
 ///
-/// Looks at the git log to find the most recent change to the status field
-/// and returns the status before it became the current (closed) status.
+/// Walks the commits that touched the status field, newest first, and returns
+/// the first status a commit set that is not closed. Reading what each commit
+/// set (rather than what it replaced) also covers a close that is not yet
+/// committed: the last committed status is then the one to restore.
 ///
 /// Returns None if:
-/// - File has no git history
-/// - No status changes found in history
-/// - Previous status was also a closed status
+/// - File has no git history (or the workspace is not a git repo)
+/// - Every status in history is closed
 pub fn previous_status(ws: &Path, file: &Path, closed_statuses: &[String]) -> Option<String> {
     let rel_path = file.strip_prefix(ws).unwrap_or(file);
 
     // Use git log -p to get patches showing status field changes
-    // We look for lines like "-status: active" followed by "+status: resolved"
     // Note: Using -G (regex grep) instead of -S (pickaxe) because -S finds commits
     // that change the COUNT of occurrences, not the VALUE. Since "status:" always
     // appears exactly once, -S won't find status value changes.
@@ -456,31 +582,15 @@ pub fn previous_status(ws: &Path, file: &Path, closed_statuses: &[String]) -> Op
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    // Parse the diff output to find status changes
-    // We want the first (most recent) change where:
-    // - The old status (line starting with "-status:") is NOT in closed_statuses
-    let mut found_old_status: Option<String> = None;
-
-    for line in stdout.lines() {
-        let line = line.trim();
-
-        // Look for removed status lines (previous status)
-        if let Some(rest) = line.strip_prefix("-status:") {
-            let status = rest.trim().to_string();
+    // Added status lines, newest commit first; the creating commit adds one too
+    stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("+status:"))
+        .map(|rest| {
             // Extract base status (without parenthetical reason)
-            let base_status = if let Some(idx) = status.find(" (") {
-                status[..idx].to_string()
-            } else {
-                status.clone()
-            };
-
-            // Check if this was an open status
-            if !closed_statuses.iter().any(|s| s == &base_status) {
-                found_old_status = Some(base_status);
-                break; // Found the most recent open status
-            }
-        }
-    }
-
-    found_old_status
+            let status = rest.trim();
+            status.split_once(" (").map_or(status, |(base, _)| base)
+        })
+        .find(|base| !closed_statuses.iter().any(|s| s == base))
+        .map(str::to_string)
 }
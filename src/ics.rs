@@ -0,0 +1,114 @@
+//! iCalendar (RFC 5545) output for `threads export ics`.
+//!
+//! Builds a VCALENDAR line by line. Callers escape TEXT values with
+//! `escape_text`; `finish` folds every line at 75 octets and ends it with CRLF.
+
+/// A VCALENDAR under construction.
+pub struct Calendar {
+    lines: Vec<String>,
+}
+
+impl Calendar {
+    pub fn new() -> Self {
+        Calendar {
+            lines: vec![
+                "BEGIN:VCALENDAR".to_string(),
+                "VERSION:2.0".to_string(),
+                "PRODID:-//threads//threads CLI//EN".to_string(),
+                "CALSCALE:GREGORIAN".to_string(),
+            ],
+        }
+    }
+
+    /// Append one unfolded content line, e.g. "SUMMARY:Release".
+    pub fn line(&mut self, line: impl Into<String>) {
+        self.lines.push(line.into());
+    }
+
+    /// Close the calendar and render it with folded lines and CRLF endings.
+    pub fn finish(mut self) -> String {
+        self.lines.push("END:VCALENDAR".to_string());
+        self.lines
+            .iter()
+            .map(|l| fold_line(l))
+            .map(|l| l + "\r\n")
+            .collect()
+    }
+}
+
+/// Escape a TEXT value: backslash, semicolon, comma and newlines.
+pub fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold a content line so no physical line exceeds 75 octets. Continuation
+/// lines start with a space (which counts toward their 75), and multi-byte
+/// UTF-8 characters are never split.
+pub fn fold_line(line: &str) -> String {
+    const LIMIT: usize = 75;
+    let mut out = String::with_capacity(line.len() + line.len() / LIMIT * 3);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_line_at_75_octets() {
+        let short = "SUMMARY:short";
+        assert_eq!(fold_line(short), short);
+
+        let long = format!("SUMMARY:{}", "x".repeat(100));
+        let folded = fold_line(&long);
+        let lines: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), 75);
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+
+        // Multi-byte characters stay whole
+        let wide = format!("SUMMARY:{}", "é".repeat(60));
+        for l in fold_line(&wide).split("\r\n") {
+            assert!(l.len() <= 75);
+        }
+        assert_eq!(fold_line(&wide).replace("\r\n ", ""), wide);
+    }
+
+    #[test]
+    fn test_escape_text() {
+        assert_eq!(escape_text("a, b; c\\d\nnext"), "a\\, b\\; c\\\\d\\nnext");
+    }
+
+    #[test]
+    fn test_calendar_crlf() {
+        let mut cal = Calendar::new();
+        cal.line("BEGIN:VEVENT");
+        cal.line("END:VEVENT");
+        let out = cal.finish();
+        assert!(out.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(out.ends_with("END:VCALENDAR\r\n"));
+        assert!(!out.replace("\r\n", "").contains('\n'));
+    }
+}
@@ -1,6 +1,6 @@
-//! Stdin input utilities.
+//! Stdin, clipboard, and interactive prompt input utilities.
 
-use std::io::{self, IsTerminal, Read};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
 
 /// Check if stdin is connected to a terminal (TTY).
 ///
@@ -26,3 +26,163 @@ pub fn read_stdin(trim: bool) -> String {
     }
     String::new()
 }
+
+/// Print `prompt` to stderr and read one trimmed line from stdin.
+///
+/// End of input (Ctrl-D) is an error, so callers abort rather than carry on
+/// with empty answers.
+pub fn prompt_line(prompt: &str) -> Result<String, String> {
+    eprint!("{}", prompt);
+    io::stderr().flush().ok();
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        Ok(0) => {
+            eprintln!();
+            Err("aborted: no input".to_string())
+        }
+        Ok(_) => Ok(line.trim().to_string()),
+        Err(e) => Err(format!("cannot read input: {}", e)),
+    }
+}
+
+/// Read text from the system clipboard.
+///
+/// Requires the `clipboard` cargo feature. Fails when no clipboard is available
+/// (e.g. headless sessions without a display server).
+#[cfg(feature = "clipboard")]
+pub fn read_clipboard() -> Result<String, String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("clipboard unavailable: {}", e))?;
+    clipboard
+        .get_text()
+        .map_err(|e| format!("cannot read clipboard: {}", e))
+}
+
+/// Read text from the system clipboard (unavailable: built without the `clipboard` feature).
+#[cfg(not(feature = "clipboard"))]
+pub fn read_clipboard() -> Result<String, String> {
+    Err("clipboard support not compiled in (rebuild with --features clipboard)".to_string())
+}
+
+/// Replace the system clipboard contents with `text`.
+#[cfg(all(feature = "clipboard", not(target_os = "linux")))]
+pub fn write_clipboard(text: &str) -> Result<(), String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("clipboard unavailable: {}", e))?;
+    clipboard
+        .set_text(text)
+        .map_err(|e| format!("cannot write clipboard: {}", e))
+}
+
+/// Environment variable that turns a `threads` process into a clipboard holder.
+#[cfg(all(feature = "clipboard", target_os = "linux"))]
+pub const CLIPBOARD_HOLDER_ENV: &str = "THREADS_CLIPBOARD_HOLDER";
+
+/// Replace the system clipboard contents with `text`.
+///
+/// On Linux the clipboard is served by the process that owns it, so contents
+/// set here would vanish when `threads` exits. Instead a detached copy of the
+/// binary (see `hold_clipboard`) takes ownership and keeps serving `text` until
+/// another application replaces it.
+#[cfg(all(feature = "clipboard", target_os = "linux"))]
+pub fn write_clipboard(text: &str) -> Result<(), String> {
+    use std::os::unix::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    // Fail here, not silently in the holder, when there is no display server
+    arboard::Clipboard::new().map_err(|e| format!("clipboard unavailable: {}", e))?;
+
+    let exe = std::env::current_exe().map_err(|e| format!("cannot write clipboard: {}", e))?;
+    let mut child = Command::new(exe)
+        .env(CLIPBOARD_HOLDER_ENV, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .process_group(0)
+        .spawn()
+        .map_err(|e| format!("cannot write clipboard: {}", e))?;
+    child
+        .stdin
+        .take()
+        .ok_or("cannot write clipboard: no pipe to holder process")?
+        .write_all(text.as_bytes())
+        .map_err(|e| format!("cannot write clipboard: {}", e))
+}
+
+/// Serve stdin as the clipboard contents until another application takes over.
+///
+/// Runs in the detached process spawned by `write_clipboard`; returns the exit code.
+#[cfg(all(feature = "clipboard", target_os = "linux"))]
+pub fn hold_clipboard() -> i32 {
+    use arboard::SetExtLinux;
+
+    let text = read_stdin(false);
+    match arboard::Clipboard::new() {
+        Ok(mut clipboard) => match clipboard.set().wait().text(text) {
+            Ok(()) => 0,
+            Err(_) => 1,
+        },
+        Err(_) => 1,
+    }
+}
+
+/// Replace the system clipboard contents (unavailable: built without the `clipboard` feature).
+#[cfg(not(feature = "clipboard"))]
+pub fn write_clipboard(_text: &str) -> Result<(), String> {
+    Err("clipboard support not compiled in (rebuild with --features clipboard)".to_string())
+}
+
+/// Prompt the user to pick one of `items`, returning its index.
+///
+/// Requires the `interactive` cargo feature and a terminal.
+#[cfg(feature = "interactive")]
+pub fn select(prompt: &str, items: &[String]) -> Result<usize, String> {
+    dialoguer::Select::new()
+        .with_prompt(prompt)
+        .items(items)
+        .default(0)
+        .interact()
+        .map_err(|e| format!("prompt failed: {}", e))
+}
+
+/// Prompt the user to pick one of `items` (unavailable: built without the `interactive` feature).
+#[cfg(not(feature = "interactive"))]
+pub fn select(_prompt: &str, _items: &[String]) -> Result<usize, String> {
+    Err("interactive prompts not compiled in (rebuild with --features interactive)".to_string())
+}
+
+/// Open `initial` in the user's editor and return the saved text.
+///
+/// Uses `$VISUAL`, then `$EDITOR`, then `vi`. The editor command runs through
+/// `sh` so values with arguments (e.g. `code --wait`) work. The temp file is
+/// removed afterwards whether or not the editor succeeded.
+pub fn edit_in_editor(initial: &str) -> Result<String, String> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string());
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let path = std::env::temp_dir().join(format!("threads-{}-{}.md", std::process::id(), nanos));
+    std::fs::write(&path, initial).map_err(|e| format!("cannot write temp file: {}", e))?;
+
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$1\"", editor))
+        .arg("sh")
+        .arg(&path)
+        .status();
+    let result = match status {
+        Ok(s) if s.success() => {
+            std::fs::read_to_string(&path).map_err(|e| format!("cannot read temp file: {}", e))
+        }
+        Ok(s) => Err(format!("editor '{}' exited with {}", editor, s)),
+        Err(e) => Err(format!("cannot run editor '{}': {}", editor, e)),
+    };
+    let _ = std::fs::remove_file(&path);
+    result
+}
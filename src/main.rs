@@ -1,4 +1,5 @@
 use std::io;
+use std::path::PathBuf;
 use std::process;
 
 use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
@@ -11,9 +12,14 @@ mod cmd;
 mod config;
 mod fuzzy;
 mod git;
+mod ics;
 mod input;
+mod notify;
 mod output;
+mod template;
 mod thread;
+mod timeutil;
+mod validate;
 mod workspace;
 mod wrap;
 
@@ -27,11 +33,26 @@ mod wrap;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Operate on the git repository containing PATH instead of the current one
+    #[arg(long, global = true, value_name = "PATH")]
+    repo: Option<PathBuf>,
+
+    /// Apply a named config profile from the manifest's `profiles:` (or THREADS_PROFILE)
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
+    #[command(flatten)]
+    color: args::ColorArgs,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// List threads
+    ///
+    /// By default only threads at the current directory level are shown.
+    /// Use --flat to list every thread in the repo regardless of PWD, or
+    /// --down/--up to search relative to the current level.
     #[command(alias = "ls")]
     List(cmd::list::ListArgs),
 
@@ -41,10 +62,16 @@ enum Commands {
     /// Create a new thread
     New(cmd::new::NewArgs),
 
+    /// Copy a thread as a new one with a fresh ID and log
+    Duplicate(cmd::duplicate::DuplicateArgs),
+
     /// Move thread to new location
     #[command(alias = "mv")]
     Move(cmd::move_cmd::MoveArgs),
 
+    /// Move closed threads into .threads/archive/ (or list/restore them)
+    Archive(cmd::archive::ArchiveArgs),
+
     /// Validate thread files
     Validate(cmd::validate::ValidateArgs),
 
@@ -70,7 +97,10 @@ enum Commands {
     /// Change thread status
     Status(cmd::status::StatusArgs),
 
-    /// Update thread title/desc
+    /// Change a thread's title and rename its file to match
+    Rename(cmd::rename::RenameArgs),
+
+    /// Update thread title/desc/priority/parent
     Update(cmd::update::UpdateArgs),
 
     /// Read or edit Body section
@@ -82,15 +112,33 @@ enum Commands {
     /// Manage todo items
     Todo(cmd::todo::TodoArgs),
 
+    /// Manage thread tags
+    Tag(cmd::tag::TagArgs),
+
+    /// Manage links to other threads (blocked-by, relates-to)
+    Link(cmd::link::LinkArgs),
+
+    /// Show threads as a parent/child tree
+    Tree(cmd::tree::TreeArgs),
+
     /// Add log entry
     Log(cmd::log::LogArgs),
 
+    /// Apply note/todo/log operations read as JSON lines from stdin
+    Apply(cmd::apply::ApplyArgs),
+
     /// Manage deadlines
     Deadline(cmd::deadline::DeadlineArgs),
 
     /// Manage events
     Event(cmd::event::EventArgs),
 
+    /// Show deadlines and events from every thread, grouped by day
+    Agenda(cmd::agenda::AgendaArgs),
+
+    /// Export deadlines and events (ics)
+    Export(cmd::export::ExportArgs),
+
     /// Migrate threads from section-based to frontmatter-based format
     Migrate(cmd::migrate::MigrateArgs),
 
@@ -128,6 +176,12 @@ enum CompletionShell {
 }
 
 fn main() {
+    // Detached clipboard holder spawned by `read --copy` (see input::write_clipboard)
+    #[cfg(all(feature = "clipboard", target_os = "linux"))]
+    if std::env::var_os(input::CLIPBOARD_HOLDER_ENV).is_some() {
+        process::exit(input::hold_clipboard());
+    }
+
     // Handle dynamic shell completions
     CompleteEnv::with_factory(Cli::command).complete();
 
@@ -148,6 +202,7 @@ fn main() {
             process::exit(exit_code);
         }
     };
+    cli.color.apply();
 
     // Handle completion before workspace lookup (doesn't need workspace)
     if let Commands::Completion(args) = &cli.command {
@@ -161,8 +216,12 @@ fn main() {
         return;
     }
 
-    // Find workspace
-    let ws = match workspace::find() {
+    // Find workspace (--repo switches into the target repo so scope and git ops follow it)
+    let ws = match cli
+        .repo
+        .as_deref()
+        .map_or_else(workspace::find, workspace::enter_repo)
+    {
         Ok(ws) => ws,
         Err(e) => {
             eprintln!("workspace not found: {}", e);
@@ -172,33 +231,52 @@ fn main() {
 
     // Load config
     let cwd = std::env::current_dir().unwrap_or_else(|_| ws.clone());
-    let loaded_config = config::load_config(&ws, &cwd);
+    let profile = cli
+        .profile
+        .clone()
+        .or_else(|| config::env_string("THREADS_PROFILE"));
+    let loaded_config = match config::load_config(&ws, &cwd, profile.as_deref()) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
     let result = match cli.command {
         Commands::List(args) => cmd::list::run(args, &ws, &loaded_config.config),
         Commands::Search(args) => cmd::search::run(args, &ws, &loaded_config.config),
-        Commands::New(args) => cmd::new::run(args, &ws, &loaded_config.config),
+        Commands::New(args) => cmd::new::run(args, &ws, &loaded_config.config, profile.as_deref()),
+        Commands::Duplicate(args) => cmd::duplicate::run(args, &ws, &loaded_config.config),
         Commands::Move(args) => cmd::move_cmd::run(args, &ws, &loaded_config.config),
+        Commands::Archive(args) => cmd::archive::run(args, &ws, &loaded_config.config),
         Commands::Validate(args) => cmd::validate::run(args, &ws, &loaded_config.config),
         Commands::Cache(args) => cmd::cache::run(args, &ws),
         Commands::Git(args) => cmd::git_cmd::run(args, &ws),
         Commands::Stats(args) => cmd::stats::run(args, &ws, &loaded_config.config),
-        Commands::Read(args) => cmd::read::run(args, &ws),
-        Commands::Info(args) => cmd::info::run(args, &ws),
+        Commands::Read(args) => cmd::read::run(args, &ws, &loaded_config.config),
+        Commands::Info(args) => cmd::info::run(args, &ws, &loaded_config.config),
         Commands::Path(args) => cmd::path::run(args, &ws),
         Commands::Status(args) => cmd::status::run(args, &ws, &loaded_config.config),
+        Commands::Rename(args) => cmd::rename::run(args, &ws, &loaded_config.config),
         Commands::Update(args) => cmd::update::run(args, &ws, &loaded_config.config),
         Commands::Body(args) => cmd::body::run(args, &ws, &loaded_config.config),
         Commands::Note(args) => cmd::note::run(args, &ws, &loaded_config.config),
         Commands::Todo(args) => cmd::todo::run(args, &ws, &loaded_config.config),
+        Commands::Tag(args) => cmd::tag::run(args, &ws, &loaded_config.config),
+        Commands::Link(args) => cmd::link::run(args, &ws, &loaded_config.config),
+        Commands::Tree(args) => cmd::tree::run(args, &ws, &loaded_config.config),
         Commands::Log(args) => cmd::log::run(args, &ws, &loaded_config.config),
+        Commands::Apply(args) => cmd::apply::run(args, &ws, &loaded_config.config),
         Commands::Deadline(args) => cmd::deadline::run(args, &ws, &loaded_config.config),
+        Commands::Agenda(args) => cmd::agenda::run(args, &ws, &loaded_config.config),
+        Commands::Export(args) => cmd::export::run(args, &ws, &loaded_config.config),
         Commands::Event(args) => cmd::event::run(args, &ws, &loaded_config.config),
         Commands::Migrate(args) => cmd::migrate::run(args, &ws),
         Commands::Close(args) => cmd::resolve::run(args, &ws, &loaded_config.config),
         Commands::Reopen(args) => cmd::reopen::run(args, &ws, &loaded_config.config),
         Commands::Remove(args) => cmd::remove::run(args, &ws, &loaded_config.config),
-        Commands::Config(args) => cmd::config_cmd::run(args, &ws),
+        Commands::Config(args) => cmd::config_cmd::run(args, &ws, profile.as_deref()),
         Commands::Completion(_) => unreachable!(), // Handled above
     };
 
@@ -0,0 +1,126 @@
+//! Reminders for deadlines and events due today (`--notify` on their agenda views
+//! and on `threads agenda`).
+
+use std::fs;
+use std::process::Command;
+
+use crate::config::Config;
+use crate::workspace;
+
+/// Marker file in the git directory recording which items were already
+/// notified, one `hash date` per line. Kept out of the worktree so it is never
+/// picked up by `git add` or shared between clones.
+const NOTIFIED_FILE: &str = "threads-notified";
+
+/// An item due today.
+pub struct DueItem<'a> {
+    pub hash: &'a str,
+    pub date: &'a str,
+    pub text: &'a str,
+    pub thread_id: &'a str,
+}
+
+/// Outcome of a `--notify` run.
+pub struct NotifySummary {
+    pub sent: usize,
+    pub skipped: usize,
+}
+
+impl NotifySummary {
+    /// One-line report, e.g. "Notified 2 deadlines due today (1 already notified)".
+    pub fn report(&self, what: &str) -> String {
+        let mut line = format!("Notified {} {} due today", self.sent, what);
+        if self.skipped > 0 {
+            line.push_str(&format!(" ({} already notified)", self.skipped));
+        }
+        line
+    }
+}
+
+/// Notify each item once per date, via `behavior.notify_command` or stderr.
+///
+/// Items already listed in the marker file are skipped. Entries for earlier
+/// dates are dropped when the marker is rewritten.
+pub fn notify_due(config: &Config, items: &[DueItem]) -> Result<NotifySummary, String> {
+    let marker = workspace::open()?.path().join(NOTIFIED_FILE);
+    let today = items.first().map(|i| i.date).unwrap_or_default();
+    let mut seen: Vec<String> = fs::read_to_string(&marker)
+        .unwrap_or_default()
+        .lines()
+        .filter(|l| l.split_once(' ').is_some_and(|(_, date)| date >= today))
+        .map(str::to_string)
+        .collect();
+
+    let mut summary = NotifySummary {
+        sent: 0,
+        skipped: 0,
+    };
+    for item in items {
+        let key = format!("{} {}", item.hash, item.date);
+        if seen.contains(&key) {
+            summary.skipped += 1;
+            continue;
+        }
+
+        match config.behavior.notify_command.as_deref() {
+            Some(template) => {
+                let command = render_command(template, item);
+                let status = Command::new("sh")
+                    .arg("-c")
+                    .arg(&command)
+                    .status()
+                    .map_err(|e| format!("failed to run notify command: {}", e))?;
+                if !status.success() {
+                    eprintln!("notify command failed for {} ({})", item.hash, status);
+                    continue;
+                }
+            }
+            None => eprintln!("Due today: {} [{}]", item.text, item.thread_id),
+        }
+
+        seen.push(key);
+        summary.sent += 1;
+    }
+
+    if summary.sent > 0 {
+        if let Some(parent) = marker.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&marker, seen.join("\n") + "\n")
+            .map_err(|e| format!("failed to write {}: {}", marker.display(), e))?;
+    }
+
+    Ok(summary)
+}
+
+/// Fill `{text}`, `{date}`, and `{id}` in the command template, shell-quoted.
+fn render_command(template: &str, item: &DueItem) -> String {
+    template
+        .replace("{text}", &shell_quote(item.text))
+        .replace("{date}", &shell_quote(item.date))
+        .replace("{id}", &shell_quote(item.thread_id))
+}
+
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_command_quotes_values() {
+        let item = DueItem {
+            hash: "ab12",
+            date: "2026-03-01",
+            text: "Ship it; don't wait",
+            thread_id: "abc123",
+        };
+        assert_eq!(
+            render_command("notify-send {text} {date} {id}", &item),
+            r"notify-send 'Ship it; don'\''t wait' '2026-03-01' 'abc123'"
+        );
+    }
+}
@@ -1,10 +1,13 @@
 //! Output formatting utilities with TTY auto-detection and semantic styling.
 
-use std::io::IsTerminal;
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
 
 use chrono::{DateTime, Local};
 use clap::ValueEnum;
-use colored::{ColoredString, Colorize};
+use colored::{Color, ColoredString, Colorize};
+use serde::Serialize;
 
 /// Output format for commands.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
@@ -18,20 +21,63 @@ pub enum OutputFormat {
     Json,
     /// Machine-readable YAML with ISO 8601 timestamps
     Yaml,
+    /// One compact JSON object per line (list, search, validate; one line elsewhere)
+    Ndjson,
+}
+
+/// When to color pretty output (`--color`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Follow NO_COLOR, FORCE_COLOR and TTY detection
+    #[default]
+    Auto,
+    /// Pretty, colored output even when piped
+    Always,
+    /// No colors, whatever the environment says
+    Never,
+}
+
+static COLOR_CHOICE: OnceLock<ColorChoice> = OnceLock::new();
+
+/// Apply `--color` for the rest of the process. Call once, before any output.
+pub fn set_color_choice(choice: ColorChoice) {
+    match choice {
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+        ColorChoice::Auto => {}
+    }
+    let _ = COLOR_CHOICE.set(choice);
+}
+
+fn color_choice() -> ColorChoice {
+    COLOR_CHOICE.get().copied().unwrap_or_default()
 }
 
 impl OutputFormat {
+    /// Json, Ndjson or Yaml: data for programs, never styled.
+    pub fn is_structured(self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Json | OutputFormat::Ndjson | OutputFormat::Yaml
+        )
+    }
+
     /// Resolve the output format, applying environment variables and TTY auto-detection.
     ///
     /// Priority for Pretty format:
-    /// 1. NO_COLOR env var (if set and non-empty) → Plain
-    /// 2. FORCE_COLOR env var (if set and non-empty) → Pretty (skip TTY check)
-    /// 3. TTY detection → Plain if not a TTY, Pretty otherwise
+    /// 1. `--color always` → Pretty; `--color never` skips FORCE_COLOR
+    /// 2. NO_COLOR env var (if set and non-empty) → Plain
+    /// 3. FORCE_COLOR env var (if set and non-empty) → Pretty (skip TTY check)
+    /// 4. TTY detection → Plain if not a TTY, Pretty otherwise
     ///
     /// Json, Yaml, and Plain formats are returned as-is (considered explicit choices).
     pub fn resolve(self) -> Self {
         match self {
             OutputFormat::Pretty => {
+                let choice = color_choice();
+                if choice == ColorChoice::Always {
+                    return OutputFormat::Pretty;
+                }
                 // NO_COLOR takes precedence (https://no-color.org/)
                 if std::env::var("NO_COLOR")
                     .map(|v| !v.is_empty())
@@ -40,9 +86,10 @@ impl OutputFormat {
                     return OutputFormat::Plain;
                 }
                 // FORCE_COLOR forces pretty output
-                if std::env::var("FORCE_COLOR")
-                    .map(|v| !v.is_empty())
-                    .unwrap_or(false)
+                if choice != ColorChoice::Never
+                    && std::env::var("FORCE_COLOR")
+                        .map(|v| !v.is_empty())
+                        .unwrap_or(false)
                 {
                     return OutputFormat::Pretty;
                 }
@@ -79,38 +126,27 @@ pub fn style_status(status: &str) -> ColoredString {
 
 /// Style status using config colors.
 ///
-/// Color names supported: green, yellow, blue, red, cyan, magenta, white, dim/dimmed
-#[allow(dead_code)]
+/// Color names are those `colored` understands (red, bright blue, ...) plus dim/dimmed;
+/// unknown names fall back to the default lifecycle colors.
 pub fn style_status_with_config(
     status: &str,
     colors: Option<&crate::config::StatusColors>,
 ) -> ColoredString {
-    let color = colors.and_then(|c| match status {
-        "active" => c.active.as_deref(),
-        "blocked" => c.blocked.as_deref(),
-        "paused" => c.paused.as_deref(),
-        "idea" => c.idea.as_deref(),
-        "planning" => c.planning.as_deref(),
-        "resolved" => c.resolved.as_deref(),
-        "superseded" => c.superseded.as_deref(),
-        "deferred" => c.deferred.as_deref(),
-        "rejected" => c.rejected.as_deref(),
-        _ => None,
-    });
-
-    match color {
-        Some("green") => status.green(),
-        Some("yellow") => status.yellow(),
-        Some("blue") => status.blue(),
-        Some("red") => status.red(),
-        Some("cyan") => status.cyan(),
-        Some("magenta") => status.magenta(),
-        Some("white") => status.white(),
+    match colors.and_then(|c| c.get(status)) {
         Some("dim") | Some("dimmed") => status.dimmed(),
-        Some(_) | None => style_status(status), // fallback to defaults
+        Some(name) => match name.parse::<Color>() {
+            Ok(color) => status.color(color),
+            Err(_) => style_status(status),
+        },
+        None => style_status(status),
     }
 }
 
+/// Whether a color name is accepted by `display.status_colors`.
+pub fn is_supported_color(name: &str) -> bool {
+    matches!(name, "dim" | "dimmed") || name.parse::<Color>().is_ok()
+}
+
 /// Style for IDs and hashes - always dimmed.
 pub fn style_id(id: &str) -> ColoredString {
     id.dimmed()
@@ -136,6 +172,71 @@ pub fn terminal_width() -> usize {
         .unwrap_or(80)
 }
 
+/// Get terminal height, or None when stdout is not a terminal.
+pub fn terminal_height() -> Option<usize> {
+    terminal_size::terminal_size().map(|(_, h)| h.0 as usize)
+}
+
+/// Print pretty output, through a pager when it is taller than the terminal.
+///
+/// Pages only when `allowed` (no `--no-pager`), `THREADS_NO_PAGER` is unset,
+/// and stdout is a terminal. The pager is `THREADS_PAGER`, then `PAGER`, then
+/// `less -R` (which keeps ANSI colors). If it cannot be started, the content
+/// is printed directly.
+pub fn page(content: &str, allowed: bool) {
+    let fits = terminal_height().is_none_or(|height| content.lines().count() < height);
+    if !allowed
+        || fits
+        || crate::config::env_is_set("THREADS_NO_PAGER")
+        || !std::io::stdout().is_terminal()
+    {
+        print!("{}", content);
+        return;
+    }
+
+    let pager = crate::config::env_string("THREADS_PAGER")
+        .or_else(|| crate::config::env_string("PAGER"))
+        .unwrap_or_else(|| "less -R".to_string());
+    let Ok(mut child) = Command::new("sh")
+        .arg("-c")
+        .arg(&pager)
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        print!("{}", content);
+        return;
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // Quitting the pager early closes the pipe; that is not an error
+        let _ = stdin.write_all(content.as_bytes());
+    }
+    // 127: the shell could not find the pager command
+    if child.wait().is_ok_and(|status| status.code() == Some(127)) {
+        print!("{}", content);
+    }
+}
+
+/// `value` as JSON: pretty for `--format json`, a single compact line for
+/// ndjson so commands without per-record output still emit one record.
+pub fn to_json<T: Serialize + ?Sized>(
+    value: &T,
+    format: OutputFormat,
+) -> serde_json::Result<String> {
+    if format == OutputFormat::Ndjson {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+/// Print one NDJSON record: `value` as compact JSON on a single line.
+pub fn print_ndjson<T: Serialize>(value: &T) -> Result<(), String> {
+    let line =
+        serde_json::to_string(value).map_err(|e| format!("JSON serialization failed: {}", e))?;
+    println!("{}", line);
+    Ok(())
+}
+
 // ============================================================================
 // User messages
 // ============================================================================
@@ -289,6 +390,18 @@ pub fn shortest_path(git_rel_path: &str, pwd_rel: &str) -> String {
     }
 }
 
+// ============================================================================
+// Delimited output
+// ============================================================================
+
+/// Escape a field for tab-separated output.
+///
+/// Tabs, carriage returns, and newlines are each replaced with a single space so
+/// every record stays on one line with a fixed number of columns. No quoting.
+pub fn tsv_field(s: &str) -> String {
+    s.replace(['\t', '\r', '\n'], " ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,4 +492,19 @@ mod tests {
             assert_eq!(OutputFormat::Plain.resolve(), OutputFormat::Plain);
         });
     }
+
+    #[test]
+    fn test_is_supported_color() {
+        for name in ["red", "bright blue", "dim", "dimmed", "magenta"] {
+            assert!(is_supported_color(name), "{} should be supported", name);
+        }
+        assert!(!is_supported_color("mauve"));
+        assert!(!is_supported_color(""));
+    }
+
+    #[test]
+    fn test_tsv_field_escapes_separators() {
+        assert_eq!(tsv_field("plain"), "plain");
+        assert_eq!(tsv_field("a\tb\nc\r\nd"), "a b c  d");
+    }
 }
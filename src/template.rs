@@ -0,0 +1,204 @@
+//! Body templates for `threads new --template`.
+//!
+//! A template is a markdown file named `<name>.md`. Lookup goes from the
+//! `.threads-config/templates/` nearest the new thread up to the repo root,
+//! then the user's `~/.config/threads/templates/`, then the built-in set;
+//! the first match wins, so any built-in can be overridden.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::config::CONFIG_DIR;
+
+/// Templates shipped with the binary.
+const BUILTIN: &[(&str, &str)] = &[
+    (
+        "bug",
+        "## Symptoms\n\nWhat happens, and what was expected instead.\n\n\
+         ## Reproduction\n\n## Cause\n\n## Fix\n",
+    ),
+    (
+        "decision",
+        "## Context\n\nWhy {{title}} needs deciding now.\n\n\
+         ## Options\n\n## Decision\n\n## Consequences\n",
+    ),
+    (
+        "feature",
+        "## Goal\n\nWhat {{title}} should make possible.\n\n\
+         ## Scope\n\n## Open questions\n",
+    ),
+];
+
+/// Where a template was found.
+#[derive(Serialize)]
+pub struct TemplateInfo {
+    pub name: String,
+    /// File path, or "built-in"
+    pub source: String,
+}
+
+/// Template directories to search for a thread created in `dir`, nearest first.
+pub fn search_dirs(git_root: &Path, dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    let git_root = git_root
+        .canonicalize()
+        .unwrap_or_else(|_| git_root.to_path_buf());
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+
+    if dir.starts_with(&git_root) {
+        for ancestor in dir.ancestors() {
+            dirs.push(ancestor.join(CONFIG_DIR).join("templates"));
+            if ancestor == git_root {
+                break;
+            }
+        }
+    } else {
+        dirs.push(git_root.join(CONFIG_DIR).join("templates"));
+    }
+    if let Some(user_dir) = dirs::config_dir() {
+        dirs.push(user_dir.join("threads").join("templates"));
+    }
+    dirs
+}
+
+/// Load the template called `name` from the first directory that has it,
+/// falling back to the built-ins.
+pub fn load(name: &str, dirs: &[PathBuf]) -> Result<String, String> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return Err(format!("invalid template name '{}'", name));
+    }
+    for dir in dirs {
+        let path = dir.join(format!("{}.md", name));
+        if path.is_file() {
+            return fs::read_to_string(&path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e));
+        }
+    }
+    if let Some((_, body)) = BUILTIN.iter().find(|(n, _)| *n == name) {
+        return Ok(body.to_string());
+    }
+
+    let searched: Vec<String> = dirs
+        .iter()
+        .map(|d| d.join(format!("{}.md", name)).display().to_string())
+        .collect();
+    Err(format!(
+        "template '{}' not found (searched: {}, built-in)",
+        name,
+        searched.join(", ")
+    ))
+}
+
+/// Every available template, sorted by name. A name found in several places
+/// is listed once, with the location `load` would use.
+pub fn list(dirs: &[PathBuf]) -> Vec<TemplateInfo> {
+    let mut found: Vec<TemplateInfo> = Vec::new();
+    let mut add = |name: String, source: String| {
+        if !found.iter().any(|t| t.name == name) {
+            found.push(TemplateInfo { name, source });
+        }
+    };
+
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(dir) else {
+            continue;
+        };
+        let mut paths: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+        paths.sort();
+        for path in paths {
+            if path.extension().is_some_and(|e| e == "md")
+                && let Some(stem) = path.file_stem()
+            {
+                add(
+                    stem.to_string_lossy().to_string(),
+                    path.display().to_string(),
+                );
+            }
+        }
+    }
+    for (name, _) in BUILTIN {
+        add(name.to_string(), "built-in".to_string());
+    }
+
+    found.sort_by(|a, b| a.name.cmp(&b.name));
+    found
+}
+
+/// Replace `{{key}}` placeholders (spaces inside the braces allowed).
+/// Unknown placeholders are left as written.
+pub fn render(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let key = after[..end].trim();
+        match vars.iter().find(|(k, _)| *k == key) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Keys of the `{{...}}` placeholders in `text`, in order of first appearance.
+pub fn placeholders(text: &str) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let key = after[..end].trim().to_string();
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+        rest = &after[end + 2..];
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_placeholders() {
+        let vars = [("title", "Fix login"), ("id", "abc123")];
+        assert_eq!(
+            render("# {{title}} ({{ id }})", &vars),
+            "# Fix login (abc123)"
+        );
+        assert_eq!(render("{{unknown}} stays", &vars), "{{unknown}} stays");
+        assert_eq!(render("open {{title", &vars), "open {{title");
+    }
+
+    #[test]
+    fn test_placeholders() {
+        assert_eq!(
+            placeholders("{{ severity }} on {{os}}, again {{severity}}"),
+            vec!["severity", "os"]
+        );
+        assert!(placeholders("no {{ closing").is_empty());
+    }
+
+    #[test]
+    fn test_load_builtin_and_missing() {
+        assert!(load("bug", &[]).unwrap().contains("## Reproduction"));
+
+        let err = load("nope", &[PathBuf::from("/x/templates")]).unwrap_err();
+        assert!(err.contains("/x/templates/nope.md"));
+        assert!(err.contains("built-in"));
+
+        assert!(load("../etc/passwd", &[]).is_err());
+    }
+}
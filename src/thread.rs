@@ -1,12 +1,16 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 use std::sync::LazyLock;
 
-use chrono::Local;
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, NaiveDateTime, SecondsFormat};
 use md5::{Digest, Md5};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
+use crate::config::Config;
+use crate::validate::{self, Issue};
+
 // Canonical section names for legacy fallback parsing (migration support)
 // "Body" is intentionally absent: body is now everything after frontmatter, not a named section.
 const CANONICAL_SECTIONS: &[&str] = &["Notes", "Todo", "Log"];
@@ -24,6 +28,9 @@ static ID_PREFIX_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9a-f]{
 static HASH_COMMENT_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"<!--\s*([a-f0-9]{4})\s*-->").unwrap());
 
+/// Body section that `archive_log_before` moves old log entries into
+pub const ARCHIVED_LOG_SECTION: &str = "Archived Log";
+
 /// Closed statuses (threads that don't need attention)
 pub const CLOSED_STATUSES: &[&str] = &["resolved", "superseded", "deferred", "rejected"];
 
@@ -31,6 +38,15 @@ pub const CLOSED_STATUSES: &[&str] = &["resolved", "superseded", "deferred", "re
 #[allow(dead_code)]
 pub const OPEN_STATUSES: &[&str] = &["idea", "planning", "active", "blocked", "paused"];
 
+/// Priority levels, most urgent first
+pub const PRIORITIES: &[&str] = &["critical", "high", "medium", "low"];
+
+/// Recurrence intervals for deadlines
+pub const RECURRENCES: &[&str] = &["daily", "weekly", "monthly"];
+
+/// Kinds of links between threads
+pub const LINK_KINDS: &[&str] = &["blocked-by", "relates-to"];
+
 // ============================================================================
 // Item types for frontmatter-based structured data
 // ============================================================================
@@ -46,6 +62,19 @@ pub struct TodoItem {
     pub text: String,
     pub hash: String,
     pub done: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>, // "YYYY-MM-DD" or absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>, // hash of the parent todo, for subtasks
+}
+
+/// Target position for `Thread::move_todo_item`; anchors are hash prefixes
+#[derive(Debug, Clone, Copy)]
+pub enum TodoPlacement<'a> {
+    Top,
+    Bottom,
+    Before(&'a str),
+    After(&'a str),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,6 +88,10 @@ pub struct DeadlineItem {
     pub date: String, // "YYYY-MM-DD"
     pub text: String,
     pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub every: Option<String>, // "daily", "weekly", "monthly", or absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day: Option<u32>, // monthly anchor day when `date` was clamped to a shorter month
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +103,13 @@ pub struct EventItem {
     pub hash: String,
 }
 
+/// A typed reference to another thread, by its 6-hex ID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Link {
+    pub kind: String, // one of LINK_KINDS
+    pub target: String,
+}
+
 // ============================================================================
 // Frontmatter
 // ============================================================================
@@ -85,6 +125,18 @@ pub struct Frontmatter {
     pub desc: String,
     #[serde(default)]
     pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<Link>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>, // ID of the parent thread
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>, // RFC 3339, set once by Thread::new
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>, // RFC 3339, bumped on every rebuild
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub notes: Vec<NoteItem>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -97,6 +149,26 @@ pub struct Frontmatter {
     pub events: Vec<EventItem>,
 }
 
+/// Top-level frontmatter keys understood by `Frontmatter`.
+/// Any other key is dropped when the frontmatter is rebuilt on write.
+pub const FRONTMATTER_KEYS: &[&str] = &[
+    "id",
+    "name",
+    "desc",
+    "status",
+    "priority",
+    "tags",
+    "links",
+    "parent",
+    "created",
+    "updated",
+    "notes",
+    "todo",
+    "log",
+    "deadlines",
+    "events",
+];
+
 /// Thread represents a parsed thread file
 #[derive(Debug, Clone)]
 pub struct Thread {
@@ -110,11 +182,15 @@ impl Thread {
     /// Parse a thread file
     pub fn parse(path: &Path) -> Result<Self, String> {
         let content = fs::read_to_string(path).map_err(|e| format!("reading file: {}", e))?;
+        Self::from_content(path, &content)
+    }
 
+    /// Parse thread content that has already been read from `path`.
+    pub fn from_content(path: &Path, content: &str) -> Result<Self, String> {
         let mut thread = Thread {
             path: path.to_string_lossy().to_string(),
             frontmatter: Frontmatter::default(),
-            content: content.clone(),
+            content: content.to_string(),
             body_start: 0,
         };
 
@@ -131,6 +207,8 @@ impl Thread {
     }
 
     fn parse_frontmatter(&mut self) -> Result<(), String> {
+        self.content = normalize_content(&self.content);
+
         if !self.content.starts_with("---\n") {
             return Err("missing frontmatter delimiter".to_string());
         }
@@ -170,6 +248,129 @@ impl Thread {
         base_status(&self.frontmatter.status)
     }
 
+    /// Get the priority level, if set
+    pub fn get_priority(&self) -> Option<&str> {
+        self.frontmatter.priority.as_deref()
+    }
+
+    /// Set or clear the priority and rebuild content.
+    /// The level is matched case-insensitively and stored lowercase.
+    pub fn set_priority(&mut self, priority: Option<&str>) -> Result<(), String> {
+        self.frontmatter.priority = match priority {
+            Some(p) => {
+                let level = p.to_lowercase();
+                if priority_rank(&level).is_none() {
+                    return Err(format!(
+                        "invalid priority '{}': use {}",
+                        p,
+                        PRIORITIES.join(", ")
+                    ));
+                }
+                Some(level)
+            }
+            None => None,
+        };
+        self.rebuild_content()
+    }
+
+    /// Get the thread's tags
+    pub fn tags(&self) -> &[String] {
+        &self.frontmatter.tags
+    }
+
+    /// Add a tag (slugified). Returns the stored tag and whether it was new.
+    pub fn add_tag(&mut self, tag: &str) -> Result<(String, bool), String> {
+        let slug = tag_slug(tag)?;
+        if self.frontmatter.tags.contains(&slug) {
+            return Ok((slug, false));
+        }
+        self.frontmatter.tags.push(slug.clone());
+        self.rebuild_content()?;
+        Ok((slug, true))
+    }
+
+    /// Remove a tag, matched after slugifying. Returns the removed tag.
+    pub fn remove_tag(&mut self, tag: &str) -> Result<String, String> {
+        let slug = tag_slug(tag)?;
+        let before = self.frontmatter.tags.len();
+        self.frontmatter.tags.retain(|t| *t != slug);
+        if self.frontmatter.tags.len() == before {
+            return Err(format!("thread has no tag '{}'", slug));
+        }
+        self.rebuild_content()?;
+        Ok(slug)
+    }
+
+    /// Check whether the thread carries every one of `tags` (already slugified)
+    pub fn has_tags(&self, tags: &[String]) -> bool {
+        tags.iter().all(|t| self.frontmatter.tags.contains(t))
+    }
+
+    /// Get the links to other threads
+    pub fn links(&self) -> &[Link] {
+        &self.frontmatter.links
+    }
+
+    /// Add a link to the thread with ID `target`. Returns false if it already exists.
+    pub fn add_link(&mut self, kind: &str, target: &str) -> Result<bool, String> {
+        if !LINK_KINDS.contains(&kind) {
+            return Err(format!(
+                "unknown link kind '{}': use {}",
+                kind,
+                LINK_KINDS.join(", ")
+            ));
+        }
+        if target == self.id() {
+            return Err("a thread cannot link to itself".to_string());
+        }
+        let link = Link {
+            kind: kind.to_string(),
+            target: target.to_string(),
+        };
+        if self.frontmatter.links.contains(&link) {
+            return Ok(false);
+        }
+        self.frontmatter.links.push(link);
+        self.rebuild_content()?;
+        Ok(true)
+    }
+
+    /// Remove every link to `target`. Returns how many were removed.
+    pub fn remove_links_to(&mut self, target: &str) -> Result<usize, String> {
+        let before = self.frontmatter.links.len();
+        self.frontmatter.links.retain(|l| l.target != target);
+        let removed = before - self.frontmatter.links.len();
+        if removed == 0 {
+            return Err(format!("no link to '{}'", target));
+        }
+        self.rebuild_content()?;
+        Ok(removed)
+    }
+
+    /// Get the parent thread's ID, if any
+    pub fn parent(&self) -> Option<&str> {
+        self.frontmatter.parent.as_deref()
+    }
+
+    /// Set or clear the parent thread ID and rebuild content
+    pub fn set_parent(&mut self, parent: Option<&str>) -> Result<(), String> {
+        if parent == Some(self.id()) {
+            return Err("a thread cannot be its own parent".to_string());
+        }
+        self.frontmatter.parent = parent.map(str::to_string);
+        self.rebuild_content()
+    }
+
+    /// Creation time recorded in frontmatter, if present and well-formed
+    pub fn created_at(&self) -> Option<DateTime<Local>> {
+        parse_timestamp(self.frontmatter.created.as_deref()?)
+    }
+
+    /// Last-rewrite time recorded in frontmatter, if present and well-formed
+    pub fn updated_at(&self) -> Option<DateTime<Local>> {
+        parse_timestamp(self.frontmatter.updated.as_deref()?)
+    }
+
     /// Get the body content after frontmatter (trimmed)
     pub fn body(&self) -> &str {
         if self.body_start >= self.content.len() {
@@ -191,6 +392,14 @@ impl Thread {
         self.rebuild_content()
     }
 
+    /// The frontmatter as `rebuild_content` writes it between the `---` delimiters,
+    /// newline-terminated.
+    pub fn frontmatter_yaml(&self) -> Result<String, String> {
+        let yaml = serde_yaml::to_string(&self.frontmatter)
+            .map_err(|e| format!("serializing YAML: {}", e))?;
+        Ok(format!("{}\n", yaml.trim_end()))
+    }
+
     /// Rebuild content from frontmatter + current body, updating body_start.
     pub fn rebuild_content(&mut self) -> Result<(), String> {
         // Extract old body before we overwrite content.
@@ -209,13 +418,11 @@ impl Thread {
             String::new()
         };
 
+        self.frontmatter.updated = Some(now_timestamp());
+
         let mut sb = String::new();
         sb.push_str("---\n");
-
-        let yaml = serde_yaml::to_string(&self.frontmatter)
-            .map_err(|e| format!("serializing YAML: {}", e))?;
-        sb.push_str(yaml.trim_end());
-        sb.push('\n');
+        sb.push_str(&self.frontmatter_yaml()?);
         sb.push_str("---\n");
 
         let new_body_start = sb.len();
@@ -248,6 +455,7 @@ impl Thread {
             name: name.to_string(),
             desc: desc.to_string(),
             status: status.to_string(),
+            created: Some(now_timestamp()),
             log: vec![LogEntry {
                 ts,
                 text: "Created thread.".to_string(),
@@ -341,6 +549,8 @@ impl Thread {
                 text: text.to_string(),
                 hash: hash.clone(),
                 done: false,
+                due: None,
+                parent: None,
             },
         );
         self.rebuild_content()?;
@@ -360,6 +570,30 @@ impl Thread {
         self.rebuild_content()
     }
 
+    /// Move frontmatter log entries dated before `date` (YYYY-MM-DD) into an
+    /// `## Archived Log` body section, oldest first. Returns the number moved.
+    pub fn archive_log_before(&mut self, date: &str) -> Result<usize, String> {
+        let (archived, kept): (Vec<LogEntry>, Vec<LogEntry>) = self
+            .frontmatter
+            .log
+            .drain(..)
+            .partition(|e| !e.ts.is_empty() && e.ts.as_str() < date);
+        self.frontmatter.log = kept;
+
+        if archived.is_empty() {
+            return Ok(0);
+        }
+
+        let lines: Vec<String> = archived
+            .iter()
+            .rev()
+            .map(|e| format!("- [{}] {}", e.ts, e.text))
+            .collect();
+        self.rebuild_content()?;
+        self.append_to_body_section(ARCHIVED_LOG_SECTION, &lines.join("\n"))?;
+        Ok(archived.len())
+    }
+
     /// Count items matching a hash prefix in the given section.
     /// Checks frontmatter if populated, otherwise falls back to section parsing.
     pub fn count_matching_items(&self, section: &str, hash: &str) -> usize {
@@ -414,7 +648,13 @@ impl Thread {
                         .iter()
                         .position(|t| t.hash.starts_with(hash))
                         .ok_or_else(|| format!("no item with hash '{}' found", hash))?;
-                    self.frontmatter.todo.remove(pos);
+                    let removed = self.frontmatter.todo.remove(pos);
+                    // Subtasks move up to the removed item's parent
+                    for item in &mut self.frontmatter.todo {
+                        if item.parent.as_deref() == Some(removed.hash.as_str()) {
+                            item.parent = removed.parent.clone();
+                        }
+                    }
                     return self.rebuild_content();
                 }
             }
@@ -443,7 +683,13 @@ impl Thread {
             item.text = new_text.to_string();
             return self.rebuild_content();
         }
-        // Fallback to section-based edit
+        // Fallback to section-based edit; legacy sections hold one item per line
+        if new_text.contains('\n') {
+            return Err(format!(
+                "multi-line text is not supported in the legacy ## {} section",
+                section
+            ));
+        }
         self.content = edit_by_hash_from_section(&self.content, section, hash, new_text)?;
         Ok(())
     }
@@ -457,8 +703,27 @@ impl Thread {
         self.frontmatter.deadlines.clone()
     }
 
-    /// Add a deadline to frontmatter (prepend). Returns the generated hash.
-    pub fn add_deadline(&mut self, date: &str, text: &str) -> Result<String, String> {
+    /// Add a deadline to frontmatter (prepend), optionally recurring. Returns the generated hash.
+    pub fn add_deadline(
+        &mut self,
+        date: &str,
+        text: &str,
+        every: Option<&str>,
+    ) -> Result<String, String> {
+        let every = match every {
+            Some(e) => {
+                let interval = e.to_lowercase();
+                if !RECURRENCES.contains(&interval.as_str()) {
+                    return Err(format!(
+                        "invalid recurrence '{}': use {}",
+                        e,
+                        RECURRENCES.join(", ")
+                    ));
+                }
+                Some(interval)
+            }
+            None => None,
+        };
         let hash = generate_hash(&format!("{}{}", date, text));
         // Check for collision
         if self
@@ -475,12 +740,62 @@ impl Thread {
                 date: date.to_string(),
                 text: text.to_string(),
                 hash: hash.clone(),
+                every,
+                day: None,
             },
         );
         self.rebuild_content()?;
         Ok(hash)
     }
 
+    /// Complete a deadline by hash prefix. A recurring deadline moves to its
+    /// next occurrence, which is returned; any other deadline is removed.
+    pub fn complete_deadline(
+        &mut self,
+        hash: &str,
+    ) -> Result<(DeadlineItem, Option<String>), String> {
+        let matches: Vec<usize> = self
+            .frontmatter
+            .deadlines
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.hash.starts_with(hash))
+            .map(|(i, _)| i)
+            .collect();
+        let pos = match matches[..] {
+            [] => return Err(format!("no deadline with hash '{}' found", hash)),
+            [pos] => pos,
+            _ => {
+                return Err(format!(
+                    "ambiguous hash '{}' matches {} deadlines",
+                    hash,
+                    matches.len()
+                ));
+            }
+        };
+
+        let item = self.frontmatter.deadlines[pos].clone();
+        let next = match item.every.as_deref() {
+            Some(every) => {
+                let date = NaiveDate::parse_from_str(&item.date, "%Y-%m-%d")
+                    .map_err(|_| format!("invalid deadline date '{}'", item.date))?;
+                let day = item.day.unwrap_or(date.day());
+                let next = next_occurrence(date, every, day)
+                    .ok_or_else(|| format!("unknown recurrence '{}'", every))?;
+                let deadline = &mut self.frontmatter.deadlines[pos];
+                deadline.date = next.format("%Y-%m-%d").to_string();
+                deadline.day = (every == "monthly" && next.day() != day).then_some(day);
+                Some(deadline.date.clone())
+            }
+            None => {
+                self.frontmatter.deadlines.remove(pos);
+                None
+            }
+        };
+        self.rebuild_content()?;
+        Ok((item, next))
+    }
+
     /// Remove a deadline by hash prefix. Errors on ambiguous or missing hash.
     pub fn remove_deadline_by_hash(&mut self, hash: &str) -> Result<(), String> {
         let count = self
@@ -590,12 +905,170 @@ impl Thread {
         self.content = set_todo_checked_from_section(&self.content, "Todo", hash, checked)?;
         Ok(())
     }
+
+    /// Replace the todo list, e.g. to persist a new order (frontmatter only).
+    pub fn set_todo_items(&mut self, items: Vec<TodoItem>) -> Result<(), String> {
+        if self.frontmatter.todo.is_empty() && !items.is_empty() {
+            return Err("todos live in a legacy section; run 'threads migrate' first".to_string());
+        }
+        self.frontmatter.todo = items;
+        self.rebuild_content()
+    }
+
+    /// Reposition a todo item by hash prefix, keeping every hash (frontmatter only).
+    pub fn move_todo_item(&mut self, hash: &str, to: TodoPlacement) -> Result<(), String> {
+        let todo = &mut self.frontmatter.todo;
+        let pos = todo
+            .iter()
+            .position(|t| t.hash.starts_with(hash))
+            .ok_or_else(|| format!("no item with hash '{}' found", hash))?;
+        let item = todo.remove(pos);
+        let index = match to {
+            TodoPlacement::Top => 0,
+            TodoPlacement::Bottom => todo.len(),
+            TodoPlacement::Before(anchor) | TodoPlacement::After(anchor) => {
+                let Some(at) = todo.iter().position(|t| t.hash.starts_with(anchor)) else {
+                    let err = if item.hash.starts_with(anchor) {
+                        format!("cannot move '{}' relative to itself", item.hash)
+                    } else {
+                        format!("no item with hash '{}' found", anchor)
+                    };
+                    todo.insert(pos, item);
+                    return Err(err);
+                };
+                if matches!(to, TodoPlacement::After(_)) {
+                    at + 1
+                } else {
+                    at
+                }
+            }
+        };
+        todo.insert(index, item);
+        self.rebuild_content()
+    }
+
+    /// Set or clear the due date of a todo item by hash prefix (frontmatter only).
+    pub fn set_todo_due(&mut self, hash: &str, due: Option<&str>) -> Result<(), String> {
+        let item = self
+            .frontmatter
+            .todo
+            .iter_mut()
+            .find(|t| t.hash.starts_with(hash))
+            .ok_or_else(|| format!("no item with hash '{}' found", hash))?;
+        item.due = due.map(|d| d.to_string());
+        self.rebuild_content()
+    }
+
+    /// Make a todo item a subtask of another, or a top-level item with `None` (frontmatter only).
+    ///
+    /// Both hashes may be prefixes; the full parent hash is stored. Refuses to create a cycle.
+    pub fn set_todo_parent(&mut self, hash: &str, parent: Option<&str>) -> Result<(), String> {
+        let todo = &self.frontmatter.todo;
+        let pos = todo
+            .iter()
+            .position(|t| t.hash.starts_with(hash))
+            .ok_or_else(|| format!("no item with hash '{}' found", hash))?;
+        let parent = match parent {
+            Some(p) => {
+                let parent_hash = todo
+                    .iter()
+                    .find(|t| t.hash.starts_with(p))
+                    .map(|t| t.hash.clone())
+                    .ok_or_else(|| format!("no parent item with hash '{}' found", p))?;
+                if todo_ancestors(todo, &parent_hash).contains(&todo[pos].hash.as_str()) {
+                    return Err(format!(
+                        "cannot put '{}' under '{}': it would become its own ancestor",
+                        todo[pos].hash, parent_hash
+                    ));
+                }
+                Some(parent_hash)
+            }
+            None => None,
+        };
+        self.frontmatter.todo[pos].parent = parent;
+        self.rebuild_content()
+    }
+
+    /// Run the per-file validation checks (frontmatter, sections, log, todos).
+    ///
+    /// Cross-file checks such as duplicate IDs are left to `threads validate`.
+    pub fn validate(&self, config: &Config) -> Vec<Issue> {
+        let mut issues =
+            validate::validate_frontmatter(&self.content, Path::new(&self.path), config);
+        issues.extend(validate::validate_body(&self.content, config));
+        validate::apply_severity_overrides(&mut issues, config);
+        issues
+    }
+
+    /// Append text under a `## <heading>` section of the body, creating the heading if absent.
+    pub fn append_to_body_section(&mut self, heading: &str, text: &str) -> Result<(), String> {
+        let heading = heading.trim();
+        if heading.is_empty() {
+            return Err("heading cannot be empty".to_string());
+        }
+        if heading == "Body" || CANONICAL_SECTIONS.contains(&heading) {
+            return Err(format!(
+                "'{}' is a reserved section name; choose another heading",
+                heading
+            ));
+        }
+        let new_body = append_under_heading(self.body(), heading, text);
+        self.content = format!("{}\n{}", &self.content[..self.body_start], new_body);
+        Ok(())
+    }
+
+    /// Merge items from legacy `## Notes`/`## Todo`/`## Log` sections into the
+    /// frontmatter, skipping ones already there (same text; same timestamp and
+    /// text for log entries), then strip the legacy sections from the body.
+    /// Returns the number of items merged.
+    pub fn merge_legacy_sections(&mut self) -> Result<usize, String> {
+        let mut merged = 0;
+        for note in get_notes_from_section(&self.content) {
+            if !self.frontmatter.notes.iter().any(|n| n.text == note.text) {
+                self.frontmatter.notes.push(note);
+                merged += 1;
+            }
+        }
+        for item in get_todo_items_from_section(&self.content) {
+            if !self.frontmatter.todo.iter().any(|t| t.text == item.text) {
+                self.frontmatter.todo.push(item);
+                merged += 1;
+            }
+        }
+        for entry in get_log_entries_from_section(&self.content) {
+            if !self
+                .frontmatter
+                .log
+                .iter()
+                .any(|e| e.ts == entry.ts && e.text == entry.text)
+            {
+                self.frontmatter.log.push(entry);
+                merged += 1;
+            }
+        }
+        // Section items carry their own hashes, which may clash with frontmatter ones
+        regenerate_duplicate_hashes(&mut self.frontmatter);
+        sort_log_newest_first(&mut self.frontmatter.log);
+
+        let body = strip_old_sections(self.body());
+        self.content = format!("{}{}", &self.content[..self.body_start], body);
+        self.rebuild_content()?;
+        Ok(merged)
+    }
 }
 
 // ============================================================================
 // Path utilities
 // ============================================================================
 
+/// Strip a leading UTF-8 BOM and convert CRLF line endings to LF.
+pub fn normalize_content(content: &str) -> String {
+    content
+        .strip_prefix('\u{feff}')
+        .unwrap_or(content)
+        .replace("\r\n", "\n")
+}
+
 /// Extract ID from filename (6-char hex prefix)
 pub fn extract_id_from_path(path: &Path) -> Option<String> {
     let filename = path.file_name()?.to_string_lossy();
@@ -604,6 +1077,335 @@ pub fn extract_id_from_path(path: &Path) -> Option<String> {
     ID_PREFIX_RE.captures(filename).map(|c| c[1].to_string())
 }
 
+/// Index of the first timestamped log entry that is newer than the one before it.
+///
+/// Logs are newest-first (`insert_log_entry` prepends); entries without a
+/// valid timestamp are ignored.
+pub fn first_log_out_of_order(log: &[LogEntry]) -> Option<usize> {
+    let mut prev: Option<&str> = None;
+    for (i, entry) in log.iter().enumerate() {
+        if !has_log_timestamp(entry) {
+            continue;
+        }
+        if prev.is_some_and(|p| entry.ts.as_str() > p) {
+            return Some(i);
+        }
+        prev = Some(&entry.ts);
+    }
+    None
+}
+
+/// Whether a log entry carries a `YYYY-MM-DD HH:MM:SS` timestamp; anything else
+/// would compare as a string and land out of order.
+fn has_log_timestamp(entry: &LogEntry) -> bool {
+    NaiveDateTime::parse_from_str(&entry.ts, "%Y-%m-%d %H:%M:%S").is_ok()
+}
+
+/// Hashes of a todo item and its ancestors, nearest first.
+///
+/// Stops at an unknown parent or when a hash repeats, so malformed files cannot loop.
+pub fn todo_ancestors<'a>(items: &'a [TodoItem], hash: &'a str) -> Vec<&'a str> {
+    let mut chain = vec![hash];
+    let mut current = hash;
+    while let Some(parent) = items
+        .iter()
+        .find(|t| t.hash == current)
+        .and_then(|t| t.parent.as_deref())
+    {
+        if chain.contains(&parent) || !items.iter().any(|t| t.hash == parent) {
+            break;
+        }
+        chain.push(parent);
+        current = parent;
+    }
+    chain
+}
+
+/// Todo items in display order with their nesting depth.
+///
+/// Subtasks follow their parent; siblings keep their relative order. Items whose
+/// parent is missing (or part of a cycle) are shown at the top level.
+pub fn todo_tree(items: &[TodoItem]) -> Vec<(usize, &TodoItem)> {
+    fn visit<'a>(
+        items: &'a [TodoItem],
+        idx: usize,
+        depth: usize,
+        seen: &mut [bool],
+        out: &mut Vec<(usize, &'a TodoItem)>,
+    ) {
+        if seen[idx] {
+            return;
+        }
+        seen[idx] = true;
+        out.push((depth, &items[idx]));
+        for (child, item) in items.iter().enumerate() {
+            if item.parent.as_deref() == Some(items[idx].hash.as_str()) {
+                visit(items, child, depth + 1, seen, out);
+            }
+        }
+    }
+
+    let mut seen = vec![false; items.len()];
+    let mut out = Vec::with_capacity(items.len());
+    for (idx, item) in items.iter().enumerate() {
+        let is_root = match item.parent.as_deref() {
+            None => true,
+            Some(p) => {
+                !items.iter().any(|t| t.hash == p)
+                    || todo_ancestors(items, p).contains(&item.hash.as_str())
+            }
+        };
+        if is_root {
+            visit(items, idx, 0, &mut seen, &mut out);
+        }
+    }
+    // Anything left is only reachable through a cycle
+    for idx in 0..items.len() {
+        visit(items, idx, 0, &mut seen, &mut out);
+    }
+    out
+}
+
+/// Todo progress as `(done, total)`.
+///
+/// With `rollup`, an item that has subtasks counts as done only when all of
+/// its subtasks do, regardless of its own checkbox.
+pub fn todo_progress(items: &[TodoItem], rollup: bool) -> (usize, usize) {
+    fn rolled_up(items: &[TodoItem], item: &TodoItem, path: &mut Vec<String>) -> bool {
+        if path.contains(&item.hash) {
+            return item.done;
+        }
+        path.push(item.hash.clone());
+        let mut children = items
+            .iter()
+            .filter(|c| c.parent.as_deref() == Some(item.hash.as_str()))
+            .peekable();
+        let done = if children.peek().is_none() {
+            item.done
+        } else {
+            children.all(|c| rolled_up(items, c, path))
+        };
+        path.pop();
+        done
+    }
+
+    let done = if rollup {
+        items
+            .iter()
+            .filter(|i| rolled_up(items, i, &mut Vec::new()))
+            .count()
+    } else {
+        items.iter().filter(|i| i.done).count()
+    };
+    (done, items.len())
+}
+
+/// Body lines holding a `<!-- hash -->` comment that matches no frontmatter item.
+///
+/// Returns `(line index within body, hash)`. Legacy `## Notes`/`## Todo` sections
+/// are skipped: their hashes stay live until `threads migrate` (W010).
+pub fn orphan_hash_comments(fm: &Frontmatter, body: &str) -> Vec<(usize, String)> {
+    let known: Vec<&str> = fm
+        .notes
+        .iter()
+        .map(|n| n.hash.as_str())
+        .chain(fm.todo.iter().map(|t| t.hash.as_str()))
+        .chain(fm.deadlines.iter().map(|d| d.hash.as_str()))
+        .chain(fm.events.iter().map(|e| e.hash.as_str()))
+        .collect();
+
+    let mut orphans = Vec::new();
+    let mut in_legacy = false;
+    for (i, line) in body.lines().enumerate() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            in_legacy = matches!(heading.trim(), "Notes" | "Todo");
+            continue;
+        }
+        if in_legacy {
+            continue;
+        }
+        for caps in HASH_COMMENT_RE.captures_iter(line) {
+            let hash = &caps[1];
+            if !known.iter().any(|k| k.starts_with(hash)) {
+                orphans.push((i, hash.to_string()));
+            }
+        }
+    }
+    orphans
+}
+
+/// Remove orphan hash comments (see `orphan_hash_comments`) from the body.
+///
+/// Lines left empty by the removal are dropped. Returns the new body and the number removed.
+pub fn strip_orphan_hash_comments(fm: &Frontmatter, body: &str) -> (String, usize) {
+    let orphans = orphan_hash_comments(fm, body);
+    if orphans.is_empty() {
+        return (body.to_string(), 0);
+    }
+
+    let mut out = Vec::new();
+    for (i, line) in body.lines().enumerate() {
+        let hashes: Vec<&str> = orphans
+            .iter()
+            .filter(|(l, _)| *l == i)
+            .map(|(_, h)| h.as_str())
+            .collect();
+        if hashes.is_empty() {
+            out.push(line.to_string());
+            continue;
+        }
+        let stripped = HASH_COMMENT_RE.replace_all(line, |caps: &regex::Captures| {
+            if hashes.contains(&&caps[1]) {
+                String::new()
+            } else {
+                caps[0].to_string()
+            }
+        });
+        let stripped = stripped.trim_end();
+        if !stripped.trim().is_empty() {
+            out.push(stripped.to_string());
+        }
+    }
+
+    let mut new_body = out.join("\n");
+    if body.ends_with('\n') {
+        new_body.push('\n');
+    }
+    (new_body, orphans.len())
+}
+
+/// Sort timestamped log entries newest-first, returning whether anything moved.
+///
+/// The sort is stable, and entries without a valid timestamp keep their positions.
+pub fn sort_log_newest_first(log: &mut [LogEntry]) -> bool {
+    let slots: Vec<usize> = (0..log.len())
+        .filter(|&i| has_log_timestamp(&log[i]))
+        .collect();
+    let mut dated: Vec<LogEntry> = slots.iter().map(|&i| log[i].clone()).collect();
+    dated.sort_by(|a, b| b.ts.cmp(&a.ts));
+
+    let mut changed = false;
+    for (slot, entry) in slots.into_iter().zip(dated) {
+        if log[slot].ts != entry.ts || log[slot].text != entry.text {
+            changed = true;
+        }
+        log[slot] = entry;
+    }
+    changed
+}
+
+/// Hashes shared by several items of one kind, per kind ("notes", "todo",
+/// "deadlines", "events"). Edits and removals take the first prefix match, so
+/// the later items of a duplicate are unreachable.
+pub fn duplicate_hashes(fm: &Frontmatter) -> Vec<(&'static str, Vec<String>)> {
+    let lists: [(&'static str, Vec<&str>); 4] = [
+        ("notes", fm.notes.iter().map(|n| n.hash.as_str()).collect()),
+        ("todo", fm.todo.iter().map(|t| t.hash.as_str()).collect()),
+        (
+            "deadlines",
+            fm.deadlines.iter().map(|d| d.hash.as_str()).collect(),
+        ),
+        (
+            "events",
+            fm.events.iter().map(|e| e.hash.as_str()).collect(),
+        ),
+    ];
+    lists
+        .into_iter()
+        .filter_map(|(kind, hashes)| {
+            let mut seen = HashSet::new();
+            let mut dups: Vec<String> = Vec::new();
+            for hash in hashes {
+                if !seen.insert(hash) && !dups.iter().any(|d| d == hash) {
+                    dups.push(hash.to_string());
+                }
+            }
+            (!dups.is_empty()).then_some((kind, dups))
+        })
+        .collect()
+}
+
+/// Give every item after the first one with a given hash a fresh hash unique
+/// within its kind. Returns the number of items rehashed. Subtasks keep
+/// pointing at the first item.
+pub fn regenerate_duplicate_hashes(fm: &mut Frontmatter) -> usize {
+    let lists: [Vec<(&mut String, &str)>; 4] = [
+        fm.notes
+            .iter_mut()
+            .map(|n| (&mut n.hash, n.text.as_str()))
+            .collect(),
+        fm.todo
+            .iter_mut()
+            .map(|t| (&mut t.hash, t.text.as_str()))
+            .collect(),
+        fm.deadlines
+            .iter_mut()
+            .map(|d| (&mut d.hash, d.text.as_str()))
+            .collect(),
+        fm.events
+            .iter_mut()
+            .map(|e| (&mut e.hash, e.text.as_str()))
+            .collect(),
+    ];
+
+    let mut rehashed = 0;
+    for list in lists {
+        let mut taken: HashSet<String> = list.iter().map(|(h, _)| h.to_string()).collect();
+        let mut seen = HashSet::new();
+        for (hash, text) in list {
+            if seen.insert(hash.clone()) {
+                continue;
+            }
+            let mut fresh = generate_hash(text);
+            while taken.contains(&fresh) {
+                fresh = generate_hash(text);
+            }
+            taken.insert(fresh.clone());
+            *hash = fresh;
+            rehashed += 1;
+        }
+    }
+    rehashed
+}
+
+/// Legacy `## Notes`/`## Todo`/`## Log` sections hidden because the frontmatter
+/// already holds items of that kind (readers prefer frontmatter), with the number
+/// of section items missing from the frontmatter.
+pub fn shadowed_sections(fm: &Frontmatter, content: &str) -> Vec<(&'static str, usize)> {
+    let mut shadowed = Vec::new();
+    if !fm.notes.is_empty() {
+        let items = get_notes_from_section(content);
+        if !items.is_empty() {
+            let missing = items
+                .iter()
+                .filter(|n| !fm.notes.iter().any(|f| f.text == n.text))
+                .count();
+            shadowed.push(("Notes", missing));
+        }
+    }
+    if !fm.todo.is_empty() {
+        let items = get_todo_items_from_section(content);
+        if !items.is_empty() {
+            let missing = items
+                .iter()
+                .filter(|t| !fm.todo.iter().any(|f| f.text == t.text))
+                .count();
+            shadowed.push(("Todo", missing));
+        }
+    }
+    if !fm.log.is_empty() {
+        let items = get_log_entries_from_section(content);
+        if !items.is_empty() {
+            let missing = items
+                .iter()
+                .filter(|e| !fm.log.iter().any(|f| f.ts == e.ts && f.text == e.text))
+                .count();
+            shadowed.push(("Log", missing));
+        }
+    }
+    shadowed
+}
+
 /// Extract name from filename (after ID prefix)
 pub fn extract_name_from_path(path: &Path) -> String {
     let filename = path
@@ -623,15 +1425,27 @@ pub fn extract_name_from_path(path: &Path) -> String {
 // Status utilities
 // ============================================================================
 
-/// Strip reason suffix from status (e.g., "blocked (waiting)" -> "blocked")
+/// Strip reason suffix from status (e.g., "blocked (waiting)" -> "blocked").
+/// A missing space before the reason ("blocked(waiting)") is tolerated; W026 flags it.
 pub fn base_status(status: &str) -> String {
-    if let Some(idx) = status.find(" (") {
-        status[..idx].to_string()
+    if let Some(idx) = status.find('(') {
+        status[..idx].trim_end().to_string()
     } else {
         status.to_string()
     }
 }
 
+/// The canonical `status (reason)` form when the space before the reason is
+/// missing, e.g. "blocked(waiting)" -> "blocked (waiting)". None if already fine.
+pub fn spaced_status_reason(status: &str) -> Option<String> {
+    let idx = status.find('(')?;
+    let (base, reason) = status.split_at(idx);
+    if base.is_empty() || base.ends_with(' ') {
+        return None;
+    }
+    Some(format!("{} {}", base, reason))
+}
+
 /// Check if a status is closed (using default status lists)
 pub fn is_closed(status: &str) -> bool {
     let base = base_status(status);
@@ -662,6 +1476,128 @@ pub fn is_valid_status_with_config(
     open_statuses.iter().any(|s| s == &base) || closed_statuses.iter().any(|s| s == &base)
 }
 
+/// Check a status change against `behavior.transitions`.
+///
+/// Reasons are ignored on both sides. Keeping the same status is always
+/// allowed, as is any change when no map is configured or `from` has no entry.
+pub fn check_transition(
+    from: &str,
+    to: &str,
+    transitions: Option<&BTreeMap<String, Vec<String>>>,
+) -> Result<(), String> {
+    let (from, to) = (base_status(from), base_status(to));
+    let Some(allowed) = transitions.and_then(|m| m.get(&from)) else {
+        return Ok(());
+    };
+    if from == to || allowed.contains(&to) {
+        return Ok(());
+    }
+    if allowed.is_empty() {
+        Err(format!(
+            "cannot change status from '{}' to '{}': '{}' allows no transitions",
+            from, to, from
+        ))
+    } else {
+        Err(format!(
+            "cannot change status from '{}' to '{}': allowed next statuses are {}",
+            from,
+            to,
+            allowed.join(", ")
+        ))
+    }
+}
+
+/// Position of a priority in `PRIORITIES` (0 = critical), or None if unknown
+pub fn priority_rank(priority: &str) -> Option<usize> {
+    PRIORITIES.iter().position(|p| *p == priority)
+}
+
+// ============================================================================
+// Date utilities
+// ============================================================================
+
+/// The date one recurrence interval after `date`, or None for an unknown interval.
+/// Monthly recurrences land on `day` of the next month, clamped to the end of
+/// shorter months, so Jan 31 -> Feb 28 -> Mar 31 rather than drifting to Mar 28.
+pub fn next_occurrence(date: NaiveDate, every: &str, day: u32) -> Option<NaiveDate> {
+    match every {
+        "daily" => date.checked_add_days(Days::new(1)),
+        "weekly" => date.checked_add_days(Days::new(7)),
+        "monthly" => {
+            let first = date.with_day(1)?.checked_add_months(Months::new(1))?;
+            let last = first.checked_add_months(Months::new(1))?.pred_opt()?.day();
+            first.with_day(day.clamp(1, last))
+        }
+        _ => None,
+    }
+}
+
+/// Current local time in the RFC 3339 form stored in `created`/`updated`.
+pub fn now_timestamp() -> String {
+    format_timestamp(Local::now())
+}
+
+/// Format a time the way `created`/`updated` store it, e.g. "2026-01-15T09:30:00+01:00".
+pub fn format_timestamp(t: DateTime<Local>) -> String {
+    t.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+/// Parse a `created`/`updated` value into local time.
+pub fn parse_timestamp(s: &str) -> Option<DateTime<Local>> {
+    DateTime::parse_from_rfc3339(s.trim())
+        .ok()
+        .map(|t| t.with_timezone(&Local))
+}
+
+// ============================================================================
+// Hierarchy utilities
+// ============================================================================
+
+/// The cycle that making `parent` the parent of `child` would close, given the
+/// existing child -> parent map: e.g. [child, parent, ..., child]. None if acyclic.
+pub fn parent_cycle(
+    parents: &HashMap<String, String>,
+    child: &str,
+    parent: &str,
+) -> Option<Vec<String>> {
+    let mut chain = vec![child.to_string(), parent.to_string()];
+    let mut current = parent;
+    while current != child {
+        // Stop at the top of the chain, or at a cycle that does not involve `child`
+        let next = parents.get(current)?;
+        if chain[1..].contains(next) {
+            return None;
+        }
+        chain.push(next.clone());
+        current = next;
+    }
+    Some(chain)
+}
+
+// ============================================================================
+// Tag utilities
+// ============================================================================
+
+/// Normalize a tag to its stored form: lowercase kebab-case, so "Bug" and
+/// "bug" are the same tag.
+pub fn tag_slug(tag: &str) -> Result<String, String> {
+    let slug = crate::workspace::slugify(tag);
+    if slug.is_empty() {
+        return Err(format!("tag '{}' has no letters or digits", tag));
+    }
+    Ok(slug)
+}
+
+/// Parse a comma-separated tag filter (e.g. `--tag bug,ui`) into stored form.
+pub fn parse_tag_filter(filter: &str) -> Result<Vec<String>, String> {
+    filter
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(tag_slug)
+        .collect()
+}
+
 // ============================================================================
 // Hash generation
 // ============================================================================
@@ -804,7 +1740,13 @@ pub fn get_todo_items_from_section(content: &str) -> Vec<TodoItem> {
                 let text = text.trim().to_string();
                 let hash = hash_part.trim().trim_end_matches("-->").trim().to_string();
                 if !hash.is_empty() {
-                    items.push(TodoItem { text, hash, done });
+                    items.push(TodoItem {
+                        text,
+                        hash,
+                        done,
+                        due: None,
+                        parent: None,
+                    });
                 }
             }
         }
@@ -1059,6 +2001,66 @@ pub fn strip_old_sections(body: &str) -> String {
     }
 }
 
+/// Append `text` as a new paragraph at the end of the `## <heading>` section of `body`.
+///
+/// The section ends at the next level-1 or level-2 header outside a code fence. If no such
+/// heading exists, it is created at the end of the body.
+pub fn append_under_heading(body: &str, heading: &str, text: &str) -> String {
+    let header = format!("## {}", heading);
+    let lines: Vec<&str> = body.trim_start_matches('\n').trim_end().lines().collect();
+    let text = text.trim();
+
+    let mut in_fence = false;
+    let mut start = None;
+    let mut end = lines.len();
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        if start.is_none() {
+            if line.trim_end() == header {
+                start = Some(i);
+            }
+        } else if line.starts_with("## ") || line.starts_with("# ") {
+            end = i;
+            break;
+        }
+    }
+
+    let mut out: Vec<&str> = Vec::new();
+    match start {
+        Some(idx) => {
+            // Insert after the section's last non-blank line
+            let mut insert_at = end;
+            while insert_at > idx + 1 && lines[insert_at - 1].trim().is_empty() {
+                insert_at -= 1;
+            }
+            out.extend(&lines[..insert_at]);
+            out.push("");
+            out.extend(text.lines());
+            if end < lines.len() {
+                out.push("");
+                out.extend(&lines[end..]);
+            }
+        }
+        None => {
+            if !lines.is_empty() {
+                out.extend(&lines);
+                out.push("");
+            }
+            out.push(&header);
+            out.push("");
+            out.extend(text.lines());
+        }
+    }
+
+    out.join("\n") + "\n"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1070,6 +2072,7 @@ mod tests {
             ("abc123-my-thread.md", Some("abc123")),
             ("/path/to/abc123-my-thread.md", Some("abc123")),
             ("deadbe-another-one.md", Some("deadbe")),
+            ("abc123-deadbe-slug-of-hex.md", Some("abc123")), // renamed slugs keep the prefix
             ("no-id-here.md", None),
             ("ABC123-uppercase.md", None), // only lowercase hex
             ("ab123-too-short.md", None),  // need 6 chars
@@ -1108,6 +2111,96 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_priority() {
+        let mut t = Thread::new("abc123", "Test", "", "active", "").unwrap();
+        assert_eq!(t.get_priority(), None);
+        assert!(!t.content.contains("priority"));
+
+        t.set_priority(Some("High")).unwrap();
+        assert_eq!(t.get_priority(), Some("high"));
+        assert!(t.content.contains("priority: high\n"));
+
+        let err = t.set_priority(Some("urgent")).unwrap_err();
+        assert_eq!(
+            err,
+            "invalid priority 'urgent': use critical, high, medium, low"
+        );
+        assert_eq!(t.get_priority(), Some("high"));
+
+        t.set_priority(None).unwrap();
+        assert!(!t.content.contains("priority"));
+        assert_eq!(priority_rank("critical"), Some(0));
+        assert_eq!(priority_rank("low"), Some(3));
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut t = Thread::new("abc123", "Test", "", "active", "").unwrap();
+        assert_eq!(t.add_tag("Bug").unwrap(), ("bug".to_string(), true));
+        assert_eq!(t.add_tag("bug").unwrap(), ("bug".to_string(), false));
+        assert_eq!(t.add_tag("Needs Review").unwrap().0, "needs-review");
+        assert_eq!(t.tags(), ["bug", "needs-review"]);
+        assert!(t.content.contains("tags:\n- bug\n- needs-review\n"));
+
+        assert!(t.has_tags(&parse_tag_filter("BUG, needs review").unwrap()));
+        assert!(!t.has_tags(&parse_tag_filter("bug,ui").unwrap()));
+
+        assert_eq!(t.remove_tag("BUG").unwrap(), "bug");
+        assert_eq!(t.remove_tag("bug").unwrap_err(), "thread has no tag 'bug'");
+        assert_eq!(
+            t.add_tag("!!").unwrap_err(),
+            "tag '!!' has no letters or digits"
+        );
+    }
+
+    #[test]
+    fn test_links() {
+        let mut t = Thread::new("abc123", "Test", "", "active", "").unwrap();
+        assert!(t.add_link("blocked-by", "def456").unwrap());
+        assert!(!t.add_link("blocked-by", "def456").unwrap());
+        assert!(t.add_link("relates-to", "def456").unwrap());
+        assert!(
+            t.content
+                .contains("links:\n- kind: blocked-by\n  target: def456\n")
+        );
+        assert_eq!(
+            t.add_link("duplicates", "def456").unwrap_err(),
+            "unknown link kind 'duplicates': use blocked-by, relates-to"
+        );
+        assert_eq!(
+            t.add_link("relates-to", "abc123").unwrap_err(),
+            "a thread cannot link to itself"
+        );
+
+        assert_eq!(t.remove_links_to("def456").unwrap(), 2);
+        assert!(t.links().is_empty());
+        assert_eq!(
+            t.remove_links_to("def456").unwrap_err(),
+            "no link to 'def456'"
+        );
+    }
+
+    #[test]
+    fn test_parent_cycle() {
+        let parents: HashMap<String, String> = [("b", "a"), ("c", "b"), ("x", "y"), ("y", "x")]
+            .iter()
+            .map(|(c, p)| (c.to_string(), p.to_string()))
+            .collect();
+
+        assert_eq!(parent_cycle(&parents, "d", "c"), None);
+        assert_eq!(
+            parent_cycle(&parents, "a", "c"),
+            Some(vec!["a".into(), "c".into(), "b".into(), "a".into()])
+        );
+        assert_eq!(
+            parent_cycle(&parents, "a", "a"),
+            Some(vec!["a".into(), "a".into()])
+        );
+        // An existing cycle elsewhere does not loop forever
+        assert_eq!(parent_cycle(&parents, "d", "x"), None);
+    }
+
     #[test]
     fn test_base_status() {
         let cases = vec![
@@ -1115,6 +2208,7 @@ mod tests {
             ("blocked (waiting for review)", "blocked"),
             ("resolved (done)", "resolved"),
             ("paused (vacation)", "paused"),
+            ("blocked(no space)", "blocked"),
             ("idea", "idea"),
         ];
 
@@ -1128,6 +2222,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_check_transition() {
+        let map: BTreeMap<String, Vec<String>> = [
+            ("idea", vec!["planning", "active"]),
+            ("active", vec!["blocked", "resolved"]),
+            ("resolved", vec![]),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.into_iter().map(str::to_string).collect()))
+        .collect();
+
+        assert!(check_transition("idea", "resolved", None).is_ok());
+        assert!(check_transition("idea", "active", Some(&map)).is_ok());
+        assert!(check_transition("active (busy)", "blocked (review)", Some(&map)).is_ok());
+        assert!(check_transition("blocked", "idea", Some(&map)).is_ok());
+        assert!(check_transition("resolved", "resolved", Some(&map)).is_ok());
+
+        let err = check_transition("idea", "resolved", Some(&map)).unwrap_err();
+        assert!(err.contains("allowed next statuses are planning, active"));
+        let err = check_transition("resolved", "active", Some(&map)).unwrap_err();
+        assert!(err.contains("allows no transitions"));
+    }
+
+    #[test]
+    fn test_spaced_status_reason() {
+        assert_eq!(
+            spaced_status_reason("blocked(waiting)").as_deref(),
+            Some("blocked (waiting)")
+        );
+        assert_eq!(spaced_status_reason("blocked (waiting)"), None);
+        assert_eq!(spaced_status_reason("active"), None);
+        assert_eq!(spaced_status_reason("(waiting)"), None);
+    }
+
     #[test]
     fn test_is_closed() {
         let cases = vec![
@@ -1572,6 +2700,136 @@ status: active
         assert_eq!(t.frontmatter.todo[0].text, "Do something");
         assert!(!t.frontmatter.todo[0].done);
         assert_eq!(t.frontmatter.todo[0].hash, hash);
+        assert!(t.frontmatter.todo[0].due.is_none());
+    }
+
+    #[test]
+    fn test_set_todo_due_roundtrip() {
+        let content = r#"---
+id: abc123
+name: Test
+status: active
+---
+"#;
+
+        let mut t = make_thread_with_content(content);
+        let hash = t.add_todo_item("Ship it").expect("add_todo_item failed");
+        t.set_todo_due(&hash, Some("2026-03-01"))
+            .expect("set_todo_due failed");
+
+        assert!(t.content.contains("due: 2026-03-01"));
+        let reparsed = make_thread_with_content(&t.content);
+        assert_eq!(
+            reparsed.frontmatter.todo[0].due.as_deref(),
+            Some("2026-03-01")
+        );
+
+        t.set_todo_due(&hash, None).expect("set_todo_due failed");
+        assert!(!t.content.contains("due:"));
+    }
+
+    #[test]
+    fn test_move_todo_item_keeps_hashes() {
+        let content = r#"---
+id: abc123
+name: Test
+status: active
+---
+"#;
+
+        let mut t = make_thread_with_content(content);
+        let c = t.add_todo_item("third").expect("add_todo_item failed");
+        let b = t.add_todo_item("second").expect("add_todo_item failed");
+        let a = t.add_todo_item("first").expect("add_todo_item failed");
+        let order = |t: &Thread| -> Vec<String> {
+            t.frontmatter.todo.iter().map(|i| i.hash.clone()).collect()
+        };
+
+        t.move_todo_item(&a, TodoPlacement::Bottom).unwrap();
+        assert_eq!(order(&t), [b.clone(), c.clone(), a.clone()]);
+        t.move_todo_item(&a, TodoPlacement::Before(&c)).unwrap();
+        assert_eq!(order(&t), [b.clone(), a.clone(), c.clone()]);
+        t.move_todo_item(&b, TodoPlacement::After(&c)).unwrap();
+        assert_eq!(order(&t), [a.clone(), c.clone(), b.clone()]);
+        t.move_todo_item(&b, TodoPlacement::Top).unwrap();
+        assert_eq!(order(&t), [b.clone(), a.clone(), c.clone()]);
+
+        let reparsed = make_thread_with_content(&t.content);
+        assert_eq!(order(&reparsed), [b.clone(), a.clone(), c.clone()]);
+
+        assert!(t.move_todo_item(&a, TodoPlacement::After(&a)).is_err());
+        assert!(t.move_todo_item(&a, TodoPlacement::Before("zzzz")).is_err());
+        assert_eq!(order(&t), [b, a, c], "failed moves leave the order alone");
+    }
+
+    #[test]
+    fn test_todo_subtasks_tree_and_cycles() {
+        let content = r#"---
+id: abc123
+name: Test
+status: active
+---
+"#;
+
+        let mut t = make_thread_with_content(content);
+        let parent = t.add_todo_item("Release").expect("add_todo_item failed");
+        let child = t
+            .add_todo_item("Write notes")
+            .expect("add_todo_item failed");
+        let grandchild = t
+            .add_todo_item("Collect PRs")
+            .expect("add_todo_item failed");
+        t.set_todo_parent(&child, Some(&parent)).unwrap();
+        t.set_todo_parent(&grandchild, Some(&child)).unwrap();
+
+        let reparsed = make_thread_with_content(&t.content);
+        let items = reparsed.get_todo_items();
+        let tree: Vec<(usize, &str)> = todo_tree(&items)
+            .into_iter()
+            .map(|(d, i)| (d, i.text.as_str()))
+            .collect();
+        assert_eq!(
+            tree,
+            vec![(0, "Release"), (1, "Write notes"), (2, "Collect PRs")]
+        );
+
+        // Neither the item itself nor a descendant can become its parent
+        assert!(t.set_todo_parent(&parent, Some(&parent)).is_err());
+        assert!(t.set_todo_parent(&parent, Some(&grandchild)).is_err());
+
+        // Removing the middle item lifts its subtask to the grandparent
+        t.remove_by_hash("Todo", &child).unwrap();
+        let items = t.get_todo_items();
+        assert_eq!(items[0].parent.as_deref(), Some(parent.as_str()));
+    }
+
+    #[test]
+    fn test_todo_progress_rollup() {
+        let item = |hash: &str, done: bool, parent: Option<&str>| TodoItem {
+            text: hash.to_string(),
+            hash: hash.to_string(),
+            done,
+            due: None,
+            parent: parent.map(|p| p.to_string()),
+        };
+        let mut items = vec![
+            item("p", true, None),
+            item("c1", true, Some("p")),
+            item("c2", false, Some("p")),
+            item("q", false, None),
+        ];
+        assert_eq!(todo_progress(&items, false), (2, 4));
+        // "p" is checked but has an open subtask
+        assert_eq!(todo_progress(&items, true), (1, 4));
+
+        items[2].done = true;
+        items[0].done = false;
+        assert_eq!(todo_progress(&items, true), (3, 4));
+
+        // A cycle in a hand-edited file must not hang
+        items[0].parent = Some("c1".to_string());
+        assert_eq!(todo_tree(&items).len(), 4);
+        todo_progress(&items, true);
     }
 
     #[test]
@@ -1683,6 +2941,17 @@ notes:
         assert_eq!(t.count_matching_items("Notes", "zzzz"), 0);
     }
 
+    #[test]
+    fn test_frontmatter_yaml_matches_rebuild() {
+        let content = "---\nid: abc123\nname: Test\nstatus: active\n---\n\nBody here.\n";
+        let mut t = make_thread_with_content(content);
+        t.add_note("New note").expect("add_note failed");
+
+        let yaml = t.frontmatter_yaml().unwrap();
+        assert_eq!(&t.content[4..4 + yaml.len()], yaml);
+        assert!(t.content[4 + yaml.len()..].starts_with("---\n"));
+    }
+
     #[test]
     fn test_rebuild_content_updates_body_start() {
         let content = r#"---
@@ -1940,4 +3209,234 @@ Some content.
         let body = t.content[t.body_start..].trim();
         assert!(body.contains("Some content."), "Body content preserved");
     }
+
+    #[test]
+    fn test_append_under_heading_creates_section() {
+        let body = "\nIntro paragraph.\n";
+        let out = append_under_heading(body, "Design", "Use a queue.");
+        assert_eq!(out, "Intro paragraph.\n\n## Design\n\nUse a queue.\n");
+
+        let out = append_under_heading("", "Design", "Use a queue.");
+        assert_eq!(out, "## Design\n\nUse a queue.\n");
+    }
+
+    #[test]
+    fn test_append_under_heading_existing_section() {
+        let body = "## Design\n\nFirst idea.\n\n## Open questions\n\nWhy?\n";
+        let out = append_under_heading(body, "Design", "Second idea.");
+        assert_eq!(
+            out,
+            "## Design\n\nFirst idea.\n\nSecond idea.\n\n## Open questions\n\nWhy?\n"
+        );
+    }
+
+    #[test]
+    fn test_append_under_heading_ignores_fenced_headers() {
+        let body = "```\n## Design\n```\n";
+        let out = append_under_heading(body, "Design", "Real.");
+        assert_eq!(out, "```\n## Design\n```\n\n## Design\n\nReal.\n");
+    }
+
+    #[test]
+    fn test_validate_matches_file_checks() {
+        let content =
+            "---\nid: abc123\nname: Test\nstatus: wibble\n---\n\n## Notes\n\n- old note\n";
+        let mut t = make_thread_with_content(content);
+        t.path = "abc123-test.md".to_string();
+        let config = Config::default();
+
+        let mut expected = validate::validate_frontmatter(content, Path::new(&t.path), &config);
+        expected.extend(validate::validate_body(content, &config));
+
+        let issues = t.validate(&config);
+        assert_eq!(issues, expected);
+        let codes: Vec<&str> = issues.iter().map(|i| i.code.as_str()).collect();
+        assert_eq!(codes, vec!["E006", "W010"]);
+    }
+
+    #[test]
+    fn test_archive_log_before_roundtrip() {
+        let content = r#"---
+id: abc123
+name: Test
+status: active
+log:
+- ts: 2026-03-05 09:00:00
+  text: Recent work
+- ts: 2026-02-10 12:00:00
+  text: Second
+- ts: 2026-01-01 08:00:00
+  text: First
+---
+
+Body text.
+"#;
+
+        let mut t = make_thread_with_content(content);
+        let moved = t.archive_log_before("2026-03-01").expect("archive failed");
+        assert_eq!(moved, 2);
+
+        let reparsed = make_thread_with_content(&t.content);
+        assert_eq!(reparsed.frontmatter.log.len(), 1);
+        assert_eq!(reparsed.frontmatter.log[0].text, "Recent work");
+        assert_eq!(
+            reparsed.body().trim_start(),
+            "Body text.\n\n## Archived Log\n\n- [2026-01-01 08:00:00] First\n- [2026-02-10 12:00:00] Second\n"
+        );
+
+        // Nothing older left: no-op
+        let mut again = reparsed.clone();
+        assert_eq!(again.archive_log_before("2026-03-01").unwrap(), 0);
+        assert_eq!(again.content, reparsed.content);
+    }
+
+    #[test]
+    fn test_sort_log_newest_first() {
+        let entry = |ts: &str, text: &str| LogEntry {
+            ts: ts.to_string(),
+            text: text.to_string(),
+        };
+        let mut log = vec![
+            entry("2026-01-01 08:00:00", "old"),
+            entry("", "undated"),
+            entry("2026-03-01 08:00:00", "new"),
+            entry("2026-03-01 08:00:00", "new twin"),
+            entry("bogus", "garbled"),
+        ];
+        assert_eq!(first_log_out_of_order(&log), Some(2));
+
+        assert!(sort_log_newest_first(&mut log));
+        let texts: Vec<&str> = log.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, vec!["new", "undated", "new twin", "old", "garbled"]);
+        assert_eq!(first_log_out_of_order(&log), None);
+
+        // Already sorted: nothing moves
+        assert!(!sort_log_newest_first(&mut log));
+
+        // An unparseable timestamp is not an ordering problem
+        let log = vec![
+            entry("2026-01-01 08:00:00", "old"),
+            entry("bogus", "garbled"),
+        ];
+        assert_eq!(first_log_out_of_order(&log), None);
+    }
+
+    #[test]
+    fn test_parse_crlf_and_bom() {
+        let crlf = "---\r\nid: abc123\r\nname: Windows\r\nstatus: active\r\n---\r\n\r\nBody.\r\n";
+        let t = make_thread_with_content(crlf);
+        assert_eq!(t.name(), "Windows");
+        assert_eq!(t.body().trim(), "Body.");
+        assert!(!t.content.contains('\r'));
+
+        let bom = "\u{feff}---\nid: abc123\nname: Bom\nstatus: active\n---\n";
+        let t = make_thread_with_content(bom);
+        assert_eq!(t.name(), "Bom");
+        assert!(t.content.starts_with("---\n"));
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let d = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            next_occurrence(d("2026-02-28"), "daily", 28),
+            Some(d("2026-03-01"))
+        );
+        assert_eq!(
+            next_occurrence(d("2026-12-29"), "weekly", 29),
+            Some(d("2027-01-05"))
+        );
+        assert_eq!(
+            next_occurrence(d("2026-01-31"), "monthly", 31),
+            Some(d("2026-02-28"))
+        );
+        assert_eq!(
+            next_occurrence(d("2026-02-28"), "monthly", 31),
+            Some(d("2026-03-31"))
+        );
+        assert_eq!(next_occurrence(d("2026-01-31"), "yearly", 31), None);
+    }
+
+    #[test]
+    fn test_complete_monthly_deadline_keeps_anchor_day() {
+        let mut t = make_thread_with_content("---\nid: abc123\nname: T\nstatus: active\n---\n");
+        let hash = t
+            .add_deadline("2026-01-31", "Report", Some("monthly"))
+            .unwrap();
+        let mut dates = Vec::new();
+        for _ in 0..4 {
+            let (_, next) = t.complete_deadline(&hash).unwrap();
+            dates.push(next.unwrap());
+        }
+        assert_eq!(
+            dates,
+            ["2026-02-28", "2026-03-31", "2026-04-30", "2026-05-31"]
+        );
+        assert_eq!(t.frontmatter.deadlines[0].day, None);
+
+        t.complete_deadline(&hash).unwrap();
+        assert_eq!(t.frontmatter.deadlines[0].date, "2026-06-30");
+        assert_eq!(t.frontmatter.deadlines[0].day, Some(31));
+        assert!(t.content.contains("day: 31"));
+    }
+
+    #[test]
+    fn test_regenerate_duplicate_hashes() {
+        let mut t = make_thread_with_content(
+            "---\nid: abc123\nname: Dup\nstatus: active\ntodo:\n- text: One\n  hash: ab12\n  done: false\n- text: Two\n  hash: ab12\n  done: false\n- text: Three\n  hash: cd34\n  done: false\nnotes:\n- text: Fine\n  hash: ef56\n---\n",
+        );
+        assert_eq!(
+            duplicate_hashes(&t.frontmatter),
+            vec![("todo", vec!["ab12".to_string()])]
+        );
+
+        assert_eq!(regenerate_duplicate_hashes(&mut t.frontmatter), 1);
+        assert_eq!(t.frontmatter.todo[0].hash, "ab12");
+        assert_ne!(t.frontmatter.todo[1].hash, "ab12");
+        assert_ne!(t.frontmatter.todo[1].hash, "cd34");
+        assert!(duplicate_hashes(&t.frontmatter).is_empty());
+        assert_eq!(regenerate_duplicate_hashes(&mut t.frontmatter), 0);
+    }
+
+    #[test]
+    fn test_merge_legacy_sections() {
+        let mut t = make_thread_with_content(
+            "---\nid: abc123\nname: Mixed\nstatus: active\nnotes:\n- text: Kept\n  hash: ab12\n---\n\nIntro.\n\n## Notes\n\n- Kept  <!-- ab12 -->\n- Hidden  <!-- ab12 -->\n\n## Todo\n\n- [ ] Task  <!-- cd34 -->\n",
+        );
+        assert_eq!(
+            shadowed_sections(&t.frontmatter, &t.content),
+            vec![("Notes", 1)]
+        );
+
+        assert_eq!(t.merge_legacy_sections().unwrap(), 2);
+        let notes: Vec<&str> = t
+            .frontmatter
+            .notes
+            .iter()
+            .map(|n| n.text.as_str())
+            .collect();
+        assert_eq!(notes, vec!["Kept", "Hidden"]);
+        assert_ne!(t.frontmatter.notes[1].hash, "ab12");
+        assert_eq!(t.frontmatter.todo[0].text, "Task");
+        assert!(!t.body().contains("## Notes"));
+        assert!(!t.body().contains("## Todo"));
+        assert!(t.body().contains("Intro."));
+    }
+
+    #[test]
+    fn test_created_updated_timestamps() {
+        let mut t = Thread::new("abc123", "Fresh", "", "active", "").unwrap();
+        assert!(t.created_at().is_some());
+        assert!(t.updated_at().is_none());
+        t.rebuild_content().unwrap();
+        assert!(t.updated_at().is_some());
+        assert!(t.content.contains("\ncreated: "));
+
+        let legacy = make_thread_with_content("---\nid: abc123\nname: Old\nstatus: active\n---\n");
+        assert!(legacy.created_at().is_none());
+
+        let ts = parse_timestamp("2026-01-15T09:30:00+01:00").unwrap();
+        assert_eq!(ts.timestamp(), 1768465800);
+        assert!(parse_timestamp("2026-01-15 09:30:00").is_none());
+    }
 }
@@ -0,0 +1,86 @@
+//! Date arguments shared by commands: `YYYY-MM-DD`, or a number of days or
+//! weeks relative to today as `7d`/`2w`.
+
+use chrono::{Days, NaiveDate};
+
+/// Resolve a date argument, counting relative forms forward from `today`
+/// (e.g. `--due-before 7d` means within the next week).
+pub fn resolve_date(s: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    match parse(s)? {
+        Parsed::Date(date) => Ok(date),
+        Parsed::Days(n) => today
+            .checked_add_days(Days::new(n))
+            .ok_or_else(|| invalid(s)),
+    }
+}
+
+/// Resolve a date argument, counting relative forms back from `today`
+/// (e.g. `--since 7d` means the last week).
+pub fn resolve_past_date(s: &str, today: NaiveDate) -> Result<NaiveDate, String> {
+    match parse(s)? {
+        Parsed::Date(date) => Ok(date),
+        Parsed::Days(n) => today
+            .checked_sub_days(Days::new(n))
+            .ok_or_else(|| invalid(s)),
+    }
+}
+
+enum Parsed {
+    Date(NaiveDate),
+    Days(u64),
+}
+
+fn parse(s: &str) -> Result<Parsed, String> {
+    let s = s.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(Parsed::Date(date));
+    }
+    let unit = s.chars().last().ok_or_else(|| invalid(s))?;
+    let n: u64 = s[..s.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid(s))?;
+    let days = match unit {
+        'd' => n,
+        'w' => n.checked_mul(7).ok_or_else(|| invalid(s))?,
+        _ => return Err(invalid(s)),
+    };
+    Ok(Parsed::Days(days))
+}
+
+fn invalid(s: &str) -> String {
+    format!(
+        "invalid date '{}': use YYYY-MM-DD or a relative form like 7d or 2w",
+        s.trim()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_resolve_date() {
+        let today = d("2026-03-30");
+        assert_eq!(resolve_date("2026-05-01", today), Ok(d("2026-05-01")));
+        assert_eq!(resolve_date("7d", today), Ok(d("2026-04-06")));
+        assert_eq!(resolve_date("2w", today), Ok(d("2026-04-13")));
+        assert_eq!(resolve_date("0d", today), Ok(today));
+        assert!(resolve_date("2x", today).is_err());
+        assert!(resolve_date("d", today).is_err());
+        assert!(resolve_date("é", today).is_err());
+        assert!(resolve_date("2026-13-01", today).is_err());
+    }
+
+    #[test]
+    fn test_resolve_past_date() {
+        let today = d("2026-03-30");
+        assert_eq!(resolve_past_date("2026-01-01", today), Ok(d("2026-01-01")));
+        assert_eq!(resolve_past_date("7d", today), Ok(d("2026-03-23")));
+        assert_eq!(resolve_past_date("2w", today), Ok(d("2026-03-16")));
+        assert!(resolve_past_date("7", today).is_err());
+    }
+}
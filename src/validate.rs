@@ -0,0 +1,1090 @@
+//! Per-file validation checks.
+//!
+//! Each check inspects a single thread file and returns a list of issues.
+//! Cross-file checks (duplicate IDs) and the `threads validate` CLI live in
+//! `cmd::validate`, which orchestrates these checks over a set of files.
+//! `validate_manifest` checks a config manifest for `threads config validate`.
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+use crate::config::{self, Config, SeverityOverride};
+use crate::thread::{self, Frontmatter, extract_id_from_path};
+use crate::workspace;
+
+// ============================================================================
+// Regexes for validation
+// ============================================================================
+
+/// Matches a valid 6-character hex ID
+static VALID_ID_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9a-f]{6}$").unwrap());
+
+/// Matches section headers (## Name)
+static SECTION_HEADER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?m)^## (.+)$").unwrap());
+
+/// Legacy section names that must not appear in fully migrated threads.
+/// Finding any of these triggers W010.
+static LEGACY_SECTIONS: &[&str] = &["Body", "Notes", "Todo", "Log"];
+
+/// Matches log date headers (### YYYY-MM-DD) - legacy format to be removed
+pub(crate) static LOG_DATE_HEADER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^### (\d{4}-\d{2}-\d{2})$").unwrap());
+
+/// Matches current log format: - [YYYY-MM-DD HH:MM:SS] text
+pub(crate) static BRACKET_LOG_FORMAT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^- \[(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})\]").unwrap());
+
+/// Matches legacy bold log format: - **YYYY-MM-DD HH:MM:SS** text
+pub(crate) static BOLD_LOG_FORMAT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^- \*\*(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})\*\*").unwrap());
+
+/// Matches legacy time-only format: - **HH:MM** text (under date header)
+pub(crate) static TIME_ONLY_FORMAT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^- \*\*(\d{2}:\d{2})\*\*").unwrap());
+
+/// Matches todo checkbox line
+pub(crate) static TODO_CHECKBOX_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^- \[([ xX])\]").unwrap());
+
+/// Matches malformed checkbox (common mistakes)
+pub(crate) static MALFORMED_CHECKBOX_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^- \[([^\] ]|.{2,})\]").unwrap());
+
+/// Issue code descriptions
+pub fn issue_description(code: &str) -> &'static str {
+    match code {
+        "E000" => "Cannot read file",
+        "E001" => "Missing frontmatter",
+        "E002" => "Invalid YAML syntax",
+        "E003" => "Missing required field",
+        "E004" => "Invalid ID format",
+        "E005" => "ID mismatch with filename",
+        "E006" => "Invalid status value",
+        "E007" => "Duplicate ID across threads",
+        "E008" => "Invalid priority value",
+        "E009" => "Link to a thread that does not exist",
+        "E010" => "Duplicate item hash within a thread",
+        "W004" => "Old log format",
+        "W005" => "Invalid timestamp",
+        "W006" => "Malformed checkbox",
+        "W007" => "Log entry missing or legacy timestamp",
+        "W008" => "Legacy date header",
+        "W009" => "Filename missing ID prefix",
+        "W010" => "Legacy markdown section found",
+        "W011" => "Tag contains whitespace",
+        "W012" => "Parent thread does not exist",
+        "W013" => "Missing created timestamp",
+        "W014" => "Unknown deadline recurrence",
+        "W015" => "Legacy section hidden by frontmatter items",
+        "W019" => "Unknown frontmatter key (dropped on write)",
+        "W020" => "Empty thread",
+        "W021" => "Non-canonical encoding (BOM or CRLF)",
+        "W022" => "Filename does not match thread name",
+        "W023" => "Duplicate thread name",
+        "W024" => "Log entries out of chronological order",
+        "W025" => "Orphan hash comment in body",
+        "W026" => "Status reason without a space before '('",
+        "W027" => "Unknown config manifest key (ignored)",
+        _ => "Unknown issue",
+    }
+}
+
+// ============================================================================
+// Data Structures
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Issue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub severity: Severity,
+    pub code: String,
+    pub message: String,
+}
+
+impl Issue {
+    pub fn error(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            severity: Severity::Error,
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn error_at(code: &str, line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            severity: Severity::Error,
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn warning(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            severity: Severity::Warning,
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn warning_at(code: &str, line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            severity: Severity::Warning,
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+// ============================================================================
+// Checks
+// ============================================================================
+
+/// Apply `validate.severity` overrides: re-grade issues by code, dropping those set to `off`.
+pub fn apply_severity_overrides(issues: &mut Vec<Issue>, config: &Config) {
+    if config.validate.severity.is_empty() {
+        return;
+    }
+    issues.retain_mut(|issue| {
+        match config.validate.severity.get(&issue.code) {
+            Some(SeverityOverride::Off) => return false,
+            Some(SeverityOverride::Error) => issue.severity = Severity::Error,
+            Some(SeverityOverride::Warning) => issue.severity = Severity::Warning,
+            None => {}
+        }
+        true
+    });
+}
+
+/// Warn about a UTF-8 BOM or CRLF line endings in the raw file content.
+///
+/// Parsing normalizes both, so this only flags files that should be rewritten.
+pub fn validate_encoding(raw: &str) -> Option<Issue> {
+    let mut found = Vec::new();
+    if raw.starts_with('\u{feff}') {
+        found.push("UTF-8 BOM");
+    }
+    if raw.contains("\r\n") {
+        found.push("CRLF line endings");
+    }
+    if found.is_empty() {
+        return None;
+    }
+    Some(Issue::warning(
+        "W021",
+        format!("{} - normalize to LF without BOM", found.join(" and ")),
+    ))
+}
+
+/// Check frontmatter delimiters, YAML syntax, required fields, ID and status.
+pub fn validate_frontmatter(content: &str, path: &Path, config: &Config) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    // E001: Check for frontmatter delimiters
+    if !content.starts_with("---\n") {
+        issues.push(Issue::error_at("E001", 1, "missing frontmatter delimiter"));
+        return issues;
+    }
+
+    // Find closing delimiter
+    let rest = &content[4..];
+    let end = match rest.find("\n---") {
+        Some(e) => e,
+        None => {
+            issues.push(Issue::error(
+                "E001",
+                "unclosed frontmatter (missing closing ---)",
+            ));
+            return issues;
+        }
+    };
+
+    let yaml_content = &rest[..end];
+
+    // E002: Parse YAML
+    let fm: Frontmatter = match serde_yaml::from_str(yaml_content) {
+        Ok(fm) => fm,
+        Err(e) => {
+            let line = extract_yaml_error_line(&e);
+            if let Some(l) = line {
+                issues.push(Issue::error_at(
+                    "E002",
+                    l + 1,
+                    format!("invalid YAML: {}", e),
+                ));
+            } else {
+                issues.push(Issue::error("E002", format!("invalid YAML: {}", e)));
+            }
+            return issues;
+        }
+    };
+
+    // W019: Keys not in Frontmatter are silently lost on the next rebuild
+    issues.extend(validate_unknown_keys(yaml_content));
+
+    // E003: Check required fields
+    if fm.id.is_empty() {
+        issues.push(Issue::error("E003", "missing required field: id"));
+    }
+    if fm.name.is_empty() {
+        issues.push(Issue::error("E003", "missing required field: name"));
+    }
+    if fm.status.is_empty() {
+        issues.push(Issue::error("E003", "missing required field: status"));
+    }
+
+    // E004: Validate ID format
+    if !fm.id.is_empty() && !VALID_ID_RE.is_match(&fm.id) {
+        issues.push(Issue::error(
+            "E004",
+            format!("invalid ID format '{}' (expected 6 hex chars)", fm.id),
+        ));
+    }
+
+    // E005: Check ID matches filename
+    if !fm.id.is_empty()
+        && let Some(filename_id) = extract_id_from_path(path)
+        && fm.id != filename_id
+    {
+        issues.push(Issue::error(
+            "E005",
+            format!(
+                "ID mismatch: frontmatter has '{}', filename has '{}'",
+                fm.id, filename_id
+            ),
+        ));
+    }
+
+    // W009: Frontmatter has ID but filename has no ID prefix
+    if !fm.id.is_empty() && extract_id_from_path(path).is_none() {
+        issues.push(Issue::warning(
+            "W009",
+            format!("frontmatter id '{}' not reflected in filename", fm.id),
+        ));
+    }
+
+    // W022: Filename slug drifted from the name (e.g. after update --title)
+    if let Some(expected) = expected_slug(&fm.name)
+        && thread::extract_name_from_path(path) != expected
+    {
+        let id = if fm.id.is_empty() { "<id>" } else { &fm.id };
+        issues.push(Issue::warning(
+            "W022",
+            format!(
+                "filename does not match name (expected '{}') - run 'threads rename {} \"{}\"'",
+                expected, id, fm.name
+            ),
+        ));
+    }
+
+    // W024: Log should be newest first; imports and hand edits can break that
+    if let Some(i) = thread::first_log_out_of_order(&fm.log) {
+        let message = format!(
+            "log entry {} ({}) is newer than the one above it - run 'threads validate fix --w024'",
+            i + 1,
+            fm.log[i].ts
+        );
+        issues.push(match log_entry_line(yaml_content, i) {
+            Some(l) => Issue::warning_at("W024", l, message),
+            None => Issue::warning("W024", message),
+        });
+    }
+
+    // W025: Hash comments left in the body by a partial migration
+    let body_line = content[..4 + end + 4].matches('\n').count() + 1;
+    for (i, hash) in thread::orphan_hash_comments(&fm, &rest[end + 4..]) {
+        issues.push(Issue::warning_at(
+            "W025",
+            body_line + i,
+            format!(
+                "orphan hash comment '{}' matches no item - run 'threads validate fix --w025'",
+                hash
+            ),
+        ));
+    }
+
+    // W026: "blocked(reason)" instead of "blocked (reason)"
+    if let Some(spaced) = thread::spaced_status_reason(&fm.status) {
+        let message = format!(
+            "status '{}' should be '{}' - run 'threads validate fix --w026'",
+            fm.status, spaced
+        );
+        let line = yaml_content
+            .lines()
+            .position(|l| l.starts_with("status:"))
+            .map(|i| i + 2);
+        issues.push(match line {
+            Some(l) => Issue::warning_at("W026", l, message),
+            None => Issue::warning("W026", message),
+        });
+    }
+
+    // E006: Validate status using config status lists
+    if !fm.status.is_empty()
+        && !thread::is_valid_status_with_config(
+            &fm.status,
+            &config.status.open,
+            &config.status.closed,
+        )
+    {
+        let base = thread::base_status(&fm.status);
+        issues.push(Issue::error("E006", format!("invalid status '{}'", base)));
+    }
+
+    // W011: Tags are written as slugs; whitespace means a hand edit
+    for tag in fm.tags.iter().filter(|t| t.contains(char::is_whitespace)) {
+        issues.push(Issue::warning(
+            "W011",
+            format!("tag '{}' contains whitespace", tag),
+        ));
+    }
+
+    // W014: `deadline done` can only advance known intervals
+    for d in &fm.deadlines {
+        if let Some(every) = &d.every
+            && !thread::RECURRENCES.contains(&every.as_str())
+        {
+            issues.push(Issue::warning(
+                "W014",
+                format!(
+                    "deadline '{}' repeats every '{}' (expected {})",
+                    d.text,
+                    every,
+                    thread::RECURRENCES.join(", ")
+                ),
+            ));
+        }
+    }
+
+    // E010: items of one kind sharing a hash; edits would only reach the first
+    for (kind, hashes) in thread::duplicate_hashes(&fm) {
+        issues.push(Issue::error(
+            "E010",
+            format!(
+                "duplicate {} hashes: {} (regenerate with 'validate fix --e010')",
+                kind,
+                hashes.join(", ")
+            ),
+        ));
+    }
+
+    // W015: frontmatter items win, so a matching legacy section is silently ignored
+    for (section, missing) in thread::shadowed_sections(&fm, content) {
+        let header = format!("## {}", section);
+        let line = content.lines().position(|l| l.trim_end() == header);
+        let message = format!(
+            "'{}' section is ignored because frontmatter has {} ({} items only in the section; merge with 'validate fix --w015')",
+            header,
+            section.to_lowercase(),
+            missing
+        );
+        issues.push(match line {
+            Some(i) => Issue::warning_at("W015", i + 1, message),
+            None => Issue::warning("W015", message),
+        });
+    }
+
+    // E008: Priority must be one of the known levels
+    if let Some(priority) = &fm.priority
+        && thread::priority_rank(priority).is_none()
+    {
+        issues.push(Issue::error(
+            "E008",
+            format!(
+                "invalid priority '{}' (expected {})",
+                priority,
+                thread::PRIORITIES.join(", ")
+            ),
+        ));
+    }
+
+    // W013: Threads predating the `created` field; `validate fix --w013` backfills it
+    if config.behavior.flag_missing_created && fm.created.is_none() {
+        issues.push(Issue::warning(
+            "W013",
+            "no 'created' timestamp (backfill with 'validate fix --w013')",
+        ));
+    }
+
+    // W020: Open stub thread with nothing beyond the creation log entry
+    if config.behavior.flag_empty_threads
+        && !fm.status.is_empty()
+        && !thread::is_closed_with_config(&fm.status, &config.status.closed)
+        && is_empty_thread(&fm, &rest[end + 4..])
+    {
+        issues.push(Issue::warning(
+            "W020",
+            "empty thread (no body, notes, or todos) - add content or remove it",
+        ));
+    }
+
+    issues
+}
+
+/// Filename slug for a thread name, or None when the name has no sluggable characters.
+pub fn expected_slug(name: &str) -> Option<String> {
+    let slug = workspace::slugify(name);
+    if slug.is_empty() { None } else { Some(slug) }
+}
+
+/// A thread is empty when it has no body, no items, and only the auto-generated creation log.
+fn is_empty_thread(fm: &Frontmatter, body: &str) -> bool {
+    body.trim().is_empty()
+        && fm.notes.is_empty()
+        && fm.todo.is_empty()
+        && fm.deadlines.is_empty()
+        && fm.events.is_empty()
+        && fm.log.iter().all(|e| e.text.starts_with("Created thread"))
+}
+
+/// File line of the `index`-th entry in the frontmatter `log:` list.
+fn log_entry_line(yaml_content: &str, index: usize) -> Option<usize> {
+    let lines: Vec<&str> = yaml_content.lines().collect();
+    let start = lines.iter().position(|l| l.trim_end() == "log:")?;
+    lines[start + 1..]
+        .iter()
+        .take_while(|l| l.starts_with(' ') || l.starts_with('-'))
+        .enumerate()
+        .filter(|(_, l)| l.starts_with("- "))
+        .nth(index)
+        // Line 1 is the opening delimiter, so YAML line N is file line N + 1
+        .map(|(i, _)| start + 1 + i + 2)
+}
+
+/// Warn about top-level frontmatter keys that `Frontmatter` does not know about.
+fn validate_unknown_keys(yaml_content: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    let map: serde_yaml::Mapping = match serde_yaml::from_str(yaml_content) {
+        Ok(m) => m,
+        Err(_) => return issues,
+    };
+
+    for key in map.keys() {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        if thread::FRONTMATTER_KEYS.contains(&key) {
+            continue;
+        }
+        // Line 1 is the opening delimiter, so YAML line N is file line N + 1
+        let prefix = format!("{}:", key);
+        let line = yaml_content
+            .lines()
+            .position(|l| l.starts_with(&prefix))
+            .map(|i| i + 2);
+        let message = format!("unknown frontmatter key '{}' (lost on next write)", key);
+        issues.push(match line {
+            Some(l) => Issue::warning_at("W019", l, message),
+            None => Issue::warning("W019", message),
+        });
+    }
+
+    issues
+}
+
+fn extract_yaml_error_line(e: &serde_yaml::Error) -> Option<usize> {
+    e.location().map(|loc| loc.line())
+}
+
+// ============================================================================
+// Config manifest checks
+// ============================================================================
+
+/// Check a config manifest (`manifest.yaml` or the user config).
+///
+/// `load_manifest` drops an unparseable file to defaults without a word, and
+/// serde ignores keys it does not know; this reports both. Syntax and type
+/// errors are E002, keys missing from the config schema are W027.
+pub fn validate_manifest(content: &str) -> Vec<Issue> {
+    let doc: serde_yaml::Value = match serde_yaml::from_str(content) {
+        Ok(doc) => doc,
+        Err(e) => return vec![yaml_issue("invalid YAML", &e)],
+    };
+
+    let mut issues = Vec::new();
+    if let Err(e) = serde_yaml::from_str::<Config>(content) {
+        issues.push(yaml_issue("invalid value", &e));
+    }
+
+    let schema: serde_json::Value =
+        serde_json::from_str(&config::json_schema()).unwrap_or_default();
+    let mut unknown = Vec::new();
+    collect_unknown_config_keys(&doc, &schema, &schema, "", &mut unknown);
+    for key in unknown {
+        let leaf = key.rsplit('.').next().unwrap_or(&key);
+        let prefix = format!("{}:", leaf);
+        let top_level = !key.contains('.');
+        let line = content
+            .lines()
+            .position(|l| {
+                let trimmed = l.trim_start();
+                trimmed.starts_with(&prefix) && (l.len() == trimmed.len()) == top_level
+            })
+            .map(|i| i + 1);
+        let message = format!("unknown config key '{}' (ignored)", key);
+        issues.push(match line {
+            Some(l) => Issue::warning_at("W027", l, message),
+            None => Issue::warning("W027", message),
+        });
+    }
+
+    issues
+}
+
+fn yaml_issue(what: &str, e: &serde_yaml::Error) -> Issue {
+    let message = format!("{}: {}", what, e);
+    match extract_yaml_error_line(e) {
+        Some(l) => Issue::error_at("E002", l, message),
+        None => Issue::error("E002", message),
+    }
+}
+
+/// Walk `value` alongside its schema `node`, recording dotted keys the schema
+/// does not define. Profiles are full configs, so they are checked against the root.
+fn collect_unknown_config_keys(
+    value: &serde_yaml::Value,
+    node: &serde_json::Value,
+    root: &serde_json::Value,
+    prefix: &str,
+    out: &mut Vec<String>,
+) {
+    let Some(map) = value.as_mapping() else {
+        return;
+    };
+    let Some(properties) = resolve_schema(node, root)
+        .get("properties")
+        .and_then(|p| p.as_object())
+    else {
+        return;
+    };
+
+    for (key, child) in map {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        let path = format!("{}{}", prefix, key);
+        if prefix.is_empty() && key == "profiles" {
+            for (name, profile) in child.as_mapping().into_iter().flatten() {
+                if let Some(name) = name.as_str() {
+                    let profile_prefix = format!("profiles.{}.", name);
+                    collect_unknown_config_keys(profile, root, root, &profile_prefix, out);
+                }
+            }
+            continue;
+        }
+        match properties.get(key) {
+            Some(child_node) => {
+                collect_unknown_config_keys(child, child_node, root, &format!("{}.", path), out)
+            }
+            None => out.push(path),
+        }
+    }
+}
+
+/// Follow `$ref`, `allOf` and `anyOf` wrappers to the schema that lists properties.
+fn resolve_schema<'a>(
+    node: &'a serde_json::Value,
+    root: &'a serde_json::Value,
+) -> &'a serde_json::Value {
+    let mut node = node;
+    for _ in 0..8 {
+        if let Some(name) = node
+            .get("$ref")
+            .and_then(|r| r.as_str())
+            .and_then(|r| r.strip_prefix("#/definitions/"))
+        {
+            match root.get("definitions").and_then(|d| d.get(name)) {
+                Some(def) => node = def,
+                None => break,
+            }
+        } else if let Some(inner) = ["allOf", "anyOf"]
+            .iter()
+            .filter_map(|k| node.get(*k).and_then(|v| v.as_array()))
+            .flatten()
+            .find(|v| v.get("$ref").is_some() || v.get("properties").is_some())
+        {
+            node = inner;
+        } else {
+            break;
+        }
+    }
+    node
+}
+
+/// Check the markdown below the frontmatter: legacy sections, log entries, and todos.
+pub fn validate_body(content: &str, config: &Config) -> Vec<Issue> {
+    let mut issues = validate_sections(content, config);
+    issues.extend(validate_log(content));
+    issues.extend(validate_todos(content));
+    issues
+}
+
+fn validate_sections(content: &str, _config: &Config) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for (line_num, line) in content.lines().enumerate() {
+        if let Some(caps) = SECTION_HEADER_RE.captures(line) {
+            let section = caps.get(1).unwrap().as_str();
+            let line_display = line_num + 1;
+
+            // W010: Any legacy section name found means the file needs migration.
+            // Non-legacy ## headers in body content are fine and are ignored.
+            if LEGACY_SECTIONS.contains(&section) {
+                issues.push(Issue::warning_at(
+                    "W010",
+                    line_display,
+                    format!(
+                        "legacy section '## {}' found — run 'threads migrate'",
+                        section
+                    ),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+fn validate_log(content: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut in_log_section = false;
+    let mut has_date_header = false;
+
+    for (line_num, line) in content.lines().enumerate() {
+        let line_display = line_num + 1;
+
+        if line.starts_with("## ") {
+            in_log_section = line.starts_with("## Log");
+            has_date_header = false;
+            continue;
+        }
+
+        if !in_log_section {
+            continue;
+        }
+
+        // W008: Legacy date headers should be removed (dates go in entries)
+        if LOG_DATE_HEADER_RE.is_match(line) {
+            has_date_header = true;
+            issues.push(Issue::warning_at(
+                "W008",
+                line_display,
+                "legacy date header - run 'validate fix --w007' to migrate",
+            ));
+            continue;
+        }
+
+        // Check log entries (lines starting with "- ")
+        if line.starts_with("- ") {
+            let entry_content = line.strip_prefix("- ").unwrap_or(line);
+
+            // Skip continuation lines (bold labels, table rows, etc.)
+            if is_non_log_list_item(entry_content) {
+                continue;
+            }
+
+            // Current format: - [YYYY-MM-DD HH:MM:SS] text
+            if line.starts_with("- [") {
+                if BRACKET_LOG_FORMAT_RE.is_match(line) {
+                    // Valid current format, check timestamp validity
+                    if let Some(caps) = BRACKET_LOG_FORMAT_RE.captures(line) {
+                        let ts = &caps[1];
+                        if !is_valid_timestamp(ts) {
+                            issues.push(Issue::warning_at(
+                                "W005",
+                                line_display,
+                                format!("invalid timestamp '{}'", ts),
+                            ));
+                        }
+                    }
+                } else {
+                    // Has brackets but not a valid timestamp - might be malformed
+                    issues.push(Issue::warning_at(
+                        "W007",
+                        line_display,
+                        "log entry missing timestamp",
+                    ));
+                }
+            } else if line.starts_with("- **") {
+                // Legacy bold formats
+                if BOLD_LOG_FORMAT_RE.is_match(line) {
+                    issues.push(Issue::warning_at(
+                        "W007",
+                        line_display,
+                        "legacy bold timestamp - run 'validate fix --w007' to migrate",
+                    ));
+                } else if TIME_ONLY_FORMAT_RE.is_match(line) {
+                    if has_date_header {
+                        issues.push(Issue::warning_at(
+                            "W007",
+                            line_display,
+                            "legacy time-only format - run 'validate fix --w007' to migrate",
+                        ));
+                    } else {
+                        issues.push(Issue::warning_at(
+                            "W004",
+                            line_display,
+                            "time-only format without date header",
+                        ));
+                    }
+                }
+                // Note: Bold text that isn't a timestamp is handled by is_non_log_list_item above
+            } else {
+                // Plain list item without any timestamp
+                issues.push(Issue::warning_at(
+                    "W007",
+                    line_display,
+                    "log entry missing timestamp",
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+fn validate_todos(content: &str) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut in_todo_section = false;
+
+    for (line_num, line) in content.lines().enumerate() {
+        let line_display = line_num + 1;
+
+        if line.starts_with("## ") {
+            in_todo_section = line.starts_with("## Todo");
+            continue;
+        }
+
+        if !in_todo_section {
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if line.starts_with("- [") {
+            if TODO_CHECKBOX_RE.is_match(line) {
+                continue;
+            }
+
+            if MALFORMED_CHECKBOX_RE.is_match(line) {
+                issues.push(Issue::warning_at(
+                    "W006",
+                    line_display,
+                    "malformed checkbox (use '- [ ]' or '- [x]')",
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+fn is_valid_timestamp(ts: &str) -> bool {
+    chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S").is_ok()
+}
+
+/// Check if a list item content looks like code/formatting rather than a log entry
+pub fn is_non_log_list_item(content: &str) -> bool {
+    let trimmed = content.trim();
+
+    // Code fence
+    if trimmed.starts_with("```") {
+        return true;
+    }
+
+    // Shell command (likely inside code block)
+    if trimmed.starts_with("$ ") || (trimmed.starts_with("# ") && !trimmed.starts_with("## ")) {
+        return true;
+    }
+
+    // Markdown header inside list item
+    if trimmed.starts_with("### ") || trimmed.starts_with("#### ") {
+        return true;
+    }
+
+    // Lines that are clearly continuations (start with common code patterns)
+    if trimmed.starts_with("git ") || trimmed.starts_with("cd ") || trimmed.starts_with("./") {
+        return true;
+    }
+
+    // Table rows (markdown tables)
+    if trimmed.starts_with('|') {
+        return true;
+    }
+
+    // Bold text that is NOT a timestamp (continuation headers like "**Results:**")
+    // Timestamps look like **YYYY-MM-DD or **HH:MM** - other bold is content
+    if let Some(after_bold) = trimmed.strip_prefix("**") {
+        // Check if it's NOT a timestamp pattern
+        // Timestamp patterns: YYYY-MM-DD or HH:MM
+        let is_date = after_bold.len() >= 10
+            && after_bold.chars().take(4).all(|c| c.is_ascii_digit())
+            && after_bold.chars().nth(4) == Some('-');
+        let is_time = after_bold.len() >= 5
+            && after_bold.chars().take(2).all(|c| c.is_ascii_digit())
+            && after_bold.chars().nth(2) == Some(':');
+        if !is_date && !is_time {
+            return true;
+        }
+    }
+
+    // "See X", "Note:", etc. - common continuation patterns
+    if trimmed.starts_with("See ") || trimmed.starts_with("Note:") {
+        return true;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "abc123-test.md";
+
+    fn codes(issues: &[Issue]) -> Vec<(&str, Option<usize>)> {
+        issues.iter().map(|i| (i.code.as_str(), i.line)).collect()
+    }
+
+    #[test]
+    fn test_clean_thread_has_no_issues() {
+        let content = "---\nid: abc123\nname: Test\nstatus: active\nlog:\n- ts: 2026-01-02 10:00:00\n  text: Created thread.\n---\n\nBody text.\n";
+        let config = Config::default();
+
+        let issues = validate_frontmatter(content, Path::new(PATH), &config);
+        assert!(issues.is_empty());
+        assert!(validate_body(content, &config).is_empty());
+    }
+
+    #[test]
+    fn test_frontmatter_issues() {
+        let content = "---\nid: abc123\nname: Test\nstatus: wibble\ncolour: red\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("W019", Some(5)), ("E006", None)]);
+
+        let content =
+            "---\nid: abc123\nname: Test\nstatus: active\npriority: urgent\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("E008", None)]);
+
+        let content = "---\nid: abc123\nname: Test\nstatus: active\ntags:\n- ok\n- needs review\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("W011", None)]);
+
+        let content = "---\nid: abc123\nname: Test\nstatus: active\ndeadlines:\n- date: 2026-03-01\n  text: Report\n  hash: ab12\n  every: fortnightly\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("W014", None)]);
+
+        let content = "---\nid: abc123\nname: Test\nstatus: active\nnotes:\n- text: A\n  hash: ab12\n- text: B\n  hash: ab12\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("E010", None)]);
+        assert!(issues[0].message.contains("notes hashes: ab12"));
+
+        let issues = validate_frontmatter("no frontmatter\n", Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("E001", Some(1))]);
+
+        let issues = validate_frontmatter(
+            "---\nid: def456\nname: Test\nstatus: active\n---\n\nText.\n",
+            Path::new(PATH),
+            &Config::default(),
+        );
+        assert_eq!(codes(&issues), vec![("E005", None)]);
+    }
+
+    #[test]
+    fn test_legacy_body_issues() {
+        let content = r#"---
+id: abc123
+name: Legacy
+status: active
+---
+
+## Body
+
+Text.
+
+## Todo
+
+- [ ] fine
+- [-] bad
+
+## Log
+
+### 2026-01-02
+
+- **10:00** time only
+- **2026-01-02 10:00:00** bold
+- plain entry
+- [2026-13-02 10:00:00] bad ts
+"#;
+        let issues = validate_body(content, &Config::default());
+        assert_eq!(
+            codes(&issues),
+            vec![
+                ("W010", Some(7)),
+                ("W010", Some(11)),
+                ("W010", Some(16)),
+                ("W008", Some(18)),
+                ("W007", Some(20)),
+                ("W007", Some(21)),
+                ("W007", Some(22)),
+                ("W005", Some(23)),
+                ("W006", Some(14)),
+            ]
+        );
+        assert!(issues.iter().all(|i| i.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_empty_thread_warning() {
+        let empty = "---\nid: abc123\nname: Test\nstatus: idea\nlog:\n- ts: 2026-01-02 10:00:00\n  text: Created thread.\n---\n";
+        let mut config = Config::default();
+
+        let issues = validate_frontmatter(empty, Path::new(PATH), &config);
+        assert_eq!(codes(&issues), vec![("W020", None)]);
+
+        // Closed threads are not flagged
+        let closed = empty.replace("status: idea", "status: resolved");
+        let issues = validate_frontmatter(&closed, Path::new(PATH), &config);
+        assert!(issues.is_empty());
+
+        // Any content beyond the creation entry clears the warning
+        let with_body = format!("{}\nSome text.\n", empty);
+        let issues = validate_frontmatter(&with_body, Path::new(PATH), &config);
+        assert!(issues.is_empty());
+
+        config.behavior.flag_empty_threads = false;
+        let issues = validate_frontmatter(empty, Path::new(PATH), &config);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_log_out_of_order() {
+        let content = "---\nid: abc123\nname: Test\nstatus: active\nlog:\n- ts: 2026-01-01 08:00:00\n  text: Old\n- ts: 2026-02-01 08:00:00\n  text: New\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("W024", Some(8))]);
+        assert!(issues[0].message.contains("log entry 2"));
+    }
+
+    #[test]
+    fn test_severity_overrides() {
+        let mut config = Config::default();
+        config
+            .validate
+            .severity
+            .insert("W020".to_string(), SeverityOverride::Error);
+        config
+            .validate
+            .severity
+            .insert("W024".to_string(), SeverityOverride::Off);
+
+        let mut issues = vec![
+            Issue::warning("W020", "empty"),
+            Issue::warning_at("W024", 8, "order"),
+            Issue::warning("W022", "name"),
+        ];
+        apply_severity_overrides(&mut issues, &config);
+
+        assert_eq!(codes(&issues), vec![("W020", None), ("W022", None)]);
+        assert_eq!(issues[0].severity, Severity::Error);
+        assert_eq!(issues[1].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_orphan_hash_comments() {
+        let content = "---\nid: abc123\nname: Test\nstatus: active\nnotes:\n- text: Kept\n  hash: ab12\n---\n\nKept <!-- ab12 -->\nStale <!-- cd34 -->\n\n## Notes\n- Legacy <!-- ef56 -->\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        // The legacy Notes section is hidden by the frontmatter notes (W015)
+        assert_eq!(codes(&issues), vec![("W025", Some(11)), ("W015", Some(13))]);
+        assert!(issues[0].message.contains("'cd34'"));
+
+        let parsed: Frontmatter =
+            serde_yaml::from_str("id: abc123\nnotes:\n- text: Kept\n  hash: ab12\n").unwrap();
+        let (body, removed) = thread::strip_orphan_hash_comments(
+            &parsed,
+            "\nKept <!-- ab12 -->\nStale <!-- cd34 -->\n<!-- cd34 -->\n",
+        );
+        assert_eq!(removed, 2);
+        assert_eq!(body, "\nKept <!-- ab12 -->\nStale\n");
+    }
+
+    #[test]
+    fn test_status_reason_without_space() {
+        let content = "---\nid: abc123\nname: Test\nstatus: blocked(waiting)\n---\n\nText.\n";
+        let issues = validate_frontmatter(content, Path::new(PATH), &Config::default());
+        assert_eq!(codes(&issues), vec![("W026", Some(4))]);
+    }
+
+    #[test]
+    fn test_filename_name_mismatch() {
+        let content = "---\nid: abc123\nname: Renamed Thread\nstatus: active\n---\n\nText.\n";
+        let config = Config::default();
+
+        let issues = validate_frontmatter(content, Path::new(PATH), &config);
+        assert_eq!(codes(&issues), vec![("W022", None)]);
+        assert!(issues[0].message.contains("'renamed-thread'"));
+
+        let issues = validate_frontmatter(content, Path::new("abc123-renamed-thread.md"), &config);
+        assert!(issues.is_empty());
+
+        // Names without sluggable characters are not flagged
+        let symbols = content.replace("Renamed Thread", "'???'");
+        let issues = validate_frontmatter(&symbols, Path::new(PATH), &config);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_encoding_warning() {
+        assert!(validate_encoding("---\nid: abc123\n---\n").is_none());
+
+        let issue = validate_encoding("\u{feff}---\r\nid: abc123\r\n---\r\n").unwrap();
+        assert_eq!(issue.code, "W021");
+        assert!(issue.message.contains("BOM"));
+        assert!(issue.message.contains("CRLF"));
+    }
+
+    #[test]
+    fn test_validate_manifest() {
+        assert!(validate_manifest("defaults:\n  new: planning\n").is_empty());
+
+        let content = "behavior:\n  quiet: maybe\n  quite: true\ncolour: red\n";
+        assert_eq!(
+            codes(&validate_manifest(content)),
+            vec![("E002", Some(2)), ("W027", Some(3)), ("W027", Some(4))]
+        );
+
+        let profile = "profiles:\n  solo:\n    defaults:\n      neww: idea\n";
+        let issues = validate_manifest(profile);
+        assert_eq!(codes(&issues), vec![("W027", Some(4))]);
+        assert!(issues[0].message.contains("profiles.solo.defaults.neww"));
+
+        assert_eq!(codes(&validate_manifest("a: [\n")), vec![("E002", Some(2))]);
+    }
+}
@@ -80,6 +80,22 @@ pub fn find() -> Result<PathBuf, String> {
     find_git_root()
 }
 
+/// Switch into the git repository containing `path` and return its root.
+///
+/// Used by `--repo`: the process CWD becomes the repo root, so scope resolution,
+/// config loading, and git operations all target that repository.
+pub fn enter_repo(path: &Path) -> Result<PathBuf, String> {
+    if !path.is_dir() {
+        return Err(format!("--repo '{}' is not a directory", path.display()));
+    }
+    let repo = Repository::discover(path)
+        .map_err(|_| format!("--repo '{}' is not inside a git repository", path.display()))?;
+    let root = git_root(&repo);
+    std::env::set_current_dir(&root)
+        .map_err(|e| format!("cannot enter {}: {}", root.display(), e))?;
+    Ok(root)
+}
+
 /// Find the git repository root using git2.
 pub fn find_git_root() -> Result<PathBuf, String> {
     let repo = open()?;
@@ -156,6 +172,37 @@ fn find_threads_recursive(
     Ok(())
 }
 
+/// Find all archived thread files (`.threads/archive/*.md`) within the git root.
+/// Mirrors `find_all_threads`, which skips them.
+pub fn find_archived_threads(git_root: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut threads = Vec::new();
+    find_archived_recursive(git_root, git_root, &mut threads);
+    threads.sort();
+    threads.dedup();
+    Ok(threads)
+}
+
+fn find_archived_recursive(dir: &Path, git_root: &Path, threads: &mut Vec<PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir.join(".threads").join("archive")) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "md") {
+                threads.push(path.canonicalize().unwrap_or(path));
+            }
+        }
+    }
+
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if path.is_dir() && !hidden && (path == git_root || !is_git_root(&path)) {
+                find_archived_recursive(&path, git_root, threads);
+            }
+        }
+    }
+}
+
 /// Find threads with options for direction controls.
 /// This is the primary search function supporting --up and --down flags.
 /// Traversal always stops at git boundaries (nested repos when going down, git root when going up).
@@ -508,11 +555,14 @@ pub fn slugify(title: &str) -> String {
 
 /// Find a thread by ID or name (with fuzzy matching).
 pub fn find_by_ref(git_root: &Path, ref_str: &str) -> Result<PathBuf, String> {
-    let threads = find_all_threads(git_root)?;
+    match_ref(&find_all_threads(git_root)?, ref_str)
+}
 
+/// Pick the thread a reference names from a list of thread files.
+pub fn match_ref(threads: &[PathBuf], ref_str: &str) -> Result<PathBuf, String> {
     // Fast path: exact ID match
     if ID_ONLY_RE.is_match(ref_str) {
-        for t in &threads {
+        for t in threads {
             if thread::extract_id_from_path(t).as_deref() == Some(ref_str) {
                 return Ok(t.clone());
             }
@@ -523,7 +573,7 @@ pub fn find_by_ref(git_root: &Path, ref_str: &str) -> Result<PathBuf, String> {
     let ref_lower = ref_str.to_lowercase();
     let mut substring_matches = Vec::new();
 
-    for t in &threads {
+    for t in threads {
         let name = thread::extract_name_from_path(t);
 
         // Exact name match
@@ -64,6 +64,105 @@ pub fn wrap_with_prefix(prefix: &str, content: &str, width: usize) -> Vec<String
         .collect()
 }
 
+/// Reflow markdown prose paragraphs to `width` columns.
+///
+/// Only plain paragraphs are rewrapped. Fenced code blocks, headers, tables,
+/// list items, block quotes, HTML comments, rules, and indented lines are kept
+/// verbatim. Hard line breaks (trailing two spaces) are preserved.
+pub fn reflow_markdown(text: &str, width: usize) -> String {
+    let mut out: Vec<String> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+
+        if let Some(marker) = fence {
+            out.push(line.to_string());
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            flush_paragraph(&mut paragraph, &mut out, width, false);
+            fence = Some(&trimmed[..3]);
+            out.push(line.to_string());
+            continue;
+        }
+
+        if line.trim().is_empty() || is_structural_line(line) {
+            flush_paragraph(&mut paragraph, &mut out, width, false);
+            out.push(line.to_string());
+            continue;
+        }
+
+        paragraph.push(line);
+        if line.ends_with("  ") {
+            flush_paragraph(&mut paragraph, &mut out, width, true);
+        }
+    }
+    flush_paragraph(&mut paragraph, &mut out, width, false);
+
+    let mut result = out.join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Lines that must never be merged into or rewrapped as prose.
+fn is_structural_line(line: &str) -> bool {
+    if line.starts_with(' ') || line.starts_with('\t') {
+        return true;
+    }
+    if line.starts_with('#')
+        || line.starts_with('|')
+        || line.starts_with('>')
+        || line.starts_with('<')
+    {
+        return true;
+    }
+    if ["- ", "* ", "+ "].iter().any(|m| line.starts_with(m)) {
+        return true;
+    }
+    // Ordered list item: "1. " or "1) "
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && (line[digits..].starts_with(". ") || line[digits..].starts_with(") ")) {
+        return true;
+    }
+    // Horizontal rule: ---, ***, ___
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && (compact.chars().all(|c| c == '-')
+            || compact.chars().all(|c| c == '*')
+            || compact.chars().all(|c| c == '_'))
+}
+
+/// Join collected paragraph lines and rewrap them into `out`.
+fn flush_paragraph(
+    paragraph: &mut Vec<&str>,
+    out: &mut Vec<String>,
+    width: usize,
+    hard_break: bool,
+) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let joined = paragraph
+        .iter()
+        .map(|l| l.trim())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut lines = wrap(&joined, width);
+    if hard_break && let Some(last) = lines.last_mut() {
+        last.push_str("  ");
+    }
+    out.extend(lines);
+    paragraph.clear();
+}
+
 /// Calculate visible width of a string, ignoring ANSI escape codes.
 ///
 /// ANSI escape sequences (e.g., `\x1b[32m` for green) have zero display width.
@@ -136,4 +235,33 @@ mod tests {
         let colored = "\x1b[32mgreen\x1b[0m and \x1b[31mred\x1b[0m";
         assert_eq!(strip_ansi(colored), "green and red");
     }
+
+    #[test]
+    fn test_reflow_joins_and_wraps_paragraphs() {
+        let text = "one two\nthree four five\n\nsix\n";
+        assert_eq!(
+            reflow_markdown(text, 9),
+            "one two\nthree\nfour five\n\nsix\n"
+        );
+    }
+
+    #[test]
+    fn test_reflow_leaves_code_fences_untouched() {
+        let text = "```\nlet a = 1; let b = 2; let c = 3;\nshort\n```\nword word\nword\n";
+        let out = reflow_markdown(text, 10);
+        assert!(out.starts_with("```\nlet a = 1; let b = 2; let c = 3;\nshort\n```\n"));
+        assert!(out.ends_with("word word\nword\n"));
+    }
+
+    #[test]
+    fn test_reflow_leaves_tables_lists_and_headers() {
+        let text = "## A very long header line here\n| a | b |\n|---|---|\n- item one is long\n  continued\n1. first\n";
+        assert_eq!(reflow_markdown(text, 8), text);
+    }
+
+    #[test]
+    fn test_reflow_preserves_hard_breaks() {
+        let text = "line one  \nline two\n";
+        assert_eq!(reflow_markdown(text, 80), text);
+    }
 }
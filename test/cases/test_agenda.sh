@@ -0,0 +1,65 @@
+#!/usr/bin/env bash
+# Tests for 'threads agenda'
+
+# Test: deadlines and events from all threads merge into one date-sorted agenda
+test_agenda_merges_threads() {
+    begin_test "agenda merges deadlines and events across threads"
+    setup_test_workspace
+
+    local today soon later overdue
+    today=$(date +%Y-%m-%d)
+    soon=$(date -d "+2 days" +%Y-%m-%d)
+    later=$(date -d "+30 days" +%Y-%m-%d)
+    overdue=$(date -d "-1 days" +%Y-%m-%d)
+
+    create_thread "abc123" "Billing" "active"
+    create_thread "def456" "Launch" "active"
+    $THREADS_BIN deadline abc123 add "$soon" "Send invoices" >/dev/null 2>&1
+    $THREADS_BIN deadline abc123 add "$later" "Annual review" >/dev/null 2>&1
+    $THREADS_BIN deadline def456 add "$overdue" "Draft post" >/dev/null 2>&1
+    $THREADS_BIN event def456 add "$today" 14:00 "Go/no-go call" >/dev/null 2>&1
+    $THREADS_BIN event def456 add "$overdue" "Kickoff" >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN agenda --json 2>/dev/null)
+    assert_eq "Draft post,Go/no-go call,Send invoices" "$(echo "$output" | jq -r 'map(.text) | join(",")')" "sorted by date, window and past events applied"
+    assert_json_field "$output" ".[0].overdue" "true" "past deadline flagged overdue"
+    assert_json_field "$output" ".[1].kind" "event" "events included"
+    assert_json_field "$output" ".[2].thread_title" "Billing" "owning thread recorded"
+
+    output=$($THREADS_BIN agenda --all --json 2>/dev/null)
+    assert_eq "4" "$(echo "$output" | jq length)" "--all lifts the window"
+
+    output=$($THREADS_BIN agenda -f plain 2>/dev/null)
+    assert_contains "$output" "$overdue (overdue)" "overdue day labelled"
+    assert_contains "$output" "$today (today)" "today labelled"
+    assert_contains "$output" "  14:00  Go/no-go call  def456 Launch" "timed event line"
+    assert_contains "$output" "  due  Send invoices  abc123 Billing" "deadline line"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: unparseable threads are skipped rather than aborting
+test_agenda_skips_broken_threads() {
+    begin_test "agenda skips threads that fail to parse"
+    setup_test_workspace
+
+    create_thread "abc123" "Billing" "active"
+    $THREADS_BIN deadline abc123 add "$(date -d "+1 days" +%Y-%m-%d)" "Send invoices" >/dev/null 2>&1
+    printf -- '---\nid: [broken\n---\n' > "$TEST_WS/.threads/def456-broken.md"
+
+    local output
+    output=$($THREADS_BIN agenda --json 2>/dev/null)
+    assert_eq "1" "$(echo "$output" | jq length)" "good thread still listed"
+
+    output=$($THREADS_BIN agenda --days 0 -f plain 2>/dev/null)
+    assert_eq "Nothing due in the next 0 days." "$output" "empty window message"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_agenda_merges_threads
+test_agenda_skips_broken_threads
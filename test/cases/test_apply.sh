@@ -0,0 +1,76 @@
+#!/usr/bin/env bash
+# Tests for 'threads apply'
+
+# Test: apply runs note/todo/log operations across threads
+test_apply_batch() {
+    begin_test "apply runs operations across threads"
+    setup_test_workspace
+
+    create_thread "abc123" "First Thread" "active"
+    create_thread "def456" "Second Thread" "active"
+
+    local output
+    output=$(printf '%s\n' \
+        '{"ref": "abc123", "op": "note.add", "args": {"text": "Batch note"}}' \
+        '{"ref": "abc123", "op": "todo.add", "args": {"text": "Batch todo"}}' \
+        '{"ref": "def456", "op": "log.add", "args": {"text": "Batch log"}}' \
+        | $THREADS_BIN apply --json 2>/dev/null)
+
+    assert_json_valid "$output"
+    assert_eq "3" "$(echo "$output" | jq '[.[] | select(.ok)] | length')" "all operations succeed"
+
+    local hash
+    hash=$(echo "$output" | jq -r '.[1].hash')
+    assert_eq "Batch note" "$($THREADS_BIN read abc123 --json | jq -r '.notes[0].text')" "note added"
+    assert_eq "Batch todo" "$($THREADS_BIN read abc123 --json | jq -r '.todo[0].text')" "todo added"
+    assert_contains "$($THREADS_BIN read def456 --json | jq -r '.log[].text')" "Batch log" "log added"
+
+    output=$(echo "{\"ref\": \"abc123\", \"op\": \"todo.check\", \"args\": {\"hash\": \"$hash\"}}" \
+        | $THREADS_BIN apply 2>/dev/null)
+    assert_contains "$output" "1: ok abc123 todo.check: Checked item $hash" "plain report"
+    assert_eq "true" "$($THREADS_BIN read abc123 --json | jq -r '.todo[0].done')" "todo checked"
+
+    echo '{"ref": "abc123", "op": "note.add", "args": {"text": "Headline\nMore detail"}}' \
+        | $THREADS_BIN apply >/dev/null 2>&1
+    assert_eq "Added note: Headline" "$($THREADS_BIN read abc123 --json | jq -r '.log[0].text')" "log keeps only the note's first line"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: apply stops at the first failure unless --keep-going
+test_apply_stops_on_error() {
+    begin_test "apply stops on first error unless --keep-going"
+    setup_test_workspace
+
+    create_thread "abc123" "First Thread" "active"
+
+    local ops output code
+    ops=$(printf '%s\n' \
+        '{"ref": "abc123", "op": "note.add", "args": {"text": "Before"}}' \
+        '{"ref": "abc123", "op": "todo.check", "args": {"hash": "zzzz"}}' \
+        '{"ref": "abc123", "op": "note.add", "args": {"text": "After"}}')
+
+    output=$(echo "$ops" | $THREADS_BIN apply --json 2>/dev/null)
+    code=$?
+    assert_eq "1" "$code" "failure should exit 1"
+    assert_eq "2" "$(echo "$output" | jq 'length')" "stops after the failing operation"
+    assert_contains "$(echo "$output" | jq -r '.[1].message')" "no item with hash 'zzzz'" "reports the error"
+    assert_eq "1" "$($THREADS_BIN read abc123 --json | jq '.notes | length')" "earlier operation is kept"
+
+    output=$(echo "$ops" | $THREADS_BIN apply --keep-going --json 2>/dev/null)
+    code=$?
+    assert_eq "1" "$code" "failures still exit 1"
+    assert_eq "3" "$(echo "$output" | jq 'length')" "every operation is reported"
+    assert_eq "3" "$($THREADS_BIN read abc123 --json | jq '.notes | length')" "later operation applied"
+
+    output=$(echo '{"ref": "abc123", "op": "note.nope"}' | $THREADS_BIN apply 2>&1)
+    assert_contains "$output" "unknown op 'note.nope'" "unknown ops are rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_apply_batch
+test_apply_stops_on_error
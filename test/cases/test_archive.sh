@@ -0,0 +1,102 @@
+#!/usr/bin/env bash
+# Tests for 'threads archive'
+
+# Test: archive moves a closed thread out of the listings; --restore brings it back
+test_archive_and_restore() {
+    begin_test "archive hides a closed thread and --restore brings it back"
+    setup_test_workspace
+
+    create_thread "abc123" "Done Work" "resolved"
+    git add . && git commit -q -m "add thread"
+
+    local output
+    output=$($THREADS_BIN archive abc123 2>/dev/null)
+    assert_contains "$output" "Archived: .threads/abc123-done-work.md → .threads/archive/abc123-done-work.md" "should report the move"
+    assert_eq "0" "$($THREADS_BIN list --include-closed --json 2>/dev/null | jq '.threads | length')" "archived thread should leave list"
+    assert_contains "$(git status --porcelain)" "R  .threads/abc123-done-work.md -> .threads/archive/abc123-done-work.md" "clean file should move with git mv"
+
+    output=$($THREADS_BIN archive --list -f plain 2>/dev/null)
+    assert_eq "abc123 [resolved] Done Work  .threads/archive/abc123-done-work.md" "$output" "--list shows archived threads"
+
+    output=$($THREADS_BIN archive --restore abc123 2>/dev/null)
+    assert_contains "$output" "Restored: .threads/archive/abc123-done-work.md → .threads/abc123-done-work.md" "should restore"
+    assert_eq "1" "$($THREADS_BIN list --include-closed --json 2>/dev/null | jq '.threads | length')" "restored thread is listed again"
+
+    output=$($THREADS_BIN archive --list 2>/dev/null)
+    assert_eq "No archived threads." "$output" "archive is empty again"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: open threads need --force
+test_archive_refuses_open() {
+    begin_test "archive refuses open threads without --force"
+    setup_test_workspace
+
+    create_thread "abc123" "Ongoing" "active"
+
+    local output exit_code
+    output=$($THREADS_BIN archive abc123 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "should fail"
+    assert_contains "$output" "thread abc123 is not closed (status: active); use --force" "should explain"
+    assert_eq "1" "$($THREADS_BIN list --json 2>/dev/null | jq '.threads | length')" "thread stays in place"
+
+    $THREADS_BIN archive abc123 --force >/dev/null 2>&1
+    assert_eq "0" "$($THREADS_BIN list --include-closed --json 2>/dev/null | jq '.threads | length')" "--force archives it"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a name already taken in the archive gets a numeric suffix
+test_archive_name_collision() {
+    begin_test "archive appends a suffix instead of overwriting"
+    setup_test_workspace
+
+    create_thread "abc123" "Done Work" "resolved"
+    mkdir -p "$TEST_WS/.threads/archive"
+    cp "$TEST_WS/.threads/abc123-done-work.md" "$TEST_WS/.threads/archive/abc123-done-work.md"
+
+    local output
+    output=$($THREADS_BIN archive abc123 2>/dev/null)
+    assert_contains "$output" ".threads/archive/abc123-done-work-2.md" "should pick a free name"
+    assert_eq "2" "$(ls "$TEST_WS/.threads/archive" | wc -l | tr -d ' ')" "both copies kept"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: links and parents pointing at an archived thread still resolve
+test_archive_keeps_references_valid() {
+    begin_test "archived threads still satisfy links and parents"
+    setup_test_workspace
+
+    create_thread "abc123" "Done Epic" "resolved"
+    create_thread "def456" "Story" "active"
+    create_thread "cde789" "Follow Up" "active"
+    $THREADS_BIN update def456 --parent abc123 >/dev/null 2>&1
+    $THREADS_BIN link cde789 --blocked-by abc123 >/dev/null 2>&1
+    $THREADS_BIN archive abc123 >/dev/null 2>&1
+
+    local output exit_code
+    output=$($THREADS_BIN validate 2>&1)
+    exit_code=$?
+    assert_eq "0" "$exit_code" "validate should pass"
+    assert_not_contains "$output" "E009" "link to archived thread is not dangling"
+    assert_not_contains "$output" "W012" "parent in the archive is not missing"
+
+    output=$($THREADS_BIN tree -f plain 2>/dev/null)
+    assert_contains "$output" "def456 [active] Story" "child shown at the root"
+    assert_not_contains "$output" "missing" "archived parent is not reported missing"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_archive_and_restore
+test_archive_refuses_open
+test_archive_name_collision
+test_archive_keeps_references_valid
@@ -114,6 +114,190 @@ test_body_empty_pipe_with_flag_fails() {
     end_test
 }
 
+# Test: body --wrap reflows prose but leaves code fences and tables alone
+test_body_wrap_reflows_prose() {
+    begin_test "body --wrap reflows prose only"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    printf 'alpha beta gamma delta epsilon\n\n```\nkeep this very long code line exactly as is\n```\n\n| a | b |\n|---|---|\n' \
+        | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+
+    $THREADS_BIN body abc123 --wrap 12 </dev/null >/dev/null 2>&1
+
+    local file
+    file=$(get_thread_path abc123)
+    assert_contains "$(cat "$file")" "$(printf 'alpha beta\ngamma delta\nepsilon')" "prose should be rewrapped"
+    assert_contains "$(cat "$file")" "keep this very long code line exactly as is" "code fence untouched"
+    assert_contains "$(cat "$file")" "|---|---|" "table untouched"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: body --wrap --dry-run shows a diff without writing
+test_body_wrap_dry_run() {
+    begin_test "body --wrap --dry-run does not write"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    create_thread "def456" "Other Thread" "active"
+
+    printf 'one two three four\n' | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    printf 'five six seven eight\n' | $THREADS_BIN body def456 --set >/dev/null 2>&1
+
+    local before output
+    before=$(cat "$(get_thread_path abc123)")
+    output=$($THREADS_BIN body --wrap 9 --all --dry-run </dev/null 2>/dev/null)
+
+    assert_contains "$output" "-one two three four" "diff shows removed line"
+    assert_contains "$output" "+one two" "diff shows wrapped line"
+    assert_contains "$output" "five six" "--all covers other threads"
+    assert_eq "$before" "$(cat "$(get_thread_path abc123)")" "file unchanged on dry run"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --from-clipboard fails clearly without clipboard support (default build / headless)
+test_body_from_clipboard_unavailable() {
+    begin_test "body --from-clipboard errors clearly when unavailable"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    local before
+    before=$(cat "$(get_thread_path abc123)")
+
+    local output code
+    output=$(cd "$TEST_WS" && DISPLAY= WAYLAND_DISPLAY= $THREADS_BIN body abc123 --from-clipboard 2>&1 </dev/null)
+    code=$?
+
+    assert_eq "1" "$code" "should fail without a clipboard"
+    assert_contains "$output" "clipboard" "error should mention the clipboard"
+    assert_eq "$before" "$(cat "$(get_thread_path abc123)")" "thread should be unchanged"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: body --render-check flags an unclosed fence, bad table, and empty link
+test_body_render_check_warnings() {
+    begin_test "body --render-check reports markdown problems"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-test.md" << 'EOF'
+---
+id: abc123
+name: Test
+status: active
+---
+
+See [the spec]() for details.
+
+| A | B |
+| - | - |
+| 1 | 2 | 3 |
+
+```rust
+fn main() {}
+EOF
+
+    local output code
+    output=$($THREADS_BIN body abc123 --render-check 2>&1)
+    code=$?
+    assert_eq "1" "$code" "warnings should fail the check"
+    assert_contains "$output" ".threads/abc123-test.md:7: warning: link 'the spec' has an empty target" "empty link with file line"
+    assert_contains "$output" ":11: warning: table row has 3 cells, header has 2" "uneven table row"
+    assert_contains "$output" ":13: warning: code fence \`\`\` is never closed" "unclosed fence"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: body --render-check passes a well-formed body, and --all covers the repo
+test_body_render_check_clean() {
+    begin_test "body --render-check passes clean bodies"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-test.md" << 'EOF'
+---
+id: abc123
+name: Test
+status: active
+---
+
+Intro with a [link](https://example.com) and `[code]()`.
+
+| A | B |
+| --- | :-: |
+| 1 | 2 |
+
+```
+| not | a table
+[not]()
+```
+EOF
+    create_thread "def456" "Other" "active"
+
+    local output code
+    output=$($THREADS_BIN body abc123 --render-check 2>&1)
+    code=$?
+    assert_eq "0" "$code" "clean body should pass"
+    assert_contains "$output" "Body renders cleanly." "clean message"
+
+    output=$($THREADS_BIN body --all --render-check 2>&1)
+    assert_contains "$output" "All 2 bodies render cleanly." "--all covers every thread"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: body --append/--prepend join with exactly one blank line
+test_body_append_prepend_spacing() {
+    begin_test "body --append and --prepend keep one blank line between parts"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    $THREADS_BIN body abc123 --set --text "Middle" >/dev/null 2>&1
+    printf '\n\nEnd\n\n' | $THREADS_BIN body abc123 --append >/dev/null 2>&1
+    local output
+    output=$($THREADS_BIN body abc123 --prepend --text "Start" 2>&1)
+    assert_contains "$output" "Body prepend:" "prepend reported"
+
+    local body
+    body=$(awk 'BEGIN{n=0} /^---$/{n++; next} n>=2' "$(get_thread_path abc123)")
+    assert_eq "$(printf 'Start\n\nMiddle\n\nEnd')" "$body" "single blank line separators"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: body --section appends under an H2, creating it when missing
+test_body_append_section() {
+    begin_test "body --section appends under a heading"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    printf 'Intro\n\n## Design\n\nFirst idea\n\n## Open questions\n\nWhy?\n' | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    $THREADS_BIN body abc123 --section "## Design" --text "Second idea" >/dev/null 2>&1
+    $THREADS_BIN body abc123 --section "Decisions" --text "Ship it" >/dev/null 2>&1
+
+    local body
+    body=$(awk 'BEGIN{n=0} /^---$/{n++; next} n>=2' "$(get_thread_path abc123)")
+    assert_contains "$body" "$(printf 'First idea\n\nSecond idea\n\n## Open questions')" "added at the end of the existing section"
+    assert_contains "$body" "$(printf 'Why?\n\n## Decisions\n\nShip it')" "missing heading created at the end"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN body abc123 --set --section Design --text x)
+    assert_eq "1" "$exit_code" "--section conflicts with --set"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_body_set_replaces
 test_body_append_adds
@@ -121,3 +305,10 @@ test_body_stdin
 test_body_multiline_stdin
 test_body_empty_pipe_fails
 test_body_empty_pipe_with_flag_fails
+test_body_wrap_reflows_prose
+test_body_wrap_dry_run
+test_body_from_clipboard_unavailable
+test_body_render_check_warnings
+test_body_render_check_clean
+test_body_append_prepend_spacing
+test_body_append_section
@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+# Tests for the search index and 'threads cache'
+
+# Test: search builds an index and picks up edited threads
+test_search_index_tracks_edits() {
+    begin_test "search index is reused and refreshed on edit"
+    setup_test_workspace
+
+    create_thread "abc123" "Caching Thread" "active"
+    echo "the widget pipeline" | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN search widget --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "1" "first search finds the body"
+    assert_eq "true" "$([ -f "$TEST_WS/.threads-config/search-index.json" ] && echo true)" "index written"
+
+    echo "the gadget pipeline" | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    output=$($THREADS_BIN search -e gadget --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "1" "edited text is re-indexed"
+    output=$($THREADS_BIN search -e widget --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "0" "stale text is gone"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: cache rebuild-index and clear manage the search index
+test_cache_rebuild_index() {
+    begin_test "cache rebuild-index and clear"
+    setup_test_workspace
+
+    create_thread "abc123" "First" "active"
+    create_thread "def456" "Second" "resolved"
+
+    local output
+    output=$($THREADS_BIN cache rebuild-index 2>&1)
+    assert_contains "$output" "Indexed 2 threads in" "every thread indexed"
+    output=$($THREADS_BIN cache status --json 2>/dev/null)
+    assert_json_field "$output" ".index_files" "2" "status reports the index"
+
+    output=$($THREADS_BIN cache clear 2>&1)
+    assert_contains "$output" "Cleared search index (2 threads)" "clear removes the index"
+    assert_eq "" "$(ls "$TEST_WS/.threads-config/search-index.json" 2>/dev/null)" "index file gone"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_search_index_tracks_edits
+test_cache_rebuild_index
@@ -259,6 +259,48 @@ test_config_init() {
     end_test
 }
 
+# Test: threads config dump reproduces the effective config as sole manifest
+test_config_dump_roundtrip() {
+    begin_test "threads config dump reproduces effective config"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  new: idea
+display:
+  root_name: "root workspace"
+EOF
+    mkdir -p "$TEST_WS/subproject/.threads-config"
+    cat > "$TEST_WS/subproject/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  new: planning
+behavior:
+  quiet: true
+EOF
+
+    local before dumped after
+    before=$(cd "$TEST_WS/subproject" && $THREADS_BIN config show)
+    dumped=$(cd "$TEST_WS/subproject" && $THREADS_BIN config dump)
+
+    assert_contains "$dumped" "# Resolved from" "should list contributing sources"
+    assert_contains "$dumped" "subproject" "should name the nested manifest"
+
+    # Replace both manifests with the dump alone
+    rm -rf "$TEST_WS/subproject/.threads-config"
+    echo "$dumped" > "$TEST_WS/.threads-config/manifest.yaml"
+
+    after=$(cd "$TEST_WS/subproject" && $THREADS_BIN config show)
+    assert_eq "$before" "$after" "dumped manifest should reproduce effective config"
+
+    local json
+    json=$(capture_stdout $THREADS_BIN config dump --json)
+    assert_json_valid "$json" "--json output should be valid JSON"
+
+    teardown_test_workspace
+    end_test
+}
+
 # ============================================================================
 # Terminology Tests (close/resolve aliases)
 # ============================================================================
@@ -422,7 +464,211 @@ EOF
     end_test
 }
 
+# Test: config set-color edits the manifest, config colors lists it
+test_config_set_color() {
+    begin_test "threads config set-color updates status_colors"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  new: idea
+EOF
+
+    local output
+    output=$($THREADS_BIN config set-color blocked red 2>&1)
+    assert_contains "$output" "Set blocked color to red" "should confirm the change"
+
+    local manifest
+    manifest=$(cat "$TEST_WS/.threads-config/manifest.yaml")
+    assert_contains "$manifest" "blocked: red" "manifest should hold the new color"
+    assert_contains "$manifest" "new: idea" "existing settings should be kept"
+
+    printf '# team palette\n%s\n' "$manifest" > "$TEST_WS/.threads-config/manifest.yaml"
+    $THREADS_BIN config set-color blocked "bright red" >/dev/null 2>&1
+    manifest=$(cat "$TEST_WS/.threads-config/manifest.yaml")
+    assert_contains "$manifest" "# team palette" "changing a color should keep comments"
+    assert_contains "$manifest" "blocked: bright red" "color should be replaced in place"
+    $THREADS_BIN config set-color blocked red >/dev/null 2>&1
+
+    output=$($THREADS_BIN config colors 2>&1)
+    assert_matches "blocked +red" "$output" "colors should list the new color"
+    assert_matches "active +green" "$output" "colors should list defaults"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN config set-color blocked mauve)" "unknown color should fail"
+    assert_eq "1" "$(get_exit_code $THREADS_BIN config set-color wibble red)" "unknown status should fail"
+
+    teardown_test_workspace
+    end_test
+}
+
 # ============================================================================
+# Test: --profile overlays a named preset from the manifest
+test_config_profile() {
+    begin_test "--profile applies a named preset"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  new: idea
+profiles:
+  solo:
+    defaults:
+      new: active
+  team:
+    behavior:
+      quiet: true
+EOF
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN config show)
+    assert_contains "$output" "new: idea" "base config without a profile"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN --profile solo config show --effective)
+    assert_contains "$output" "new: active" "profile should override the manifest"
+    assert_contains "$output" "profile 'solo'" "profile should be listed as a source"
+
+    output=$(cd "$TEST_WS" && THREADS_PROFILE=solo $THREADS_BIN config show)
+    assert_contains "$output" "new: active" "THREADS_PROFILE should select the profile"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN --profile nope list)" "unknown profile should fail"
+    output=$(cd "$TEST_WS" && $THREADS_BIN --profile nope list 2>&1)
+    assert_contains "$output" "unknown profile 'nope' (available: solo, team)" "should list profiles"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: reopen restores the last committed open status when the close is uncommitted
+test_reopen_uncommitted_close() {
+    begin_test "reopen restores last committed status after an uncommitted close"
+    setup_test_workspace
+
+    create_thread "aaa001" "Test Thread" "idea"
+    git -C "$TEST_WS" add .threads
+    git -C "$TEST_WS" commit -q -m "initial"
+    $THREADS_BIN status aaa001 paused >/dev/null 2>&1
+    git -C "$TEST_WS" commit -q -am "paused"
+
+    # Resolve without committing
+    $THREADS_BIN resolve aaa001 >/dev/null 2>&1
+
+    local output
+    output=$(capture_all $THREADS_BIN reopen aaa001)
+    assert_eq "paused" "$(get_thread_field "aaa001" "status")" "should restore the committed open status"
+    assert_contains "$(cat "$TEST_WS"/.threads/aaa001-*.md)" "Reopened as paused." "log names the restored status"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: an explicit --status wins over history, even when it is the old default
+test_reopen_explicit_status() {
+    begin_test "reopen --status overrides git history"
+    setup_test_workspace
+
+    create_thread "aaa001" "Test Thread" "blocked"
+    git -C "$TEST_WS" add .threads
+    git -C "$TEST_WS" commit -q -m "initial"
+    $THREADS_BIN resolve aaa001 >/dev/null 2>&1
+
+    $THREADS_BIN reopen aaa001 --status active >/dev/null 2>&1
+    assert_eq "active" "$(get_thread_field "aaa001" "status")" "--status active is honoured"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN reopen aaa001 --status bogus)
+    assert_eq "1" "$exit_code" "invalid --status rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
+# ============================================================================
+# Test: config get/set read and write single values by dotted key
+test_config_get_set() {
+    begin_test "threads config get/set edits one value"
+    setup_test_workspace
+
+    local output
+    output=$($THREADS_BIN config get behavior.quiet 2>&1)
+    assert_eq "false
+# source: default" "$output" "default value and source"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+# Team settings
+behavior:
+  quiet: false  # chatty by default
+EOF
+
+    output=$($THREADS_BIN config set behavior.quiet true 2>&1)
+    assert_contains "$output" "Set behavior.quiet to true in .threads-config/manifest.yaml" "should confirm the change"
+    local manifest
+    manifest=$(cat "$TEST_WS/.threads-config/manifest.yaml")
+    assert_contains "$manifest" "# Team settings" "comments should survive"
+    assert_contains "$manifest" "quiet: true  # chatty by default" "value edited in place"
+
+    output=$($THREADS_BIN config get behavior.quiet 2>&1)
+    assert_contains "$output" "# source: .threads-config/manifest.yaml" "manifest should be the source"
+
+    $THREADS_BIN config set display.root_name 2024 >/dev/null 2>&1
+    output=$($THREADS_BIN config get display.root_name 2>&1)
+    assert_contains "$output" "'2024'" "numbers accepted as text for string keys"
+
+    output=$(THREADS_QUIET=0 $THREADS_BIN config get behavior.quiet 2>&1)
+    assert_contains "$output" "# source: \$THREADS_QUIET" "environment overrides the manifest"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN config set behavior.quiet maybe)" "type mismatch should fail"
+    assert_eq "1" "$(get_exit_code $THREADS_BIN config set behavior.loud true)" "unknown key should fail"
+    assert_eq "1" "$(get_exit_code $THREADS_BIN config get behavior.loud)" "unknown key should fail for get"
+    assert_contains "$(cat "$TEST_WS/.threads-config/manifest.yaml")" "quiet: true" "failed sets leave the manifest alone"
+
+    XDG_CONFIG_HOME="$TEST_WS/xdg" $THREADS_BIN config set --global defaults.new planning >/dev/null 2>&1
+    assert_contains "$(cat "$TEST_WS/xdg/threads/config.yaml")" "new: planning" "--global writes the user config"
+    output=$(XDG_CONFIG_HOME="$TEST_WS/xdg" $THREADS_BIN config get defaults.new 2>&1)
+    assert_contains "$output" "# source: ~/.config/threads/config.yaml" "user config reported as source"
+
+    teardown_test_workspace
+    end_test
+}
+
+# ============================================================================
+# Test: config validate reports what loading would silently drop
+test_config_validate() {
+    begin_test "threads config validate reports manifest problems"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  new: planning
+EOF
+
+    local output
+    output=$($THREADS_BIN config validate 2>&1)
+    assert_contains "$output" "OK: .threads-config/manifest.yaml" "clean manifest passes"
+    assert_eq "0" "$(get_exit_code $THREADS_BIN config validate)" "clean manifest exits 0"
+
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+behavior:
+  quiet: maybe
+colour: red
+EOF
+    output=$($THREADS_BIN config validate . 2>&1)
+    assert_contains "$output" "ERROR: .threads-config/manifest.yaml:2: [E002]" "type error with line"
+    assert_contains "$output" "WARNING: .threads-config/manifest.yaml:3: [W027] unknown config key 'colour'" "unknown key warned"
+    assert_eq "1" "$(get_exit_code $THREADS_BIN config validate)" "errors exit 1"
+
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+colour: red
+EOF
+    assert_eq "0" "$(get_exit_code $THREADS_BIN config validate)" "warnings alone exit 0"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 # ============================================================================
 
@@ -440,6 +686,7 @@ test_config_show
 test_config_env
 test_config_schema
 test_config_init
+test_config_dump_roundtrip
 test_display_root_name
 
 # Terminology tests
@@ -451,3 +698,9 @@ test_include_concluded_alias
 # Git history tests
 test_reopen_git_history
 test_reopen_fallback_default
+test_reopen_uncommitted_close
+test_reopen_explicit_status
+test_config_set_color
+test_config_profile
+test_config_get_set
+test_config_validate
@@ -0,0 +1,56 @@
+#!/usr/bin/env bash
+# Tests for 'threads deadline' recurrence and done
+
+# Test: done advances a recurring deadline and logs the completion
+test_deadline_recurring_done() {
+    begin_test "deadline done advances a recurring deadline"
+    setup_test_workspace
+
+    create_thread "abc123" "Chores" "active"
+
+    local output hash
+    output=$($THREADS_BIN deadline abc123 add 2026-01-31 "Pay rent" --every monthly 2>/dev/null)
+    assert_contains "$output" "Added deadline: 2026-01-31 Pay rent (every monthly)" "add reports the interval"
+    hash=$($THREADS_BIN deadline abc123 --json 2>/dev/null | jq -r '.[0].hash')
+
+    output=$($THREADS_BIN deadline abc123 -f plain 2>/dev/null)
+    assert_contains "$output" "2026-01-31 ⟳ monthly  Pay rent" "list marks recurring deadlines"
+
+    output=$($THREADS_BIN deadline abc123 done "$hash" 2>/dev/null)
+    assert_contains "$output" "Completed deadline: Pay rent (next: 2026-02-28)" "done reports the next date"
+
+    output=$($THREADS_BIN read abc123 --json 2>/dev/null)
+    assert_json_field "$output" ".deadlines[0].date" "2026-02-28" "date clamps to the end of February"
+    assert_json_field "$output" ".deadlines[0].every" "monthly" "recurrence kept"
+    assert_json_field "$output" ".log[0].text" "Completed deadline: Pay rent (next: 2026-02-28)" "completion logged"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: done removes a one-off deadline; unknown intervals are rejected
+test_deadline_done_one_off() {
+    begin_test "deadline done removes one-off deadlines"
+    setup_test_workspace
+
+    create_thread "abc123" "Launch" "active"
+    $THREADS_BIN deadline abc123 add 2026-06-01 "Ship it" >/dev/null 2>&1
+
+    local output hash exit_code
+    hash=$($THREADS_BIN deadline abc123 --json 2>/dev/null | jq -r '.[0].hash')
+    output=$($THREADS_BIN deadline abc123 done "$hash" 2>/dev/null)
+    assert_eq "Completed deadline: Ship it" "$(echo "$output" | head -1)" "done reports the deadline"
+    assert_eq "0" "$($THREADS_BIN deadline abc123 --json 2>/dev/null | jq length)" "one-off deadline removed"
+
+    output=$($THREADS_BIN deadline abc123 add 2026-06-01 "Standup" --every hourly 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "unknown interval fails"
+    assert_contains "$output" "invalid recurrence 'hourly': use daily, weekly, monthly" "error lists intervals"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_deadline_recurring_done
+test_deadline_done_one_off
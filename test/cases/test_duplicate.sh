@@ -0,0 +1,57 @@
+#!/usr/bin/env bash
+# Tests for 'threads duplicate'
+
+# Test: the copy gets a fresh ID, status and log but keeps notes and body
+test_duplicate_thread() {
+    begin_test "duplicate copies notes and body with a fresh log"
+    setup_test_workspace
+
+    create_thread "abc123" "Spike Auth" "active"
+    echo "Background on the spike." | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    $THREADS_BIN note abc123 add "Token lifetime is 15m" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "Write tests" >/dev/null 2>&1
+    $THREADS_BIN log abc123 "Tried JWT" >/dev/null 2>&1
+
+    local output new_id
+    output=$($THREADS_BIN duplicate abc123 "Spike Billing" --json 2>/dev/null)
+    new_id=$(echo "$output" | jq -r '.id')
+    assert_json_field "$output" ".source" "abc123" "source ID reported"
+    assert_contains "$(echo "$output" | jq -r '.path_absolute')" "/.threads/${new_id}-spike-billing.md" "lands next to the source"
+
+    output=$($THREADS_BIN read "$new_id" --json 2>/dev/null)
+    assert_json_field "$output" ".status" "idea" "status reset to the default"
+    assert_json_field "$output" ".name" "Spike Billing" "new title"
+    assert_json_field "$output" ".notes[0].text" "Token lifetime is 15m" "notes copied"
+    assert_json_field "$output" ".todo | length" "0" "todos dropped by default"
+    assert_json_field "$output" ".log | length" "1" "log reset"
+    assert_json_field "$output" ".log[0].text" "Created thread (cloned from abc123)." "log records the source"
+    assert_contains "$(echo "$output" | jq -r '.body')" "Background on the spike." "body copied"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --with-todos keeps todos; the default title marks the copy
+test_duplicate_with_todos() {
+    begin_test "duplicate --with-todos keeps todos"
+    setup_test_workspace
+
+    create_thread "abc123" "Release" "active"
+    $THREADS_BIN todo abc123 add "Tag version" >/dev/null 2>&1
+
+    local output new_id
+    output=$($THREADS_BIN duplicate abc123 --with-todos -f plain 2>/dev/null)
+    assert_contains "$output" "Duplicated abc123 as " "plain output names both IDs"
+    new_id=$(echo "$output" | head -1 | awk '{print $NF}')
+
+    output=$($THREADS_BIN read "$new_id" --json 2>/dev/null)
+    assert_json_field "$output" ".name" "Release (copy)" "default title"
+    assert_json_field "$output" ".todo[0].text" "Tag version" "todos kept"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_duplicate_thread
+test_duplicate_with_todos
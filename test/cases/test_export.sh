@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+# Tests for 'threads export'
+
+# Test: export ics emits all-day deadlines and timed events with CRLF endings
+test_export_ics() {
+    begin_test "export ics writes a VCALENDAR with deadlines and events"
+    setup_test_workspace
+
+    create_thread "abc123" "Release Planning" "active"
+    $THREADS_BIN deadline abc123 add 2026-03-01 "Cut the branch" --every weekly >/dev/null 2>&1
+    $THREADS_BIN event abc123 add 2026-03-02 14:30 "Go, no-go call" >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN export ics 2>/dev/null)
+    assert_contains "$output" "BEGIN:VCALENDAR" "calendar header"
+    assert_eq "2" "$(echo "$output" | grep -c '^BEGIN:VEVENT')" "one VEVENT per item"
+    assert_contains "$output" "DTSTART;VALUE=DATE:20260301" "deadline is all-day"
+    assert_contains "$output" "RRULE:FREQ=WEEKLY" "recurring deadline repeats"
+    assert_contains "$output" "DTSTART:20260302T143000" "event with a time is timed"
+    assert_contains "$output" "SUMMARY:Release Planning" "thread title as summary"
+    assert_contains "$output" "DESCRIPTION:Go\\, no-go call (abc123)" "text escaped"
+    assert_contains "$output" "UID:abc123-" "UID carries the thread ID"
+    assert_eq "0" "$(printf '%s\n' "$output" | grep -vc $'\r$')" "every line ends in CRLF"
+
+    $THREADS_BIN export ics --output "$TEST_WS/feed.ics" >/dev/null 2>&1
+    assert_eq "$(echo "$output" | grep -v DTSTAMP)" "$(grep -v DTSTAMP "$TEST_WS/feed.ics")" "--output writes the same feed"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_export_ics
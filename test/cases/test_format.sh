@@ -553,6 +553,122 @@ test_force_color_env
 test_no_color_precedence
 test_format_flag_overrides_env
 
+# ====================================================================================
+# --color flag tests
+# ====================================================================================
+
+# Test: --color always colors pretty output even when piped
+test_color_always() {
+    begin_test "--color always forces colored pretty output"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output
+    output=$(NO_COLOR=1 $THREADS_BIN list --color always 2>/dev/null)
+    assert_contains "$output" "│" "should use the pretty table"
+    assert_contains "$output" $'\e[' "should contain ANSI color codes"
+
+    output=$($THREADS_BIN --color=always read abc123 2>/dev/null)
+    assert_contains "$output" $'\e[' "flag is accepted before the subcommand"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --color never wins over FORCE_COLOR
+test_color_never() {
+    begin_test "--color never overrides FORCE_COLOR"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output
+    output=$(FORCE_COLOR=1 $THREADS_BIN list --color never 2>/dev/null)
+    assert_not_contains "$output" $'\e[' "should contain no ANSI codes"
+    assert_contains "$output" " | " "should fall back to plain output when piped"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN list --color sometimes)" "unknown value should fail"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --color always leaves json and yaml alone
+test_color_structured_output() {
+    begin_test "--color always never colors json or yaml"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output
+    output=$($THREADS_BIN list --json --color always 2>/dev/null)
+    assert_json_valid "$output" "json should stay valid"
+    assert_not_contains "$output" $'\e[' "json should have no ANSI codes"
+
+    output=$($THREADS_BIN read abc123 --yaml --color always 2>/dev/null)
+    assert_yaml_valid "$output" "yaml should stay valid"
+    assert_not_contains "$output" $'\e[' "yaml should have no ANSI codes"
+
+    teardown_test_workspace
+    end_test
+}
+
+# --color tests
+test_color_always
+test_color_never
+test_color_structured_output
+
+# ====================================================================================
+# ndjson format tests
+# ====================================================================================
+
+# Test: list/search/validate stream one JSON object per line
+test_ndjson_format() {
+    begin_test "--format ndjson emits one JSON object per line"
+    setup_test_workspace
+
+    create_thread "abc123" "First Thread" "active"
+    create_thread "def456" "Second Thread" "active"
+
+    local output line
+    output=$($THREADS_BIN list --format ndjson 2>/dev/null)
+    assert_eq "3" "$(echo "$output" | wc -l | tr -d ' ')" "metadata line plus one line per thread"
+    while IFS= read -r line; do
+        assert_json_valid "$line" "each line should be a JSON object"
+    done <<< "$output"
+    assert_json_field "$(echo "$output" | head -1)" ".pwd_relative" "." "first line carries the metadata"
+    assert_contains "$(echo "$output" | tail -n +2)" '"id":"abc123"' "threads follow the metadata"
+
+    output=$(THREADS_FORMAT=ndjson $THREADS_BIN search Second 2>/dev/null)
+    assert_json_field "$(echo "$output" | head -1)" ".query" "Second" "search metadata holds the query"
+    assert_json_field "$(echo "$output" | sed -n 2p)" ".id" "def456" "then one line per match"
+
+    echo "not: [valid" > "$TEST_WS/.threads/zzz999-broken.md"
+    output=$($THREADS_BIN validate --format ndjson 2>/dev/null)
+    assert_json_field "$(echo "$output" | head -1)" ".total" "3" "validate starts with the counts"
+    assert_contains "$(echo "$output" | tail -n +2)" '"path":".threads/zzz999-broken.md"' "issues carry their path"
+
+    output=$($THREADS_BIN read abc123 --format ndjson 2>/dev/null)
+    assert_eq "1" "$(echo "$output" | wc -l | tr -d ' ')" "other commands print one compact line"
+    assert_json_field "$output" ".id" "abc123" "other commands fall back to JSON"
+
+    output=$($THREADS_BIN read abc123 def456 --format ndjson 2>/dev/null)
+    assert_eq "2" "$(echo "$output" | wc -l | tr -d ' ')" "read prints one line per thread"
+    assert_json_field "$(echo "$output" | tail -1)" ".id" "def456" "in the order given"
+
+    for cmd in "info abc123" "stats" "tree" "path abc123" "git status"; do
+        output=$($THREADS_BIN $cmd --format ndjson 2>/dev/null)
+        assert_eq "1" "$(echo "$output" | wc -l | tr -d ' ')" "$cmd should print a single line"
+        assert_json_valid "$output" "$cmd line should be a JSON object"
+    done
+
+    teardown_test_workspace
+    end_test
+}
+
+test_ndjson_format
+
 # ====================================================================================
 # --yaml shorthand and single-thread list format tests
 # ====================================================================================
@@ -754,6 +870,118 @@ EOF
     end_test
 }
 
+# Test: info --json reports git history as structured commits
+test_info_json_git_history() {
+    begin_test "info --json git_history has structured commits"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    git -C "$TEST_WS" add .threads
+    git -C "$TEST_WS" commit -q -m "Add thread"
+
+    local output
+    output=$($THREADS_BIN info abc123 --json 2>/dev/null)
+
+    assert_json_valid "$output" "info --json should be valid JSON"
+    assert_eq "Add thread" "$(echo "$output" | jq -r '.git_history[0].message')" "commit message should be a field"
+    assert_eq "number" "$(echo "$output" | jq -r '.git_history[0].insertions | type')" "insertions should be numeric"
+    assert_eq "number" "$(echo "$output" | jq -r '.git_history[0].deletions | type')" "deletions should be numeric"
+    assert_gt "$(echo "$output" | jq -r '.git_history[0].insertions')" "0" "insertions should count added lines"
+    assert_json_has_field "$output" ".git_history[0].hash" "commit should have a hash"
+    assert_json_has_field "$output" ".git_history[0].timestamp" "commit should have a timestamp"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: info --log merges log entries and commits
+test_info_log_timeline() {
+    begin_test "info --log shows log entries and commits together"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    git -C "$TEST_WS" add .threads
+    git -C "$TEST_WS" commit -q -m "Add thread"
+    # Log timestamps have one-second resolution
+    sleep 1
+    $THREADS_BIN log abc123 "Old entry" >/dev/null 2>&1
+    $THREADS_BIN log abc123 "Newest entry" >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN info abc123 --log --format pretty 2>/dev/null)
+    assert_contains "$output" "Activity" "pretty output adds an activity section"
+    assert_contains "$output" "⬡" "log entries are marked"
+    assert_contains "$output" "● " "commits are marked"
+    assert_contains "$output" "Add thread" "commit messages are listed"
+
+    output=$($THREADS_BIN info abc123 --log=1 --format plain 2>/dev/null)
+    assert_contains "$output" "Activity:" "plain output has the section"
+    assert_contains "$output" "Newest entry" "the most recent entry is kept"
+    assert_not_contains "$output" "Old entry" "--log=N keeps only the last N items"
+
+    output=$($THREADS_BIN info abc123 --log=2 --json 2>/dev/null)
+    assert_eq "Old entry,Newest entry" "$(echo "$output" | jq -r '[.log[].text] | join(",")')" "json lists the last log entries oldest first"
+    assert_eq "Add thread" "$(echo "$output" | jq -r '.git_history[0].message')" "commits stay in git_history"
+
+    output=$($THREADS_BIN info abc123 --json 2>/dev/null)
+    assert_eq "false" "$(echo "$output" | jq 'has("log")')" "no log array without --log"
+
+    output=$($THREADS_BIN info --log abc123 --json 2>/dev/null)
+    assert_eq "abc123" "$(echo "$output" | jq -r '.id')" "--log before the ID does not swallow it"
+    assert_eq "true" "$(echo "$output" | jq 'has("log")')" "bare --log still enables the timeline"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: info counts todos and log entries kept in frontmatter
+test_info_frontmatter_counts() {
+    begin_test "info counts frontmatter todos and log entries"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-migrated.md" << 'EOF'
+---
+id: abc123
+name: Migrated
+desc: ''
+status: active
+todo:
+- text: First task
+  hash: aaa1
+  done: true
+- text: Second task
+  hash: bbb2
+  done: false
+- text: Third task
+  hash: ccc3
+  done: false
+log:
+- ts: 2026-01-02 10:00:00
+  text: Second entry
+- ts: 2026-01-01 10:00:00
+  text: First entry
+---
+
+Body without legacy sections.
+EOF
+
+    local output
+    output=$($THREADS_BIN info abc123 --json 2>/dev/null)
+    assert_json_field "$output" ".log_count" "2" "json log_count from frontmatter"
+    assert_json_field "$output" ".todo_count" "3" "json todo_count from frontmatter"
+    assert_json_field "$output" ".todo_done" "1" "json todo_done from item.done"
+
+    output=$($THREADS_BIN info abc123 --format plain 2>/dev/null)
+    assert_contains "$output" "2 log entries | 1/3 todos" "plain counts from frontmatter"
+
+    output=$($THREADS_BIN info abc123 --format pretty 2>/dev/null)
+    assert_contains "$output" "1/3" "pretty todo progress from frontmatter"
+    assert_matches "Migrated +2 · " "$output" "pretty log count from frontmatter"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run new tests
 test_format_yaml_shorthand
 test_todo_single_json
@@ -765,3 +993,6 @@ test_deadline_single_yaml
 test_event_single_json
 test_event_single_yaml
 test_validate_fix_json_dry_run
+test_info_json_git_history
+test_info_log_timeline
+test_info_frontmatter_counts
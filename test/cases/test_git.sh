@@ -100,9 +100,111 @@ test_commit_with_message() {
     end_test
 }
 
+# Test: commit without IDs commits pending threads with a summarizing message
+test_commit_defaults_to_pending() {
+    begin_test "commit without IDs commits pending threads"
+    setup_git_workspace
+
+    create_thread "aaa001" "First Thread" "active"
+    create_thread "bbb002" "Second Thread" "blocked"
+    echo "notes" > "$TEST_WS/scratch.txt"
+
+    local output
+    output=$($THREADS_BIN git commit --auto 2>&1)
+    assert_contains "$output" "Committed 2 thread(s)" "both threads committed"
+
+    local body
+    body=$(git -C "$TEST_WS" log -1 --format=%B)
+    assert_contains "$body" "- add aaa001 First Thread" "message lists IDs and titles"
+    assert_contains "$body" "- add bbb002 Second Thread" "every thread summarized"
+    assert_eq "?? scratch.txt" "$(git -C "$TEST_WS" status --porcelain)" "unrelated files untouched"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: staged non-thread files block the commit unless --allow-mixed
+test_commit_refuses_mixed_index() {
+    begin_test "commit refuses staged non-thread files"
+    setup_git_workspace
+
+    create_thread "abc123" "Thread to Commit" "active"
+    echo "code" > "$TEST_WS/main.rs"
+    git -C "$TEST_WS" add main.rs
+
+    local before output exit_code
+    before=$(git -C "$TEST_WS" rev-list --count --all)
+    output=$($THREADS_BIN git commit abc123 -m "threads" 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "mixed index refused"
+    assert_contains "$output" "non-thread files are staged: main.rs" "offending file named"
+    assert_eq "$before" "$(git -C "$TEST_WS" rev-list --count --all)" "nothing committed"
+
+    output=$($THREADS_BIN git commit abc123 -m "threads" --allow-mixed 2>&1)
+    assert_contains "$output" "Left 1 staged non-thread file(s) uncommitted" "--allow-mixed proceeds"
+    assert_eq "A  main.rs" "$(git -C "$TEST_WS" status --porcelain)" "main.rs stays staged"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: behavior.auto_commit commits each mutation; the env var overrides it
+test_auto_commit_from_config() {
+    begin_test "auto_commit config commits mutations"
+    setup_git_workspace
+
+    create_thread "abc123" "Thread to Commit" "active"
+    $THREADS_BIN git commit abc123 -m "add thread" >/dev/null 2>&1
+    mkdir -p "$TEST_WS/.threads-config"
+    printf 'behavior:\n  auto_commit: true\n' > "$TEST_WS/.threads-config/manifest.yaml"
+
+    $THREADS_BIN status abc123 blocked >/dev/null 2>&1
+    assert_eq "threads: status abc123" "$(git -C "$TEST_WS" log -1 --format=%s)" "status change committed"
+    $THREADS_BIN note abc123 add "Checked the logs" >/dev/null 2>&1
+    assert_eq "threads: note abc123" "$(git -C "$TEST_WS" log -1 --format=%s)" "note committed"
+    assert_eq "" "$(git -C "$TEST_WS" status --porcelain .threads)" "nothing left pending"
+
+    THREADS_AUTO_COMMIT=0 $THREADS_BIN todo abc123 add "Write it up" >/dev/null 2>&1
+    assert_eq "threads: note abc123" "$(git -C "$TEST_WS" log -1 --format=%s)" "env var turns it off"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a detached HEAD or an unfinished merge skips the commit, not the mutation
+test_auto_commit_skips_unsafe_head() {
+    begin_test "auto-commit skips detached HEAD and merges"
+    setup_git_workspace
+
+    create_thread "abc123" "Thread to Commit" "active"
+    $THREADS_BIN git commit abc123 -m "add thread" >/dev/null 2>&1
+
+    local before output exit_code
+    before=$(git -C "$TEST_WS" rev-list --count --all)
+    git -C "$TEST_WS" rev-parse HEAD > "$TEST_WS/.git/MERGE_HEAD"
+    output=$(THREADS_AUTO_COMMIT=1 $THREADS_BIN status abc123 blocked 2>&1 </dev/null)
+    exit_code=$?
+    assert_eq "0" "$exit_code" "mutation succeeds mid-merge"
+    assert_contains "$output" "Warning: a merge is in progress" "merge reported"
+    assert_contains "$(cat "$TEST_WS"/.threads/abc123-*.md)" "status: blocked" "change written"
+    rm "$TEST_WS/.git/MERGE_HEAD"
+
+    git -C "$TEST_WS" checkout -q --detach
+    output=$(THREADS_AUTO_COMMIT=1 $THREADS_BIN note abc123 add "Detached" 2>&1 </dev/null)
+    assert_contains "$output" "Warning: HEAD is detached" "detached HEAD reported"
+    assert_eq "$before" "$(git -C "$TEST_WS" rev-list --count --all)" "nothing committed"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_git_shows_pending
 test_git_clean_workspace
 test_commit_single_thread
 test_commit_pending
 test_commit_with_message
+test_commit_defaults_to_pending
+test_commit_refuses_mixed_index
+test_auto_commit_from_config
+test_auto_commit_skips_unsafe_head
@@ -189,6 +189,39 @@ test_worktree_handling() {
     end_test
 }
 
+# ====================================================================================
+# Targeting another repository
+# ====================================================================================
+
+# Test: --repo operates on another repo without cd
+test_repo_flag_targets_other_repo() {
+    begin_test "--repo targets another repository"
+    setup_test_workspace
+
+    create_thread "abc123" "Root Thread" "active"
+    create_nested_repo_with_threads "$TEST_WS/other"
+    create_thread "def456" "Other Thread" "active" "" "$TEST_WS/other"
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN --repo "$TEST_WS/other" list 2>/dev/null)
+    assert_contains "$output" "def456" "should list the other repo's thread"
+    assert_not_contains "$output" "abc123" "should not list the current repo's thread"
+
+    output=$(cd / && $THREADS_BIN list --repo "$TEST_WS/other/.threads" 2>/dev/null)
+    assert_contains "$output" "def456" "should resolve a subdirectory to its repo root"
+
+    local non_repo exit_code
+    non_repo=$(mktemp -d)
+    exit_code=$(get_exit_code $THREADS_BIN --repo "$non_repo" list)
+    assert_eq "1" "$exit_code" "non-git directory should fail"
+    output=$($THREADS_BIN --repo "$non_repo" list 2>&1)
+    assert_contains "$output" "not inside a git repository" "should explain the failure"
+    rm -rf "$non_repo"
+
+    teardown_test_workspace
+    end_test
+}
+
 # ====================================================================================
 # Run all tests
 # ====================================================================================
@@ -208,3 +241,6 @@ test_nested_repo_no_threads_dir
 # Special git structures
 test_submodule_handling
 test_worktree_handling
+
+# Targeting another repository
+test_repo_flag_targets_other_repo
@@ -1,5 +1,5 @@
 #!/usr/bin/env bash
-# Tests for thread lifecycle commands: status, resolve, reopen, remove
+# Tests for thread lifecycle commands: status, resolve, reopen, remove, update
 
 # Test: status command changes status field
 test_status_change() {
@@ -80,9 +80,281 @@ test_remove_deletes_file() {
     end_test
 }
 
+# Test: remove warns about links to the removed thread; --clean-links drops them
+test_remove_linked_thread() {
+    begin_test "remove warns about or cleans links to the removed thread"
+    setup_test_workspace
+
+    create_thread "abc123" "Blocker" "active"
+    create_thread "def456" "Blocked" "active"
+    create_thread "cde789" "Other Blocker" "active"
+    $THREADS_BIN link def456 --blocked-by abc123 >/dev/null 2>&1
+    $THREADS_BIN link def456 --relates-to cde789 >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN remove abc123 2>&1)
+    assert_contains "$output" "Warning: 1 link(s) still point at abc123" "default warns"
+    assert_contains "$output" "def456" "warning names the linking thread"
+    assert_eq "blocked-by" "$($THREADS_BIN read def456 --json | jq -r '.links[0].kind')" "default keeps the link"
+
+    output=$($THREADS_BIN remove cde789 --clean-links 2>&1)
+    assert_contains "$output" "Removed links from 1 thread(s)" "clean-links reports the cleanup"
+    assert_eq "abc123" "$($THREADS_BIN read def456 --json | jq -r '[.links[].target] | join(",")')" "only the removed target's link is dropped"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: close + reopen moves a thread to the top of the default list order
+test_reopen_sorts_to_top() {
+    begin_test "close + reopen sorts thread to top of list"
+    setup_test_workspace
+
+    create_thread "abc123" "Older Thread" "active"
+    git add . && GIT_COMMITTER_DATE="2020-01-01T00:00:00" git commit -q -m "older"
+    create_thread "def456" "Newer Thread" "active"
+    git add . && GIT_COMMITTER_DATE="2021-01-01T00:00:00" git commit -q -m "newer"
+
+    local first
+    first=$($THREADS_BIN list --tsv 2>/dev/null | sed -n 2p | cut -f1)
+    assert_eq "def456" "$first" "most recently committed thread first"
+
+    $THREADS_BIN close abc123 >/dev/null 2>&1
+    $THREADS_BIN reopen abc123 >/dev/null 2>&1
+
+    # Uncommitted: updated falls back to filesystem mtime
+    first=$($THREADS_BIN list --tsv 2>/dev/null | sed -n 2p | cut -f1)
+    assert_eq "abc123" "$first" "reopened thread first while uncommitted"
+
+    git add . && git commit -q -m "reopen"
+    first=$($THREADS_BIN list --tsv 2>/dev/null | sed -n 2p | cut -f1)
+    assert_eq "abc123" "$first" "reopened thread first after commit"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: update --clear-desc blanks the description and logs it
+test_update_clear_desc() {
+    begin_test "update --clear-desc empties the description"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active" "Stale description"
+
+    $THREADS_BIN update abc123 --clear-desc >/dev/null 2>&1
+
+    local json pretty
+    json=$($THREADS_BIN read abc123 --json 2>/dev/null)
+    assert_json_field "$json" ".desc" "" "JSON desc should be empty"
+    assert_json_field "$json" ".log[0].text" "Cleared description." "clearing should be logged"
+
+    pretty=$($THREADS_BIN read abc123 -f pretty 2>/dev/null)
+    assert_not_contains "$pretty" "Stale description" "pretty output should not show old desc"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: update --priority sets the level and list --sort priority orders by it
+test_update_priority() {
+    begin_test "update --priority and list --sort priority"
+    setup_test_workspace
+
+    create_thread "abc123" "Low Thread" "active"
+    create_thread "def456" "Critical Thread" "active"
+    create_thread "fed789" "Unranked Thread" "active"
+    git add . && git commit -q -m "threads"
+
+    $THREADS_BIN update abc123 --priority low >/dev/null 2>&1
+    local output
+    output=$($THREADS_BIN update def456 --priority Critical 2>&1)
+    assert_contains "$output" "Updated priority: critical" "level is normalized to lowercase"
+
+    local json
+    json=$($THREADS_BIN read def456 --json 2>/dev/null)
+    assert_json_field "$json" ".priority" "critical" "priority stored in frontmatter"
+
+    local order
+    order=$($THREADS_BIN list --sort priority --tsv 2>/dev/null | tail -n +2 | cut -f1 | tr '\n' ' ')
+    assert_eq "def456 abc123 fed789 " "$order" "critical first, unset last"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN update abc123 --priority urgent)" "unknown level should fail"
+
+    $THREADS_BIN update abc123 --clear-priority >/dev/null 2>&1
+    assert_not_contains "$(cat "$(get_thread_path abc123)")" "priority:" "cleared priority is removed"
+
+    # A hand-edited unknown level is a validation error
+    sed -i 's/^priority: critical$/priority: urgent/' "$(get_thread_path def456)"
+    output=$($THREADS_BIN validate 2>&1)
+    assert_contains "$output" "E008" "unknown priority should be flagged"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: status --all changes every selected thread, with --dry-run previewing
+test_status_all() {
+    begin_test "status --all changes matching threads"
+    setup_nested_workspace
+
+    create_thread "aaa001" "First" "active"
+    create_thread "bbb002" "Second" "blocked"
+    create_thread "ccc003" "Third" "resolved"
+    create_thread "ddd004" "Already" "paused"
+    create_thread_at_category "eee005" "Nested" "cat1" "active"
+
+    local output
+    output=$($THREADS_BIN status --all paused --dry-run -f plain 2>/dev/null)
+    assert_contains "$output" "aaa001  active → paused" "open thread previewed"
+    assert_contains "$output" "bbb002  blocked → paused" "every open thread previewed"
+    assert_not_contains "$output" "ccc003" "closed threads skipped by default"
+    assert_not_contains "$output" "ddd004" "threads already there skipped"
+    assert_not_contains "$output" "eee005" "other levels need --down"
+    assert_contains "$output" "Would change 2 threads" "dry-run summary"
+    assert_eq "active" "$(get_thread_field aaa001 status)" "dry-run writes nothing"
+
+    output=$($THREADS_BIN status --all paused --status active --down -f plain 2>/dev/null)
+    assert_contains "$output" "Changed 2 threads" "summary"
+    assert_eq "paused" "$(get_thread_field aaa001 status)" "active thread changed"
+    assert_eq "blocked" "$(get_thread_field bbb002 status)" "--status narrows the batch"
+    assert_contains "$(cat "$TEST_WS"/cat1/.threads/eee005-*.md)" "status: paused" "--down reaches subdirectories"
+
+    output=$($THREADS_BIN status --all active --include-closed --json 2>/dev/null)
+    assert_eq "aaa001,bbb002,ccc003,ddd004" "$(echo "$output" | jq -r '[.changed[].id] | sort | join(",")')" "--include-closed adds closed threads"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: status --all validates first and aborts on unparseable files
+test_status_all_aborts() {
+    begin_test "status --all aborts the whole batch"
+    setup_test_workspace
+
+    create_thread "aaa001" "First" "active"
+
+    local output exit_code
+    output=$($THREADS_BIN status --all bogus 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "invalid status rejected"
+    assert_contains "$output" "Invalid status 'bogus'" "status validated"
+
+    printf -- '---\nid: [broken\n---\n' > "$TEST_WS/.threads/bbb002-broken.md"
+    output=$($THREADS_BIN status --all blocked 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "broken file aborts"
+    assert_contains "$output" "bbb002-broken.md" "broken file named"
+    assert_eq "active" "$(get_thread_field aaa001 status)" "nothing written"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a configured transitions map rejects changes it does not list
+test_status_transitions() {
+    begin_test "status enforces behavior.transitions"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+behavior:
+  transitions:
+    idea: [planning, active]
+    active: [blocked, resolved]
+    resolved: []
+EOF
+    create_thread "aaa001" "First" "idea"
+    create_thread "bbb002" "Second" "active"
+
+    local output exit_code
+    output=$($THREADS_BIN status aaa001 resolved 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "skipping active is rejected"
+    assert_contains "$output" "cannot change status from 'idea' to 'resolved': allowed next statuses are planning, active" "allowed targets listed"
+    assert_eq "idea" "$(get_thread_field aaa001 status)" "file untouched"
+
+    $THREADS_BIN status aaa001 active >/dev/null 2>&1
+    $THREADS_BIN status aaa001 "blocked (review)" >/dev/null 2>&1
+    assert_eq "blocked (review)" "$(get_thread_field aaa001 status)" "workflow path allowed, reasons ignored"
+    $THREADS_BIN status aaa001 idea >/dev/null 2>&1
+    assert_eq "idea" "$(get_thread_field aaa001 status)" "statuses without an entry are unrestricted"
+
+    $THREADS_BIN status bbb002 resolved >/dev/null 2>&1
+    output=$($THREADS_BIN status bbb002 active 2>&1)
+    assert_contains "$output" "'resolved' allows no transitions" "empty list locks the status"
+
+    output=$($THREADS_BIN status --all resolved 2>&1)
+    assert_contains "$output" "aaa001: cannot change status from 'idea'" "--all names the offending thread"
+    assert_eq "idea" "$(get_thread_field aaa001 status)" "--all aborts before writing"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: resolve and reopen honour behavior.transitions too
+test_resolve_reopen_transitions() {
+    begin_test "resolve and reopen enforce behavior.transitions"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+behavior:
+  transitions:
+    idea: [planning, active]
+    active: [blocked, resolved]
+    resolved: []
+EOF
+    create_thread "aaa001" "First" "idea"
+    create_thread "bbb002" "Second" "active"
+
+    local output exit_code
+    output=$($THREADS_BIN resolve aaa001 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "resolving an idea is rejected"
+    assert_contains "$output" "cannot change status from 'idea' to 'resolved'" "transition error shown"
+    assert_eq "idea" "$(get_thread_field aaa001 status)" "file untouched"
+
+    $THREADS_BIN resolve bbb002 >/dev/null 2>&1
+    assert_eq "resolved" "$(get_thread_field bbb002 status)" "listed transition allowed"
+
+    output=$($THREADS_BIN reopen bbb002 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "reopening a locked status is rejected"
+    assert_contains "$output" "'resolved' allows no transitions" "reopen reports the lock"
+    assert_eq "resolved" "$(get_thread_field bbb002 status)" "reopen left file untouched"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: without a transitions map any valid status is reachable
+test_status_transitions_default() {
+    begin_test "status allows any transition by default"
+    setup_test_workspace
+
+    create_thread "aaa001" "First" "idea"
+
+    $THREADS_BIN status aaa001 resolved >/dev/null 2>&1
+    assert_eq "resolved" "$(get_thread_field aaa001 status)" "idea straight to resolved"
+    $THREADS_BIN status aaa001 idea >/dev/null 2>&1
+    assert_eq "idea" "$(get_thread_field aaa001 status)" "and back"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_status_change
 test_resolve_sets_resolved
 test_reopen_sets_active
 test_reopen_custom_status
 test_remove_deletes_file
+test_remove_linked_thread
+test_reopen_sorts_to_top
+test_update_clear_desc
+test_update_priority
+test_status_all
+test_status_all_aborts
+test_status_transitions
+test_resolve_reopen_transitions
+test_status_transitions_default
@@ -0,0 +1,69 @@
+#!/usr/bin/env bash
+# Tests for 'threads link' command
+
+# Test: link --blocked-by records the target and shows its status
+test_link_blocked_by() {
+    begin_test "link --blocked-by records and lists links"
+    setup_test_workspace
+
+    create_thread "abc123" "Ship Release" "active"
+    create_thread "def456" "Fix Build" "blocked"
+
+    local output
+    output=$($THREADS_BIN link abc123 --blocked-by def456 2>/dev/null)
+    assert_contains "$output" "Linked: blocked-by def456" "should confirm the link"
+
+    local content
+    content=$(cat "$(get_thread_path abc123)")
+    assert_contains "$content" "target: def456" "link stored in frontmatter"
+
+    output=$($THREADS_BIN link abc123 -f plain 2>/dev/null)
+    assert_eq "blocked-by def456 Fix Build [blocked]" "$output" "ls shows target status"
+
+    output=$($THREADS_BIN info abc123 -f plain 2>/dev/null)
+    assert_contains "$output" "blocked-by def456 Fix Build [blocked]" "info shows links"
+
+    $THREADS_BIN close def456 >/dev/null 2>&1
+    output=$($THREADS_BIN link abc123 --json 2>/dev/null)
+    assert_json_field "$output" ".[0].status" "resolved" "status follows the target"
+
+    $THREADS_BIN link abc123 --rm def456 >/dev/null 2>&1
+    output=$($THREADS_BIN link abc123 2>/dev/null)
+    assert_eq "No links." "$output" "rm removes the link"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: linking to a missing thread fails; a dangling link is E009
+test_link_missing_target() {
+    begin_test "link to a missing thread fails and dangling links are E009"
+    setup_test_workspace
+
+    create_thread "abc123" "Ship Release" "active"
+    create_thread "def456" "Fix Build" "active"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN link abc123 --relates-to fff999)" "missing target should fail"
+    assert_not_contains "$(cat "$(get_thread_path abc123)")" "links:" "nothing written"
+
+    $THREADS_BIN link abc123 --relates-to def456 >/dev/null 2>&1
+    rm "$(get_thread_path def456)"
+
+    local output
+    output=$($THREADS_BIN validate 2>&1)
+    assert_contains "$output" "E009" "dangling link reported"
+    assert_contains "$output" "def456" "names the missing target"
+
+    output=$($THREADS_BIN link abc123 -f plain 2>/dev/null)
+    assert_eq "relates-to def456 [missing]" "$output" "missing target shown"
+
+    $THREADS_BIN link abc123 --rm def456 >/dev/null 2>&1
+    assert_not_contains "$(cat "$(get_thread_path abc123)")" "links:" "dangling link can be removed"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_link_blocked_by
+test_link_missing_target
@@ -584,7 +584,251 @@ test_ls_alias() {
     end_test
 }
 
+# Test: --tsv prints a header and one tab-separated line per thread
+test_list_tsv() {
+    begin_test "list --tsv prints tab-separated records"
+    setup_test_workspace
+
+    create_thread "abc123" "Active Thread" "active"
+    create_thread "def456" "Idea Thread" "idea"
+
+    local output
+    output=$($THREADS_BIN list --tsv 2>/dev/null)
+
+    assert_eq "$(printf 'ID\tSTATUS\tCREATED\tUPDATED\tPATH\tGIT\tDUE\tTITLE')" "$(echo "$output" | head -1)" "header row"
+    assert_eq "3" "$(echo "$output" | wc -l | tr -d ' ')" "header plus one line per thread"
+    assert_eq "8" "$(echo "$output" | grep abc123 | awk -F'\t' '{print NF}')" "eight tab-separated fields"
+    assert_not_contains "$output" "PWD:" "no context preamble"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --csv quotes commas, quotes and newlines per RFC 4180
+test_list_csv() {
+    begin_test "list --csv prints RFC 4180 records"
+    setup_test_workspace
+
+    create_thread "abc123" 'Fix "login", then deploy' "active"
+    create_thread "def456" "Plain Title" "idea"
+
+    local output
+    output=$($THREADS_BIN list --csv 2>/dev/null)
+
+    assert_eq "id,status,created,updated,path,git_status,due,title" "$(echo "$output" | head -1)" "header of JSON key names"
+    assert_contains "$output" '"Fix ""login"", then deploy"' "commas and quotes are quoted and doubled"
+    assert_contains "$output" ",Plain Title" "plain fields stay unquoted"
+    assert_not_contains "$output" "PWD:" "no context preamble"
+
+    output=$($THREADS_BIN list --csv --fields title,id 2>/dev/null)
+    assert_eq 'title,id' "$(echo "$output" | head -1)" "--fields picks the columns"
+    assert_contains "$output" '"Fix ""login"", then deploy",abc123' "records follow the field order"
+
+    if command -v python3 >/dev/null 2>&1; then
+        assert_eq "3 8" "$(printf '%s\n' "$($THREADS_BIN list --csv 2>/dev/null)" | python3 -c 'import csv,sys; r=list(csv.reader(sys.stdin)); print(len(r), len(r[1]))')" "a CSV reader sees a header plus two eight-column records"
+    fi
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: list --flat shows every thread regardless of PWD
+test_list_flat() {
+    begin_test "list --flat lists the whole repo from any PWD"
+    setup_test_workspace
+
+    create_thread "abc123" "Root Thread" "active"
+    create_thread_at_category "def456" "Category Thread" "cat1" "active"
+    create_thread_at_project "ghi789" "Project Thread" "cat1" "proj1" "active"
+
+    local output
+    output=$(cd "$TEST_WS/cat1/proj1" && $THREADS_BIN list --flat -f plain 2>/dev/null)
+
+    assert_contains "$output" "abc123" "should include root thread"
+    assert_contains "$output" "def456" "should include category thread"
+    assert_contains "$output" "ghi789" "should include project thread"
+    assert_contains "$output" "whole repo, ignoring PWD" "summary should say so"
+
+    # Default behavior unchanged
+    output=$(cd "$TEST_WS/cat1/proj1" && $THREADS_BIN list -f plain 2>/dev/null)
+    assert_not_contains "$output" "abc123" "default should stay at PWD level"
+
+    local code
+    code=$(get_exit_code $THREADS_BIN list --flat --down 1)
+    assert_eq "1" "$code" "--flat conflicts with direction flags"
+
+    teardown_test_workspace
+    end_test
+}
+
 # ====================================================================================
+# Test: --tree groups threads under their directories
+test_list_tree() {
+    begin_test "list --tree renders a directory tree"
+    setup_nested_workspace
+
+    create_thread "aaa001" "Root Thread" "active"
+    create_thread_at_category "bbb001" "Category Thread" "cat1" "blocked"
+    create_thread_at_project "ccc001" "Project Thread" "cat1" "proj1" "active"
+    create_thread_at_category "ddd001" "Closed Thread" "cat2" "resolved"
+
+    local output
+    output=$($THREADS_BIN list --down --tree -f pretty 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g')
+    assert_contains "$output" "├── aaa001 active Root Thread" "root thread should be a top-level leaf"
+    assert_contains "$output" "└── cat1/" "directory should be a branch"
+    assert_contains "$output" "    ├── bbb001 blocked Category Thread" "category thread under cat1"
+    assert_contains "$output" "    └── proj1/" "nested directory under cat1"
+    assert_contains "$output" "        └── ccc001 active Project Thread" "project thread under proj1"
+    assert_not_contains "$output" "ddd001" "closed threads stay filtered"
+
+    output=$($THREADS_BIN list --down --tree -c -f pretty 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g')
+    assert_contains "$output" "ddd001 resolved Closed Thread" "--include-closed applies to the tree"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN list --tree --json)
+    assert_eq "1" "$exit_code" "--tree should be pretty-only"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --sort chains keys with optional directions
+test_list_sort_keys() {
+    begin_test "list --sort with multiple keys"
+    setup_test_workspace
+
+    create_thread "aaa001" "Bravo" "active"
+    create_thread "aaa002" "alpha" "active"
+    create_thread "aaa003" "Charlie" "idea"
+    create_thread "aaa004" "Delta" "blocked"
+    $THREADS_BIN deadline aaa004 add "$(date -d "+3 days" +%Y-%m-%d)" "Ship" >/dev/null 2>&1
+    $THREADS_BIN deadline aaa001 add "$(date -d "+9 days" +%Y-%m-%d)" "Review" >/dev/null 2>&1
+
+    local order
+    order=$($THREADS_BIN list --sort status,title --tsv 2>/dev/null | tail -n +2 | cut -f1 | tr '\n' ' ')
+    assert_eq "aaa003 aaa002 aaa001 aaa004 " "$order" "configured status order, then title case-insensitively"
+
+    order=$($THREADS_BIN list --sort title:desc --tsv 2>/dev/null | tail -n +2 | cut -f1 | tr '\n' ' ')
+    assert_eq "aaa004 aaa003 aaa001 aaa002 " "$order" "descending title"
+
+    order=$($THREADS_BIN list --sort due:desc,id --tsv 2>/dev/null | tail -n +2 | cut -f1 | tr '\n' ' ')
+    assert_eq "aaa001 aaa004 aaa002 aaa003 " "$order" "undated threads stay last"
+
+    order=$($THREADS_BIN list --sort id:desc --tsv 2>/dev/null | tail -n +2 | cut -f1 | tr '\n' ' ')
+    assert_eq "aaa004 aaa003 aaa002 aaa001 " "$order" "id descending"
+
+    local output
+    output=$($THREADS_BIN list --sort size 2>&1)
+    assert_contains "$output" "unknown sort key 'size'" "unknown key rejected"
+    output=$($THREADS_BIN list --sort title:up 2>&1)
+    assert_contains "$output" "invalid sort direction 'up'" "unknown direction rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --due-before/--due-after filter on the next upcoming deadline
+test_list_due_window() {
+    begin_test "list --due-before/--due-after"
+    setup_test_workspace
+
+    create_thread "aaa001" "Soon" "active"
+    create_thread "aaa002" "Later" "active"
+    create_thread "aaa003" "Undated" "active"
+    create_thread "aaa004" "Soon Closed" "resolved"
+    $THREADS_BIN deadline aaa001 add "$(date -d "+3 days" +%Y-%m-%d)" "Ship" >/dev/null 2>&1
+    $THREADS_BIN deadline aaa002 add "$(date -d "+20 days" +%Y-%m-%d)" "Review" >/dev/null 2>&1
+    $THREADS_BIN deadline aaa004 add "$(date -d "+2 days" +%Y-%m-%d)" "Archive" >/dev/null 2>&1
+
+    local ids
+    ids=$($THREADS_BIN list --due-before 7d --json 2>/dev/null | jq -r '[.threads[].id] | sort | join(",")')
+    assert_eq "aaa001" "$ids" "relative days, closed and undated excluded"
+
+    ids=$($THREADS_BIN list --due-after 1w --json 2>/dev/null | jq -r '[.threads[].id] | sort | join(",")')
+    assert_eq "aaa002" "$ids" "relative weeks"
+
+    ids=$($THREADS_BIN list --due-after "$(date -d "+3 days" +%Y-%m-%d)" --due-before 3w -c --json 2>/dev/null | jq -r '[.threads[].id] | sort | join(",")')
+    assert_eq "aaa001,aaa002" "$ids" "absolute dates are inclusive and combine"
+
+    ids=$($THREADS_BIN list --due-before 7d -c --json 2>/dev/null | jq -r '[.threads[].id] | sort | join(",")')
+    assert_eq "aaa001,aaa004" "$ids" "--include-closed still applies"
+
+    local output
+    output=$($THREADS_BIN list --due-before soon 2>&1)
+    assert_contains "$output" "invalid date 'soon'" "bad date rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: long pretty output goes through the pager only at a terminal
+test_list_pager() {
+    begin_test "list and read page long pretty output"
+    setup_test_workspace
+
+    local i
+    for i in 1 2 3 4 5 6; do
+        create_thread "abc00$i" "Thread $i" "active"
+    done
+
+    local output
+    output=$(THREADS_PAGER="sed s/^/PAGED:/" $THREADS_BIN list 2>/dev/null)
+    assert_not_contains "$output" "PAGED:" "never pages when stdout is not a terminal"
+
+    if command -v script >/dev/null 2>&1; then
+        local run="cd '$TEST_WS' && stty rows 4 cols 120 && THREADS_PAGER='sed s/^/PAGED:/'"
+        output=$(script -qec "$run $THREADS_BIN list" /dev/null < /dev/null)
+        assert_contains "$output" "PAGED:" "list output taller than the terminal is paged"
+
+        output=$(script -qec "$run $THREADS_BIN list --no-pager" /dev/null < /dev/null)
+        assert_not_contains "$output" "PAGED:" "--no-pager prints directly"
+
+        output=$(script -qec "$run THREADS_NO_PAGER=1 $THREADS_BIN read abc001" /dev/null < /dev/null)
+        assert_not_contains "$output" "PAGED:" "THREADS_NO_PAGER prints directly"
+
+        output=$(script -qec "$run $THREADS_BIN read abc001" /dev/null < /dev/null)
+        assert_contains "$output" "PAGED:" "read is paged too"
+
+        output=$(script -qec "$run $THREADS_BIN read abc001 -f plain" /dev/null < /dev/null)
+        assert_not_contains "$output" "PAGED:" "plain output is never paged"
+    fi
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --fields picks and orders columns in every format
+test_list_fields() {
+    begin_test "list --fields selects and orders columns"
+    setup_test_workspace
+
+    create_thread "abc123" "Field Test" "active"
+
+    local output
+    output=$($THREADS_BIN list --fields title,id --format plain 2>/dev/null)
+    assert_contains "$output" "TITLE | ID" "plain header follows the requested order"
+    assert_contains "$output" "Field Test | abc123" "plain rows follow the requested order"
+    assert_not_contains "$output" "STATUS" "unrequested columns are dropped"
+
+    output=$($THREADS_BIN list --fields id,status --tsv 2>/dev/null)
+    assert_eq "$(printf 'ID\tSTATUS\nabc123\tactive')" "$output" "tsv honors --fields"
+
+    output=$($THREADS_BIN list --fields id,title,due --format pretty --no-pager 2>/dev/null)
+    assert_matches "│ ID +│ TITLE +│ DUE +│" "$output" "pretty table shows only the requested columns"
+    assert_not_contains "$output" "STATUS" "pretty table drops the rest"
+
+    output=$($THREADS_BIN list --fields due,id --json 2>/dev/null)
+    assert_eq '["due","id"]' "$(echo "$output" | jq -c '.threads[0] | keys_unsorted')" "json keeps only the requested keys, in order"
+    assert_json_field "$output" ".threads[0].due" "null" "requested but unset keys are null"
+
+    output=$($THREADS_BIN list --fields id,bogus 2>&1)
+    assert_contains "$output" "unknown field 'bogus'" "unknown fields are rejected"
+    assert_contains "$output" "id, status, priority" "error lists the valid fields"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 # ====================================================================================
 
@@ -629,3 +873,13 @@ test_list_status_and_recursive_combined
 
 # Alias tests
 test_ls_alias
+
+# Machine formats
+test_list_tsv
+test_list_csv
+test_list_flat
+test_list_tree
+test_list_sort_keys
+test_list_due_window
+test_list_pager
+test_list_fields
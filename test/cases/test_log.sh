@@ -58,7 +58,139 @@ test_log_entry_format() {
     end_test
 }
 
+# Test: log --count and --archive-before
+test_log_count_and_archive() {
+    begin_test "log --count and --archive-before move old entries to body"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-test-thread.md" << 'EOF'
+---
+id: abc123
+name: Test Thread
+status: active
+log:
+- ts: 2026-03-05 09:00:00
+  text: Recent work
+- ts: 2026-01-01 08:00:00
+  text: Old work
+---
+
+Body.
+EOF
+
+    assert_eq "2" "$($THREADS_BIN log abc123 --count 2>/dev/null)" "should count entries"
+
+    local output
+    output=$($THREADS_BIN log abc123 --archive-before 2026-02-01 2>/dev/null </dev/null)
+    assert_contains "$output" "Archived 1 log entries" "should report archived count"
+
+    local body
+    body=$(sed '1,/^---$/d' "$(get_thread_path abc123)")
+    assert_contains "$body" "## Archived Log" "body should gain archive section"
+    assert_contains "$body" "- [2026-01-01 08:00:00] Old work" "old entry should move to body"
+
+    # Recent entry plus the archive note remain in frontmatter
+    assert_eq "2" "$($THREADS_BIN log abc123 --count 2>/dev/null)" "recent entries stay"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: log --csv exports quoted, chronologically sorted rows
+test_log_csv_export() {
+    begin_test "log --csv exports timestamp,thread_id,text rows"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-first.md" << 'EOF'
+---
+id: abc123
+name: First
+status: active
+log:
+- ts: 2026-01-03 09:00:00
+  text: Later, with a comma
+- ts: ''
+  text: Undated
+---
+EOF
+    cat > "$TEST_WS/.threads/def456-second.md" << 'EOF'
+---
+id: def456
+name: Second
+status: resolved
+log:
+- ts: 2026-01-02 09:00:00
+  text: Earlier
+---
+EOF
+
+    local output
+    output=$($THREADS_BIN log abc123 --csv 2>/dev/null)
+    assert_eq "timestamp,thread_id,text" "$(echo "$output" | head -1)" "should start with a header row"
+    assert_eq ",abc123,Undated" "$(echo "$output" | sed -n 2p)" "blank timestamps should sort first"
+    assert_contains "$output" '2026-01-03 09:00:00,abc123,"Later, with a comma"' "commas should be quoted"
+    assert_not_contains "$output" "def456" "single-thread export should skip other threads"
+
+    output=$($THREADS_BIN log --csv --all 2>/dev/null)
+    assert_eq "2026-01-02 09:00:00,def456,Earlier" "$(echo "$output" | sed -n 3p)" "--all should merge threads chronologically"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN log --csv)
+    assert_eq "1" "$exit_code" "--csv without id or --all should fail"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: log --since/--until/--tail filter a thread's entries
+test_log_date_filters() {
+    begin_test "log --since, --until and --tail filter entries"
+    setup_test_workspace
+
+    local recent old
+    recent=$(date -d "-2 days" +%Y-%m-%d)
+    old=$(date -d "-30 days" +%Y-%m-%d)
+    cat > "$TEST_WS/.threads/abc123-history.md" << EOF
+---
+id: abc123
+name: History
+status: active
+log:
+- ts: $recent 10:00:00
+  text: Recent work
+- ts: $old 09:00:00
+  text: Old work
+- ts: ''
+  text: Undated note
+---
+EOF
+
+    local output
+    output=$($THREADS_BIN log abc123 --since 7d --json 2>/dev/null)
+    assert_eq "Recent work" "$(echo "$output" | jq -r 'map(.text) | join(",")')" "relative --since keeps the last week"
+
+    output=$($THREADS_BIN log abc123 --until "$(date -d '-7 days' +%Y-%m-%d)" --json 2>/dev/null)
+    assert_eq "Old work" "$(echo "$output" | jq -r 'map(.text) | join(",")')" "--until keeps older entries, undated dropped"
+
+    output=$($THREADS_BIN log abc123 -n 2 --json 2>/dev/null)
+    assert_eq "Recent work,Old work" "$(echo "$output" | jq -r 'map(.text) | join(",")')" "--tail keeps the most recent"
+
+    output=$($THREADS_BIN log --since 1d --json 2>/dev/null)
+    assert_eq "[]" "$(echo "$output" | jq -c .)" "empty filter result is an empty JSON array"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN log abc123 --since yesterday)
+    assert_eq "1" "$exit_code" "invalid date rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_log_adds_entry
 test_log_creates_timestamp_entry
 test_log_entry_format
+test_log_count_and_archive
+test_log_csv_export
+test_log_date_filters
@@ -113,6 +113,189 @@ test_move_between_categories() {
     end_test
 }
 
+# Test: move --interactive refuses to prompt without a terminal
+test_move_interactive_requires_tty() {
+    begin_test "move --interactive errors without a terminal"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    mkdir -p "$TEST_WS/cat1"
+
+    local output code
+    output=$(cd "$TEST_WS" && $THREADS_BIN move abc123 --interactive 2>&1 </dev/null)
+    code=$?
+
+    assert_eq "1" "$code" "should fail without a TTY"
+    assert_contains "$output" "explicit destination path" "should point to the explicit path form"
+    assert_file_exists "$(get_thread_path abc123)" "thread should stay in place"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move --up moves a thread to the parent directory
+test_move_up() {
+    begin_test "move --up moves to parent directory"
+    setup_nested_workspace
+
+    create_thread_at_project "abc123" "Project Thread" "cat1" "proj1" "active"
+
+    local output
+    output=$($THREADS_BIN move abc123 --up 2>&1)
+
+    assert_contains "$output" "cat1/.threads/" "should report the parent destination"
+    assert_file_not_exists "$(get_thread_path abc123 "$TEST_WS/cat1/proj1")" "should leave proj1"
+    assert_file_exists "$TEST_WS/cat1/.threads/abc123-project-thread.md" "should land in cat1"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move --up refuses to leave the repo
+test_move_up_at_root() {
+    begin_test "move --up fails at repo root"
+    setup_test_workspace
+
+    create_thread "abc123" "Root Thread" "active"
+
+    local output code
+    output=$($THREADS_BIN move abc123 --up 2>&1)
+    code=$?
+
+    assert_eq "1" "$code" "should fail at the repo root"
+    assert_contains "$output" "already at the repo root" "should explain why"
+    assert_file_exists "$(get_thread_path abc123)" "thread should stay in place"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move --into-subdir moves into a child directory and creates .threads/
+test_move_into_subdir() {
+    begin_test "move --into-subdir moves into child directory"
+    setup_nested_workspace
+
+    create_thread_at_category "abc123" "Category Thread" "cat1" "active"
+    mkdir -p "$TEST_WS/cat1/proj2"
+
+    $THREADS_BIN move abc123 --into-subdir proj2 >/dev/null 2>&1
+
+    assert_file_not_exists "$TEST_WS/cat1/.threads/abc123-category-thread.md" "should leave cat1"
+    assert_file_exists "$TEST_WS/cat1/proj2/.threads/abc123-category-thread.md" "should land in cat1/proj2"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move --into-subdir rejects missing directories and paths
+test_move_into_subdir_invalid() {
+    begin_test "move --into-subdir rejects bad names"
+    setup_nested_workspace
+
+    create_thread_at_category "abc123" "Category Thread" "cat1" "active"
+
+    local output code
+    output=$($THREADS_BIN move abc123 --into-subdir nope 2>&1)
+    code=$?
+    assert_eq "1" "$code" "should fail for a missing directory"
+    assert_contains "$output" "no directory 'nope' in cat1" "should name the missing directory"
+
+    output=$($THREADS_BIN move abc123 --into-subdir ../cat2 2>&1)
+    code=$?
+    assert_eq "1" "$code" "should fail for a path"
+    assert_contains "$output" "single directory name" "should require a plain name"
+
+    assert_file_exists "$(get_thread_path abc123 "$TEST_WS/cat1")" "thread should stay in place"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move --into-subdir refuses to cross into a nested repo
+test_move_into_subdir_nested_repo() {
+    begin_test "move --into-subdir refuses nested git repo"
+    setup_test_workspace
+
+    create_thread "abc123" "Root Thread" "active"
+    mkdir -p "$TEST_WS/vendor"
+    git -C "$TEST_WS/vendor" init -q
+
+    local output code
+    output=$($THREADS_BIN move abc123 --into-subdir vendor 2>&1)
+    code=$?
+
+    assert_eq "1" "$code" "should fail for a nested repo"
+    assert_contains "$output" "nested git repository" "should explain the boundary"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move reports threads that point at the moved one
+test_move_reports_references() {
+    begin_test "move lists referencing threads"
+    setup_nested_workspace
+
+    create_thread "abc123" "Thread to Move" "active"
+    create_thread "def456" "Child Thread" "active"
+    create_thread "ghi789" "Blocked Thread" "active"
+    $THREADS_BIN update def456 --parent abc123 >/dev/null 2>&1
+    $THREADS_BIN link ghi789 --blocked-by abc123 >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN move abc123 cat1 -f plain 2>/dev/null)
+    assert_contains "$output" "Moved: .threads/abc123-thread-to-move.md → cat1/.threads/abc123-thread-to-move.md" "move reported"
+    assert_contains "$output" "Referenced by 2 thread(s):" "reference count"
+    assert_contains "$output" "def456  .threads/def456-child-thread.md  (parent)" "parent reference listed"
+    assert_contains "$output" "ghi789  .threads/ghi789-blocked-thread.md  (blocked-by)" "link reference listed"
+    assert_eq "abc123" "$(get_thread_field "def456" "parent")" "parent left intact"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --dry-run previews the destination and references without moving
+test_move_dry_run() {
+    begin_test "move --dry-run writes nothing"
+    setup_nested_workspace
+
+    create_thread "abc123" "Thread to Move" "active"
+    create_thread "def456" "Child Thread" "active"
+    $THREADS_BIN update def456 --parent abc123 >/dev/null 2>&1
+
+    local old_path output
+    old_path=$(get_thread_path "abc123")
+    output=$($THREADS_BIN move abc123 cat1 --dry-run -f plain 2>&1)
+    assert_contains "$output" "Would move: .threads/abc123-thread-to-move.md → cat1/.threads/abc123-thread-to-move.md" "destination previewed"
+    assert_contains "$output" "def456" "reference previewed"
+    assert_not_contains "$output" "uncommitted" "no commit hint"
+    assert_file_exists "$old_path" "thread stays put"
+    assert_file_not_exists "$TEST_WS/cat1/.threads/abc123-thread-to-move.md" "nothing at the destination"
+
+    output=$($THREADS_BIN move abc123 cat1 --dry-run --json 2>/dev/null)
+    assert_json_field "$output" ".dry_run" "true" "JSON flags the preview"
+    assert_json_field "$output" ".references[0].kind" "parent" "JSON lists references"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a clean tracked thread moves with git mv
+test_move_uses_git_mv() {
+    begin_test "move stages a rename for clean threads"
+    setup_git_workspace
+
+    create_thread "abc123" "Thread to Move" "active"
+    mkdir -p "$TEST_WS/cat1"
+    $THREADS_BIN git commit abc123 -m "add thread" >/dev/null 2>&1
+
+    $THREADS_BIN move abc123 cat1 >/dev/null 2>&1
+    assert_contains "$(git -C "$TEST_WS" status --porcelain)" "R  .threads/abc123-thread-to-move.md -> cat1/.threads/abc123-thread-to-move.md" "rename staged"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_move_relocates_file
 test_move_preserves_content
@@ -120,3 +303,12 @@ test_move_to_project
 test_move_nonexistent_thread
 test_move_invalid_destination
 test_move_between_categories
+test_move_interactive_requires_tty
+test_move_up
+test_move_up_at_root
+test_move_into_subdir
+test_move_into_subdir_invalid
+test_move_into_subdir_nested_repo
+test_move_reports_references
+test_move_dry_run
+test_move_uses_git_mv
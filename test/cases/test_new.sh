@@ -118,6 +118,240 @@ test_new_outputs_id() {
     end_test
 }
 
+# Test: non-default --status is recorded in the initial log entry
+test_new_status_logged() {
+    begin_test "new --status records non-default status in log"
+    setup_test_workspace
+
+    local output id
+    output=$($THREADS_BIN new . "Planned Thread" --status planning --json 2>/dev/null)
+    id=$(echo "$output" | jq -r '.id')
+
+    local log
+    log=$($THREADS_BIN read "$id" --json 2>/dev/null | jq -r '.log[0].text')
+    assert_eq "Created thread as planning." "$log" "log should mention chosen status"
+
+    output=$($THREADS_BIN new . "Default Thread" --json 2>/dev/null)
+    id=$(echo "$output" | jq -r '.id')
+    log=$($THREADS_BIN read "$id" --json 2>/dev/null | jq -r '.log[0].text')
+    assert_eq "Created thread." "$log" "default status uses plain entry"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: invalid --status lists the valid set
+test_new_invalid_status() {
+    begin_test "new --status rejects unknown status"
+    setup_test_workspace
+
+    local output
+    output=$($THREADS_BIN new . "Bad Status" --status bogus 2>&1)
+
+    assert_contains "$output" "Invalid status 'bogus'" "should reject unknown status"
+    assert_contains "$output" "planning" "should list valid statuses"
+    assert_eq "0" "$(count_threads)" "no thread should be created"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: new refuses a near-duplicate title unless --force or --no-dup-check
+test_new_duplicate_check() {
+    begin_test "new warns about similar existing threads"
+    setup_test_workspace
+
+    create_thread "abc123" "Fix login bug" "active"
+
+    local output code
+    output=$($THREADS_BIN new "Fix login bugs" --desc "d" 2>&1 </dev/null)
+    code=$?
+    assert_eq "1" "$code" "near-duplicate should be refused"
+    assert_contains "$output" "abc123  Fix login bug" "names the existing thread"
+    assert_contains "$output" "--force" "points to --force"
+    assert_eq "1" "$(count_threads)" "no thread created"
+
+    code=$(get_exit_code $THREADS_BIN new "Fix login bugs" --desc "d" --force)
+    assert_eq "0" "$code" "--force creates it"
+    code=$(get_exit_code $THREADS_BIN new "Fix login bug" --desc "d" --no-dup-check)
+    assert_eq "0" "$code" "--no-dup-check creates it"
+
+    code=$(get_exit_code $THREADS_BIN new "Write release notes" --desc "d")
+    assert_eq "0" "$code" "distinct title is not flagged"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: closed threads do not count as duplicates
+test_new_duplicate_check_ignores_closed() {
+    begin_test "new ignores closed threads in duplicate check"
+    setup_test_workspace
+
+    create_thread "abc123" "Fix login bug" "resolved"
+
+    local code
+    code=$(get_exit_code $THREADS_BIN new "Fix login bug" --desc "d")
+    assert_eq "0" "$code" "closed thread should not block"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: the deepest manifest over the target directory supplies status and tags
+test_new_directory_defaults() {
+    begin_test "new applies defaults from the nearest manifest"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config" "$TEST_WS/src/infra/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  tags: [team]
+EOF
+    cat > "$TEST_WS/src/infra/.threads-config/manifest.yaml" << 'EOF'
+defaults:
+  new: planning
+  tags: [infra, ops]
+EOF
+
+    local output id
+    output=$($THREADS_BIN new . "Root Thread" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    assert_eq "idea" "$(get_thread_field "$id" "status")" "root keeps the built-in status"
+    assert_contains "$(cat "$(get_thread_path "$id")")" "- team" "root manifest tags applied"
+
+    output=$($THREADS_BIN new src/infra "Infra Thread" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    assert_eq "planning" "$(get_thread_field "$id" "status")" "deeper manifest status from the repo root"
+    local content
+    content=$(cat "$(get_thread_path "$id")")
+    assert_contains "$content" "- infra" "deeper manifest tags applied"
+    assert_not_contains "$content" "- team" "deeper tags replace shallower ones"
+
+    output=$(cd "$TEST_WS/src/infra" && $THREADS_BIN new "Local Thread" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    assert_eq "planning" "$(get_thread_field "$id" "status")" "deeper manifest status from inside the directory"
+
+    output=$($THREADS_BIN new src/infra "Active Infra" --status active 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    assert_eq "active" "$(get_thread_field "$id" "status")" "--status still wins"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --tag sets normalized tags at creation and mentions them in the log
+test_new_with_tags() {
+    begin_test "new --tag adds tags"
+    setup_test_workspace
+
+    local output id
+    output=$($THREADS_BIN new "Tagged Thread" --desc "d" --tag Backend --tag "UI Work" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    assert_eq "backend,ui-work" "$($THREADS_BIN read "$id" --json | jq -r '.tags | join(",")')" "tags normalized in frontmatter"
+    assert_eq "Created thread with tags backend, ui-work." "$($THREADS_BIN read "$id" --json | jq -r '.log[0].text')" "log mentions the tags"
+
+    $THREADS_BIN new "Other Work" --desc "d" >/dev/null 2>&1
+    output=$($THREADS_BIN list --tag backend --json 2>/dev/null)
+    assert_eq "$id" "$(echo "$output" | jq -r '[.threads[].id] | join(",")')" "list --tag finds only the tagged thread"
+
+    output=$($THREADS_BIN new "Bad Tag" --desc "d" --tag "!!!" 2>&1)
+    assert_contains "$output" "invalid tag '!!!'" "unusable tag rejected"
+    assert_eq "2" "$(count_threads)" "nothing written for a bad tag"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --template fills the body from a project, or built-in, template
+test_new_template() {
+    begin_test "new --template starts the body from a template"
+    setup_test_workspace
+
+    local output
+    output=$($THREADS_BIN new --list-templates -f plain 2>/dev/null)
+    assert_contains "$output" "bug" "built-in bug template listed"
+    assert_contains "$output" "built-in" "source shown"
+
+    mkdir -p "$TEST_WS/.threads-config/templates"
+    printf '## Report\n\n{{title}} seen on {{date}} ({{id}})\n' > "$TEST_WS/.threads-config/templates/bug.md"
+    output=$($THREADS_BIN new --list-templates --json 2>/dev/null)
+    assert_eq ".threads-config/templates/bug.md" "$(echo "$output" | jq -r '.[] | select(.name == "bug") | .source' | sed "s|^$TEST_WS/||")" "project template overrides the built-in"
+
+    local id content
+    output=$($THREADS_BIN new "Crash on save" --template bug --desc "d" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    content=$(cat "$(get_thread_path "$id")")
+    assert_contains "$content" "Crash on save seen on $(date +%Y-%m-%d) ($id)" "placeholders substituted"
+
+    output=$($THREADS_BIN new "Pick a database" --template decision --desc "d" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    assert_contains "$(cat "$(get_thread_path "$id")")" "## Consequences" "built-in template used"
+
+    output=$($THREADS_BIN new "Missing" --template nope --desc "d" 2>&1)
+    assert_contains "$output" "template 'nope' not found" "missing template errors"
+    assert_contains "$output" ".threads-config/templates/nope.md" "searched paths listed"
+    assert_eq "2" "$(count_threads)" "nothing written for a missing template"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --var fills custom template placeholders; leftovers are reported
+test_new_template_vars() {
+    begin_test "new --var substitutes template placeholders"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads-config/templates"
+    printf '## Report\n\n{{name}}: severity {{severity}} on {{ os }} ({{owner}})\n' > "$TEST_WS/.threads-config/templates/report.md"
+
+    local output id content
+    output=$($THREADS_BIN new "Crash on save" --template report --desc "d" \
+        --var severity=high --var "os=Linux 6.1" --var owner=ops 2>&1)
+    id=$(extract_id_from_output "$output")
+    content=$(cat "$(get_thread_path "$id")")
+    assert_contains "$content" "Crash on save: severity high on Linux 6.1 (ops)" "vars and {{name}} substituted"
+    assert_not_contains "$output" "unfilled" "no warning when every placeholder is filled"
+
+    output=$($THREADS_BIN new "Slow startup" --template report --desc "d" --var severity=low 2>&1)
+    assert_contains "$output" "Warning: template placeholders left unfilled: {{os}}, {{owner}}" "missing vars listed"
+    id=$(extract_id_from_output "$output")
+    assert_contains "$(cat "$(get_thread_path "$id")")" "{{ os }}" "unfilled placeholders stay in the body"
+
+    output=$($THREADS_BIN new "Bad var" --template report --desc "d" --var novalue 2>&1)
+    assert_contains "$output" "invalid --var 'novalue': expected key=value" "malformed --var rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a bare `new` prompts at a terminal and stays strict otherwise
+test_new_interactive_prompt() {
+    begin_test "new without a title prompts only at a terminal"
+    setup_test_workspace
+
+    local output
+    output=$($THREADS_BIN new < /dev/null 2>&1)
+    assert_contains "$output" "title is required" "non-TTY keeps the strict error"
+    assert_eq "0" "$(count_threads)" "nothing written without a title"
+
+    if command -v script >/dev/null 2>&1; then
+        output=$(printf 'Prompted Thread\nFrom the prompt\n2\n' | (cd "$TEST_WS" && script -qec "$THREADS_BIN new" /dev/null) 2>&1)
+        assert_contains "$output" "1) idea (default)" "open statuses offered as a menu"
+        local id
+        id=$(extract_id_from_output "$output")
+        assert_eq "planning" "$(get_thread_field "$id" "status")" "menu choice used"
+        assert_eq "From the prompt" "$(get_thread_field "$id" "desc")" "description prompted"
+
+        output=$(printf 'Abandoned\n' | (cd "$TEST_WS" && script -qec "$THREADS_BIN new" /dev/null) 2>&1)
+        assert_contains "$output" "aborted" "EOF aborts"
+        assert_eq "1" "$(count_threads)" "aborted prompt writes nothing"
+    fi
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_new_creates_file
 test_new_generates_id
@@ -126,3 +360,12 @@ test_new_with_desc
 test_new_default_status_idea
 test_new_with_status
 test_new_outputs_id
+test_new_status_logged
+test_new_invalid_status
+test_new_duplicate_check
+test_new_duplicate_check_ignores_closed
+test_new_directory_defaults
+test_new_with_tags
+test_new_template
+test_new_template_vars
+test_new_interactive_prompt
@@ -89,7 +89,97 @@ test_note_edit() {
     end_test
 }
 
+# Test: note promote moves a note into the body under a heading
+test_note_promote() {
+    begin_test "note promote moves note into body"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output hash
+    output=$($THREADS_BIN note abc123 add "Queue writes behind a lock" 2>/dev/null)
+    hash=$(extract_hash_from_output "$output")
+
+    $THREADS_BIN note abc123 promote "$hash" --heading "Design" >/dev/null 2>&1
+    assert_eq "0" "$?" "promote should succeed"
+
+    local notes_output
+    notes_output=$($THREADS_BIN note abc123 list 2>/dev/null)
+    assert_not_contains "$notes_output" "Queue writes" "note should be gone from frontmatter"
+
+    local body
+    body=$(sed '1,/^---$/d' "$(get_thread_path abc123)")
+    assert_contains "$body" "## Design" "body should gain the heading"
+    assert_contains "$body" "Queue writes behind a lock" "body should contain the note text"
+
+    local log
+    log=$($THREADS_BIN read abc123 --json 2>/dev/null | jq -r '.log[0].text')
+    assert_contains "$log" "Moved note $hash to body" "promotion should be logged"
+
+    # Reserved section names are rejected
+    output=$($THREADS_BIN note abc123 add "Another" 2>/dev/null)
+    hash=$(extract_hash_from_output "$output")
+    local code
+    code=$(get_exit_code $THREADS_BIN note abc123 promote "$hash" --heading "Notes")
+    assert_eq "1" "$code" "reserved heading should fail"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: note edit without new text opens $EDITOR; multi-line notes render indented
+test_note_edit_in_editor() {
+    begin_test "note edit opens \$EDITOR and keeps multi-line notes"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output hash
+    output=$($THREADS_BIN note abc123 add "Draft note" 2>/dev/null)
+    hash=$(extract_hash_from_output "$output")
+
+    output=$(EDITOR="sed -i s/Draft/Final/" $THREADS_BIN note abc123 edit "$hash" 2>&1 </dev/null)
+    assert_contains "$output" "Edited note $hash" "editor result saved"
+    assert_json_field "$($THREADS_BIN note abc123 --json 2>/dev/null)" ".[0].text" "Final note" "text replaced"
+
+    printf 'Final note\nsecond line\n' > "$TEST_WS/new.txt"
+    EDITOR="cp $TEST_WS/new.txt" $THREADS_BIN note abc123 edit "$hash" >/dev/null 2>&1 </dev/null
+    assert_eq "Final note|second line" "$($THREADS_BIN note abc123 --json 2>/dev/null | jq -r '.[0].text | split("\n") | join("|")')" "newlines stored in text"
+    output=$($THREADS_BIN note abc123 -f plain 2>/dev/null)
+    assert_contains "$output" "- Final note ($hash)" "first line carries the hash"
+    assert_contains "$output" "  second line" "continuation indented in list"
+    output=$($THREADS_BIN read abc123 -f pretty --width 80 2>/dev/null | sed 's/\x1b\[[0-9;]*m//g')
+    assert_contains "$output" "│ • Final note" "bullet on first line"
+    assert_contains "$output" "│   second line" "continuation indented under the bullet"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: an empty editor buffer cancels the edit
+test_note_edit_empty_cancels() {
+    begin_test "note edit with empty editor output leaves the file alone"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output hash before
+    output=$($THREADS_BIN note abc123 add "Keep me" 2>/dev/null)
+    hash=$(extract_hash_from_output "$output")
+    before=$(cat "$(get_thread_path abc123)")
+
+    output=$(EDITOR="truncate -s 0" $THREADS_BIN note abc123 edit "$hash" 2>&1 </dev/null)
+    assert_contains "$output" "cancelled" "cancel reported"
+    assert_eq "$before" "$(cat "$(get_thread_path abc123)")" "thread file unchanged"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_note_add
 test_note_remove
 test_note_edit
+test_note_promote
+test_note_edit_in_editor
+test_note_edit_empty_cancels
@@ -0,0 +1,95 @@
+#!/usr/bin/env bash
+# Tests for 'threads deadline --notify' / 'threads event --notify' / 'threads agenda --notify'
+
+# Test: without notify_command, due-today items go to stderr once per day
+test_deadline_notify_stderr() {
+    begin_test "deadline --notify falls back to stderr and notifies once"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    local today
+    today=$(date +%Y-%m-%d)
+    $THREADS_BIN deadline abc123 add "$today" "Ship release" >/dev/null 2>&1
+    $THREADS_BIN deadline abc123 add 2099-01-01 "Far future" >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN deadline --notify 2>&1 >/dev/null)
+    assert_contains "$output" "Due today: Ship release [abc123]" "should print the reminder to stderr"
+    assert_not_contains "$output" "Far future" "only items due today are notified"
+
+    output=$($THREADS_BIN deadline --notify 2>&1)
+    assert_contains "$output" "Notified 0 deadlines due today (1 already notified)" "should not notify twice"
+    assert_not_contains "$output" "Due today:" "marker should suppress the repeat"
+    assert_file_exists "$TEST_WS/.git/threads-notified" "marker file should be written"
+    assert_not_contains "$(git status --porcelain --untracked-files=all)" "notified" "marker should stay out of the worktree"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: notify_command runs with substituted placeholders
+test_event_notify_command() {
+    begin_test "event --notify runs behavior.notify_command"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    local today
+    today=$(date +%Y-%m-%d)
+    $THREADS_BIN event abc123 add "$today" 14:00 "Team's sync" >/dev/null 2>&1
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << EOF
+behavior:
+  notify_command: "printf '%s|%s|%s\\\\n' {text} {date} {id} >> $TEST_WS/notified.log"
+EOF
+
+    local output
+    output=$($THREADS_BIN event --notify 2>&1)
+    assert_contains "$output" "Notified 1 events due today" "should report the notification"
+    assert_eq "Team's sync|$today|abc123" "$(cat "$TEST_WS/notified.log")" "command should receive quoted values"
+
+    $THREADS_BIN event --notify >/dev/null 2>&1
+    assert_eq "1" "$(wc -l < "$TEST_WS/notified.log" | tr -d ' ')" "command should run once per day"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN event abc123 --notify)
+    assert_eq "1" "$exit_code" "--notify should reject a thread id"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: agenda --notify covers deadlines and events together
+test_agenda_notify() {
+    begin_test "agenda --notify notifies deadlines and events due today"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    create_thread "def456" "Other Thread" "active"
+    local today
+    today=$(date +%Y-%m-%d)
+    $THREADS_BIN deadline abc123 add "$today" "Ship release" >/dev/null 2>&1
+    $THREADS_BIN deadline abc123 add 2000-01-01 "Long overdue" >/dev/null 2>&1
+    $THREADS_BIN event def456 add "$today" 09:00 "Standup" >/dev/null 2>&1
+    $THREADS_BIN event def456 add 2099-01-01 "Far future" >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN agenda --notify 2>&1)
+    assert_contains "$output" "Due today: Ship release [abc123]" "should notify the deadline"
+    assert_contains "$output" "Due today: Standup [def456]" "should notify the event"
+    assert_contains "$output" "Notified 2 deadlines and events due today" "should report both"
+    assert_not_contains "$output" "Long overdue" "overdue deadlines are not due today"
+    assert_not_contains "$output" "Far future" "only items due today are notified"
+
+    output=$($THREADS_BIN agenda --notify 2>&1)
+    assert_contains "$output" "(2 already notified)" "should not notify twice"
+    assert_not_contains "$output" "Due today:" "marker should suppress the repeat"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_deadline_notify_stderr
+test_event_notify_command
+test_agenda_notify
@@ -56,7 +56,338 @@ test_read_exact_id_required() {
     end_test
 }
 
+# Test: display.read_sections reorders and filters pretty output
+test_read_sections_order() {
+    begin_test "display.read_sections controls pretty section order"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-ordered.md" << 'EOF'
+---
+id: abc123
+name: Ordered
+status: active
+notes:
+- text: NOTEMARK
+  hash: a1b2
+log:
+- ts: 2026-01-02 10:00:00
+  text: LOGMARK
+---
+
+BODYMARK
+EOF
+
+    local output body_line log_line
+    output=$($THREADS_BIN read abc123 -f pretty --width 80 2>/dev/null)
+    body_line=$(echo "$output" | grep -n BODYMARK | cut -d: -f1)
+    log_line=$(echo "$output" | grep -n LOGMARK | cut -d: -f1)
+    assert_gt "$log_line" "$body_line" "default order shows body before log"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+display:
+  read_sections: [log, body]
+EOF
+    output=$($THREADS_BIN read abc123 -f pretty --width 80 2>/dev/null)
+    body_line=$(echo "$output" | grep -n BODYMARK | cut -d: -f1)
+    log_line=$(echo "$output" | grep -n LOGMARK | cut -d: -f1)
+    assert_gt "$body_line" "$log_line" "configured order shows log before body"
+    assert_not_contains "$output" "NOTEMARK" "omitted sections are hidden"
+
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+display:
+  read_sections: [log, comments]
+EOF
+    output=$(cd "$TEST_WS" && $THREADS_BIN read abc123 -f pretty 2>&1)
+    assert_contains "$output" "unknown section 'comments'" "unknown names should error"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: read --stat shows header, counts, and footer but no content sections
+test_read_stat() {
+    begin_test "read --stat shows only the summary"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-summary.md" << 'EOF'
+---
+id: abc123
+name: Summary
+desc: DESCMARK
+status: active
+notes:
+- text: NOTEMARK
+  hash: a1b2
+todo:
+- text: TODOMARK
+  hash: c3d4
+  done: true
+log:
+- ts: 2026-01-02 10:00:00
+  text: LOGMARK
+---
+
+BODYMARK
+EOF
+
+    local output
+    output=$($THREADS_BIN read abc123 -f pretty --stat --width 80 2>/dev/null)
+    assert_contains "$output" "Summary" "should show title"
+    assert_contains "$output" "DESCMARK" "should show description"
+    assert_contains "$output" "1 notes · 1/1 todos done" "should show item counts"
+    assert_contains "$output" ".threads/abc123-summary.md" "should show footer path"
+    assert_not_contains "$output" "BODYMARK" "should skip body"
+    assert_not_contains "$output" "NOTEMARK" "should skip notes"
+    assert_not_contains "$output" "LOGMARK" "should skip log"
+
+    local debug
+    debug=$($THREADS_BIN read abc123 -f pretty --stat --width 80 --debug-widths 2>&1 >/dev/null)
+    assert_not_contains "$debug" "(expected 80)" "box lines should all be padded to the full width"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --absolute-dates / display.absolute_dates replace relative times
+test_read_absolute_dates() {
+    begin_test "read and list --absolute-dates show YYYY-MM-DD"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-dated.md" << 'EOF'
+---
+id: abc123
+name: Dated
+status: active
+log:
+- ts: 2020-01-02 10:00:00
+  text: LOGMARK
+---
+
+Body.
+EOF
+    git -C "$TEST_WS" add .threads
+    git -C "$TEST_WS" commit -q -m "add dated"
+
+    local output today
+    today=$(date +%Y-%m-%d)
+    output=$($THREADS_BIN read abc123 -f pretty --width 80 2>/dev/null)
+    assert_not_contains "$output" "2020-01-02" "default log times are relative"
+    assert_matches "[0-9]+y LOGMARK" "$output" "default shows years ago"
+
+    output=$($THREADS_BIN read abc123 -f pretty --absolute-dates --width 80 2>/dev/null)
+    assert_contains "$output" "2020-01-02 LOGMARK" "log should show the absolute date"
+    assert_contains "$output" "$today" "history should show the commit date"
+
+    output=$($THREADS_BIN list -f pretty --absolute-dates 2>/dev/null)
+    assert_contains "$output" "$today" "list should show absolute created/modified dates"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+display:
+  absolute_dates: true
+EOF
+    output=$($THREADS_BIN read abc123 -f pretty --width 80 2>/dev/null)
+    assert_contains "$output" "2020-01-02 LOGMARK" "config should enable absolute dates"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: read accepts several ids, arrays in JSON, --keep-going skips bad refs
+test_read_multiple_ids() {
+    begin_test "read renders multiple ids in order"
+    setup_test_workspace
+
+    create_thread "abc123" "First Thread" "active"
+    create_thread "def456" "Second Thread" "idea"
+
+    local output
+    output=$($THREADS_BIN read abc123 def456 --json 2>/dev/null)
+    assert_eq "abc123,def456" "$(echo "$output" | jq -r '[.[].id] | join(",")')" "JSON should be an array in argument order"
+
+    output=$($THREADS_BIN read abc123 --json 2>/dev/null)
+    assert_eq "abc123" "$(echo "$output" | jq -r '.id')" "single id should keep the object shape"
+
+    output=$($THREADS_BIN read def456 abc123 -f pretty --width 60 2>/dev/null)
+    assert_eq "2" "$(echo "$output" | grep -c '╭')" "should render one box per thread"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN read abc123 ffffff)
+    assert_eq "1" "$exit_code" "missing ref should fail without --keep-going"
+
+    output=$($THREADS_BIN read abc123 ffffff def456 --keep-going --json 2>/dev/null)
+    assert_eq "abc123,def456" "$(echo "$output" | jq -r '[.[].id] | join(",")')" "--keep-going should render the rest"
+    output=$($THREADS_BIN read abc123 ffffff --keep-going --json 2>&1 >/dev/null)
+    assert_contains "$output" "ffffff" "should report the failing ref"
+    exit_code=$(get_exit_code $THREADS_BIN read abc123 ffffff --keep-going)
+    assert_eq "1" "$exit_code" "--keep-going should still exit non-zero"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --copy fails clearly without clipboard support (default build / headless)
+test_read_copy_unavailable() {
+    begin_test "read --copy errors clearly when unavailable"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output code
+    output=$(cd "$TEST_WS" && DISPLAY= WAYLAND_DISPLAY= $THREADS_BIN read abc123 --copy 2>&1 </dev/null)
+    code=$?
+
+    assert_eq "1" "$code" "should fail without a clipboard"
+    assert_contains "$output" "clipboard" "error should mention the clipboard"
+    assert_not_contains "$output" "id: abc123" "should not fall back to printing the thread"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN read abc123 --copy --stat)
+    assert_eq "1" "$exit_code" "--copy should conflict with --stat"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: markdown tables in the body are sized to the box
+test_read_body_table_fits_box() {
+    begin_test "read fits wide markdown tables inside the box"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-table.md" << 'EOF'
+---
+id: abc123
+name: Table
+status: active
+---
+
+| Name | Owner | Status | Notes about the item |
+|------|-------|--------|----------------------|
+| Alpha release candidate | Jordan Smith | in progress | needs review from the platform team |
+| Beta | Sam | done | shipped |
+EOF
+
+    local output debug
+    output=$($THREADS_BIN read abc123 -f pretty --width 40 2>/dev/null)
+    assert_contains "$output" "Owner" "table header should be rendered"
+    assert_contains "$output" "Beta" "table rows should be rendered"
+
+    debug=$($THREADS_BIN read abc123 -f pretty --width 40 --debug-widths 2>&1 >/dev/null)
+    assert_not_contains "$debug" "(expected 40)" "no table line should exceed the box width"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: read shows the raw file when the frontmatter is unterminated
+test_read_unparsable_falls_back_to_raw() {
+    begin_test "read falls back to raw content for broken frontmatter"
+    setup_test_workspace
+
+    printf -- '---\nid: bad001\nname: Broken\nstatus: active\n\nBody text.\n' > "$TEST_WS/.threads/bad001-broken.md"
+
+    local output stderr code
+    output=$($THREADS_BIN read bad001 -f pretty --width 80 2>/dev/null)
+    code=$?
+    stderr=$($THREADS_BIN read bad001 -f pretty --width 80 2>&1 >/dev/null)
+    assert_eq "0" "$code" "pretty read should succeed"
+    assert_contains "$output" "Body text." "pretty shows the raw file"
+    assert_contains "$stderr" "could not be parsed" "pretty warns on stderr"
+
+    stderr=$($THREADS_BIN read bad001 -f plain 2>&1 >/dev/null)
+    assert_contains "$stderr" "unclosed frontmatter" "plain warns with the parse error"
+
+    output=$($THREADS_BIN read bad001 --json 2>/dev/null)
+    assert_json_valid "$output"
+    assert_contains "$(echo "$output" | jq -r '.error')" "unclosed frontmatter" "json carries the error"
+    assert_eq ".threads/bad001-broken.md" "$(echo "$output" | jq -r '.path')" "json carries the path"
+    assert_contains "$(echo "$output" | jq -r '.raw')" "Body text." "json carries the raw file"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: read --section prints only the requested sections
+test_read_section() {
+    begin_test "read --section narrows output to the requested sections"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    printf 'Design notes here\n' | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "Write the **tests**" >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN read abc123 --section todo -f plain 2>/dev/null)
+    assert_eq "☐ Write the tests" "$output" "plain todo list without styling"
+
+    output=$($THREADS_BIN read abc123 --section body -f plain 2>/dev/null)
+    assert_eq "Design notes here" "$output" "body as raw text"
+
+    output=$($THREADS_BIN read abc123 --section body,todo -f plain 2>/dev/null)
+    assert_eq "$(printf 'Design notes here\n\n☐ Write the tests')" "$output" "sections in the requested order"
+
+    output=$($THREADS_BIN read abc123 --section notes -f plain 2>/dev/null)
+    assert_eq "0" "$?" "empty section exits 0"
+    assert_eq "" "$output" "empty section prints nothing"
+
+    output=$($THREADS_BIN read abc123 --section todo,notes --json 2>/dev/null)
+    assert_eq "id,notes,todo" "$(echo "$output" | jq -r 'keys | join(",")')" "JSON narrowed to the sections"
+    assert_json_field "$output" ".todo[0].text" "Write the **tests**" "todo items kept"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN read abc123 --section bogus)
+    assert_eq "1" "$exit_code" "unknown section rejected"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --frontmatter-only and --no-body trim the output to the metadata
+test_read_frontmatter_only() {
+    begin_test "read --frontmatter-only and --no-body"
+    setup_test_workspace
+
+    local output id path
+    output=$($THREADS_BIN new "Reader" --desc "d" --body "hello body" 2>/dev/null)
+    id=$(extract_id_from_output "$output")
+    path=$(get_thread_path "$id")
+
+    output=$($THREADS_BIN read "$id" --frontmatter-only 2>/dev/null)
+    assert_eq "$(sed -n '2,/^---$/p' "$path" | sed '$d')" "$output" "frontmatter printed as written"
+    assert_not_contains "$output" "hello body" "no body"
+
+    output=$($THREADS_BIN read "$id" --frontmatter-only --json 2>/dev/null)
+    assert_json_field "$output" ".name" "Reader" "JSON keeps the metadata"
+    assert_eq "false" "$(echo "$output" | jq 'has("body") or has("raw")')" "JSON omits body and raw"
+
+    output=$($THREADS_BIN read "$id" --no-body --json 2>/dev/null)
+    assert_eq "false" "$(echo "$output" | jq 'has("body")')" "--no-body drops body from JSON"
+
+    output=$($THREADS_BIN read "$id" --no-body -f plain 2>/dev/null)
+    assert_contains "$output" "name: Reader" "plain --no-body keeps the frontmatter"
+    assert_not_contains "$output" "hello body" "plain --no-body drops the body"
+
+    output=$($THREADS_BIN read "$id" --no-body --width 60 2>/dev/null)
+    assert_contains "$output" "Reader" "pretty header still shown"
+    assert_not_contains "$output" "hello body" "pretty --no-body drops the body"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_read_by_id
 test_read_outputs_content
 test_read_exact_id_required
+test_read_sections_order
+test_read_stat
+test_read_absolute_dates
+test_read_multiple_ids
+test_read_copy_unavailable
+test_read_body_table_fits_box
+test_read_unparsable_falls_back_to_raw
+test_read_section
+test_read_frontmatter_only
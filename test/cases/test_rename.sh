@@ -0,0 +1,48 @@
+#!/usr/bin/env bash
+# Tests for 'threads rename'
+
+# Test: rename updates the title and moves the file, keeping the ID prefix
+test_rename_thread() {
+    begin_test "rename changes title and filename"
+    setup_test_workspace
+
+    create_thread "abc123" "Old Name" "active"
+    git add . && git commit -q -m "add thread"
+
+    local output
+    output=$($THREADS_BIN rename abc123 "Better Name" 2>/dev/null)
+    assert_contains "$output" ".threads/abc123-old-name.md → .threads/abc123-better-name.md" "should report the move"
+    assert_eq "$TEST_WS/.threads/abc123-better-name.md" "$($THREADS_BIN path abc123 2>/dev/null)" "ID still resolves to the new file"
+    assert_json_field "$($THREADS_BIN read abc123 --json 2>/dev/null)" ".name" "Better Name" "title updated"
+    assert_contains "$(git status --porcelain)" "R" "clean file should move with git mv"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --title-only keeps the filename; an existing target refuses
+test_rename_title_only_and_collision() {
+    begin_test "rename --title-only and target collisions"
+    setup_test_workspace
+
+    create_thread "abc123" "Old Name" "active"
+
+    $THREADS_BIN rename abc123 "Kept File" --title-only >/dev/null 2>&1
+    assert_eq "$TEST_WS/.threads/abc123-old-name.md" "$($THREADS_BIN path abc123 2>/dev/null)" "filename unchanged"
+    assert_json_field "$($THREADS_BIN read abc123 --json 2>/dev/null)" ".name" "Kept File" "title updated"
+
+    touch "$TEST_WS/.threads/abc123-taken.md"
+    local output exit_code
+    output=$($THREADS_BIN rename abc123 "Taken" 2>&1)
+    exit_code=$?
+    assert_eq "1" "$exit_code" "should fail"
+    assert_contains "$output" "cannot rename: .threads/abc123-taken.md already exists" "should name the target"
+    assert_json_field "$($THREADS_BIN read abc123 --json 2>/dev/null)" ".name" "Kept File" "title untouched on failure"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_rename_thread
+test_rename_title_only_and_collision
@@ -21,8 +21,8 @@ test_commit_isolates_thread_files() {
     echo "unrelated content" > "$TEST_WS/unrelated.txt"
     git -C "$TEST_WS" add unrelated.txt
 
-    # Commit only the thread
-    $THREADS_BIN git commit abc123 -m "threads: add abc123" >/dev/null 2>&1
+    # Commit only the thread (staged non-thread files need --allow-mixed)
+    $THREADS_BIN git commit abc123 -m "threads: add abc123" --allow-mixed >/dev/null 2>&1
 
     # Check: unrelated file should still be staged
     local status
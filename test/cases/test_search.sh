@@ -106,8 +106,110 @@ test_search_hints_about_closed_metadata_matches() {
     end_test
 }
 
+test_search_in_scope() {
+    begin_test "search --in restricts matching to one part"
+    setup_test_workspace
+
+    create_thread "abc123" "Caching Thread" "active"
+    $THREADS_BIN todo abc123 add "Add caching layer" >/dev/null 2>&1
+    create_thread "def456" "Other Thread" "active"
+    echo "caching is mentioned in prose" | $THREADS_BIN body def456 --set >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN search "caching" --in todo --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "1" "only the todo match"
+    assert_json_field "$output" ".matches[0].id" "abc123" "todo thread matches"
+    assert_json_field "$output" ".matches[0].matched_in" "todo" "reports todo as matched field"
+
+    output=$($THREADS_BIN search "caching" --in body --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "1" "only the body match"
+    assert_json_field "$output" ".matches[0].id" "def456" "body thread matches"
+
+    output=$($THREADS_BIN search "caching" --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "2" "default scope searches everything"
+
+    teardown_test_workspace
+    end_test
+}
+
+test_search_regex() {
+    begin_test "search --regex lists matching lines with line numbers"
+    setup_test_workspace
+
+    create_thread "abc123" "Caching Thread" "active"
+    $THREADS_BIN note abc123 add "Cache TTL is 300s" >/dev/null 2>&1
+    printf 'first line\nTTL tuning: 60s\n' | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    create_thread "def456" "Other Thread" "active"
+
+    local output body_line
+    output=$($THREADS_BIN search -e 'ttl.*[0-9]+s' --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "1" "one matching thread"
+    assert_json_field "$output" ".matches[0].matches | length" "3" "note, its log entry, and body line match"
+    assert_json_field "$output" ".matches[0].matches[0].section" "notes" "note reported"
+    body_line=$(grep -n "^TTL tuning" "$TEST_WS"/.threads/abc123-*.md | cut -d: -f1)
+    assert_json_field "$output" ".matches[0].matches[2].line" "$body_line" "body match at its file line"
+
+    output=$($THREADS_BIN search -e 'ttl' --ignore-case=false --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "0" "--ignore-case=false matches case"
+
+    output=$($THREADS_BIN search -e 'ttl' --section log --json 2>/dev/null)
+    assert_json_field "$output" ".matches[0].matches | length" "1" "--section restricts fields"
+    assert_json_field "$output" ".matches[0].matched_in" "log" "log entry matched"
+
+    output=$($THREADS_BIN search -e 'TTL tuning' -f plain 2>/dev/null)
+    assert_contains "$output" "-caching-thread.md:$body_line: TTL tuning: 60s" "plain lists file, line and text"
+
+    local code
+    code=$(get_exit_code $THREADS_BIN search -e 'ttl(')
+    assert_eq "1" "$code" "invalid regex fails"
+    output=$($THREADS_BIN search -e 'ttl(' 2>&1)
+    assert_contains "$output" "invalid regex 'ttl('" "invalid regex is reported"
+
+    teardown_test_workspace
+    end_test
+}
+
+test_search_context() {
+    begin_test "search --context shows body lines around each hit"
+    setup_test_workspace
+
+    create_thread "abc123" "Caching Thread" "active"
+    printf 'intro\nbefore one\ncaching layer\nafter one\nfiller\nfiller\nfiller\nçé ünï caching\nlast\nmore caching\n' | $THREADS_BIN body abc123 --set >/dev/null 2>&1
+    local file
+    file=$(cd "$TEST_WS" && ls .threads/abc123-*.md)
+    local hit
+    hit=$(grep -n "^caching layer" "$TEST_WS/$file" | cut -d: -f1)
+
+    local output
+    output=$($THREADS_BIN search caching --in body -C 1 -f plain 2>/dev/null)
+    assert_contains "$output" "$file-$((hit - 1))- before one" "line before the hit"
+    assert_contains "$output" "$file:$hit: caching layer" "hit in grep style"
+    assert_contains "$output" "$file-$((hit + 1))- after one" "line after the hit"
+    assert_contains "$output" "$file:$((hit + 5)): çé ünï caching" "multibyte line listed"
+    assert_contains "$output" "--" "gaps are separated"
+    assert_eq "3" "$(echo "$output" | grep -c ": .*caching")" "--max-matches defaults to 3"
+
+    output=$($THREADS_BIN search caching --in body -C 0 --max-matches 1 -f plain 2>/dev/null)
+    assert_eq "1" "$(echo "$output" | grep -c ": .*caching")" "--max-matches limits hits"
+
+    output=$($THREADS_BIN search caching --in body -C 1 -f pretty 2>/dev/null)
+    assert_contains "$output" "çé ünï caching" "pretty output handles multibyte text"
+
+    output=$($THREADS_BIN search caching --in body -C 1 --max-matches 1 -f pretty 2>/dev/null)
+    assert_contains "$output" "more (see --max-matches)" "hidden hits are counted"
+
+    output=$($THREADS_BIN search caching -f plain 2>/dev/null)
+    assert_contains "$output" "SCORE | ID" "without --context the table is unchanged"
+
+    teardown_test_workspace
+    end_test
+}
+
 test_search_finds_body_content
 test_search_excludes_resolved_by_default
 test_search_ranks_by_closeness
 test_search_respects_direction_flags
 test_search_hints_about_closed_metadata_matches
+test_search_in_scope
+test_search_regex
+test_search_context
@@ -89,8 +89,189 @@ test_stats_specific_path() {
     end_test
 }
 
+# Test: stats --machine prints only status<TAB>count lines
+test_stats_machine() {
+    begin_test "stats --machine prints tab-separated counts"
+    setup_nested_workspace
+
+    create_thread "aaa001" "Active Thread" "active"
+    create_thread "aaa002" "Another Active" "active"
+    create_thread "ccc001" "Resolved Thread" "resolved"
+    create_thread_at_category "bbb001" "Category Thread" "cat1" "blocked"
+
+    local output
+    output=$(capture_stdout $THREADS_BIN stats --machine)
+
+    assert_eq "$(printf 'active\t2')" "$output" "only open root-level counts, no header"
+
+    output=$(capture_stdout $THREADS_BIN stats --porcelain -c --down)
+    assert_contains "$output" "$(printf 'resolved\t1')" "respects --include-closed"
+    assert_contains "$output" "$(printf 'blocked\t1')" "respects --down"
+    assert_not_contains "$output" "Total" "no total line"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: JSON/YAML stats share the {generated_at, scope, filters, data} envelope
+test_stats_envelope() {
+    begin_test "stats --json/--yaml use a common envelope"
+    setup_nested_workspace
+
+    create_thread "aaa001" "Active Thread" "active"
+    create_thread "ccc001" "Resolved Thread" "resolved"
+    create_thread_at_category "bbb001" "Category Thread" "cat1" "blocked"
+
+    local output
+    output=$(capture_stdout $THREADS_BIN stats --json)
+    assert_json_valid "$output" "stats JSON should be valid"
+    assert_eq "generated_at,scope,filters,data" "$(echo "$output" | jq -r 'keys_unsorted | join(",")')" "envelope fields in order"
+    assert_matches '^[0-9]{4}-[0-9]{2}-[0-9]{2} [0-9]{2}:[0-9]{2}:[0-9]{2}$' "$(echo "$output" | jq -r '.generated_at')" "generated_at uses the log timestamp format"
+    assert_eq "." "$(echo "$output" | jq -r '.scope.path')" "scope records the path"
+    assert_eq "false" "$(echo "$output" | jq -r '.filters.include_closed')" "filters record include_closed"
+    assert_eq "null" "$(echo "$output" | jq -r '.filters.direction')" "no direction when local only"
+    assert_eq "1" "$(echo "$output" | jq -r '.data.total')" "data holds the total"
+    assert_eq "active" "$(echo "$output" | jq -r '.data.counts[0].status')" "data holds the counts"
+
+    output=$(capture_stdout $THREADS_BIN stats --json -c --down)
+    assert_eq "true" "$(echo "$output" | jq -r '.filters.include_closed')" "filters reflect --include-closed"
+    assert_eq "down" "$(echo "$output" | jq -r '.filters.direction')" "filters reflect --down"
+    assert_eq "3" "$(echo "$output" | jq -r '.data.total')" "total covers nested and closed threads"
+
+    output=$(capture_stdout $THREADS_BIN stats --yaml)
+    assert_contains "$output" "generated_at:" "YAML has generated_at"
+    assert_contains "$output" "scope:" "YAML has scope"
+    assert_contains "$output" "filters:" "YAML has filters"
+    assert_contains "$output" "data:" "YAML has data"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: stats --compare-ref shows per-status deltas against a git ref
+test_stats_compare_ref() {
+    begin_test "stats --compare-ref shows status deltas"
+    setup_nested_workspace
+
+    create_thread "abc001" "First" "active"
+    create_thread "abc002" "Second" "active"
+    create_thread "abc003" "Third" "blocked"
+    create_thread_at_category "cat001" "Category" "cat1" "active"
+    git add -A && git commit -q -m "base"
+    git tag base
+
+    sed -i 's/^status: active/status: resolved/' "$TEST_WS/.threads/abc001-first.md"
+    sed -i 's/^status: blocked/status: active/' "$TEST_WS/.threads/abc003-third.md"
+    create_thread "abc004" "Fourth" "idea"
+    git add -A && git commit -q -m "progress"
+
+    local output
+    output=$($THREADS_BIN stats --compare-ref base --include-closed -f plain 2>&1)
+    assert_contains "$output" "active: 2 → 2 (+0)" "active unchanged in count"
+    assert_contains "$output" "resolved: 0 → 1 (+1)" "resolved gained one"
+    assert_contains "$output" "blocked: 1 → 0 (-1)" "blocked lost one"
+    assert_contains "$output" "idea: 0 → 1 (+1)" "new thread counted"
+    assert_contains "$output" "Total: 3 → 4 (+1)" "total delta"
+    assert_contains "$output" "1 new, 0 removed since base" "new threads reported"
+
+    output=$($THREADS_BIN stats --compare-ref base --down --json 2>/dev/null)
+    assert_json_valid "$output"
+    assert_eq "base" "$(echo "$output" | jq -r '.data.ref')" "json names the ref"
+    assert_eq "3" "$(echo "$output" | jq '.data.counts[] | select(.status == "active") | .after')" "--down includes cat1"
+    assert_eq "4" "$(echo "$output" | jq '.data.total.before')" "open threads at base"
+
+    local code
+    code=$(get_exit_code $THREADS_BIN stats --compare-ref no-such-ref)
+    assert_eq "1" "$code" "unknown ref should fail"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: stats --stale buckets open threads by time since their last change
+test_stats_stale() {
+    begin_test "stats --stale buckets threads by age"
+    setup_test_workspace
+
+    create_thread "abc001" "Old Work" "active"
+    create_thread "abc002" "Done Work" "resolved"
+    git add -A
+    GIT_AUTHOR_DATE="2020-01-01T12:00:00" GIT_COMMITTER_DATE="2020-01-01T12:00:00" git commit -q -m "old"
+    create_thread "abc003" "Fresh Work" "active"
+    git add -A && git commit -q -m "fresh"
+
+    local output
+    output=$($THREADS_BIN stats --stale --json 2>/dev/null)
+    assert_json_valid "$output"
+    assert_eq "30" "$(echo "$output" | jq '.data.threshold_days')" "default threshold is 30 days"
+    assert_eq "1" "$(echo "$output" | jq '.data.stale')" "old open thread is stale"
+    assert_eq "2" "$(echo "$output" | jq '.data.total')" "closed thread excluded"
+    assert_eq "1,0,0,1" "$(echo "$output" | jq -r '[.data.buckets[].count] | join(",")')" "bucket counts"
+    assert_eq ">90d" "$(echo "$output" | jq -r '.data.buckets[3].bucket')" "bucket labels"
+
+    output=$($THREADS_BIN stats --stale -c --json 2>/dev/null)
+    assert_eq "2" "$(echo "$output" | jq '.data.stale')" "--include-closed counts closed threads"
+
+    output=$($THREADS_BIN stats --stale 10000 -f plain 2>/dev/null)
+    assert_contains "$output" "≤7d | 1" "plain bucket rows"
+    assert_contains "$output" "0 of 2 not modified in 10000+ days" "explicit threshold"
+
+    output=$($THREADS_BIN stats -f plain 2>/dev/null)
+    assert_contains "$output" "STATUS | COUNT" "status counts remain the default"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: stats --by groups along path, tag and priority
+test_stats_by() {
+    begin_test "stats --by groups by path, tag and priority"
+    setup_test_workspace
+
+    create_thread "aaa001" "Root One" "active"
+    create_thread "aaa002" "Root Two" "active"
+    create_thread "aaa003" "Root Closed" "resolved"
+    create_thread_at_category "bbb001" "Category Thread" "cat1" "blocked"
+    $THREADS_BIN tag aaa001 add backend >/dev/null 2>&1
+    $THREADS_BIN tag aaa001 add urgent >/dev/null 2>&1
+    $THREADS_BIN tag aaa002 add backend >/dev/null 2>&1
+    $THREADS_BIN update aaa001 --priority high >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN stats --by path --down --machine 2>/dev/null)
+    assert_eq "$(printf '.\t2\ncat1\t1')" "$output" "path groups by directory, closed excluded"
+
+    output=$($THREADS_BIN stats --by path --down -c --machine 2>/dev/null)
+    assert_contains "$output" "$(printf '.\t3')" "--include-closed is respected"
+
+    output=$($THREADS_BIN stats --by tag --json 2>/dev/null)
+    assert_eq "tag" "$(echo "$output" | jq -r '.data.by')" "json names the dimension"
+    assert_eq '{"key":"backend","count":2}' "$(echo "$output" | jq -c '.data.counts[0]')" "json emits key/count pairs, largest first"
+    assert_eq "1" "$(echo "$output" | jq '.data.counts[] | select(.key == "urgent") | .count')" "a thread counts once per tag"
+    assert_eq "2" "$(echo "$output" | jq '.data.total')" "total counts threads, not tags"
+
+    output=$($THREADS_BIN stats --by priority --format plain 2>/dev/null)
+    assert_contains "$output" "PRIORITY | COUNT" "plain header names the dimension"
+    assert_contains "$output" "high | 1" "priority groups"
+    assert_contains "$output" "(none) | 1" "threads without one count as (none)"
+
+    output=$($THREADS_BIN stats --by tag --format pretty 2>/dev/null)
+    assert_matches "│ TAG +│ COUNT │" "$output" "pretty table header names the dimension"
+    assert_matches "│ Total +│ 2 +│" "$output" "pretty table has a total row"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN stats --by tag --stale)" "--by conflicts with --stale"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_stats_shows_counts
 test_stats_empty_workspace
 test_stats_recursive
 test_stats_specific_path
+test_stats_machine
+test_stats_envelope
+test_stats_compare_ref
+test_stats_stale
+test_stats_by
@@ -0,0 +1,65 @@
+#!/usr/bin/env bash
+# Tests for 'threads tag' command and --tag filters
+
+# Test: tag add/rm/ls normalizes and stores tags in frontmatter
+test_tag_add_remove() {
+    begin_test "tag add, ls, and rm"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output
+    output=$($THREADS_BIN tag abc123 add Bug "Needs Review" 2>/dev/null)
+    assert_contains "$output" "Added tag: bug" "tag is lowercased"
+    assert_contains "$output" "Added tag: needs-review" "spaces become dashes"
+
+    output=$($THREADS_BIN tag abc123 add bug 2>/dev/null)
+    assert_contains "$output" "Already tagged: bug" "duplicate is not added twice"
+
+    output=$($THREADS_BIN tag abc123 ls 2>/dev/null)
+    assert_eq "$(printf '#bug\n#needs-review')" "$output" "ls lists tags"
+
+    local json
+    json=$($THREADS_BIN read abc123 --json 2>/dev/null)
+    assert_json_field "$json" ".tags | length" "2" "read JSON includes tags"
+
+    $THREADS_BIN tag abc123 rm BUG >/dev/null 2>&1
+    output=$($THREADS_BIN tag abc123 ls 2>/dev/null)
+    assert_eq "#needs-review" "$output" "rm removes the tag"
+
+    assert_eq "1" "$(get_exit_code $THREADS_BIN tag abc123 rm bug)" "removing a missing tag fails"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --tag on list and search keeps threads carrying all given tags
+test_tag_filters() {
+    begin_test "list and search --tag filter by all tags"
+    setup_test_workspace
+
+    create_thread "abc123" "Login Bug" "active"
+    create_thread "def456" "Login Page" "active"
+    $THREADS_BIN tag abc123 add bug ui >/dev/null 2>&1
+    $THREADS_BIN tag def456 add ui >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN list --tag ui --tsv 2>/dev/null)
+    assert_contains "$output" "abc123" "both carry ui"
+    assert_contains "$output" "def456" "both carry ui"
+
+    output=$($THREADS_BIN list --tag UI,bug --tsv 2>/dev/null)
+    assert_contains "$output" "abc123" "abc123 carries ui and bug"
+    assert_not_contains "$output" "def456" "def456 lacks bug"
+
+    output=$($THREADS_BIN search login --tag bug --json 2>/dev/null)
+    assert_json_field "$output" ".matches | length" "1" "search keeps one thread"
+    assert_json_field "$output" ".matches[0].tags[0]" "bug" "search JSON includes tags"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_tag_add_remove
+test_tag_filters
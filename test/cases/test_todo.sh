@@ -123,9 +123,300 @@ test_todo_list_format() {
     end_test
 }
 
+# Test: todo promote moves an item into the body as a checklist entry
+test_todo_promote() {
+    begin_test "todo promote moves item into body"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output hash
+    output=$($THREADS_BIN todo abc123 add "Write migration guide" 2>/dev/null)
+    hash=$(extract_hash_from_output "$output")
+
+    $THREADS_BIN todo abc123 promote "$hash" --move-to-body "Follow-up" >/dev/null 2>&1
+
+    local todo_output
+    todo_output=$($THREADS_BIN todo abc123 list 2>/dev/null)
+    assert_not_contains "$todo_output" "Write migration guide" "todo should be gone from frontmatter"
+
+    local body
+    body=$(sed '1,/^---$/d' "$(get_thread_path abc123)")
+    assert_contains "$body" "## Follow-up" "body should gain the heading"
+    assert_contains "$body" "- [ ] Write migration guide" "body should contain the item"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: todo list --sort orders the view without touching the file
+test_todo_list_sort() {
+    begin_test "todo list --sort done|due|text"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output hash
+    # Items are prepended, so file order is: charlie, bravo, alpha
+    output=$($THREADS_BIN todo abc123 add "alpha" 2>/dev/null)
+    hash=$(extract_hash_from_output "$output")
+    $THREADS_BIN todo abc123 check "$hash" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "bravo" --due 2026-05-01 >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "charlie" >/dev/null 2>&1
+
+    local before order
+    before=$(cat "$(get_thread_path abc123)")
+
+    order=$($THREADS_BIN todo abc123 list --sort text --json 2>/dev/null | jq -r '[.[].text] | join(",")')
+    assert_eq "alpha,bravo,charlie" "$order" "text sort should be alphabetical"
+
+    order=$($THREADS_BIN todo abc123 list --sort done --json 2>/dev/null | jq -r '[.[].text] | join(",")')
+    assert_eq "charlie,bravo,alpha" "$order" "done sort should put done items last"
+
+    order=$($THREADS_BIN todo abc123 list --sort due --json 2>/dev/null | jq -r '[.[].text] | join(",")')
+    assert_eq "bravo,charlie,alpha" "$order" "due sort should put dated items first"
+
+    assert_eq "$before" "$(cat "$(get_thread_path abc123)")" "display sort should not modify the file"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --reorder-persist writes the sorted order into the file
+test_todo_reorder_persist() {
+    begin_test "todo list --sort --reorder-persist rewrites order"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    $THREADS_BIN todo abc123 add "alpha" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "bravo" >/dev/null 2>&1
+
+    local output order
+    output=$($THREADS_BIN todo abc123 list --sort text --reorder-persist 2>&1)
+    assert_contains "$output" "Reordered 2 todo items" "should report reorder"
+
+    order=$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r '[.[].text] | join(",")')
+    assert_eq "alpha,bravo" "$order" "file order should be persisted"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 list --reorder-persist)
+    assert_eq "1" "$exit_code" "--reorder-persist should require --sort"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: subtasks via --under, nesting guards, and roll-up progress
+test_todo_subtasks() {
+    begin_test "todo add --under nests subtasks"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output parent child grandchild
+    output=$($THREADS_BIN todo abc123 add "Release" 2>/dev/null)
+    parent=$(extract_hash_from_output "$output")
+    output=$($THREADS_BIN todo abc123 add "Write notes" --under "$parent" 2>/dev/null)
+    child=$(extract_hash_from_output "$output")
+    output=$($THREADS_BIN todo abc123 add "Collect PRs" --under "$child" 2>/dev/null)
+    grandchild=$(extract_hash_from_output "$output")
+
+    local parent_field
+    parent_field=$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r --arg h "$child" '.[] | select(.hash == $h) | .parent')
+    assert_eq "$parent" "$parent_field" "subtask should record its parent hash"
+
+    output=$($THREADS_BIN todo abc123 list 2>/dev/null)
+    assert_eq "[ ] Release ($parent)" "$(echo "$output" | sed -n 1p)" "parent should be listed first"
+    assert_eq "  [ ] Write notes ($child)" "$(echo "$output" | sed -n 2p)" "subtask should be indented"
+    assert_eq "    [ ] Collect PRs ($grandchild)" "$(echo "$output" | sed -n 3p)" "nested subtask should be indented twice"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 nest "$parent" --under "$grandchild")
+    assert_eq "1" "$exit_code" "nesting under a descendant should fail"
+    output=$($THREADS_BIN todo abc123 nest "$parent" --under "$grandchild" 2>&1)
+    assert_contains "$output" "its own ancestor" "should explain the cycle"
+
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 add "Orphan" --under zzzz)
+    assert_eq "1" "$exit_code" "unknown parent should fail"
+
+    # Parent checked, one subtask still open
+    $THREADS_BIN todo abc123 check "$parent" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 check "$grandchild" >/dev/null 2>&1
+    output=$($THREADS_BIN read abc123 -f pretty --stat --width 100 2>/dev/null)
+    assert_contains "$output" "2/3 todos done" "without roll-up each checkbox counts"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+display:
+  todo_rollup: true
+EOF
+    output=$($THREADS_BIN read abc123 -f pretty --stat --width 100 2>/dev/null)
+    assert_contains "$output" "3/3 todos done" "checked leaf rolls up through its ancestors"
+    $THREADS_BIN todo abc123 uncheck "$grandchild" >/dev/null 2>&1
+    output=$($THREADS_BIN read abc123 -f pretty --stat --width 100 2>/dev/null)
+    assert_contains "$output" "0/3 todos done" "open leaf keeps its ancestors open"
+
+    output=$($THREADS_BIN todo abc123 nest "$grandchild" 2>&1)
+    assert_contains "$output" "top level" "nest without --under should un-nest"
+    parent_field=$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r --arg h "$grandchild" '.[] | select(.hash == $h) | .parent')
+    assert_eq "null" "$parent_field" "un-nested item should drop its parent"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: todo check/remove --match resolve items by text
+test_todo_match_by_text() {
+    begin_test "todo --match resolves items by text"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+    $THREADS_BIN todo abc123 add "Write release notes" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "Write tests" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "Tag the release" >/dev/null 2>&1
+
+    local output code
+    output=$($THREADS_BIN todo abc123 check --match "TAG THE" 2>&1)
+    assert_contains "$output" "Checked item" "unique match checks the item"
+    assert_eq "true" "$($THREADS_BIN read abc123 --json | jq -r '.todo[] | select(.text == "Tag the release") | .done')" "item checked"
+
+    output=$($THREADS_BIN todo abc123 check --match "deploy" 2>&1)
+    code=$?
+    assert_eq "1" "$code" "no match should fail"
+    assert_contains "$output" "no unchecked item matches 'deploy'" "no-match message"
+
+    output=$($THREADS_BIN todo abc123 check --match "write" 2>&1)
+    code=$?
+    assert_eq "1" "$code" "ambiguous match should fail"
+    assert_contains "$output" "matches 2 unchecked items" "ambiguous message"
+    assert_contains "$output" "Write release notes" "lists the first candidate"
+    assert_contains "$output" "Write tests" "lists the second candidate"
+
+    output=$($THREADS_BIN todo abc123 remove --match "tests" 2>&1)
+    assert_contains "$output" "Removed item" "remove by match"
+    assert_eq "2" "$($THREADS_BIN read abc123 --json | jq '.todo | length')" "one item removed"
+
+    output=$($THREADS_BIN todo abc123 add --match "x" 2>&1)
+    assert_contains "$output" "--match applies to" "add rejects --match"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move repositions an item by hash and keeps every hash
+test_todo_move() {
+    begin_test "todo move repositions items"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output a b c
+    output=$($THREADS_BIN todo abc123 add "charlie" 2>/dev/null)
+    c=$(extract_hash_from_output "$output")
+    output=$($THREADS_BIN todo abc123 add "bravo" 2>/dev/null)
+    b=$(extract_hash_from_output "$output")
+    output=$($THREADS_BIN todo abc123 add "alpha" 2>/dev/null)
+    a=$(extract_hash_from_output "$output")
+
+    output=$($THREADS_BIN todo abc123 move "$a" --bottom 2>/dev/null)
+    assert_contains "$output" "Moved item $a to the bottom" "move reported"
+    assert_eq "bravo,charlie,alpha" "$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r 'map(.text) | join(",")')" "--bottom"
+    $THREADS_BIN todo abc123 move "${a:0:3}" --before "$c" >/dev/null 2>&1
+    assert_eq "bravo,alpha,charlie" "$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r 'map(.text) | join(",")')" "--before with a hash prefix"
+    $THREADS_BIN todo abc123 move "$b" --after "$c" >/dev/null 2>&1
+    assert_eq "alpha,charlie,bravo" "$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r 'map(.text) | join(",")')" "--after"
+    $THREADS_BIN todo abc123 move --match brav --top >/dev/null 2>&1
+    assert_eq "bravo,alpha,charlie" "$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r 'map(.text) | join(",")')" "--top with --match"
+    assert_eq "$b,$a,$c" "$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r 'map(.hash) | join(",")')" "hashes preserved"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 move "$a")
+    assert_eq "1" "$exit_code" "a placement is required"
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 move "$a" --before zzzz)
+    assert_eq "1" "$exit_code" "unknown anchor rejected"
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 move "$a" --top --bottom)
+    assert_eq "1" "$exit_code" "placements conflict"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: move refuses ambiguous hash prefixes
+test_todo_move_ambiguous() {
+    begin_test "todo move rejects ambiguous hashes"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-test.md" << 'EOF'
+---
+id: abc123
+name: Test
+status: active
+todo:
+  - text: first
+    hash: ab12
+    done: false
+  - text: second
+    hash: ab34
+    done: false
+  - text: third
+    hash: cd56
+    done: false
+---
+EOF
+
+    local output
+    output=$($THREADS_BIN todo abc123 move cd56 --after ab 2>&1)
+    assert_contains "$output" "ambiguous hash 'ab' matches 2 items" "ambiguous anchor"
+    output=$($THREADS_BIN todo abc123 move ab --top 2>&1)
+    assert_contains "$output" "ambiguous hash 'ab' matches 2 items" "ambiguous item"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: sort --done-last pushes completed items to the end, keeping relative order
+test_todo_sort_done_last() {
+    begin_test "todo sort --done-last"
+    setup_test_workspace
+
+    create_thread "abc123" "Test Thread" "active"
+
+    local output first third
+    output=$($THREADS_BIN todo abc123 add "third" 2>/dev/null)
+    third=$(extract_hash_from_output "$output")
+    $THREADS_BIN todo abc123 add "second" >/dev/null 2>&1
+    output=$($THREADS_BIN todo abc123 add "first" 2>/dev/null)
+    first=$(extract_hash_from_output "$output")
+    $THREADS_BIN todo abc123 check "$first" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 check "$third" >/dev/null 2>&1
+
+    output=$($THREADS_BIN todo abc123 sort --done-last 2>/dev/null)
+    assert_contains "$output" "Moved 2 item(s) to put done items last" "sort reports items that changed position"
+    assert_eq "second,first,third" "$($THREADS_BIN todo abc123 list --json 2>/dev/null | jq -r 'map(.text) | join(",")')" "done items last, stable"
+
+    output=$($THREADS_BIN todo abc123 sort --done-last 2>/dev/null)
+    assert_contains "$output" "Moved 0 item(s)" "already sorted list moves nothing"
+
+    local exit_code
+    exit_code=$(get_exit_code $THREADS_BIN todo abc123 sort)
+    assert_eq "1" "$exit_code" "sort needs --done-last"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_todo_add
 test_todo_check
 test_todo_uncheck
 test_todo_remove
 test_todo_list_format
+test_todo_promote
+test_todo_list_sort
+test_todo_reorder_persist
+test_todo_subtasks
+test_todo_match_by_text
+test_todo_move
+test_todo_move_ambiguous
+test_todo_sort_done_last
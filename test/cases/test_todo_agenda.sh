@@ -170,6 +170,79 @@ test_todo_single_thread_unaffected() {
     end_test
 }
 
+# Test: --due-soon shows only open todos due within the window, sorted by due date
+test_todo_agenda_due_soon() {
+    begin_test "todo agenda: --due-soon filters and sorts by due date"
+    setup_test_workspace
+
+    create_thread "abc123" "Due Thread" "active"
+    create_thread "def456" "Other Thread" "active"
+
+    local soon later overdue
+    soon=$(date -d "+2 days" +%Y-%m-%d)
+    later=$(date -d "+30 days" +%Y-%m-%d)
+    overdue=$(date -d "-1 days" +%Y-%m-%d)
+
+    $THREADS_BIN todo abc123 add "Soon task" --due "$soon" >/dev/null 2>&1
+    $THREADS_BIN todo abc123 add "Later task" --due "$later" >/dev/null 2>&1
+    $THREADS_BIN todo def456 add "Overdue task" --due "$overdue" >/dev/null 2>&1
+    $THREADS_BIN todo def456 add "Undated task" >/dev/null 2>&1
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN todo --due-soon --within 3d --json 2>/dev/null)
+
+    assert_json_valid "$output" "output should be valid JSON"
+    assert_json_field "$output" "length" "2" "only soon and overdue todos"
+    assert_json_field "$output" ".[0].text" "Overdue task" "overdue todo sorts first"
+    assert_json_field "$output" ".[0].overdue" "true" "overdue flag set"
+    assert_json_field "$output" ".[0].thread_id" "def456" "thread id present"
+    assert_json_field "$output" ".[1].due" "$soon" "due date present"
+    assert_json_field "$output" ".[1].overdue" "false" "soon todo not overdue"
+
+    local wide
+    wide=$(cd "$TEST_WS" && $THREADS_BIN todo --due-soon --within 5w 2>/dev/null)
+    assert_contains "$wide" "Later task" "wider window includes later todo"
+    assert_not_contains "$wide" "Undated task" "undated todos never due soon"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --due rejects malformed dates
+test_todo_add_due_invalid() {
+    begin_test "todo add: --due rejects invalid date"
+    setup_test_workspace
+
+    create_thread "abc123" "Due Thread" "active"
+
+    local output
+    output=$($THREADS_BIN todo abc123 add "Bad date" --due "tomorrow" 2>&1)
+
+    assert_contains "$output" "invalid due date" "should reject non-ISO date"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --within rejects windows that overflow the calendar instead of panicking
+test_todo_agenda_within_overflow() {
+    begin_test "todo agenda: --within overflow is an error"
+    setup_test_workspace
+
+    create_thread "abc123" "Due Thread" "active"
+
+    local output exit_code
+    output=$(cd "$TEST_WS" && $THREADS_BIN todo --due-soon --within 999999999d 2>&1)
+    exit_code=$?
+
+    assert_eq "1" "$exit_code" "should exit 1"
+    assert_contains "$output" "invalid date" "should report invalid window"
+    assert_not_contains "$output" "panicked" "should not panic"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_todo_agenda_empty
 test_todo_agenda_open_todo
@@ -179,3 +252,6 @@ test_todo_agenda_down
 test_todo_agenda_json
 test_todo_agenda_multiple_threads
 test_todo_single_thread_unaffected
+test_todo_agenda_due_soon
+test_todo_add_due_invalid
+test_todo_agenda_within_overflow
@@ -0,0 +1,71 @@
+#!/usr/bin/env bash
+# Tests for 'threads tree' and update --parent
+
+# Test: update --parent builds a hierarchy shown by tree
+test_tree_parent_child() {
+    begin_test "tree shows children under their parent"
+    setup_test_workspace
+
+    create_thread "abc123" "Epic" "active"
+    create_thread "def456" "Story" "active"
+    create_thread "fed789" "Task" "active"
+
+    local output
+    output=$($THREADS_BIN update def456 --parent abc123 2>/dev/null)
+    assert_contains "$output" "Updated parent: abc123" "parent set"
+    $THREADS_BIN update fed789 --parent def456 >/dev/null 2>&1
+
+    output=$($THREADS_BIN tree -f plain 2>/dev/null)
+    assert_eq "$(printf 'abc123 [active] Epic\n└── def456 [active] Story\n    └── fed789 [active] Task')" "$output" "indented tree"
+
+    output=$($THREADS_BIN tree def456 --json 2>/dev/null)
+    assert_json_field "$output" ".[0].id" "def456" "subtree root"
+    assert_json_field "$output" ".[0].children[0].id" "fed789" "nested children"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a parent that would close a cycle is refused
+test_tree_rejects_cycle() {
+    begin_test "update --parent rejects cycles"
+    setup_test_workspace
+
+    create_thread "abc123" "Epic" "active"
+    create_thread "def456" "Story" "active"
+    $THREADS_BIN update def456 --parent abc123 >/dev/null 2>&1
+
+    local output
+    output=$($THREADS_BIN update abc123 --parent def456 2>&1)
+    assert_contains "$output" "would create a cycle: abc123 → def456 → abc123" "names the cycle"
+    assert_not_contains "$(cat "$(get_thread_path abc123)")" "parent:" "nothing written"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: a missing parent is W012 and the thread shows at the root
+test_tree_missing_parent() {
+    begin_test "missing parent is W012 and shown at the root"
+    setup_test_workspace
+
+    create_thread "abc123" "Epic" "active"
+    create_thread "def456" "Story" "active"
+    $THREADS_BIN update def456 --parent abc123 >/dev/null 2>&1
+    rm "$(get_thread_path abc123)"
+
+    local output
+    output=$($THREADS_BIN tree -f plain 2>/dev/null)
+    assert_eq "def456 [active] Story (parent abc123 missing)" "$output" "orphan at the root"
+
+    output=$($THREADS_BIN validate 2>&1)
+    assert_contains "$output" "W012" "missing parent reported"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Run all tests
+test_tree_parent_child
+test_tree_rejects_cycle
+test_tree_missing_parent
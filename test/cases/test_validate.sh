@@ -133,6 +133,627 @@ test_validate_error_count_accuracy() {
     end_test
 }
 
+# Test: unknown frontmatter keys are reported as W019 warnings
+test_validate_unknown_key() {
+    begin_test "validate warns about unknown frontmatter keys"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-typo.md" << 'EOF'
+---
+id: abc123
+name: Typo Thread
+desc: Has a typo
+status: active
+statuss: blocked
+---
+EOF
+
+    local output exit_code
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    exit_code=$(get_exit_code $THREADS_BIN validate)
+
+    assert_eq "0" "$exit_code" "warnings should not fail validation"
+    assert_json_field "$output" ".files[0].issues[0].code" "W019" "should report W019"
+    assert_json_field "$output" ".files[0].issues[0].line" "6" "should point at the key's line"
+    assert_contains "$output" "statuss" "should name the unknown key"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W020 flags open stub threads, honours behavior.flag_empty_threads
+test_validate_empty_thread() {
+    begin_test "validate warns about empty open threads"
+    setup_test_workspace
+
+    (cd "$TEST_WS" && $THREADS_BIN new "Stub" >/dev/null 2>&1)
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_json_field "$output" ".files[0].issues[0].code" "W020" "should report W020"
+    assert_contains "$output" "add content or remove it" "should suggest a fix"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+behavior:
+  flag_empty_threads: false
+EOF
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W020" "config should disable the check"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: CRLF and BOM files parse, with W021 suggesting normalization
+test_validate_crlf_bom() {
+    begin_test "validate accepts CRLF/BOM files and warns W021"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    printf -- '---\r\nid: abc123\r\nname: Crlf\r\nstatus: active\r\n---\r\n\r\nBody.\r\n' \
+        > "$TEST_WS/.threads/abc123-crlf.md"
+    printf -- '\xef\xbb\xbf---\nid: def456\nname: Bom\nstatus: active\n---\n\nBody.\n' \
+        > "$TEST_WS/.threads/def456-bom.md"
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "E001" "should not report missing frontmatter"
+    assert_contains "$output" "CRLF line endings" "should flag CRLF"
+    assert_contains "$output" "UTF-8 BOM" "should flag BOM"
+
+    assert_eq "Crlf" "$($THREADS_BIN read abc123 --json 2>/dev/null | jq -r '.name')" "CRLF file should parse"
+    assert_eq "Bom" "$($THREADS_BIN read def456 --json 2>/dev/null | jq -r '.name')" "BOM file should parse"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W022 flags a filename that drifted from the name, fix --w022 renames it
+test_validate_name_mismatch() {
+    begin_test "validate warns W022 on name/filename mismatch and fixes it"
+    setup_test_workspace
+
+    create_thread "abc123" "Old Name" "active"
+    $THREADS_BIN update abc123 --title "New Name" >/dev/null 2>&1
+    git add . && git commit -q -m "add thread"
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_contains "$output" "W022" "should flag name mismatch"
+    assert_contains "$output" "new-name" "should suggest the expected slug"
+    assert_contains "$output" "threads rename abc123" "should point at rename"
+
+    $THREADS_BIN validate fix --w022 --dry-run >/dev/null 2>&1
+    assert_file_exists "$TEST_WS/.threads/abc123-old-name.md" "dry run should not rename"
+
+    output=$($THREADS_BIN validate fix --w022 2>&1)
+    assert_contains "$output" "Renamed" "should report rename"
+    assert_file_exists "$TEST_WS/.threads/abc123-new-name.md" "file should be renamed"
+    assert_contains "$(git status --porcelain)" "R" "clean file should move with git mv"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W022" "mismatch should be resolved"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W023 flags open threads sharing a name (case-insensitive)
+test_validate_duplicate_names() {
+    begin_test "validate warns W023 on duplicate thread names"
+    setup_nested_workspace
+
+    create_thread "abc123" "Same Name" "active"
+    create_thread "def456" "same name" "idea"
+    create_thread "aaa111" "Same Name" "active" "" "$TEST_WS/cat1"
+    create_thread "bbb222" "Closed Twin" "resolved"
+    create_thread "ccc333" "Closed Twin" "active"
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --all --json 2>/dev/null)
+    assert_contains "$output" "W023" "should flag duplicate names"
+    assert_contains "$output" "abc123" "should name the colliding id"
+    assert_eq "1" "$(echo "$output" | grep -o 'W023' | wc -l | tr -d ' ')" "only the same-directory pair should collide"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --all --global-names --json 2>/dev/null)
+    assert_eq "2" "$(echo "$output" | grep -o 'W023' | wc -l | tr -d ' ')" "--global-names should include other directories"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --all --include-closed --json 2>/dev/null)
+    assert_contains "$output" "Closed Twin" "closed threads collide with --include-closed"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: validate fix re-checks fixed content in the same pass, matching fix-then-validate
+test_validate_fix_single_pass() {
+    begin_test "validate fix reports remaining issues like a follow-up validate"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-old-name.md" << 'EOF'
+---
+id: abc123
+name: Quoting: broken
+status: active
+---
+
+Body.
+EOF
+    cat > "$TEST_WS/.threads/def456-odd.md" << 'EOF'
+---
+id: def456
+name: Odd
+status: wibble
+---
+
+Body.
+EOF
+    cp -r "$TEST_WS/.threads" "$TEST_WS/threads.orig"
+
+    local combined combined_files sequential sequential_files
+    combined=$($THREADS_BIN validate fix --e002 --w022 --json 2>/dev/null \
+        | jq -c '[.remaining.files[] | {path, codes: [.issues[].code]}]')
+    combined_files=$(cat "$TEST_WS"/.threads/*.md)
+
+    rm -rf "$TEST_WS/.threads"
+    cp -r "$TEST_WS/threads.orig" "$TEST_WS/.threads"
+
+    $THREADS_BIN validate fix --e002 --w022 >/dev/null 2>&1
+    sequential=$($THREADS_BIN validate --json 2>/dev/null \
+        | jq -c '[.files[] | {path, codes: [.issues[].code]}]')
+    sequential_files=$(cat "$TEST_WS"/.threads/*.md)
+
+    assert_eq "$sequential" "$combined" "remaining issues should match a separate validate run"
+    assert_eq "$sequential_files" "$combined_files" "fixed files should be identical"
+    assert_contains "$combined" "E006" "unfixable issues should be reported"
+    assert_contains "$combined" "quoting-broken" "renamed path should be reported"
+
+    local output
+    rm -rf "$TEST_WS/.threads"
+    cp -r "$TEST_WS/threads.orig" "$TEST_WS/.threads"
+    output=$($THREADS_BIN validate fix --e002 -f plain 2>/dev/null)
+    assert_contains "$output" "invalid status 'wibble'" "plain output should list remaining issues"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W024 flags an out-of-order log and fix --w024 re-sorts it
+test_validate_log_order() {
+    begin_test "validate warns W024 on out-of-order log and fixes it"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-test.md" << 'EOF'
+---
+id: abc123
+name: Test
+status: active
+log:
+- ts: 2026-01-01 08:00:00
+  text: Imported old entry
+- ts: 2026-02-01 08:00:00
+  text: Newer entry
+---
+
+Body.
+EOF
+
+    local output
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_contains "$output" "W024" "should flag out-of-order log"
+
+    $THREADS_BIN validate fix --w024 --dry-run >/dev/null 2>&1
+    assert_eq "Imported old entry" "$($THREADS_BIN read abc123 --json | jq -r '.log[0].text')" "dry run should not change the file"
+
+    output=$($THREADS_BIN validate fix --w024 2>&1)
+    assert_contains "$output" "1 logs re-sorted" "should report the fix"
+    assert_eq "Newer entry" "$($THREADS_BIN read abc123 --json | jq -r '.log[0].text')" "newest entry should come first"
+
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W024" "log order should be fixed"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W025 orphan hash comments in the body
+test_validate_orphan_hash_comments() {
+    begin_test "validate warns W025 on orphan hash comments and strips them"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-test.md" << 'EOF'
+---
+id: abc123
+name: Test
+status: active
+notes:
+- text: Kept note
+  hash: ab12
+---
+
+Body text. <!-- ab12 -->
+Leftover line <!-- cd34 -->
+<!-- ef56 -->
+EOF
+
+    local output
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_eq "2" "$(echo "$output" | jq '[.files[].issues[] | select(.code == "W025")] | length')" "should flag both orphans"
+    assert_eq "11" "$(echo "$output" | jq '[.files[].issues[] | select(.code == "W025")][0].line')" "should report the body line"
+
+    $THREADS_BIN validate fix --w025 --dry-run >/dev/null 2>&1
+    assert_contains "$(cat "$TEST_WS/.threads/abc123-test.md")" "<!-- cd34 -->" "dry run should not change the file"
+
+    output=$($THREADS_BIN validate fix --w025 2>&1)
+    assert_contains "$output" "2 orphan hash comments" "should report the fix"
+
+    local content
+    content=$(cat "$TEST_WS/.threads/abc123-test.md")
+    assert_contains "$content" "Body text. <!-- ab12 -->" "live item hash should stay"
+    assert_contains "$content" "Leftover line" "line text should stay"
+    assert_not_contains "$content" "cd34" "orphan comment should be removed"
+    assert_not_contains "$content" "ef56" "comment-only line should be removed"
+
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W025" "orphans should be fixed"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: validate.severity promotes, demotes, or silences issue codes
+test_validate_severity_overrides() {
+    begin_test "validate.severity config overrides issue severity"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-test.md" << 'EOF'
+---
+id: abc123
+name: Test
+status: active
+log:
+- ts: 2026-01-01 08:00:00
+  text: Imported old entry
+- ts: 2026-02-01 08:00:00
+  text: Newer entry
+---
+
+Body.
+EOF
+
+    local exit_code output
+    exit_code=$(get_exit_code $THREADS_BIN validate)
+    assert_eq "0" "$exit_code" "warnings alone should not fail"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+validate:
+  severity:
+    w024: error
+EOF
+    exit_code=$(get_exit_code $THREADS_BIN validate)
+    assert_eq "1" "$exit_code" "promoted warning should fail validation"
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_eq "error" "$(echo "$output" | jq -r '.files[0].issues[] | select(.code == "W024") | .severity')" "W024 reported as error"
+    assert_eq "1" "$(echo "$output" | jq '.errors')" "summary counts the promoted issue"
+
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+validate:
+  severity:
+    W024: off
+EOF
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W024" "silenced code should be dropped"
+    assert_eq "1" "$(echo "$output" | jq '.valid')" "file should count as valid"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: validate --summary-only prints just the counts
+test_validate_summary_only() {
+    begin_test "validate --summary-only prints counts"
+    setup_nested_workspace
+
+    create_thread "abc123" "Root Thread" "active"
+    create_thread_at_category "def456" "Cat Thread" "cat1" "active"
+    printf -- '---\nid: bad001\nname: Broken\n' > "$TEST_WS/cat1/.threads/bad001-broken.md"
+
+    local output exit_code
+    output=$($THREADS_BIN validate --summary-only 2>&1)
+    assert_matches "^total=1 valid=[01] errors=0 warnings=[0-9]+$" "$output" "root scope only"
+
+    output=$($THREADS_BIN validate --summary-only --all 2>&1)
+    assert_matches "^total=3 valid=[0-2] errors=[1-9][0-9]* warnings=[0-9]+$" "$output" "--all counts every thread"
+    exit_code=$(get_exit_code $THREADS_BIN validate --summary-only --all)
+    assert_eq "1" "$exit_code" "errors should fail"
+
+    output=$($THREADS_BIN validate cat1 --summary-only --json 2>/dev/null)
+    assert_json_valid "$output"
+    assert_eq "2" "$(echo "$output" | jq '.total')" "cat1 scope total"
+    assert_eq "null" "$(echo "$output" | jq '.files')" "no per-file detail"
+
+    output=$($THREADS_BIN validate --summary-only --down 2>&1)
+    assert_contains "$output" "total=3" "--down includes nested threads"
+
+    exit_code=$(get_exit_code $THREADS_BIN validate stats --summary-only)
+    assert_eq "1" "$exit_code" "stats should reject --summary-only"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W026 flags "status(reason)" and fix --w026 inserts the space
+test_validate_status_reason_spacing() {
+    begin_test "validate W026 status reason spacing"
+    setup_test_workspace
+
+    create_thread "abc123" "Test" "blocked(waiting on review)"
+
+    local output exit_code
+    output=$($THREADS_BIN validate -f plain 2>&1)
+    assert_contains "$output" "W026" "missing space is flagged"
+    assert_not_contains "$output" "E006" "base status is still recognized"
+
+    $THREADS_BIN validate fix --w026 >/dev/null 2>&1
+    assert_eq "blocked (waiting on review)" "$($THREADS_BIN read abc123 --json | jq -r '.status')" "space inserted"
+
+    output=$($THREADS_BIN validate -f plain 2>&1)
+    assert_not_contains "$output" "W026" "fixed file is clean"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: created/updated frontmatter timestamps, W013, and fix --w013
+test_validate_created_timestamp() {
+    begin_test "new records created; fix --w013 backfills it from git"
+    setup_test_workspace
+
+    (cd "$TEST_WS" && $THREADS_BIN new "Fresh" >/dev/null 2>&1)
+    assert_contains "$(cat "$TEST_WS"/.threads/*-fresh.md)" "created: " "new should record created"
+
+    cat > "$TEST_WS/.threads/abc123-legacy.md" << 'EOF'
+---
+id: abc123
+name: Legacy
+status: active
+---
+
+Body.
+EOF
+    (cd "$TEST_WS" && git add . && GIT_COMMITTER_DATE="2020-01-01T12:00:00+00:00" git commit -q -m "legacy")
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W013" "W013 should be off by default"
+
+    mkdir -p "$TEST_WS/.threads-config"
+    cat > "$TEST_WS/.threads-config/manifest.yaml" << 'EOF'
+behavior:
+  flag_missing_created: true
+EOF
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_contains "$output" "W013" "config should enable W013"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate fix --w013 2>&1)
+    assert_contains "$output" "1 created timestamps" "should backfill the committed thread only"
+    output=$(cd "$TEST_WS" && $THREADS_BIN info abc123 --json 2>/dev/null)
+    assert_json_field "$output" ".created" "2020-01-01T12:00:00+00:00" "created should match the first commit"
+    assert_contains "$(cat "$TEST_WS/.threads/abc123-legacy.md")" "updated: " "rewrite should bump updated"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN validate --json 2>/dev/null)
+    assert_not_contains "$output" "W013" "W013 should be fixed"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: uncommitted threads take created/updated from frontmatter, not file times
+test_created_updated_fallback() {
+    begin_test "list and info prefer frontmatter timestamps for uncommitted threads"
+    setup_test_workspace
+
+    cat > "$TEST_WS/.threads/abc123-copied.md" << 'EOF'
+---
+id: abc123
+name: Copied
+status: active
+created: 2020-03-04T05:06:07+00:00
+updated: 2021-03-04T05:06:07+00:00
+---
+
+Body.
+EOF
+
+    local output
+    output=$(cd "$TEST_WS" && $THREADS_BIN info abc123 --json 2>/dev/null)
+    assert_json_field "$output" ".created" "2020-03-04T05:06:07+00:00" "info created from frontmatter"
+    assert_json_field "$output" ".updated" "2021-03-04T05:06:07+00:00" "info updated from frontmatter"
+
+    output=$(cd "$TEST_WS" && $THREADS_BIN list --json 2>/dev/null)
+    assert_contains "$output" "2021-03-04" "list updated from frontmatter"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: --error-level and --max-warnings control the exit code
+test_validate_exit_policy() {
+    begin_test "validate --error-level and --max-warnings set the exit code"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-typo.md" << 'EOF'
+---
+id: abc123
+name: Typo
+desc: Has a typo
+status: active
+statuss: blocked
+---
+EOF
+
+    local output exit_code
+    output=$($THREADS_BIN validate --json 2>/dev/null)
+    assert_json_field "$output" ".exit_code" "0" "warnings pass by default"
+
+    exit_code=$(get_exit_code $THREADS_BIN validate --error-level warning)
+    assert_eq "1" "$exit_code" "--error-level warning fails on warnings"
+    output=$($THREADS_BIN validate --error-level warning --json 2>/dev/null)
+    assert_json_field "$output" ".exit_code" "1" "exit_code reported in JSON"
+    output=$($THREADS_BIN validate --summary-only --error-level warning --json 2>/dev/null)
+    assert_json_field "$output" ".exit_code" "1" "exit_code in summary counts"
+
+    local warnings
+    warnings=$($THREADS_BIN validate --json 2>/dev/null | jq '.warnings')
+    exit_code=$(get_exit_code $THREADS_BIN validate --max-warnings "$warnings")
+    assert_eq "0" "$exit_code" "at the limit passes"
+    exit_code=$(get_exit_code $THREADS_BIN validate --max-warnings $((warnings - 1)))
+    assert_eq "1" "$exit_code" "over the limit fails"
+    output=$($THREADS_BIN validate --max-warnings 0 2>&1 >/dev/null)
+    assert_contains "$output" "exceed --max-warnings 0" "reason on stderr"
+
+    exit_code=$(get_exit_code $THREADS_BIN validate stats --max-warnings 0)
+    assert_eq "1" "$exit_code" "rejected outside check"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: E010 flags duplicate item hashes, fix --e010 regenerates them
+test_validate_duplicate_hashes() {
+    begin_test "validate reports E010 on duplicate hashes and fixes them"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-dup.md" << 'EOF'
+---
+id: abc123
+name: Dup
+status: active
+todo:
+- text: First
+  hash: ab12
+  done: false
+- text: Second
+  hash: ab12
+  done: false
+---
+
+Body.
+EOF
+
+    local output exit_code
+    output=$($THREADS_BIN validate 2>&1)
+    exit_code=$(get_exit_code $THREADS_BIN validate)
+    assert_eq "1" "$exit_code" "duplicate hashes are errors"
+    assert_contains "$output" "E010" "should report E010"
+    assert_contains "$output" "duplicate todo hashes: ab12" "names the colliding hash"
+
+    output=$($THREADS_BIN validate fix --e010 2>&1)
+    assert_contains "$output" "1 duplicate hashes" "fix reports regenerated hashes"
+    output=$($THREADS_BIN todo abc123 --json 2>/dev/null)
+    assert_eq "2" "$(echo "$output" | jq '[.[].hash] | unique | length')" "hashes now distinct"
+    assert_json_field "$output" ".[0].hash" "ab12" "first item keeps its hash"
+    assert_not_contains "$($THREADS_BIN validate 2>&1)" "E010" "E010 resolved"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: W015 flags a legacy section hidden by frontmatter items, fix --w015 merges it
+test_validate_shadowed_section() {
+    begin_test "validate warns W015 on hidden legacy sections and merges them"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-mixed.md" << 'EOF'
+---
+id: abc123
+name: Mixed
+status: active
+notes:
+- text: In frontmatter
+  hash: ab12
+---
+
+Intro.
+
+## Notes
+
+- In frontmatter  <!-- ab12 -->
+- Only in section  <!-- cd34 -->
+EOF
+
+    local output
+    output=$($THREADS_BIN validate 2>&1)
+    assert_contains "$output" "W015" "should report W015"
+    assert_contains "$output" "1 items only in the section" "counts the hidden items"
+
+    $THREADS_BIN validate fix --w015 >/dev/null 2>&1
+    output=$($THREADS_BIN note abc123 --json 2>/dev/null)
+    assert_eq "In frontmatter,Only in section" "$(echo "$output" | jq -r 'map(.text) | join(",")')" "unique section items merged"
+    local content
+    content=$(cat "$TEST_WS/.threads/abc123-mixed.md")
+    assert_not_contains "$content" "## Notes" "legacy section stripped"
+    assert_contains "$content" "Intro." "other body content kept"
+    assert_not_contains "$($THREADS_BIN validate 2>&1)" "W015" "W015 resolved"
+
+    teardown_test_workspace
+    end_test
+}
+
+# Test: fix --w006 normalizes clear checkbox variants and reports unclear ones
+test_validate_fix_checkboxes() {
+    begin_test "validate fix --w006 rewrites malformed checkboxes"
+    setup_test_workspace
+
+    mkdir -p "$TEST_WS/.threads"
+    cat > "$TEST_WS/.threads/abc123-boxes.md" << 'EOF'
+---
+id: abc123
+name: Boxes
+status: active
+---
+
+## Todo
+
+- [ x] Spaced  <!-- ab12 -->
+- [done] Finished  <!-- cd34 -->
+- [o] Open circle  <!-- ef56 -->
+- [-] Dash  <!-- 0a1b -->
+EOF
+
+    create_thread "def456" "Migrated" "active"
+    $THREADS_BIN todo def456 add "Already canonical" >/dev/null 2>&1
+    local before
+    before=$(cat "$(get_thread_path def456)")
+
+    local output
+    output=$($THREADS_BIN validate fix --w006 --dry-run 2>&1)
+    assert_contains "$output" "+ - [x] Spaced  <!-- ab12 -->" "dry run previews the rewrite"
+    assert_contains "$(cat "$TEST_WS/.threads/abc123-boxes.md")" "- [ x] Spaced" "dry run leaves the file alone"
+
+    output=$($THREADS_BIN validate fix --w006 2>&1)
+    assert_contains "$output" "unclear checkbox '[o]'" "[o] reported"
+    assert_contains "$output" "unclear checkbox '[-]'" "[-] reported"
+    local content
+    content=$(cat "$TEST_WS/.threads/abc123-boxes.md")
+    assert_contains "$content" "- [x] Spaced  <!-- ab12 -->" "[ x] checked, hash kept"
+    assert_contains "$content" "- [x] Finished  <!-- cd34 -->" "[done] checked"
+    assert_contains "$content" "- [o] Open circle" "[o] untouched"
+    assert_contains "$content" "- [-] Dash" "[-] untouched"
+
+    assert_eq "$before" "$(cat "$(get_thread_path def456)")" "migrated file unchanged"
+    assert_contains "$output" "Fixed 2 checkboxes in 1 files" "only the legacy file counted"
+
+    teardown_test_workspace
+    end_test
+}
+
 # Run all tests
 test_validate_valid_thread
 test_validate_no_frontmatter
@@ -141,3 +762,20 @@ test_validate_missing_id
 test_validate_missing_name
 test_validate_recursive
 test_validate_error_count_accuracy
+test_validate_unknown_key
+test_validate_empty_thread
+test_validate_crlf_bom
+test_validate_name_mismatch
+test_validate_duplicate_names
+test_validate_fix_single_pass
+test_validate_log_order
+test_validate_orphan_hash_comments
+test_validate_severity_overrides
+test_validate_summary_only
+test_validate_status_reason_spacing
+test_validate_created_timestamp
+test_created_updated_fallback
+test_validate_exit_policy
+test_validate_duplicate_hashes
+test_validate_shadowed_section
+test_validate_fix_checkboxes